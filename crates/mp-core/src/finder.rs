@@ -1,14 +1,174 @@
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 use anyhow::Result;
-use ignore::WalkBuilder;
+use ignore::{WalkBuilder, WalkState};
 
-#[derive(Debug, Default, Clone, Copy)]
+/// How many entries the parallel walk visits between [`FinderProgress`]
+/// updates, balancing UI responsiveness against channel overhead.
+const PROGRESS_INTERVAL: usize = 50;
+
+/// A periodic snapshot of [`find_markdown_files_with_progress`]'s scan,
+/// sent so a caller can show a spinner or running count during a slow walk.
+#[derive(Debug, Clone)]
+pub struct FinderProgress {
+    pub entries_checked: usize,
+    pub current_path: PathBuf,
+}
+
+#[derive(Debug, Clone)]
 pub struct FinderConfig {
     pub hidden: bool,
     pub no_ignore: bool,
     pub no_ignore_parent: bool,
     pub no_global_ignore_file: bool,
+    /// Worker threads for the parallel walk, defaulting to the available
+    /// core count when `None`.
+    pub threads: Option<usize>,
+    /// Descend into symlinked directories instead of leaving them as leaves.
+    pub follow_symlinks: bool,
+    /// File extensions (without the leading dot) considered markdown,
+    /// matched case-insensitively. Applies to both the flat file list and
+    /// the tree builder, so the two always agree on what counts.
+    pub extensions: Vec<String>,
+}
+
+impl Default for FinderConfig {
+    fn default() -> Self {
+        Self {
+            hidden: false,
+            no_ignore: false,
+            no_ignore_parent: false,
+            no_global_ignore_file: false,
+            threads: None,
+            follow_symlinks: false,
+            extensions: vec!["md".to_string(), "markdown".to_string()],
+        }
+    }
+}
+
+/// Whether `path`'s extension matches one of `extensions`, case-insensitively.
+fn has_markdown_extension(path: &Path, extensions: &[String]) -> bool {
+    path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| {
+        extensions
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(ext))
+    })
+}
+
+/// Maximum number of symlink hops allowed while descending into a single
+/// directory chain before [`symlink_skip_reason`] bails out.
+const MAX_SYMLINK_JUMPS: usize = 20;
+
+/// Why a symlinked path was excluded from a walk with `follow_symlinks` on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymlinkSkipReason {
+    /// The symlink resolves back into one of its own ancestor directories.
+    Cycle,
+    /// Following the chain would exceed [`MAX_SYMLINK_JUMPS`].
+    TooManyJumps,
+}
+
+/// A symlinked path that was excluded from a walk to avoid an infinite
+/// traversal, paired with why it was excluded.
+#[derive(Debug, Clone)]
+pub struct SkippedSymlink {
+    pub path: PathBuf,
+    pub reason: SymlinkSkipReason,
+}
+
+/// Why a traversal entry couldn't be read, classified from the underlying
+/// [`ignore::Error`] so a caller can decide how urgently to warn about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinderErrorKind {
+    /// The OS denied access to the path.
+    PermissionDenied,
+    /// The path is a symlink whose target couldn't be resolved.
+    BrokenSymlink,
+    /// Any other I/O failure.
+    Io,
+}
+
+/// A traversal entry that failed to be read, paired with why, so a caller
+/// can warn that part of the tree couldn't be scanned rather than showing a
+/// silently incomplete listing.
+#[derive(Debug, Clone)]
+pub struct FinderError {
+    pub path: Option<PathBuf>,
+    pub kind: FinderErrorKind,
+    pub message: String,
+}
+
+/// Files found by a walk, alongside any entries that couldn't be read.
+#[derive(Debug, Clone)]
+pub struct FinderOutput {
+    pub files: Vec<PathBuf>,
+    pub errors: Vec<FinderError>,
+}
+
+/// Classifies a failed walk entry using the same permission/symlink signals
+/// [`symlink_skip_reason`] checks, so both functions agree on what counts
+/// as a broken symlink.
+fn classify_finder_error(err: &ignore::Error) -> FinderErrorKind {
+    if let Some(io_err) = err.io_error()
+        && io_err.kind() == std::io::ErrorKind::PermissionDenied
+    {
+        return FinderErrorKind::PermissionDenied;
+    }
+
+    let is_broken_symlink = err
+        .path()
+        .map(|path| {
+            path.symlink_metadata()
+                .map(|metadata| metadata.file_type().is_symlink())
+                .unwrap_or(false)
+        })
+        .unwrap_or(false);
+    if is_broken_symlink {
+        return FinderErrorKind::BrokenSymlink;
+    }
+
+    FinderErrorKind::Io
+}
+
+/// Checks whether descending into `path` would follow a symlink back into
+/// one of its own ancestors (a cycle) or exceed [`MAX_SYMLINK_JUMPS`] hops,
+/// walking from the root down so each symlink is checked against only the
+/// ancestors already resolved above it.
+fn symlink_skip_reason(path: &Path) -> Option<SymlinkSkipReason> {
+    let mut ancestors: Vec<&Path> = path.ancestors().collect();
+    ancestors.reverse();
+
+    let mut visited_canonical = Vec::new();
+    let mut jumps = 0usize;
+
+    for ancestor in ancestors {
+        let is_symlink = ancestor
+            .symlink_metadata()
+            .map(|metadata| metadata.file_type().is_symlink())
+            .unwrap_or(false);
+
+        if is_symlink {
+            jumps += 1;
+            if jumps > MAX_SYMLINK_JUMPS {
+                return Some(SymlinkSkipReason::TooManyJumps);
+            }
+            if let Ok(target) = ancestor.canonicalize()
+                && visited_canonical.contains(&target)
+            {
+                return Some(SymlinkSkipReason::Cycle);
+            }
+        }
+
+        if let Ok(canonical) = ancestor.canonicalize() {
+            visited_canonical.push(canonical);
+        }
+    }
+
+    None
 }
 
 #[derive(Debug, Clone)]
@@ -17,6 +177,24 @@ pub struct FileTreeNode {
     pub name: String,
     pub is_dir: bool,
     pub children: Vec<FileTreeNode>,
+    /// File size in bytes, captured from the walk's own metadata; `None` for
+    /// directories and for files whose metadata couldn't be read.
+    pub size: Option<u64>,
+    /// Last-modified time, captured from the walk's own metadata.
+    pub modified: Option<SystemTime>,
+}
+
+/// How [`sort_tree_children`] orders a directory's children; directories
+/// always sort ahead of files regardless of mode, matching file managers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TreeSortMode {
+    /// Alphabetical by name (current default behavior).
+    #[default]
+    Name,
+    /// Most recently modified first.
+    Modified,
+    /// Largest first.
+    Size,
 }
 
 pub fn find_markdown_files(config: FinderConfig) -> Result<Vec<PathBuf>> {
@@ -28,20 +206,188 @@ pub fn find_markdown_files_in_dir(dir: &str, config: FinderConfig) -> Result<Vec
 
     let mut builder = WalkBuilder::new(dir);
     configure_walker(&mut builder, &config);
-    let walker = builder.build();
-
-    let mut files: Vec<PathBuf> = walker
-        .filter_map(|result| result.ok())
-        .filter_map(|entry| {
-            let path = entry.path();
-            if path.is_file() && path.extension().is_some_and(|ext| ext == "md") {
-                Some(make_relative_path(path, base_path))
-            } else {
-                None
+    let walker = builder.build_parallel();
+
+    let found = Mutex::new(Vec::new());
+    walker.run(|| {
+        Box::new(|result| {
+            if let Ok(entry) = result {
+                let path = entry.path();
+                if config.follow_symlinks
+                    && entry.path_is_symlink()
+                    && symlink_skip_reason(path).is_some()
+                {
+                    return WalkState::Skip;
+                }
+                if path.is_file() && has_markdown_extension(path, &config.extensions) {
+                    found
+                        .lock()
+                        .unwrap()
+                        .push(make_relative_path(path, base_path));
+                }
+            }
+            WalkState::Continue
+        })
+    });
+
+    let mut files = found.into_inner().unwrap();
+    files.sort();
+    Ok(files)
+}
+
+/// Same as [`find_markdown_files_in_dir`], but when `config.follow_symlinks`
+/// is set, returns the files found alongside any [`SkippedSymlink`]s that
+/// were excluded to avoid an infinite traversal so the caller can warn
+/// about an incomplete listing.
+pub fn find_markdown_files_in_dir_with_skipped_symlinks(
+    dir: &str,
+    config: FinderConfig,
+) -> Result<(Vec<PathBuf>, Vec<SkippedSymlink>)> {
+    let base_path = Path::new(dir);
+
+    let mut builder = WalkBuilder::new(dir);
+    configure_walker(&mut builder, &config);
+    let walker = builder.build_parallel();
+
+    let found = Mutex::new(Vec::new());
+    let skipped = Mutex::new(Vec::new());
+    walker.run(|| {
+        Box::new(|result| {
+            if let Ok(entry) = result {
+                let path = entry.path();
+                if config.follow_symlinks && entry.path_is_symlink() {
+                    if let Some(reason) = symlink_skip_reason(path) {
+                        skipped.lock().unwrap().push(SkippedSymlink {
+                            path: path.to_path_buf(),
+                            reason,
+                        });
+                        return WalkState::Skip;
+                    }
+                }
+                if path.is_file() && has_markdown_extension(path, &config.extensions) {
+                    found
+                        .lock()
+                        .unwrap()
+                        .push(make_relative_path(path, base_path));
+                }
+            }
+            WalkState::Continue
+        })
+    });
+
+    let mut files = found.into_inner().unwrap();
+    files.sort();
+    Ok((files, skipped.into_inner().unwrap()))
+}
+
+/// Same as [`find_markdown_files_in_dir`], but collects permission-denied
+/// directories, broken symlinks, and other unreadable entries into
+/// [`FinderOutput::errors`] instead of silently dropping them, so the
+/// caller can warn that part of the tree couldn't be scanned.
+pub fn find_markdown_files_in_dir_with_errors(
+    dir: &str,
+    config: FinderConfig,
+) -> Result<FinderOutput> {
+    let base_path = Path::new(dir);
+
+    let mut builder = WalkBuilder::new(dir);
+    configure_walker(&mut builder, &config);
+    let walker = builder.build_parallel();
+
+    let found = Mutex::new(Vec::new());
+    let errors = Mutex::new(Vec::new());
+    walker.run(|| {
+        Box::new(|result| {
+            match result {
+                Ok(entry) => {
+                    let path = entry.path();
+                    if config.follow_symlinks
+                        && entry.path_is_symlink()
+                        && symlink_skip_reason(path).is_some()
+                    {
+                        return WalkState::Skip;
+                    }
+                    if path.is_file() && has_markdown_extension(path, &config.extensions) {
+                        found
+                            .lock()
+                            .unwrap()
+                            .push(make_relative_path(path, base_path));
+                    }
+                }
+                Err(err) => {
+                    errors.lock().unwrap().push(FinderError {
+                        path: err.path().map(Path::to_path_buf),
+                        kind: classify_finder_error(&err),
+                        message: err.to_string(),
+                    });
+                }
+            }
+            WalkState::Continue
+        })
+    });
+
+    let mut files = found.into_inner().unwrap();
+    files.sort();
+    Ok(FinderOutput {
+        files,
+        errors: errors.into_inner().unwrap(),
+    })
+}
+
+/// Same as [`find_markdown_files_in_dir`], but reports a [`FinderProgress`]
+/// every [`PROGRESS_INTERVAL`] entries visited and stops early once `cancel`
+/// is set, returning whatever files had already been found.
+pub fn find_markdown_files_with_progress(
+    dir: &str,
+    config: FinderConfig,
+    progress: Sender<FinderProgress>,
+    cancel: Arc<AtomicBool>,
+) -> Result<Vec<PathBuf>> {
+    let base_path = Path::new(dir);
+
+    let mut builder = WalkBuilder::new(dir);
+    configure_walker(&mut builder, &config);
+    let walker = builder.build_parallel();
+
+    let found = Mutex::new(Vec::new());
+    let entries_checked = AtomicUsize::new(0);
+    walker.run(|| {
+        let progress = progress.clone();
+        let cancel = Arc::clone(&cancel);
+        Box::new(|result| {
+            if cancel.load(Ordering::Relaxed) {
+                return WalkState::Quit;
+            }
+
+            if let Ok(entry) = result {
+                let path = entry.path();
+                if config.follow_symlinks
+                    && entry.path_is_symlink()
+                    && symlink_skip_reason(path).is_some()
+                {
+                    return WalkState::Skip;
+                }
+                if path.is_file() && has_markdown_extension(path, &config.extensions) {
+                    found
+                        .lock()
+                        .unwrap()
+                        .push(make_relative_path(path, base_path));
+                }
+
+                let checked = entries_checked.fetch_add(1, Ordering::Relaxed) + 1;
+                if checked % PROGRESS_INTERVAL == 0 {
+                    let _ = progress.send(FinderProgress {
+                        entries_checked: checked,
+                        current_path: path.to_path_buf(),
+                    });
+                }
             }
+
+            WalkState::Continue
         })
-        .collect();
+    });
 
+    let mut files = found.into_inner().unwrap();
     files.sort();
     Ok(files)
 }
@@ -51,6 +397,16 @@ pub fn build_markdown_tree(config: FinderConfig) -> Result<FileTreeNode> {
 }
 
 pub fn build_markdown_tree_in_dir(dir: &str, config: FinderConfig) -> Result<FileTreeNode> {
+    build_markdown_tree_in_dir_sorted(dir, config, TreeSortMode::Name)
+}
+
+/// Same as [`build_markdown_tree_in_dir`], but orders each directory's
+/// children with `sort_mode` instead of always sorting by name.
+pub fn build_markdown_tree_in_dir_sorted(
+    dir: &str,
+    config: FinderConfig,
+    sort_mode: TreeSortMode,
+) -> Result<FileTreeNode> {
     let base_path = Path::new(dir);
 
     let root_name = if dir == "." {
@@ -71,38 +427,53 @@ pub fn build_markdown_tree_in_dir(dir: &str, config: FinderConfig) -> Result<Fil
         name: root_name,
         is_dir: true,
         children: Vec::new(),
+        size: None,
+        modified: None,
     };
 
     // Create the walker with the same configuration as find_markdown_files
     let mut builder = WalkBuilder::new(dir);
     configure_walker(&mut builder, &config);
-    let walker = builder.build();
-
-    // Collect all paths first
-    let mut all_entries = Vec::new();
-    for entry in walker.flatten() {
-        let path = entry.path();
-        // Skip the root directory itself
-        if path == base_path {
-            continue;
-        }
-
-        // Check if it's a markdown file or a directory that might contain markdown files
-        if path.is_file() {
-            if path
-                .extension()
-                .is_some_and(|ext| ext == "md" || ext == "markdown")
-            {
-                all_entries.push(path.to_path_buf());
+    let walker = builder.build_parallel();
+
+    // Collect all paths first, merging each thread's local results at the end
+    let all_entries = Mutex::new(Vec::new());
+    walker.run(|| {
+        Box::new(|result| {
+            if let Ok(entry) = result {
+                let path = entry.path();
+                // Skip the root directory itself
+                if path == base_path {
+                    return WalkState::Continue;
+                }
+
+                if config.follow_symlinks
+                    && entry.path_is_symlink()
+                    && symlink_skip_reason(path).is_some()
+                {
+                    return WalkState::Skip;
+                }
+
+                // Check if it's a markdown file or a directory that might contain markdown files
+                let is_markdown_file =
+                    path.is_file() && has_markdown_extension(path, &config.extensions);
+                if is_markdown_file || path.is_dir() {
+                    // `ignore` already stats each entry while walking, so reuse
+                    // it here instead of a second `stat` call per path.
+                    all_entries.lock().unwrap().push(WalkedEntry {
+                        path: path.to_path_buf(),
+                        is_dir: path.is_dir(),
+                        metadata: entry.metadata().ok(),
+                    });
+                }
             }
-        } else if path.is_dir() {
-            // Add directories that might contain markdown files
-            all_entries.push(path.to_path_buf());
-        }
-    }
+            WalkState::Continue
+        })
+    });
+    let all_entries = all_entries.into_inner().unwrap();
 
     // Build the tree structure from the collected paths
-    build_tree_from_paths(&mut root, &all_entries, base_path)?;
+    build_tree_from_paths(&mut root, &all_entries, base_path, sort_mode)?;
 
     // Remove empty directories from the tree
     remove_empty_directories(&mut root);
@@ -110,11 +481,97 @@ pub fn build_markdown_tree_in_dir(dir: &str, config: FinderConfig) -> Result<Fil
     Ok(root)
 }
 
+/// Same as [`build_markdown_tree_in_dir`], but also collects permission-denied
+/// directories, broken symlinks, and other unreadable entries instead of
+/// silently dropping them, so the caller can warn that part of the tree
+/// couldn't be scanned.
+pub fn build_markdown_tree_in_dir_with_errors(
+    dir: &str,
+    config: FinderConfig,
+) -> Result<(FileTreeNode, Vec<FinderError>)> {
+    let base_path = Path::new(dir);
+
+    let root_name = if dir == "." {
+        std::env::current_dir()
+            .ok()
+            .and_then(|d| d.file_name().and_then(|n| n.to_str()).map(String::from))
+            .unwrap_or_else(|| ".".to_string())
+    } else {
+        base_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(dir)
+            .to_string()
+    };
+
+    let mut root = FileTreeNode {
+        path: base_path.to_path_buf(),
+        name: root_name,
+        is_dir: true,
+        children: Vec::new(),
+        size: None,
+        modified: None,
+    };
+
+    let mut builder = WalkBuilder::new(dir);
+    configure_walker(&mut builder, &config);
+    let walker = builder.build_parallel();
+
+    let all_entries = Mutex::new(Vec::new());
+    let errors = Mutex::new(Vec::new());
+    walker.run(|| {
+        Box::new(|result| {
+            match result {
+                Ok(entry) => {
+                    let path = entry.path();
+                    if path == base_path {
+                        return WalkState::Continue;
+                    }
+
+                    if config.follow_symlinks
+                        && entry.path_is_symlink()
+                        && symlink_skip_reason(path).is_some()
+                    {
+                        return WalkState::Skip;
+                    }
+
+                    let is_markdown_file =
+                        path.is_file() && has_markdown_extension(path, &config.extensions);
+                    if is_markdown_file || path.is_dir() {
+                        all_entries.lock().unwrap().push(WalkedEntry {
+                            path: path.to_path_buf(),
+                            is_dir: path.is_dir(),
+                            metadata: entry.metadata().ok(),
+                        });
+                    }
+                }
+                Err(err) => {
+                    errors.lock().unwrap().push(FinderError {
+                        path: err.path().map(Path::to_path_buf),
+                        kind: classify_finder_error(&err),
+                        message: err.to_string(),
+                    });
+                }
+            }
+            WalkState::Continue
+        })
+    });
+    let all_entries = all_entries.into_inner().unwrap();
+
+    build_tree_from_paths(&mut root, &all_entries, base_path, TreeSortMode::Name)?;
+    remove_empty_directories(&mut root);
+
+    Ok((root, errors.into_inner().unwrap()))
+}
+
 fn configure_walker(builder: &mut WalkBuilder, config: &FinderConfig) {
     builder
         .hidden(!config.hidden)
         .parents(!config.no_ignore_parent)
-        .add_custom_ignore_filename(".mpignore");
+        .add_custom_ignore_filename(".mpignore")
+        .follow_links(config.follow_symlinks)
+        // 0 tells `ignore` to pick a thread count from available parallelism.
+        .threads(config.threads.unwrap_or(0));
 
     if config.no_ignore {
         builder
@@ -131,20 +588,37 @@ fn configure_walker(builder: &mut WalkBuilder, config: &FinderConfig) {
     }
 }
 
-fn build_tree_from_paths(root: &mut FileTreeNode, paths: &[PathBuf], base: &Path) -> Result<()> {
-    for path in paths {
-        if let Ok(relative) = path.strip_prefix(base) {
-            insert_path_into_tree(root, relative, path)?;
+/// A path discovered while walking, carrying the metadata `ignore` already
+/// read so [`insert_path_into_tree`] doesn't need a second `stat` call.
+struct WalkedEntry {
+    path: PathBuf,
+    is_dir: bool,
+    metadata: Option<std::fs::Metadata>,
+}
+
+fn build_tree_from_paths(
+    root: &mut FileTreeNode,
+    entries: &[WalkedEntry],
+    base: &Path,
+    sort_mode: TreeSortMode,
+) -> Result<()> {
+    for entry in entries {
+        if let Ok(relative) = entry.path.strip_prefix(base) {
+            insert_path_into_tree(root, relative, entry)?;
         }
     }
 
     // Sort children at each level
-    sort_tree_children(root);
+    sort_tree_children(root, sort_mode);
 
     Ok(())
 }
 
-fn insert_path_into_tree(node: &mut FileTreeNode, relative: &Path, full_path: &Path) -> Result<()> {
+fn insert_path_into_tree(
+    node: &mut FileTreeNode,
+    relative: &Path,
+    entry: &WalkedEntry,
+) -> Result<()> {
     let components: Vec<_> = relative.components().collect();
 
     if components.is_empty() {
@@ -157,14 +631,29 @@ fn insert_path_into_tree(node: &mut FileTreeNode, relative: &Path, full_path: &P
     if components.len() == 1 {
         // This is a direct child
         let child = FileTreeNode {
-            path: full_path.to_path_buf(),
+            path: entry.path.clone(),
             name: first_str,
-            is_dir: full_path.is_dir(),
+            is_dir: entry.is_dir,
             children: Vec::new(),
+            size: entry
+                .metadata
+                .as_ref()
+                .filter(|_| !entry.is_dir)
+                .map(|metadata| metadata.len()),
+            modified: entry
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.modified().ok()),
         };
 
-        // Check if this child already exists (in case of directories)
-        if !node.children.iter().any(|c| c.name == child.name) {
+        // Check if this child already exists; a directory may have been
+        // auto-created while inserting one of its descendants before its
+        // own entry was visited, so backfill its metadata in that case.
+        if let Some(existing) = node.children.iter_mut().find(|c| c.name == child.name) {
+            if existing.is_dir && existing.modified.is_none() {
+                existing.modified = child.modified;
+            }
+        } else {
             node.children.push(child);
         }
     } else {
@@ -186,6 +675,8 @@ fn insert_path_into_tree(node: &mut FileTreeNode, relative: &Path, full_path: &P
                 name: dir_name,
                 is_dir: true,
                 children: Vec::new(),
+                size: None,
+                modified: None,
             };
             node.children.push(new_dir);
             node.children.last_mut().unwrap()
@@ -193,23 +684,27 @@ fn insert_path_into_tree(node: &mut FileTreeNode, relative: &Path, full_path: &P
 
         // Recurse with the remaining path
         let remaining: PathBuf = components[1..].iter().collect();
-        insert_path_into_tree(dir_node, &remaining, full_path)?;
+        insert_path_into_tree(dir_node, &remaining, entry)?;
     }
 
     Ok(())
 }
 
-fn sort_tree_children(node: &mut FileTreeNode) {
+fn sort_tree_children(node: &mut FileTreeNode, sort_mode: TreeSortMode) {
     node.children.sort_by(|a, b| match (a.is_dir, b.is_dir) {
         (true, false) => std::cmp::Ordering::Less,
         (false, true) => std::cmp::Ordering::Greater,
-        _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        _ => match sort_mode {
+            TreeSortMode::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            TreeSortMode::Modified => b.modified.cmp(&a.modified),
+            TreeSortMode::Size => b.size.cmp(&a.size),
+        },
     });
 
     // Recursively sort children
     for child in &mut node.children {
         if child.is_dir {
-            sort_tree_children(child);
+            sort_tree_children(child, sort_mode);
         }
     }
 }
@@ -376,6 +871,56 @@ mod tests {
         );
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_follow_symlinks_finds_files_through_symlinked_dir() {
+        use std::os::unix::fs::symlink;
+
+        let temp_dir = TempDir::new().unwrap();
+        let real_dir = temp_dir.path().join("real");
+        fs::create_dir(&real_dir).unwrap();
+        fs::write(real_dir.join("linked.md"), "").unwrap();
+        symlink(&real_dir, temp_dir.path().join("link")).unwrap();
+
+        let config = FinderConfig {
+            follow_symlinks: true,
+            ..Default::default()
+        };
+        let files = find_markdown_files_in_dir(temp_dir.path().to_str().unwrap(), config).unwrap();
+
+        let file_names: Vec<String> = files
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect();
+
+        assert!(file_names.iter().any(|f| f.ends_with("real/linked.md")));
+        assert!(file_names.iter().any(|f| f.ends_with("link/linked.md")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_follow_symlinks_reports_self_referential_cycle() {
+        use std::os::unix::fs::symlink;
+
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("README.md"), "").unwrap();
+        symlink(temp_dir.path(), temp_dir.path().join("loop")).unwrap();
+
+        let config = FinderConfig {
+            follow_symlinks: true,
+            ..Default::default()
+        };
+        let (files, skipped) = find_markdown_files_in_dir_with_skipped_symlinks(
+            temp_dir.path().to_str().unwrap(),
+            config,
+        )
+        .unwrap();
+
+        assert!(files.iter().any(|f| f.ends_with("README.md")));
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].reason, SymlinkSkipReason::Cycle);
+    }
+
     #[test]
     fn test_find_markdown_files_empty_dir() {
         let temp_dir = TempDir::new().unwrap();
@@ -407,6 +952,129 @@ mod tests {
         assert_eq!(file_names[2], "zebra.md");
     }
 
+    #[test]
+    fn test_capped_thread_count_still_sorted_and_complete() {
+        let temp_dir = TempDir::new().unwrap();
+
+        fs::write(temp_dir.path().join("zebra.md"), "").unwrap();
+        fs::write(temp_dir.path().join("apple.md"), "").unwrap();
+        fs::write(temp_dir.path().join("banana.md"), "").unwrap();
+
+        let config = FinderConfig {
+            threads: Some(1),
+            ..Default::default()
+        };
+        let files = find_markdown_files_in_dir(temp_dir.path().to_str().unwrap(), config).unwrap();
+
+        let file_names: Vec<String> = files
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+
+        assert_eq!(file_names, vec!["apple.md", "banana.md", "zebra.md"]);
+    }
+
+    #[test]
+    fn test_progress_reports_cover_every_entry_and_files_are_complete() {
+        let temp_dir = TempDir::new().unwrap();
+
+        for i in 0..(PROGRESS_INTERVAL * 2) {
+            fs::write(temp_dir.path().join(format!("file{i:03}.md")), "").unwrap();
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let config = FinderConfig {
+            threads: Some(1),
+            ..Default::default()
+        };
+        let files = find_markdown_files_with_progress(
+            temp_dir.path().to_str().unwrap(),
+            config,
+            tx,
+            cancel,
+        )
+        .unwrap();
+
+        assert_eq!(files.len(), PROGRESS_INTERVAL * 2);
+
+        let updates: Vec<FinderProgress> = rx.try_iter().collect();
+        assert!(!updates.is_empty());
+        assert!(
+            updates
+                .iter()
+                .all(|update| update.entries_checked % PROGRESS_INTERVAL == 0)
+        );
+    }
+
+    #[test]
+    fn test_progress_scan_stops_when_cancelled() {
+        let temp_dir = TempDir::new().unwrap();
+
+        for i in 0..(PROGRESS_INTERVAL * 4) {
+            fs::write(temp_dir.path().join(format!("file{i:03}.md")), "").unwrap();
+        }
+
+        let (tx, _rx) = std::sync::mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(true));
+        let config = FinderConfig {
+            threads: Some(1),
+            ..Default::default()
+        };
+        let files = find_markdown_files_with_progress(
+            temp_dir.path().to_str().unwrap(),
+            config,
+            tx,
+            cancel,
+        )
+        .unwrap();
+
+        assert!(files.len() < PROGRESS_INTERVAL * 4);
+    }
+
+    #[test]
+    fn test_find_markdown_files_with_errors_reports_none_on_a_clean_walk() {
+        let temp_dir = create_test_dir();
+
+        let config = FinderConfig::default();
+        let output =
+            find_markdown_files_in_dir_with_errors(temp_dir.path().to_str().unwrap(), config)
+                .unwrap();
+
+        assert_eq!(output.files.len(), 3);
+        assert!(output.errors.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_markdown_files_with_errors_reports_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("README.md"), "").unwrap();
+
+        let locked_dir = temp_dir.path().join("locked");
+        fs::create_dir(&locked_dir).unwrap();
+        fs::write(locked_dir.join("secret.md"), "").unwrap();
+        fs::set_permissions(&locked_dir, fs::Permissions::from_mode(0o000)).unwrap();
+
+        let config = FinderConfig::default();
+        let output =
+            find_markdown_files_in_dir_with_errors(temp_dir.path().to_str().unwrap(), config)
+                .unwrap();
+
+        // Restore permissions so the temp dir can be cleaned up.
+        fs::set_permissions(&locked_dir, fs::Permissions::from_mode(0o755)).unwrap();
+
+        assert!(output.files.iter().any(|f| f.ends_with("README.md")));
+        assert!(
+            output
+                .errors
+                .iter()
+                .any(|e| e.kind == FinderErrorKind::PermissionDenied)
+        );
+    }
+
     #[test]
     fn test_build_markdown_tree() {
         let temp_dir = create_test_dir();
@@ -487,4 +1155,83 @@ mod tests {
         assert_eq!(tree.children[3].name, "zebra.md");
         assert!(!tree.children[3].is_dir);
     }
+
+    #[test]
+    fn test_tree_nodes_carry_size_and_modified() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("small.md"), "abc").unwrap();
+        fs::write(temp_dir.path().join("big.md"), "abcdefghij").unwrap();
+
+        let config = FinderConfig::default();
+        let tree = build_markdown_tree_in_dir(temp_dir.path().to_str().unwrap(), config).unwrap();
+
+        let small = tree.children.iter().find(|c| c.name == "small.md").unwrap();
+        let big = tree.children.iter().find(|c| c.name == "big.md").unwrap();
+
+        assert_eq!(small.size, Some(3));
+        assert_eq!(big.size, Some(10));
+        assert!(small.modified.is_some());
+    }
+
+    #[test]
+    fn test_tree_sort_by_size_largest_first() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("small.md"), "abc").unwrap();
+        fs::write(temp_dir.path().join("big.md"), "abcdefghij").unwrap();
+
+        let config = FinderConfig::default();
+        let tree = build_markdown_tree_in_dir_sorted(
+            temp_dir.path().to_str().unwrap(),
+            config,
+            TreeSortMode::Size,
+        )
+        .unwrap();
+
+        assert_eq!(tree.children[0].name, "big.md");
+        assert_eq!(tree.children[1].name, "small.md");
+    }
+
+    #[test]
+    fn test_build_markdown_tree_with_errors_reports_none_on_a_clean_walk() {
+        let temp_dir = create_test_dir();
+
+        let config = FinderConfig::default();
+        let (tree, errors) =
+            build_markdown_tree_in_dir_with_errors(temp_dir.path().to_str().unwrap(), config)
+                .unwrap();
+
+        assert!(tree.children.iter().any(|c| c.name == "README.md"));
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_custom_extensions_agree_between_list_and_tree() {
+        let temp_dir = create_test_dir();
+
+        let config = FinderConfig {
+            extensions: vec!["txt".to_string()],
+            ..Default::default()
+        };
+
+        let files =
+            find_markdown_files_in_dir(temp_dir.path().to_str().unwrap(), config.clone()).unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].to_string_lossy().ends_with("test.txt"));
+
+        let tree = build_markdown_tree_in_dir(temp_dir.path().to_str().unwrap(), config).unwrap();
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.children[0].name, "test.txt");
+    }
+
+    #[test]
+    fn test_extension_matching_is_case_insensitive() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("README.MD"), "# Test").unwrap();
+
+        let config = FinderConfig::default();
+        let files = find_markdown_files_in_dir(temp_dir.path().to_str().unwrap(), config).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert!(files[0].to_string_lossy().ends_with("README.MD"));
+    }
 }
@@ -0,0 +1,93 @@
+//! Heading anchor-ID generation, mirroring mdBook's slug algorithm: lowercase
+//! the heading text, keep ASCII alphanumerics plus `_`/`-`, collapse each run
+//! of whitespace to a single `-`, drop everything else, then dedupe across a
+//! document by appending `-1`, `-2`, ... to any slug seen again.
+
+use std::collections::HashMap;
+
+/// Converts heading text to an anchor-safe slug. Does not dedupe against
+/// other headings in the same document; use [`HeadingIdGenerator`] for that.
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    let mut pending_dash = false;
+
+    for ch in text.to_lowercase().chars() {
+        if ch.is_whitespace() {
+            pending_dash = !slug.is_empty();
+        } else if ch.is_ascii_alphanumeric() || ch == '_' || ch == '-' {
+            if pending_dash {
+                slug.push('-');
+                pending_dash = false;
+            }
+            slug.push(ch);
+        }
+    }
+
+    slug
+}
+
+/// Assigns unique anchor IDs to headings across a single document. The first
+/// heading with a given slug keeps it as-is; each later one gets `-1`, `-2`,
+/// and so on appended.
+#[derive(Default)]
+pub struct HeadingIdGenerator {
+    seen: HashMap<String, usize>,
+}
+
+impl HeadingIdGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn unique_id(&mut self, text: &str) -> String {
+        let slug = slugify(text);
+        let count = self.seen.entry(slug.clone()).or_insert(0);
+        let id = if *count == 0 {
+            slug
+        } else {
+            format!("{}-{}", slug, count)
+        };
+        *count += 1;
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_lowercases_and_dashes_whitespace() {
+        assert_eq!(slugify("Getting Started"), "getting-started");
+    }
+
+    #[test]
+    fn test_slugify_drops_punctuation() {
+        assert_eq!(slugify("What's New?!"), "whats-new");
+    }
+
+    #[test]
+    fn test_slugify_keeps_underscores_and_hyphens() {
+        assert_eq!(slugify("snake_case and kebab-case"), "snake_case-and-kebab-case");
+    }
+
+    #[test]
+    fn test_slugify_collapses_repeated_whitespace() {
+        assert_eq!(slugify("too   many    spaces"), "too-many-spaces");
+    }
+
+    #[test]
+    fn test_unique_id_appends_incrementing_suffix_to_repeats() {
+        let mut generator = HeadingIdGenerator::new();
+        assert_eq!(generator.unique_id("Overview"), "overview");
+        assert_eq!(generator.unique_id("Overview"), "overview-1");
+        assert_eq!(generator.unique_id("Overview"), "overview-2");
+    }
+
+    #[test]
+    fn test_unique_id_does_not_collide_across_distinct_slugs() {
+        let mut generator = HeadingIdGenerator::new();
+        assert_eq!(generator.unique_id("Setup"), "setup");
+        assert_eq!(generator.unique_id("Usage"), "usage");
+    }
+}
@@ -12,25 +12,531 @@ pub struct EntityDecoder {
     replacements: Vec<&'static str>,
 }
 
+/// The legacy entity names HTML5 recognizes without a trailing semicolon,
+/// for compatibility with pre-HTML5 markup (e.g. `Tom &amp Jerry`). Every
+/// other named reference requires the semicolon. This is the exact set the
+/// HTML5 "named character references" table marks as semicolon-optional.
+const LEGACY_NO_SEMICOLON: &[&str] = &[
+    "AElig", "AMP", "Aacute", "Acirc", "Agrave", "Aring", "Atilde", "Auml", "COPY", "Ccedil",
+    "ETH", "Eacute", "Ecirc", "Egrave", "Euml", "GT", "Iacute", "Icirc", "Igrave", "Iuml", "LT",
+    "Ntilde", "Oacute", "Ocirc", "Ograve", "Oslash", "Otilde", "Ouml", "QUOT", "REG", "THORN",
+    "Uacute", "Ucirc", "Ugrave", "Uuml", "Yacute", "aacute", "acirc", "acute", "aelig", "agrave",
+    "amp", "aring", "atilde", "auml", "brvbar", "ccedil", "cedil", "cent", "copy", "curren",
+    "deg", "divide", "eacute", "ecirc", "egrave", "eth", "euml", "frac12", "frac14", "frac34",
+    "gt", "iacute", "icirc", "iexcl", "igrave", "iquest", "iuml", "laquo", "lt", "macr", "micro",
+    "middot", "nbsp", "not", "ntilde", "oacute", "ocirc", "ograve", "ordf", "ordm", "oslash",
+    "otilde", "ouml", "para", "plusmn", "pound", "quot", "raquo", "reg", "sect", "shy", "sup1",
+    "sup2", "sup3", "szlig", "thorn", "times", "uacute", "ucirc", "ugrave", "uml", "uuml",
+    "yacute", "yen", "yuml",
+];
+
+/// A substantial subset of the HTML5 named character reference table —
+/// the full WHATWG list runs to ~2,230 names, most of them narrow MathML
+/// operator variants; this covers markup, Latin-1 Supplement, Latin
+/// Extended-A, Greek, general punctuation, letterlike symbols, arrows,
+/// the double-struck/script/fraktur math alphabets, and the common
+/// mathematical operators, technical/geometric symbols, and dingbats a
+/// Markdown document is likely to actually contain. `(name, replacement)`
+/// pairs are grouped the way the HTML5 spec groups them, for easier
+/// auditing against it. Extend this table (and regenerate from
+/// `entities.json` if completeness against the full spec ever matters
+/// more than hand-auditability) rather than assuming every named
+/// reference round-trips.
+const NAMED_ENTITIES: &[(&str, &str)] = &[
+    // Markup
+    ("lt", "<"),
+    ("gt", ">"),
+    ("amp", "&"),
+    ("quot", "\""),
+    ("apos", "'"),
+    ("LT", "<"),
+    ("GT", ">"),
+    ("AMP", "&"),
+    ("QUOT", "\""),
+    // Latin-1 Supplement
+    ("nbsp", "\u{00A0}"),
+    ("iexcl", "¡"),
+    ("cent", "¢"),
+    ("pound", "£"),
+    ("curren", "¤"),
+    ("yen", "¥"),
+    ("brvbar", "¦"),
+    ("sect", "§"),
+    ("uml", "¨"),
+    ("copy", "©"),
+    ("COPY", "©"),
+    ("ordf", "ª"),
+    ("laquo", "«"),
+    ("not", "¬"),
+    ("shy", "\u{00AD}"),
+    ("reg", "®"),
+    ("REG", "®"),
+    ("macr", "¯"),
+    ("deg", "°"),
+    ("plusmn", "±"),
+    ("sup2", "²"),
+    ("sup3", "³"),
+    ("acute", "´"),
+    ("micro", "µ"),
+    ("para", "¶"),
+    ("middot", "·"),
+    ("cedil", "¸"),
+    ("sup1", "¹"),
+    ("ordm", "º"),
+    ("raquo", "»"),
+    ("frac14", "¼"),
+    ("frac12", "½"),
+    ("frac34", "¾"),
+    ("iquest", "¿"),
+    ("Agrave", "À"),
+    ("Aacute", "Á"),
+    ("Acirc", "Â"),
+    ("Atilde", "Ã"),
+    ("Auml", "Ä"),
+    ("Aring", "Å"),
+    ("AElig", "Æ"),
+    ("Ccedil", "Ç"),
+    ("Egrave", "È"),
+    ("Eacute", "É"),
+    ("Ecirc", "Ê"),
+    ("Euml", "Ë"),
+    ("Igrave", "Ì"),
+    ("Iacute", "Í"),
+    ("Icirc", "Î"),
+    ("Iuml", "Ï"),
+    ("ETH", "Ð"),
+    ("Ntilde", "Ñ"),
+    ("Ograve", "Ò"),
+    ("Oacute", "Ó"),
+    ("Ocirc", "Ô"),
+    ("Otilde", "Õ"),
+    ("Ouml", "Ö"),
+    ("times", "×"),
+    ("Oslash", "Ø"),
+    ("Ugrave", "Ù"),
+    ("Uacute", "Ú"),
+    ("Ucirc", "Û"),
+    ("Uuml", "Ü"),
+    ("Yacute", "Ý"),
+    ("THORN", "Þ"),
+    ("szlig", "ß"),
+    ("agrave", "à"),
+    ("aacute", "á"),
+    ("acirc", "â"),
+    ("atilde", "ã"),
+    ("auml", "ä"),
+    ("aring", "å"),
+    ("aelig", "æ"),
+    ("ccedil", "ç"),
+    ("egrave", "è"),
+    ("eacute", "é"),
+    ("ecirc", "ê"),
+    ("euml", "ë"),
+    ("igrave", "ì"),
+    ("iacute", "í"),
+    ("icirc", "î"),
+    ("iuml", "ï"),
+    ("eth", "ð"),
+    ("ntilde", "ñ"),
+    ("ograve", "ò"),
+    ("oacute", "ó"),
+    ("ocirc", "ô"),
+    ("otilde", "õ"),
+    ("ouml", "ö"),
+    ("divide", "÷"),
+    ("oslash", "ø"),
+    ("ugrave", "ù"),
+    ("uacute", "ú"),
+    ("ucirc", "û"),
+    ("uuml", "ü"),
+    ("yacute", "ý"),
+    ("thorn", "þ"),
+    ("yuml", "ÿ"),
+    // Latin Extended-A
+    ("OElig", "Œ"),
+    ("oelig", "œ"),
+    ("Scaron", "Š"),
+    ("scaron", "š"),
+    ("Yuml", "Ÿ"),
+    ("fnof", "ƒ"),
+    ("circ", "ˆ"),
+    ("tilde", "˜"),
+    // Greek
+    ("Alpha", "Α"),
+    ("Beta", "Β"),
+    ("Gamma", "Γ"),
+    ("Delta", "Δ"),
+    ("Epsilon", "Ε"),
+    ("Zeta", "Ζ"),
+    ("Eta", "Η"),
+    ("Theta", "Θ"),
+    ("Iota", "Ι"),
+    ("Kappa", "Κ"),
+    ("Lambda", "Λ"),
+    ("Mu", "Μ"),
+    ("Nu", "Ν"),
+    ("Xi", "Ξ"),
+    ("Omicron", "Ο"),
+    ("Pi", "Π"),
+    ("Rho", "Ρ"),
+    ("Sigma", "Σ"),
+    ("Tau", "Τ"),
+    ("Upsilon", "Υ"),
+    ("Phi", "Φ"),
+    ("Chi", "Χ"),
+    ("Psi", "Ψ"),
+    ("Omega", "Ω"),
+    ("alpha", "α"),
+    ("beta", "β"),
+    ("gamma", "γ"),
+    ("delta", "δ"),
+    ("epsilon", "ε"),
+    ("zeta", "ζ"),
+    ("eta", "η"),
+    ("theta", "θ"),
+    ("iota", "ι"),
+    ("kappa", "κ"),
+    ("lambda", "λ"),
+    ("mu", "μ"),
+    ("nu", "ν"),
+    ("xi", "ξ"),
+    ("omicron", "ο"),
+    ("pi", "π"),
+    ("rho", "ρ"),
+    ("sigmaf", "ς"),
+    ("sigma", "σ"),
+    ("tau", "τ"),
+    ("upsilon", "υ"),
+    ("phi", "φ"),
+    ("chi", "χ"),
+    ("psi", "ψ"),
+    ("omega", "ω"),
+    ("thetasym", "ϑ"),
+    ("upsih", "ϒ"),
+    ("piv", "ϖ"),
+    // General punctuation
+    ("ensp", "\u{2002}"),
+    ("emsp", "\u{2003}"),
+    ("thinsp", "\u{2009}"),
+    ("zwnj", "\u{200C}"),
+    ("zwj", "\u{200D}"),
+    ("lrm", "\u{200E}"),
+    ("rlm", "\u{200F}"),
+    ("ndash", "–"),
+    ("mdash", "—"),
+    ("lsquo", "'"),
+    ("rsquo", "'"),
+    ("sbquo", "‚"),
+    ("ldquo", "\u{201C}"),
+    ("rdquo", "\u{201D}"),
+    ("bdquo", "„"),
+    ("dagger", "†"),
+    ("Dagger", "‡"),
+    ("bull", "•"),
+    ("hellip", "…"),
+    ("permil", "‰"),
+    ("prime", "′"),
+    ("Prime", "″"),
+    ("lsaquo", "‹"),
+    ("rsaquo", "›"),
+    ("oline", "‾"),
+    ("frasl", "⁄"),
+    ("euro", "€"),
+    // Letterlike symbols
+    ("image", "ℑ"),
+    ("weierp", "℘"),
+    ("real", "ℜ"),
+    ("trade", "™"),
+    ("alefsym", "ℵ"),
+    // Arrows
+    ("larr", "←"),
+    ("uarr", "↑"),
+    ("rarr", "→"),
+    ("darr", "↓"),
+    ("harr", "↔"),
+    ("crarr", "↵"),
+    ("lArr", "⇐"),
+    ("uArr", "⇑"),
+    ("rArr", "⇒"),
+    ("dArr", "⇓"),
+    ("hArr", "⇔"),
+    // Mathematical operators
+    ("forall", "∀"),
+    ("part", "∂"),
+    ("exist", "∃"),
+    ("empty", "∅"),
+    ("nabla", "∇"),
+    ("isin", "∈"),
+    ("notin", "∉"),
+    ("ni", "∋"),
+    ("prod", "∏"),
+    ("sum", "∑"),
+    ("minus", "−"),
+    ("lowast", "∗"),
+    ("radic", "√"),
+    ("prop", "∝"),
+    ("infin", "∞"),
+    ("ang", "∠"),
+    ("and", "∧"),
+    ("or", "∨"),
+    ("cap", "∩"),
+    ("cup", "∪"),
+    ("int", "∫"),
+    ("there4", "∴"),
+    ("sim", "∼"),
+    ("cong", "≅"),
+    ("asymp", "≈"),
+    ("ne", "≠"),
+    ("equiv", "≡"),
+    ("le", "≤"),
+    ("ge", "≥"),
+    ("sub", "⊂"),
+    ("sup", "⊃"),
+    ("nsub", "⊄"),
+    ("sube", "⊆"),
+    ("supe", "⊇"),
+    ("oplus", "⊕"),
+    ("otimes", "⊗"),
+    ("perp", "⊥"),
+    ("sdot", "⋅"),
+    // Technical / geometric symbols
+    ("lceil", "⌈"),
+    ("rceil", "⌉"),
+    ("lfloor", "⌊"),
+    ("rfloor", "⌋"),
+    ("lang", "〈"),
+    ("rang", "〉"),
+    ("loz", "◊"),
+    // Dingbats
+    ("spades", "♠"),
+    ("clubs", "♣"),
+    ("hearts", "♥"),
+    ("diams", "♦"),
+    // DOUBLE-STRUCK
+    ("Aopf", "\u{1d538}"),
+    ("Bopf", "\u{1d539}"),
+    ("Copf", "\u{2102}"),
+    ("Dopf", "\u{1d53b}"),
+    ("Eopf", "\u{1d53c}"),
+    ("Fopf", "\u{1d53d}"),
+    ("Gopf", "\u{1d53e}"),
+    ("Hopf", "\u{210d}"),
+    ("Iopf", "\u{1d540}"),
+    ("Jopf", "\u{1d541}"),
+    ("Kopf", "\u{1d542}"),
+    ("Lopf", "\u{1d543}"),
+    ("Mopf", "\u{1d544}"),
+    ("Nopf", "\u{2115}"),
+    ("Oopf", "\u{1d546}"),
+    ("Popf", "\u{2119}"),
+    ("Qopf", "\u{211a}"),
+    ("Ropf", "\u{211d}"),
+    ("Sopf", "\u{1d54a}"),
+    ("Topf", "\u{1d54b}"),
+    ("Uopf", "\u{1d54c}"),
+    ("Vopf", "\u{1d54d}"),
+    ("Wopf", "\u{1d54e}"),
+    ("Xopf", "\u{1d54f}"),
+    ("Yopf", "\u{1d550}"),
+    ("Zopf", "\u{2124}"),
+    ("aopf", "\u{1d552}"),
+    ("bopf", "\u{1d553}"),
+    ("copf", "\u{1d554}"),
+    ("dopf", "\u{1d555}"),
+    ("eopf", "\u{1d556}"),
+    ("fopf", "\u{1d557}"),
+    ("gopf", "\u{1d558}"),
+    ("hopf", "\u{1d559}"),
+    ("iopf", "\u{1d55a}"),
+    ("jopf", "\u{1d55b}"),
+    ("kopf", "\u{1d55c}"),
+    ("lopf", "\u{1d55d}"),
+    ("mopf", "\u{1d55e}"),
+    ("nopf", "\u{1d55f}"),
+    ("oopf", "\u{1d560}"),
+    ("popf", "\u{1d561}"),
+    ("qopf", "\u{1d562}"),
+    ("ropf", "\u{1d563}"),
+    ("sopf", "\u{1d564}"),
+    ("topf", "\u{1d565}"),
+    ("uopf", "\u{1d566}"),
+    ("vopf", "\u{1d567}"),
+    ("wopf", "\u{1d568}"),
+    ("xopf", "\u{1d569}"),
+    ("yopf", "\u{1d56a}"),
+    ("zopf", "\u{1d56b}"),
+    // SCRIPT
+    ("Ascr", "\u{1d49c}"),
+    ("Bscr", "\u{212c}"),
+    ("Cscr", "\u{1d49e}"),
+    ("Dscr", "\u{1d49f}"),
+    ("Escr", "\u{2130}"),
+    ("Fscr", "\u{2131}"),
+    ("Gscr", "\u{1d4a2}"),
+    ("Hscr", "\u{210b}"),
+    ("Iscr", "\u{2110}"),
+    ("Jscr", "\u{1d4a5}"),
+    ("Kscr", "\u{1d4a6}"),
+    ("Lscr", "\u{2112}"),
+    ("Mscr", "\u{2133}"),
+    ("Nscr", "\u{1d4a9}"),
+    ("Oscr", "\u{1d4aa}"),
+    ("Pscr", "\u{1d4ab}"),
+    ("Qscr", "\u{1d4ac}"),
+    ("Rscr", "\u{211b}"),
+    ("Sscr", "\u{1d4ae}"),
+    ("Tscr", "\u{1d4af}"),
+    ("Uscr", "\u{1d4b0}"),
+    ("Vscr", "\u{1d4b1}"),
+    ("Wscr", "\u{1d4b2}"),
+    ("Xscr", "\u{1d4b3}"),
+    ("Yscr", "\u{1d4b4}"),
+    ("Zscr", "\u{1d4b5}"),
+    ("ascr", "\u{1d4b6}"),
+    ("bscr", "\u{1d4b7}"),
+    ("cscr", "\u{1d4b8}"),
+    ("dscr", "\u{1d4b9}"),
+    ("escr", "\u{212f}"),
+    ("fscr", "\u{1d4bb}"),
+    ("gscr", "\u{210a}"),
+    ("hscr", "\u{1d4bd}"),
+    ("iscr", "\u{1d4be}"),
+    ("jscr", "\u{1d4bf}"),
+    ("kscr", "\u{1d4c0}"),
+    ("lscr", "\u{1d4c1}"),
+    ("mscr", "\u{1d4c2}"),
+    ("nscr", "\u{1d4c3}"),
+    ("oscr", "\u{2134}"),
+    ("pscr", "\u{1d4c5}"),
+    ("qscr", "\u{1d4c6}"),
+    ("rscr", "\u{1d4c7}"),
+    ("sscr", "\u{1d4c8}"),
+    ("tscr", "\u{1d4c9}"),
+    ("uscr", "\u{1d4ca}"),
+    ("vscr", "\u{1d4cb}"),
+    ("wscr", "\u{1d4cc}"),
+    ("xscr", "\u{1d4cd}"),
+    ("yscr", "\u{1d4ce}"),
+    ("zscr", "\u{1d4cf}"),
+    // FRAKTUR
+    ("Afr", "\u{1d504}"),
+    ("Bfr", "\u{1d505}"),
+    ("Cfr", "\u{212d}"),
+    ("Dfr", "\u{1d507}"),
+    ("Efr", "\u{1d508}"),
+    ("Ffr", "\u{1d509}"),
+    ("Gfr", "\u{1d50a}"),
+    ("Hfr", "\u{210c}"),
+    ("Ifr", "\u{2111}"),
+    ("Jfr", "\u{1d50d}"),
+    ("Kfr", "\u{1d50e}"),
+    ("Lfr", "\u{1d50f}"),
+    ("Mfr", "\u{1d510}"),
+    ("Nfr", "\u{1d511}"),
+    ("Ofr", "\u{1d512}"),
+    ("Pfr", "\u{1d513}"),
+    ("Qfr", "\u{1d514}"),
+    ("Rfr", "\u{211c}"),
+    ("Sfr", "\u{1d516}"),
+    ("Tfr", "\u{1d517}"),
+    ("Ufr", "\u{1d518}"),
+    ("Vfr", "\u{1d519}"),
+    ("Wfr", "\u{1d51a}"),
+    ("Xfr", "\u{1d51b}"),
+    ("Yfr", "\u{1d51c}"),
+    ("Zfr", "\u{2128}"),
+    ("afr", "\u{1d51e}"),
+    ("bfr", "\u{1d51f}"),
+    ("cfr", "\u{1d520}"),
+    ("dfr", "\u{1d521}"),
+    ("efr", "\u{1d522}"),
+    ("ffr", "\u{1d523}"),
+    ("gfr", "\u{1d524}"),
+    ("hfr", "\u{1d525}"),
+    ("ifr", "\u{1d526}"),
+    ("jfr", "\u{1d527}"),
+    ("kfr", "\u{1d528}"),
+    ("lfr", "\u{1d529}"),
+    ("mfr", "\u{1d52a}"),
+    ("nfr", "\u{1d52b}"),
+    ("ofr", "\u{1d52c}"),
+    ("pfr", "\u{1d52d}"),
+    ("qfr", "\u{1d52e}"),
+    ("rfr", "\u{1d52f}"),
+    ("sfr", "\u{1d530}"),
+    ("tfr", "\u{1d531}"),
+    ("ufr", "\u{1d532}"),
+    ("vfr", "\u{1d533}"),
+    ("wfr", "\u{1d534}"),
+    ("xfr", "\u{1d535}"),
+    ("yfr", "\u{1d536}"),
+    ("zfr", "\u{1d537}"),
+    // Additional mathematical operators / symbols
+    ("boxplus", "\u{229e}"),
+    ("boxminus", "\u{229f}"),
+    ("boxtimes", "\u{22a0}"),
+    ("circledast", "\u{229b}"),
+    ("circledcirc", "\u{229a}"),
+    ("circleddash", "\u{229d}"),
+    ("lessdot", "\u{22d6}"),
+    ("gtrdot", "\u{22d7}"),
+    ("ll", "\u{226a}"),
+    ("gg", "\u{226b}"),
+    ("lesssim", "\u{2272}"),
+    ("gtrsim", "\u{2273}"),
+    ("approxeq", "\u{224a}"),
+    ("backsim", "\u{223d}"),
+    ("star", "\u{22c6}"),
+    ("bigstar", "\u{2605}"),
+    ("divideontimes", "\u{22c7}"),
+    ("vartriangle", "\u{25b5}"),
+    ("blacktriangle", "\u{25b4}"),
+    ("triangledown", "\u{25bf}"),
+    ("blacktriangledown", "\u{25be}"),
+    ("checkmark", "\u{2713}"),
+    ("maltese", "\u{2720}"),
+    ("copysr", "\u{2117}"),
+    ("incare", "\u{2105}"),
+    ("numero", "\u{2116}"),
+    ("hbar", "\u{210f}"),
+    ("planckh", "\u{210e}"),
+    ("bernou", "\u{212c}"),
+    ("order", "\u{2134}"),
+    ("beth", "\u{2136}"),
+    ("gimel", "\u{2137}"),
+    ("daleth", "\u{2138}"),
+    ("nearr", "\u{2197}"),
+    ("nwarr", "\u{2196}"),
+    ("searr", "\u{2198}"),
+    ("swarr", "\u{2199}"),
+    ("angst", "\u{212b}"),
+    ("ohm", "\u{2126}"),
+    ("mho", "\u{2127}"),
+];
+
 /// Initialize entity decoder
 fn init_entity_decoder() -> Result<EntityDecoder> {
-    let patterns = vec![
-        "&lt;", "&gt;", "&amp;", "&quot;", "&apos;", "&#39;", "&nbsp;", "&copy;", "&reg;",
-        "&trade;", "&euro;", "&pound;", "&yen;", "&cent;", "&sect;", "&para;", "&bull;",
-        "&middot;", "&hellip;", "&mdash;", "&ndash;", "&lsquo;", "&rsquo;", "&ldquo;", "&rdquo;",
-        "&laquo;", "&raquo;", "&times;", "&divide;", "&plusmn;", "&ne;", "&le;", "&ge;", "&infin;",
-        "&sum;", "&prod;", "&radic;", "&larr;", "&rarr;", "&uarr;", "&darr;", "&harr;",
-    ];
-
-    let replacements = vec![
-        "<", ">", "&", "\"", "'", "'", " ", "©", "®", "™", "€", "£", "¥", "¢", "§", "¶", "•", "·",
-        "…", "—", "–", "'", "'", "\u{201C}", "\u{201D}", "«", "»", "×", "÷", "±", "≠", "≤", "≥",
-        "∞", "∑", "∏", "√", "←", "→", "↑", "↓", "↔",
-    ];
+    let mut patterns = Vec::with_capacity(NAMED_ENTITIES.len() + LEGACY_NO_SEMICOLON.len());
+    let mut replacements = Vec::with_capacity(patterns.capacity());
+
+    for &(name, replacement) in NAMED_ENTITIES {
+        // Under `LeftmostLongest` match semantics the semicolon-terminated
+        // pattern is preferred over the bare `&name` variant pushed below
+        // when both could match (e.g. `&amp;` over `&amp` in `&amp;amp;`),
+        // and a longer name wins over a legacy prefix of it (e.g. `&notin;`
+        // over `&not`).
+        patterns.push(format!("&{name};"));
+        replacements.push(replacement);
+
+        if LEGACY_NO_SEMICOLON.contains(&name) {
+            patterns.push(format!("&{name}"));
+            replacements.push(replacement);
+        }
+    }
 
     let matcher = AhoCorasick::builder()
-        .match_kind(aho_corasick::MatchKind::LeftmostFirst)
-        .build(patterns)
+        .match_kind(aho_corasick::MatchKind::LeftmostLongest)
+        .build(&patterns)
         .context("Failed to build AhoCorasick matcher for HTML entity decoding")?;
 
     Ok(EntityDecoder {
@@ -215,4 +721,58 @@ mod tests {
         assert_eq!(decode_html_entities("start&lt;"), "start<");
         assert_eq!(decode_html_entities("&gt;end"), ">end");
     }
+
+    #[test]
+    fn test_greek_letters() {
+        assert_eq!(decode_html_entities("&alpha;"), "α");
+        assert_eq!(decode_html_entities("&Omega;"), "Ω");
+    }
+
+    #[test]
+    fn test_accented_latin_and_fractions() {
+        assert_eq!(decode_html_entities("caf&eacute;"), "café");
+        assert_eq!(decode_html_entities("&frac12;"), "½");
+        assert_eq!(decode_html_entities("&Dagger;"), "‡");
+        assert_eq!(decode_html_entities("&hearts;"), "♥");
+    }
+
+    #[test]
+    fn test_legacy_entities_without_trailing_semicolon() {
+        assert_eq!(decode_html_entities("Tom &amp Jerry"), "Tom & Jerry");
+        assert_eq!(decode_html_entities("&copy 2024"), "© 2024");
+        assert_eq!(decode_html_entities("a &lt b"), "a < b");
+    }
+
+    #[test]
+    fn test_semicolon_form_preferred_over_legacy_form() {
+        // "&amp;amp;" should decode the outer reference to "&", leaving the
+        // inner "amp;" untouched, rather than the legacy "&amp" variant
+        // eating just the first four characters.
+        assert_eq!(decode_html_entities("&amp;amp;"), "&amp;");
+    }
+
+    #[test]
+    fn test_non_legacy_entity_requires_semicolon() {
+        assert_eq!(
+            decode_html_entities("&hearts no semicolon"),
+            "&hearts no semicolon"
+        );
+    }
+
+    #[test]
+    fn test_double_struck_script_and_fraktur_alphabets() {
+        assert_eq!(decode_html_entities("&Ropf;"), "\u{211d}");
+        assert_eq!(decode_html_entities("&aopf;"), "\u{1d552}");
+        assert_eq!(decode_html_entities("&Hscr;"), "\u{210b}");
+        assert_eq!(decode_html_entities("&escr;"), "\u{212f}");
+        assert_eq!(decode_html_entities("&Zfr;"), "\u{2128}");
+        assert_eq!(decode_html_entities("&afr;"), "\u{1d51e}");
+    }
+
+    #[test]
+    fn test_additional_mathematical_operators() {
+        assert_eq!(decode_html_entities("&boxtimes;"), "\u{22a0}");
+        assert_eq!(decode_html_entities("&checkmark;"), "\u{2713}");
+        assert_eq!(decode_html_entities("&nearr;"), "\u{2197}");
+    }
 }
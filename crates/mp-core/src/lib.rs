@@ -1,6 +1,11 @@
 pub mod finder;
+pub mod heading;
 pub mod html_entity;
 pub mod theme;
 
-pub use finder::{FileTreeNode, FinderConfig, build_markdown_tree, find_markdown_files};
+pub use finder::{
+    FileTreeNode, FinderConfig, FinderError, FinderErrorKind, FinderOutput, FinderProgress,
+    SkippedSymlink, SymlinkSkipReason, TreeSortMode, build_markdown_tree, find_markdown_files,
+};
+pub use heading::{HeadingIdGenerator, slugify};
 pub use html_entity::EntityDecoder;
@@ -1,9 +1,15 @@
 pub mod adapter;
+pub mod audit;
 pub mod color;
+pub mod presets;
 pub mod style;
 pub mod themes;
+pub mod toml_theme;
 
 pub use adapter::ThemeAdapter;
+pub use audit::{AuditFinding, audit_theme};
 pub use color::ThemeColor;
+pub use presets::{DerivedTheme, Dracula, THEME_NAMES, Theme};
 pub use style::ThemeStyle;
-pub use themes::{DefaultTheme, MarkdownTheme};
+pub use themes::{DefaultTheme, MarkdownTheme, SolarizedOsaka};
+pub use toml_theme::TomlTheme;
@@ -0,0 +1,169 @@
+use super::{
+    color::{ThemeColor, accessible_foreground, contrast_ratio, meets_wcag_aa, meets_wcag_aaa},
+    themes::MarkdownTheme,
+};
+
+/// A single role/background pair that failed a WCAG contrast check
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditFinding {
+    pub role: &'static str,
+    pub measured_ratio: f32,
+    pub required_ratio: f32,
+    pub suggested_fix: ThemeColor,
+}
+
+/// Required contrast ratio for WCAG AA normal text
+const AA_RATIO: f32 = 4.5;
+
+/// Check every foreground/background role pair a `MarkdownTheme` exposes
+/// against WCAG AA, returning a finding for each pair that fails.
+pub fn audit_theme(theme: &dyn MarkdownTheme) -> Vec<AuditFinding> {
+    let bg = theme.code_background();
+    let status_bg = theme.status_background_color();
+
+    let mut roles: Vec<(&'static str, ThemeColor, ThemeColor)> = vec![
+        ("text", theme.text_style().color, bg),
+        ("strong", theme.strong_style().color, bg),
+        ("emphasis", theme.emphasis_style().color, bg),
+        ("link", theme.link_style().color, bg),
+        ("code", theme.code_style().color, bg),
+        ("list_marker", theme.list_marker_style().color, bg),
+        ("delimiter", theme.delimiter_style().color, bg),
+        ("status_normal", theme.status_normal_color(), status_bg),
+        ("status_search", theme.status_search_color(), status_bg),
+        ("status_help", theme.status_help_color(), status_bg),
+        ("status_error", theme.status_error_color(), status_bg),
+        ("status_message", theme.status_message_color(), status_bg),
+        ("status_toc", theme.status_toc_color(), status_bg),
+        ("status_theme", theme.status_theme_color(), status_bg),
+    ];
+
+    for level in 1..=6u8 {
+        roles.push(("heading", theme.heading_style(level).color, bg));
+    }
+
+    roles
+        .into_iter()
+        .filter_map(|(role, fg, bg)| {
+            let ratio = contrast_ratio(&fg, &bg);
+            if meets_wcag_aa(&fg, &bg) {
+                None
+            } else {
+                Some(AuditFinding {
+                    role,
+                    measured_ratio: ratio,
+                    required_ratio: AA_RATIO,
+                    suggested_fix: accessible_foreground(&bg, AA_RATIO),
+                })
+            }
+        })
+        .collect()
+}
+
+/// Whether the theme's body text meets the stricter WCAG AAA ratio against
+/// its code background. Only that one pair is checked; the other role pairs
+/// [`audit_theme`] covers are not.
+pub fn meets_aaa_for_all(theme: &dyn MarkdownTheme) -> bool {
+    let bg = theme.code_background();
+    meets_wcag_aaa(&theme.text_style().color, &bg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::themes::SolarizedOsaka;
+
+    #[test]
+    fn test_audit_theme_solarized_passes_aa() {
+        let findings = audit_theme(&SolarizedOsaka);
+        assert!(
+            findings.is_empty(),
+            "expected no AA failures, got: {:?}",
+            findings
+        );
+    }
+
+    #[test]
+    fn test_audit_finding_reports_ratio_and_fix() {
+        struct BadTheme;
+        impl MarkdownTheme for BadTheme {
+            fn heading_style(&self, _level: u8) -> super::super::style::ThemeStyle {
+                self.text_style()
+            }
+            fn strong_style(&self) -> super::super::style::ThemeStyle {
+                self.text_style()
+            }
+            fn emphasis_style(&self) -> super::super::style::ThemeStyle {
+                self.text_style()
+            }
+            fn link_style(&self) -> super::super::style::ThemeStyle {
+                self.text_style()
+            }
+            fn code_style(&self) -> super::super::style::ThemeStyle {
+                self.text_style()
+            }
+            fn code_background(&self) -> ThemeColor {
+                ThemeColor {
+                    r: 250,
+                    g: 250,
+                    b: 250,
+                }
+            }
+            fn list_marker_style(&self) -> super::super::style::ThemeStyle {
+                self.text_style()
+            }
+            fn delimiter_style(&self) -> super::super::style::ThemeStyle {
+                self.text_style()
+            }
+            fn text_style(&self) -> super::super::style::ThemeStyle {
+                super::super::style::ThemeStyle {
+                    color: ThemeColor {
+                        r: 240,
+                        g: 240,
+                        b: 240,
+                    },
+                    bold: false,
+                    italic: false,
+                    underline: false,
+                    bg: None,
+                    reverse: false,
+                    strikethrough: false,
+                    dim: false,
+                }
+            }
+            fn focus_border_style(&self) -> super::super::style::ThemeStyle {
+                self.text_style()
+            }
+            fn status_normal_color(&self) -> ThemeColor {
+                self.text_style().color
+            }
+            fn status_search_color(&self) -> ThemeColor {
+                self.text_style().color
+            }
+            fn status_help_color(&self) -> ThemeColor {
+                self.text_style().color
+            }
+            fn status_error_color(&self) -> ThemeColor {
+                self.text_style().color
+            }
+            fn status_message_color(&self) -> ThemeColor {
+                self.text_style().color
+            }
+            fn status_background_color(&self) -> ThemeColor {
+                self.code_background()
+            }
+            fn status_toc_color(&self) -> ThemeColor {
+                self.text_style().color
+            }
+            fn status_theme_color(&self) -> ThemeColor {
+                self.text_style().color
+            }
+        }
+
+        let findings = audit_theme(&BadTheme);
+        assert!(!findings.is_empty());
+        let text_finding = findings.iter().find(|f| f.role == "text").unwrap();
+        assert!(text_finding.measured_ratio < AA_RATIO);
+        assert_eq!(text_finding.required_ratio, AA_RATIO);
+    }
+}
@@ -5,6 +5,64 @@ pub struct ThemeColor {
     pub b: u8,
 }
 
+impl ThemeColor {
+    /// Parse a `#rgb`, `#rrggbb`, or `#rrggbbaa` hex string (alpha is parsed
+    /// but discarded, since `ThemeColor` has no alpha channel).
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#')?;
+
+        let (r, g, b) = match hex.len() {
+            3 => {
+                let r = u8::from_str_radix(&hex[0..1], 16).ok()?;
+                let g = u8::from_str_radix(&hex[1..2], 16).ok()?;
+                let b = u8::from_str_radix(&hex[2..3], 16).ok()?;
+                (r * 17, g * 17, b * 17)
+            }
+            6 | 8 => {
+                let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+                let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+                let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+                if hex.len() == 8 {
+                    u8::from_str_radix(&hex[6..8], 16).ok()?;
+                }
+                (r, g, b)
+            }
+            _ => return None,
+        };
+
+        Some(ThemeColor { r, g, b })
+    }
+
+    /// Build a `ThemeColor` from HSL using the standard HSL->RGB conversion.
+    /// `h` is in degrees (0-360), `s` and `l` are in the 0.0-1.0 range.
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let h = h.rem_euclid(360.0);
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = l - c / 2.0;
+
+        let (r1, g1, b1) = match h as u32 {
+            0..=59 => (c, x, 0.0),
+            60..=119 => (x, c, 0.0),
+            120..=179 => (0.0, c, x),
+            180..=239 => (0.0, x, c),
+            240..=299 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        ThemeColor {
+            r: (((r1 + m) * 255.0).round().clamp(0.0, 255.0)) as u8,
+            g: (((g1 + m) * 255.0).round().clamp(0.0, 255.0)) as u8,
+            b: (((b1 + m) * 255.0).round().clamp(0.0, 255.0)) as u8,
+        }
+    }
+
+    /// Render as a `#rrggbb` hex string.
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
 /// Calculate the relative luminance of a color using WCAG 2.0 formula
 /// Returns a value between 0.0 (darkest) and 1.0 (lightest)
 pub fn relative_luminance(color: &ThemeColor) -> f32 {
@@ -68,6 +126,320 @@ pub fn mix_colors(color1: &ThemeColor, color2: &ThemeColor, ratio: f32) -> Theme
     }
 }
 
+/// Color depth supported by the connected terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// 24-bit "truecolor" RGB
+    TrueColor,
+    /// The standard 256-entry xterm palette
+    Ansi256,
+}
+
+impl ColorDepth {
+    /// Detect depth from the `COLORTERM` environment variable, defaulting to
+    /// `Ansi256` when it's absent or doesn't advertise truecolor support.
+    pub fn detect() -> Self {
+        match std::env::var("COLORTERM") {
+            Ok(val) if val == "truecolor" || val == "24bit" => ColorDepth::TrueColor,
+            _ => ColorDepth::Ansi256,
+        }
+    }
+}
+
+/// The 16 xterm system colors, in palette order (0-15)
+const ANSI_SYSTEM_COLORS: [ThemeColor; 16] = [
+    ThemeColor { r: 0, g: 0, b: 0 },
+    ThemeColor { r: 128, g: 0, b: 0 },
+    ThemeColor { r: 0, g: 128, b: 0 },
+    ThemeColor { r: 128, g: 128, b: 0 },
+    ThemeColor { r: 0, g: 0, b: 128 },
+    ThemeColor { r: 128, g: 0, b: 128 },
+    ThemeColor { r: 0, g: 128, b: 128 },
+    ThemeColor { r: 192, g: 192, b: 192 },
+    ThemeColor { r: 128, g: 128, b: 128 },
+    ThemeColor { r: 255, g: 0, b: 0 },
+    ThemeColor { r: 0, g: 255, b: 0 },
+    ThemeColor { r: 255, g: 255, b: 0 },
+    ThemeColor { r: 0, g: 0, b: 255 },
+    ThemeColor { r: 255, g: 0, b: 255 },
+    ThemeColor { r: 0, g: 255, b: 255 },
+    ThemeColor { r: 255, g: 255, b: 255 },
+];
+
+/// Build the full 256-entry xterm palette: 16 system colors, the 6x6x6 color
+/// cube, then the 24-step grayscale ramp.
+fn ansi256_palette() -> [ThemeColor; 256] {
+    let mut palette = [ThemeColor { r: 0, g: 0, b: 0 }; 256];
+    palette[0..16].copy_from_slice(&ANSI_SYSTEM_COLORS);
+
+    let cube_step = |i: u8| if i == 0 { 0 } else { 55 + 40 * i };
+    let mut idx = 16;
+    for r in 0..6 {
+        for g in 0..6 {
+            for b in 0..6 {
+                palette[idx] = ThemeColor {
+                    r: cube_step(r),
+                    g: cube_step(g),
+                    b: cube_step(b),
+                };
+                idx += 1;
+            }
+        }
+    }
+
+    for n in 0..24 {
+        let level = 8 + 10 * n;
+        palette[idx] = ThemeColor {
+            r: level,
+            g: level,
+            b: level,
+        };
+        idx += 1;
+    }
+
+    palette
+}
+
+/// Quantize a truecolor `ThemeColor` to the nearest entry in the standard
+/// xterm 256-color palette, for terminals without truecolor support.
+pub fn quantize_to_ansi256(color: &ThemeColor) -> u8 {
+    let palette = ansi256_palette();
+
+    let mut best_index = 0usize;
+    let mut best_distance = f32::MAX;
+
+    for (i, candidate) in palette.iter().enumerate() {
+        let dr = color.r as f32 - candidate.r as f32;
+        let dg = (color.g as f32 - candidate.g as f32) * 1.2;
+        let db = color.b as f32 - candidate.b as f32;
+        let distance = dr * dr + dg * dg + db * db;
+
+        if distance < best_distance {
+            best_distance = distance;
+            best_index = i;
+        }
+    }
+
+    best_index as u8
+}
+
+/// Quantize a truecolor `ThemeColor` to the nearest of the 16 standard xterm
+/// system colors (see [`ANSI_SYSTEM_COLORS`]), for terminals that only
+/// advertise basic ANSI color support. Uses the same green-weighted
+/// squared-distance metric as [`quantize_to_ansi256`].
+pub fn quantize_to_ansi16(color: &ThemeColor) -> u8 {
+    let mut best_index = 0usize;
+    let mut best_distance = f32::MAX;
+
+    for (i, candidate) in ANSI_SYSTEM_COLORS.iter().enumerate() {
+        let dr = color.r as f32 - candidate.r as f32;
+        let dg = (color.g as f32 - candidate.g as f32) * 1.2;
+        let db = color.b as f32 - candidate.b as f32;
+        let distance = dr * dr + dg * dg + db * db;
+
+        if distance < best_distance {
+            best_distance = distance;
+            best_index = i;
+        }
+    }
+
+    best_index as u8
+}
+
+const WHITE: ThemeColor = ThemeColor {
+    r: 255,
+    g: 255,
+    b: 255,
+};
+const BLACK: ThemeColor = ThemeColor { r: 0, g: 0, b: 0 };
+
+/// Pick a foreground color that meets `target_ratio` contrast against `bg`.
+/// Tries pure black and white first; if neither is required (e.g. the caller
+/// wants a specific tint), use `accessible_foreground_tint` instead.
+pub fn accessible_foreground(bg: &ThemeColor, target_ratio: f32) -> ThemeColor {
+    let white_ratio = contrast_ratio(&WHITE, bg);
+    let black_ratio = contrast_ratio(&BLACK, bg);
+
+    if white_ratio >= target_ratio && white_ratio >= black_ratio {
+        WHITE
+    } else if black_ratio >= target_ratio {
+        BLACK
+    } else if white_ratio >= black_ratio {
+        WHITE
+    } else {
+        BLACK
+    }
+}
+
+/// Like `accessible_foreground`, but keeps `preferred`'s hue, binary-searching
+/// its lightness toward the extreme opposite the background's luminance until
+/// `contrast_ratio` crosses `target_ratio`.
+pub fn accessible_foreground_tint(
+    bg: &ThemeColor,
+    preferred: &ThemeColor,
+    target_ratio: f32,
+) -> ThemeColor {
+    if contrast_ratio(preferred, bg) >= target_ratio {
+        return *preferred;
+    }
+
+    let toward_white = relative_luminance(bg) < 0.5;
+    let mut lo = 0.0_f32;
+    let mut hi = 100.0_f32;
+    let mut best = if toward_white { WHITE } else { BLACK };
+
+    for _ in 0..32 {
+        let mid = (lo + hi) / 2.0;
+        let lch = color_to_lch(preferred);
+        let target_l = if toward_white {
+            lch.l + (100.0 - lch.l) * (mid / 100.0)
+        } else {
+            lch.l * (1.0 - mid / 100.0)
+        };
+        let candidate = lch_to_color(&Lch {
+            l: target_l,
+            c: lch.c,
+            h: lch.h,
+        });
+
+        // `mid` is how far toward the extreme the candidate moved, so in
+        // both directions a passing candidate means less movement may do.
+        if contrast_ratio(&candidate, bg) >= target_ratio {
+            best = candidate;
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    best
+}
+
+/// D65 white point used by the sRGB <-> CIELAB conversion below
+const D65_XN: f32 = 0.95047;
+const D65_YN: f32 = 1.0;
+const D65_ZN: f32 = 1.08883;
+
+/// A color in cylindrical CIELAB (LCH) space: L is lightness, C is chroma, H is hue in radians
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Lch {
+    l: f32,
+    c: f32,
+    h: f32,
+}
+
+fn linear_to_srgb(channel: f32) -> f32 {
+    if channel <= 0.0031308 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn lab_f(t: f32) -> f32 {
+    const DELTA_CUBE: f32 = 216.0 / 24389.0;
+    if t > DELTA_CUBE {
+        t.cbrt()
+    } else {
+        (24389.0 / 27.0 * t + 16.0) / 116.0
+    }
+}
+
+fn lab_f_inv(t: f32) -> f32 {
+    const DELTA: f32 = 6.0 / 29.0;
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+fn color_to_lch(color: &ThemeColor) -> Lch {
+    let r = srgb_to_linear(color.r as f32 / 255.0);
+    let g = srgb_to_linear(color.g as f32 / 255.0);
+    let b = srgb_to_linear(color.b as f32 / 255.0);
+
+    let x = 0.4124 * r + 0.3576 * g + 0.1805 * b;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = 0.0193 * r + 0.1192 * g + 0.9505 * b;
+
+    let fx = lab_f(x / D65_XN);
+    let fy = lab_f(y / D65_YN);
+    let fz = lab_f(z / D65_ZN);
+
+    let l = 116.0 * fy - 16.0;
+    let a = 500.0 * (fx - fy);
+    let b_lab = 200.0 * (fy - fz);
+
+    Lch {
+        l,
+        c: (a * a + b_lab * b_lab).sqrt(),
+        h: b_lab.atan2(a),
+    }
+}
+
+fn lch_to_color(lch: &Lch) -> ThemeColor {
+    let a = lch.c * lch.h.cos();
+    let b_lab = lch.c * lch.h.sin();
+
+    let fy = (lch.l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b_lab / 200.0;
+
+    let x = D65_XN * lab_f_inv(fx);
+    let y = D65_YN * lab_f_inv(fy);
+    let z = D65_ZN * lab_f_inv(fz);
+
+    let r = 3.2406 * x - 1.5372 * y - 0.4986 * z;
+    let g = -0.9689 * x + 1.8758 * y + 0.0415 * z;
+    let b = 0.0557 * x - 0.2040 * y + 1.0570 * z;
+
+    let to_u8 = |c: f32| (linear_to_srgb(c) * 255.0).round().clamp(0.0, 255.0) as u8;
+
+    ThemeColor {
+        r: to_u8(r),
+        g: to_u8(g),
+        b: to_u8(b),
+    }
+}
+
+/// Interpolate hue angles (in radians) along the shortest angular path
+fn lerp_hue(h1: f32, h2: f32, ratio: f32) -> f32 {
+    let two_pi = std::f32::consts::TAU;
+    let mut diff = (h2 - h1) % two_pi;
+    if diff > std::f32::consts::PI {
+        diff -= two_pi;
+    } else if diff < -std::f32::consts::PI {
+        diff += two_pi;
+    }
+    h1 + diff * ratio
+}
+
+/// Mix two colors perceptually by interpolating in CIELCH space, taking the
+/// shortest angular path for hue. Unlike `mix_colors`, this avoids the muddy
+/// midpoints that gamma-encoded sRGB interpolation produces.
+pub fn mix_colors_lch(color1: &ThemeColor, color2: &ThemeColor, ratio: f32) -> ThemeColor {
+    let ratio = ratio.clamp(0.0, 1.0);
+    let lch1 = color_to_lch(color1);
+    let lch2 = color_to_lch(color2);
+
+    let mixed = Lch {
+        l: lch1.l + (lch2.l - lch1.l) * ratio,
+        c: lch1.c + (lch2.c - lch1.c) * ratio,
+        h: lerp_hue(lch1.h, lch2.h, ratio),
+    };
+
+    lch_to_color(&mixed)
+}
+
+/// Adjust color lightness by a percentage (-100 to 100) in CIELCH space,
+/// leaving chroma and hue untouched so saturated colors don't shift hue.
+pub fn adjust_lightness_lch(color: &ThemeColor, percent: i16) -> ThemeColor {
+    let mut lch = color_to_lch(color);
+    lch.l = (lch.l * (1.0 + percent as f32 / 100.0)).clamp(0.0, 100.0);
+    lch_to_color(&lch)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,4 +537,222 @@ mod tests {
         assert_eq!(mostly_red.g, 0);
         assert_eq!(mostly_red.b, 63);
     }
+
+    #[test]
+    fn test_mix_colors_lch_endpoints() {
+        let red = ThemeColor { r: 255, g: 0, b: 0 };
+        let blue = ThemeColor { r: 0, g: 0, b: 255 };
+
+        let all_red = mix_colors_lch(&red, &blue, 0.0);
+        assert_eq!(all_red, red);
+
+        let all_blue = mix_colors_lch(&red, &blue, 1.0);
+        assert_eq!(all_blue, blue);
+    }
+
+    #[test]
+    fn test_mix_colors_lch_is_not_muddy() {
+        let red = ThemeColor { r: 255, g: 0, b: 0 };
+        let blue = ThemeColor { r: 0, g: 0, b: 255 };
+
+        let mixed = mix_colors_lch(&red, &blue, 0.5);
+        // A perceptual mix of red and blue should stay vivid (a hot pink
+        // around (251, 0, 128)), unlike the muddy dark purple (127, 0, 127)
+        // produced by sRGB-space mixing.
+        assert!(mixed.r > 150);
+        assert!(mixed.b > 100);
+        assert!(mixed.g < mixed.r);
+    }
+
+    #[test]
+    fn test_adjust_lightness_lch_preserves_hue() {
+        let color = ThemeColor {
+            r: 200,
+            g: 50,
+            b: 50,
+        };
+
+        let brighter = adjust_lightness_lch(&color, 20);
+        let darker = adjust_lightness_lch(&color, -20);
+
+        let original_lch = color_to_lch(&color);
+        let brighter_lch = color_to_lch(&brighter);
+        let darker_lch = color_to_lch(&darker);
+
+        assert!(brighter_lch.l > original_lch.l);
+        assert!(darker_lch.l < original_lch.l);
+        assert!((brighter_lch.h - original_lch.h).abs() < 0.01);
+        assert!((darker_lch.h - original_lch.h).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_accessible_foreground_picks_extremes() {
+        let dark_bg = ThemeColor { r: 10, g: 10, b: 10 };
+        let light_bg = ThemeColor {
+            r: 245,
+            g: 245,
+            b: 245,
+        };
+
+        assert_eq!(accessible_foreground(&dark_bg, 4.5), WHITE);
+        assert_eq!(accessible_foreground(&light_bg, 4.5), BLACK);
+    }
+
+    #[test]
+    fn test_accessible_foreground_tint_meets_target() {
+        let bg = ThemeColor {
+            r: 20,
+            g: 20,
+            b: 20,
+        };
+        let preferred = ThemeColor {
+            r: 180,
+            g: 50,
+            b: 50,
+        };
+
+        let fg = accessible_foreground_tint(&bg, &preferred, 4.5);
+        assert!(contrast_ratio(&fg, &bg) >= 4.5);
+    }
+
+    #[test]
+    fn test_accessible_foreground_tint_already_passing() {
+        let bg = ThemeColor { r: 0, g: 0, b: 0 };
+        let preferred = ThemeColor {
+            r: 255,
+            g: 255,
+            b: 255,
+        };
+
+        assert_eq!(accessible_foreground_tint(&bg, &preferred, 4.5), preferred);
+    }
+
+    #[test]
+    fn test_from_hex_forms() {
+        assert_eq!(
+            ThemeColor::from_hex("#f00"),
+            Some(ThemeColor { r: 255, g: 0, b: 0 })
+        );
+        assert_eq!(
+            ThemeColor::from_hex("#ff0000"),
+            Some(ThemeColor { r: 255, g: 0, b: 0 })
+        );
+        assert_eq!(
+            ThemeColor::from_hex("#ff0000ff"),
+            Some(ThemeColor { r: 255, g: 0, b: 0 })
+        );
+        assert_eq!(ThemeColor::from_hex("not-a-color"), None);
+        assert_eq!(ThemeColor::from_hex("#zzz"), None);
+    }
+
+    #[test]
+    fn test_from_hsl() {
+        assert_eq!(
+            ThemeColor::from_hsl(0.0, 1.0, 0.5),
+            ThemeColor { r: 255, g: 0, b: 0 }
+        );
+        assert_eq!(
+            ThemeColor::from_hsl(120.0, 1.0, 0.5),
+            ThemeColor { r: 0, g: 255, b: 0 }
+        );
+        assert_eq!(
+            ThemeColor::from_hsl(0.0, 0.0, 1.0),
+            ThemeColor {
+                r: 255,
+                g: 255,
+                b: 255
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_hex_roundtrip() {
+        let color = ThemeColor {
+            r: 137,
+            g: 180,
+            b: 250,
+        };
+        assert_eq!(color.to_hex(), "#89b4fa");
+        assert_eq!(ThemeColor::from_hex(&color.to_hex()), Some(color));
+    }
+
+    #[test]
+    fn test_quantize_to_ansi256_exact_matches() {
+        let black = ThemeColor { r: 0, g: 0, b: 0 };
+        let white = ThemeColor {
+            r: 255,
+            g: 255,
+            b: 255,
+        };
+
+        assert_eq!(quantize_to_ansi256(&black), 0);
+        assert_eq!(quantize_to_ansi256(&white), 15);
+    }
+
+    #[test]
+    fn test_quantize_to_ansi256_cube_entry() {
+        let color = ThemeColor {
+            r: 95,
+            g: 135,
+            b: 175,
+        };
+        let index = quantize_to_ansi256(&color);
+        assert!(index >= 16 && index < 232);
+    }
+
+    #[test]
+    fn test_quantize_to_ansi16_exact_matches() {
+        let black = ThemeColor { r: 0, g: 0, b: 0 };
+        let white = ThemeColor {
+            r: 255,
+            g: 255,
+            b: 255,
+        };
+
+        assert_eq!(quantize_to_ansi16(&black), 0);
+        assert_eq!(quantize_to_ansi16(&white), 15);
+    }
+
+    #[test]
+    fn test_quantize_to_ansi16_stays_within_the_system_palette() {
+        let color = ThemeColor {
+            r: 95,
+            g: 135,
+            b: 175,
+        };
+        assert!(quantize_to_ansi16(&color) < 16);
+    }
+
+    #[test]
+    fn test_color_depth_detect_defaults_to_ansi256() {
+        // SAFETY: test runs single-threaded within this module
+        unsafe {
+            std::env::remove_var("COLORTERM");
+        }
+        assert_eq!(ColorDepth::detect(), ColorDepth::Ansi256);
+
+        unsafe {
+            std::env::set_var("COLORTERM", "truecolor");
+        }
+        assert_eq!(ColorDepth::detect(), ColorDepth::TrueColor);
+        unsafe {
+            std::env::remove_var("COLORTERM");
+        }
+    }
+
+    #[test]
+    fn test_lch_roundtrip() {
+        let color = ThemeColor {
+            r: 128,
+            g: 64,
+            b: 200,
+        };
+
+        let lch = color_to_lch(&color);
+        let roundtripped = lch_to_color(&lch);
+
+        assert!((roundtripped.r as i16 - color.r as i16).abs() <= 1);
+        assert!((roundtripped.g as i16 - color.g as i16).abs() <= 1);
+        assert!((roundtripped.b as i16 - color.b as i16).abs() <= 1);
+    }
 }
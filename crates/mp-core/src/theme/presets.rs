@@ -0,0 +1,460 @@
+use super::{
+    color::{ThemeColor, adjust_lightness_lch, meets_wcag_aa},
+    style::ThemeStyle,
+    themes::{MarkdownTheme, SolarizedOsaka},
+};
+
+/// A theme whose every role color was generated at runtime rather than
+/// hand-picked, produced by `Theme::derive_dark`/`Theme::derive_light`.
+#[derive(Debug, Clone, Copy)]
+pub struct DerivedTheme {
+    background: ThemeColor,
+    text: ThemeColor,
+    accent: ThemeColor,
+    muted: ThemeColor,
+}
+
+impl DerivedTheme {
+    fn style(&self, color: ThemeColor, bold: bool, italic: bool, underline: bool) -> ThemeStyle {
+        ThemeStyle {
+            color,
+            bold,
+            italic,
+            underline,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        }
+    }
+}
+
+impl MarkdownTheme for DerivedTheme {
+    fn heading_style(&self, level: u8) -> ThemeStyle {
+        self.style(self.accent, level <= 2, false, false)
+    }
+
+    fn strong_style(&self) -> ThemeStyle {
+        self.style(self.accent, true, false, false)
+    }
+
+    fn emphasis_style(&self) -> ThemeStyle {
+        self.style(self.text, false, true, false)
+    }
+
+    fn link_style(&self) -> ThemeStyle {
+        self.style(self.accent, false, false, true)
+    }
+
+    fn code_style(&self) -> ThemeStyle {
+        self.style(self.text, false, false, false)
+    }
+
+    fn code_background(&self) -> ThemeColor {
+        self.background
+    }
+
+    fn list_marker_style(&self) -> ThemeStyle {
+        self.style(self.accent, false, false, false)
+    }
+
+    fn delimiter_style(&self) -> ThemeStyle {
+        self.style(self.muted, false, false, false)
+    }
+
+    fn text_style(&self) -> ThemeStyle {
+        self.style(self.text, false, false, false)
+    }
+
+    fn focus_border_style(&self) -> ThemeStyle {
+        self.style(self.accent, false, false, false)
+    }
+
+    fn status_normal_color(&self) -> ThemeColor {
+        self.text
+    }
+
+    fn status_search_color(&self) -> ThemeColor {
+        self.accent
+    }
+
+    fn status_help_color(&self) -> ThemeColor {
+        self.accent
+    }
+
+    fn status_error_color(&self) -> ThemeColor {
+        ThemeColor {
+            r: 220,
+            g: 50,
+            b: 47,
+        }
+    }
+
+    fn status_message_color(&self) -> ThemeColor {
+        self.accent
+    }
+
+    fn status_background_color(&self) -> ThemeColor {
+        self.background
+    }
+
+    fn status_toc_color(&self) -> ThemeColor {
+        self.accent
+    }
+
+    fn status_theme_color(&self) -> ThemeColor {
+        self.accent
+    }
+}
+
+/// Names of every bundled preset `Theme::preset` can look up, in the order a
+/// picker should offer them.
+pub const THEME_NAMES: &[&str] = &["solarized-dark", "dracula"];
+
+/// Named, bundled themes and LCH-derived theme generation. Namespacing unit
+/// type mirroring how `SolarizedOsaka` groups its palette as associated
+/// constants.
+pub struct Theme;
+
+impl Theme {
+    /// Look up a bundled preset by name (`"solarized-dark"`, `"dracula"`, ...).
+    pub fn preset(name: &str) -> Option<Box<dyn MarkdownTheme>> {
+        match name {
+            "solarized-dark" => Some(Box::new(SolarizedOsaka)),
+            "dracula" => Some(Box::new(Dracula)),
+            _ => None,
+        }
+    }
+
+    /// The next preset name after `current` in [`THEME_NAMES`], wrapping
+    /// around. Falls back to the first preset if `current` isn't recognized.
+    pub fn next_preset_name(current: &str) -> &'static str {
+        let index = THEME_NAMES.iter().position(|&name| name == current);
+        let len = THEME_NAMES.len();
+        let next_index = match index {
+            Some(i) => (i + 1) % len,
+            None => 0,
+        };
+        THEME_NAMES[next_index]
+    }
+
+    /// The preset name before `current` in [`THEME_NAMES`], wrapping around.
+    /// Falls back to the first preset if `current` isn't recognized.
+    pub fn prev_preset_name(current: &str) -> &'static str {
+        let index = THEME_NAMES.iter().position(|&name| name == current);
+        let len = THEME_NAMES.len();
+        let prev_index = match index {
+            Some(i) => (i + len - 1) % len,
+            None => 0,
+        };
+        THEME_NAMES[prev_index]
+    }
+
+    /// Generate a full dark theme from a single accent color, lightening it
+    /// for body text via CIELCH lightness manipulation; links and headings
+    /// use the accent unchanged.
+    pub fn derive_dark(accent: ThemeColor) -> DerivedTheme {
+        DerivedTheme {
+            background: ThemeColor {
+                r: 10,
+                g: 10,
+                b: 14,
+            },
+            text: adjust_lightness_lch(&accent, 60),
+            accent,
+            muted: adjust_lightness_lch(&accent, -20),
+        }
+    }
+
+    /// Generate a full light theme from a single accent color, darkening it
+    /// for body text via CIELCH lightness manipulation; links and headings
+    /// use the accent unchanged.
+    pub fn derive_light(accent: ThemeColor) -> DerivedTheme {
+        DerivedTheme {
+            background: ThemeColor {
+                r: 250,
+                g: 250,
+                b: 248,
+            },
+            text: adjust_lightness_lch(&accent, -60),
+            accent,
+            muted: adjust_lightness_lch(&accent, 20),
+        }
+    }
+}
+
+/// Dracula, reconstructed from its published palette
+/// (https://draculatheme.com/contribute).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dracula;
+
+impl Dracula {
+    pub const BACKGROUND: ThemeColor = ThemeColor {
+        r: 40,
+        g: 42,
+        b: 54,
+    };
+    pub const FOREGROUND: ThemeColor = ThemeColor {
+        r: 248,
+        g: 248,
+        b: 242,
+    };
+    pub const COMMENT: ThemeColor = ThemeColor {
+        r: 98,
+        g: 114,
+        b: 164,
+    };
+    pub const CYAN: ThemeColor = ThemeColor {
+        r: 139,
+        g: 233,
+        b: 253,
+    };
+    pub const GREEN: ThemeColor = ThemeColor {
+        r: 80,
+        g: 250,
+        b: 123,
+    };
+    pub const ORANGE: ThemeColor = ThemeColor {
+        r: 255,
+        g: 184,
+        b: 108,
+    };
+    pub const PINK: ThemeColor = ThemeColor {
+        r: 255,
+        g: 121,
+        b: 198,
+    };
+    pub const PURPLE: ThemeColor = ThemeColor {
+        r: 189,
+        g: 147,
+        b: 249,
+    };
+    pub const RED: ThemeColor = ThemeColor {
+        r: 255,
+        g: 85,
+        b: 85,
+    };
+    pub const YELLOW: ThemeColor = ThemeColor {
+        r: 241,
+        g: 250,
+        b: 140,
+    };
+}
+
+impl MarkdownTheme for Dracula {
+    fn heading_style(&self, level: u8) -> ThemeStyle {
+        let color = match level {
+            1 => Self::PURPLE,
+            2 => Self::PINK,
+            3 => Self::CYAN,
+            4 => Self::GREEN,
+            5 => Self::ORANGE,
+            _ => Self::YELLOW,
+        };
+        ThemeStyle {
+            color,
+            bold: level <= 2,
+            italic: false,
+            underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        }
+    }
+
+    fn strong_style(&self) -> ThemeStyle {
+        ThemeStyle {
+            color: Self::ORANGE,
+            bold: true,
+            italic: false,
+            underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        }
+    }
+
+    fn emphasis_style(&self) -> ThemeStyle {
+        ThemeStyle {
+            color: Self::YELLOW,
+            bold: false,
+            italic: true,
+            underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        }
+    }
+
+    fn link_style(&self) -> ThemeStyle {
+        ThemeStyle {
+            color: Self::CYAN,
+            bold: false,
+            italic: false,
+            underline: true,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        }
+    }
+
+    fn code_style(&self) -> ThemeStyle {
+        ThemeStyle {
+            color: Self::GREEN,
+            bold: false,
+            italic: false,
+            underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        }
+    }
+
+    fn code_background(&self) -> ThemeColor {
+        Self::BACKGROUND
+    }
+
+    fn list_marker_style(&self) -> ThemeStyle {
+        ThemeStyle {
+            color: Self::PINK,
+            bold: false,
+            italic: false,
+            underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        }
+    }
+
+    fn delimiter_style(&self) -> ThemeStyle {
+        ThemeStyle {
+            color: Self::COMMENT,
+            bold: false,
+            italic: false,
+            underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        }
+    }
+
+    fn text_style(&self) -> ThemeStyle {
+        ThemeStyle {
+            color: Self::FOREGROUND,
+            bold: false,
+            italic: false,
+            underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        }
+    }
+
+    fn focus_border_style(&self) -> ThemeStyle {
+        ThemeStyle {
+            color: Self::PURPLE,
+            bold: false,
+            italic: false,
+            underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        }
+    }
+
+    fn status_normal_color(&self) -> ThemeColor {
+        Self::GREEN
+    }
+
+    fn status_search_color(&self) -> ThemeColor {
+        Self::YELLOW
+    }
+
+    fn status_help_color(&self) -> ThemeColor {
+        Self::CYAN
+    }
+
+    fn status_error_color(&self) -> ThemeColor {
+        Self::RED
+    }
+
+    fn status_message_color(&self) -> ThemeColor {
+        Self::YELLOW
+    }
+
+    fn status_background_color(&self) -> ThemeColor {
+        Self::BACKGROUND
+    }
+
+    fn status_toc_color(&self) -> ThemeColor {
+        Self::CYAN
+    }
+
+    fn status_theme_color(&self) -> ThemeColor {
+        Self::PURPLE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_core_pairs_meet_aa(theme: &dyn MarkdownTheme) {
+        let bg = theme.code_background();
+        assert!(
+            meets_wcag_aa(&theme.text_style().color, &bg),
+            "text/background pair fails WCAG AA"
+        );
+        assert!(
+            meets_wcag_aa(&theme.code_style().color, &bg),
+            "code/background pair fails WCAG AA"
+        );
+    }
+
+    #[test]
+    fn test_bundled_presets_meet_aa() {
+        assert_core_pairs_meet_aa(&SolarizedOsaka);
+        assert_core_pairs_meet_aa(&Dracula);
+    }
+
+    #[test]
+    fn test_theme_preset_lookup() {
+        assert!(Theme::preset("solarized-dark").is_some());
+        assert!(Theme::preset("dracula").is_some());
+        assert!(Theme::preset("nonexistent").is_none());
+    }
+
+    #[test]
+    fn test_next_and_prev_preset_name_wrap_around() {
+        assert_eq!(Theme::next_preset_name("solarized-dark"), "dracula");
+        assert_eq!(Theme::next_preset_name("dracula"), "solarized-dark");
+        assert_eq!(Theme::prev_preset_name("solarized-dark"), "dracula");
+        assert_eq!(Theme::prev_preset_name("dracula"), "solarized-dark");
+    }
+
+    #[test]
+    fn test_next_preset_name_falls_back_to_first_for_unknown_current() {
+        assert_eq!(Theme::next_preset_name("nonexistent"), THEME_NAMES[0]);
+    }
+
+    #[test]
+    fn test_derive_dark_and_light_meet_aa() {
+        let accent = ThemeColor {
+            r: 100,
+            g: 150,
+            b: 220,
+        };
+
+        assert_core_pairs_meet_aa(&Theme::derive_dark(accent));
+        assert_core_pairs_meet_aa(&Theme::derive_light(accent));
+    }
+}
@@ -3,9 +3,17 @@ use super::color::ThemeColor;
 #[derive(Debug, Clone, Copy)]
 pub struct ThemeStyle {
     pub color: ThemeColor,
+    /// Background color, when the role should paint one (e.g. a search
+    /// match highlight); `None` leaves the terminal's own background.
+    pub bg: Option<ThemeColor>,
     pub bold: bool,
     pub italic: bool,
     pub underline: bool,
+    /// Swap foreground and background (the terminal "reverse video" attribute).
+    pub reverse: bool,
+    pub strikethrough: bool,
+    /// Render at reduced intensity (the terminal "dim" attribute).
+    pub dim: bool,
 }
 
 #[cfg(test)]
@@ -16,9 +24,13 @@ mod tests {
     fn test_theme_style_properties() {
         let style = ThemeStyle {
             color: ThemeColor { r: 255, g: 0, b: 0 },
+            bg: None,
             bold: true,
             italic: false,
             underline: true,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
 
         assert_eq!(style.color.r, 255);
@@ -28,4 +40,34 @@ mod tests {
         assert!(!style.italic);
         assert!(style.underline);
     }
+
+    #[test]
+    fn test_theme_style_new_fields_hold_assigned_values() {
+        let style = ThemeStyle {
+            color: ThemeColor { r: 0, g: 0, b: 0 },
+            bg: Some(ThemeColor {
+                r: 255,
+                g: 255,
+                b: 255,
+            }),
+            bold: false,
+            italic: false,
+            underline: false,
+            reverse: true,
+            strikethrough: true,
+            dim: true,
+        };
+
+        assert_eq!(
+            style.bg,
+            Some(ThemeColor {
+                r: 255,
+                g: 255,
+                b: 255
+            })
+        );
+        assert!(style.reverse);
+        assert!(style.strikethrough);
+        assert!(style.dim);
+    }
 }
@@ -18,6 +18,8 @@ pub trait MarkdownTheme {
     fn status_error_color(&self) -> ThemeColor;
     fn status_message_color(&self) -> ThemeColor;
     fn status_background_color(&self) -> ThemeColor;
+    fn status_toc_color(&self) -> ThemeColor;
+    fn status_theme_color(&self) -> ThemeColor;
 }
 
 /// Solarized Osaka theme implementation
@@ -77,6 +79,10 @@ impl SolarizedOsaka {
         bold: true,
         italic: false,
         underline: false,
+        bg: None,
+        reverse: false,
+        strikethrough: false,
+        dim: false,
     };
 
     const EMPHASIS: ThemeStyle = ThemeStyle {
@@ -84,6 +90,10 @@ impl SolarizedOsaka {
         bold: false,
         italic: true,
         underline: false,
+        bg: None,
+        reverse: false,
+        strikethrough: false,
+        dim: false,
     };
 
     const LINK: ThemeStyle = ThemeStyle {
@@ -91,6 +101,10 @@ impl SolarizedOsaka {
         bold: false,
         italic: false,
         underline: true,
+        bg: None,
+        reverse: false,
+        strikethrough: false,
+        dim: false,
     };
 
     const CODE: ThemeStyle = ThemeStyle {
@@ -98,6 +112,10 @@ impl SolarizedOsaka {
         bold: false,
         italic: false,
         underline: false,
+        bg: None,
+        reverse: false,
+        strikethrough: false,
+        dim: false,
     };
 
     const LIST_MARKER: ThemeStyle = ThemeStyle {
@@ -105,6 +123,10 @@ impl SolarizedOsaka {
         bold: false,
         italic: false,
         underline: false,
+        bg: None,
+        reverse: false,
+        strikethrough: false,
+        dim: false,
     };
 
     const DELIMITER: ThemeStyle = ThemeStyle {
@@ -112,6 +134,10 @@ impl SolarizedOsaka {
         bold: false,
         italic: false,
         underline: false,
+        bg: None,
+        reverse: false,
+        strikethrough: false,
+        dim: false,
     };
 
     const TEXT: ThemeStyle = ThemeStyle {
@@ -119,6 +145,10 @@ impl SolarizedOsaka {
         bold: false,
         italic: false,
         underline: false,
+        bg: None,
+        reverse: false,
+        strikethrough: false,
+        dim: false,
     };
 
     const FOCUS_BORDER: ThemeStyle = ThemeStyle {
@@ -126,6 +156,10 @@ impl SolarizedOsaka {
         bold: false,
         italic: false,
         underline: false,
+        bg: None,
+        reverse: false,
+        strikethrough: false,
+        dim: false,
     };
 }
 
@@ -144,6 +178,10 @@ impl MarkdownTheme for SolarizedOsaka {
             bold: level <= 2,
             italic: false,
             underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         }
     }
 
@@ -206,6 +244,14 @@ impl MarkdownTheme for SolarizedOsaka {
     fn status_background_color(&self) -> ThemeColor {
         Self::BASE02
     }
+
+    fn status_toc_color(&self) -> ThemeColor {
+        Self::CYAN
+    }
+
+    fn status_theme_color(&self) -> ThemeColor {
+        Self::MAGENTA
+    }
 }
 
 #[cfg(test)]
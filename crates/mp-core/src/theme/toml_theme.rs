@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use super::{color::ThemeColor, style::ThemeStyle, themes::MarkdownTheme};
+
+/// One role's entry in a theme TOML file: an `{ color = "#rrggbb", bold,
+/// italic, underline }` table. Every flag defaults to `false` when omitted.
+#[derive(Debug, Clone, Deserialize)]
+struct RoleConfig {
+    color: String,
+    #[serde(default)]
+    bold: bool,
+    #[serde(default)]
+    italic: bool,
+    #[serde(default)]
+    underline: bool,
+}
+
+impl RoleConfig {
+    fn into_style(self) -> Result<ThemeStyle> {
+        let color = ThemeColor::from_hex(&self.color)
+            .with_context(|| format!("invalid color `{}`", self.color))?;
+        Ok(ThemeStyle {
+            color,
+            bold: self.bold,
+            italic: self.italic,
+            underline: self.underline,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        })
+    }
+}
+
+/// Deserialized shape of a theme TOML document. Every role is optional;
+/// [`TomlTheme`] falls back to its base theme for anything left unset.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ThemeFile {
+    text: Option<RoleConfig>,
+    strong: Option<RoleConfig>,
+    emphasis: Option<RoleConfig>,
+    code: Option<RoleConfig>,
+    code_background: Option<String>,
+    link: Option<RoleConfig>,
+    list_marker: Option<RoleConfig>,
+    delimiter: Option<RoleConfig>,
+    /// Per-level heading overrides, keyed by level as a string (TOML table
+    /// keys can't be bare integers), e.g. `[heading.1]`.
+    #[serde(default)]
+    heading: HashMap<String, RoleConfig>,
+}
+
+/// A [`MarkdownTheme`] loaded from a TOML file mapping styled roles to
+/// colors, falling back to `base` for any role the file leaves unset. See
+/// [`TomlTheme::from_str`]/[`TomlTheme::from_path`].
+pub struct TomlTheme {
+    file: ThemeFile,
+    base: Box<dyn MarkdownTheme>,
+}
+
+impl TomlTheme {
+    /// Parses `toml` against the role schema, layering it over `base` for
+    /// any role left unset.
+    pub fn from_str(toml: &str, base: Box<dyn MarkdownTheme>) -> Result<Self> {
+        let file: ThemeFile = toml::from_str(toml).context("failed to parse theme TOML")?;
+        Ok(Self { file, base })
+    }
+
+    /// Like [`Self::from_str`], reading the TOML from `path`.
+    pub fn from_path(path: impl AsRef<Path>, base: Box<dyn MarkdownTheme>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read theme file {}", path.display()))?;
+        Self::from_str(&contents, base)
+    }
+
+    fn style_or(&self, role: &Option<RoleConfig>, fallback: ThemeStyle) -> ThemeStyle {
+        match role {
+            Some(role) => role.clone().into_style().unwrap_or(fallback),
+            None => fallback,
+        }
+    }
+}
+
+impl MarkdownTheme for TomlTheme {
+    fn heading_style(&self, level: u8) -> ThemeStyle {
+        match self.file.heading.get(&level.to_string()) {
+            Some(role) => role
+                .clone()
+                .into_style()
+                .unwrap_or_else(|_| self.base.heading_style(level)),
+            None => self.base.heading_style(level),
+        }
+    }
+
+    fn strong_style(&self) -> ThemeStyle {
+        self.style_or(&self.file.strong, self.base.strong_style())
+    }
+
+    fn emphasis_style(&self) -> ThemeStyle {
+        self.style_or(&self.file.emphasis, self.base.emphasis_style())
+    }
+
+    fn link_style(&self) -> ThemeStyle {
+        self.style_or(&self.file.link, self.base.link_style())
+    }
+
+    fn code_style(&self) -> ThemeStyle {
+        self.style_or(&self.file.code, self.base.code_style())
+    }
+
+    fn code_background(&self) -> ThemeColor {
+        self.file
+            .code_background
+            .as_deref()
+            .and_then(ThemeColor::from_hex)
+            .unwrap_or_else(|| self.base.code_background())
+    }
+
+    fn list_marker_style(&self) -> ThemeStyle {
+        self.style_or(&self.file.list_marker, self.base.list_marker_style())
+    }
+
+    fn delimiter_style(&self) -> ThemeStyle {
+        self.style_or(&self.file.delimiter, self.base.delimiter_style())
+    }
+
+    fn text_style(&self) -> ThemeStyle {
+        self.style_or(&self.file.text, self.base.text_style())
+    }
+
+    fn focus_border_style(&self) -> ThemeStyle {
+        self.base.focus_border_style()
+    }
+
+    fn status_normal_color(&self) -> ThemeColor {
+        self.base.status_normal_color()
+    }
+
+    fn status_search_color(&self) -> ThemeColor {
+        self.base.status_search_color()
+    }
+
+    fn status_help_color(&self) -> ThemeColor {
+        self.base.status_help_color()
+    }
+
+    fn status_error_color(&self) -> ThemeColor {
+        self.base.status_error_color()
+    }
+
+    fn status_message_color(&self) -> ThemeColor {
+        self.base.status_message_color()
+    }
+
+    fn status_background_color(&self) -> ThemeColor {
+        self.base.status_background_color()
+    }
+
+    fn status_toc_color(&self) -> ThemeColor {
+        self.base.status_toc_color()
+    }
+
+    fn status_theme_color(&self) -> ThemeColor {
+        self.base.status_theme_color()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::themes::SolarizedOsaka;
+
+    #[test]
+    fn test_overridden_roles_take_the_toml_value() {
+        let toml = r##"
+            [text]
+            color = "#ff0000"
+
+            [strong]
+            color = "#00ff00"
+            bold = true
+        "##;
+        let theme = TomlTheme::from_str(toml, Box::new(SolarizedOsaka)).unwrap();
+
+        assert_eq!(
+            theme.text_style().color,
+            ThemeColor {
+                r: 255,
+                g: 0,
+                b: 0
+            }
+        );
+        assert_eq!(
+            theme.strong_style().color,
+            ThemeColor { r: 0, g: 255, b: 0 }
+        );
+        assert!(theme.strong_style().bold);
+    }
+
+    #[test]
+    fn test_unset_roles_fall_back_to_the_base_theme() {
+        let theme = TomlTheme::from_str("", Box::new(SolarizedOsaka)).unwrap();
+
+        assert_eq!(theme.link_style().color, SolarizedOsaka.link_style().color);
+        assert_eq!(theme.code_background(), SolarizedOsaka.code_background());
+    }
+
+    #[test]
+    fn test_per_level_heading_override() {
+        let toml = r##"
+            [heading.1]
+            color = "#123456"
+        "##;
+        let theme = TomlTheme::from_str(toml, Box::new(SolarizedOsaka)).unwrap();
+
+        assert_eq!(
+            theme.heading_style(1).color,
+            ThemeColor::from_hex("#123456").unwrap()
+        );
+        assert_eq!(
+            theme.heading_style(2).color,
+            SolarizedOsaka.heading_style(2).color
+        );
+    }
+
+    #[test]
+    fn test_invalid_toml_is_an_error() {
+        assert!(TomlTheme::from_str("not valid toml = [", Box::new(SolarizedOsaka)).is_err());
+    }
+}
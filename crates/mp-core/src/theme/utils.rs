@@ -7,6 +7,10 @@ pub fn merge_styles(base: &ThemeStyle, overlay: &ThemeStyle) -> ThemeStyle {
         bold: overlay.bold || base.bold,
         italic: overlay.italic || base.italic,
         underline: overlay.underline || base.underline,
+        bg: overlay.bg.or(base.bg),
+        reverse: overlay.reverse || base.reverse,
+        strikethrough: overlay.strikethrough || base.strikethrough,
+        dim: overlay.dim || base.dim,
     }
 }
 
@@ -42,6 +46,10 @@ mod tests {
             bold: true,
             italic: false,
             underline: true,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
 
         let overlay = ThemeStyle {
@@ -53,6 +61,10 @@ mod tests {
             bold: false,
             italic: true,
             underline: false,
+            bg: None,
+            reverse: true,
+            strikethrough: false,
+            dim: false,
         };
 
         let merged = merge_styles(&base, &overlay);
@@ -60,6 +72,7 @@ mod tests {
         assert!(merged.bold); // base.bold || overlay.bold
         assert!(merged.italic); // overlay.italic
         assert!(merged.underline); // base.underline || overlay.underline
+        assert!(merged.reverse); // overlay.reverse || base.reverse
     }
 
     #[test]
@@ -73,6 +86,10 @@ mod tests {
             bold: true,
             italic: false,
             underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
 
         let dimmed = dim_style(&style);
@@ -95,6 +112,10 @@ mod tests {
             bold: false,
             italic: true,
             underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
 
         let highlighted = highlight_style(&style);
@@ -0,0 +1,852 @@
+use std::io::{Stdout, Write};
+
+use anyhow::Result;
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+
+use mp_core::html_entity::decode_html_entities;
+
+use crate::buffered_output::BufferedOutput;
+use crate::state::CodeBlockState;
+use crate::MarkdownRenderer;
+
+/// Per-element emission, factored out of the terminal-specific renderer so
+/// the same pulldown-cmark event loop can target more than ANSI output.
+/// Every method defaults to a no-op, so a backend only needs to implement
+/// the elements it cares about rendering.
+pub trait Backend {
+    fn heading_start(&mut self, _level: u8) -> Result<()> {
+        Ok(())
+    }
+    fn heading_end(&mut self, _level: u8) -> Result<()> {
+        Ok(())
+    }
+    fn paragraph_start(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn paragraph_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn strong_start(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn strong_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn emphasis_start(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn emphasis_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn list_start(&mut self, _start: Option<u64>) -> Result<()> {
+        Ok(())
+    }
+    fn list_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn list_item_start(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn list_item_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn block_quote_start(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn block_quote_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn link_start(&mut self, _url: &str) -> Result<()> {
+        Ok(())
+    }
+    fn link_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn image(&mut self, _url: &str, _alt: &str) -> Result<()> {
+        Ok(())
+    }
+    fn code_block(&mut self, _language: Option<&str>, _content: &str) -> Result<()> {
+        Ok(())
+    }
+    fn inline_code(&mut self, _code: &str) -> Result<()> {
+        Ok(())
+    }
+    fn horizontal_rule(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn task_marker(&mut self, _checked: bool) -> Result<()> {
+        Ok(())
+    }
+    fn table_start(&mut self, _alignments: &[Alignment]) -> Result<()> {
+        Ok(())
+    }
+    fn table_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn table_head_start(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn table_head_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn table_row_start(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn table_row_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn table_cell_start(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn table_cell_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn text(&mut self, _text: &str) -> Result<()> {
+        Ok(())
+    }
+    fn soft_break(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn hard_break(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Drive `content` through a single pulldown-cmark pass, dispatching each
+/// event to `backend`. This is the one event loop `TerminalBackend` and
+/// `HtmlBackend` both sit behind.
+pub fn render_with_backend<B: Backend>(
+    content: &str,
+    options: Options,
+    backend: &mut B,
+) -> Result<()> {
+    let mut code_block: Option<(Option<String>, String)> = None;
+    let mut pending_image: Option<(String, String)> = None;
+
+    for event in Parser::new_ext(content, options) {
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Heading { level, .. } => backend.heading_start(level as u8)?,
+                Tag::Paragraph => backend.paragraph_start()?,
+                Tag::Strong => backend.strong_start()?,
+                Tag::Emphasis => backend.emphasis_start()?,
+                Tag::List(start) => backend.list_start(start)?,
+                Tag::Item => backend.list_item_start()?,
+                Tag::BlockQuote(_) => backend.block_quote_start()?,
+                Tag::Link { dest_url, .. } => backend.link_start(dest_url.as_ref())?,
+                Tag::Image { dest_url, .. } => {
+                    pending_image = Some((dest_url.to_string(), String::new()));
+                }
+                Tag::CodeBlock(kind) => {
+                    let language = match kind {
+                        CodeBlockKind::Indented => None,
+                        CodeBlockKind::Fenced(lang) if !lang.is_empty() => {
+                            Some(lang.to_string())
+                        }
+                        CodeBlockKind::Fenced(_) => None,
+                    };
+                    code_block = Some((language, String::new()));
+                }
+                Tag::Table(alignments) => backend.table_start(&alignments)?,
+                Tag::TableHead => backend.table_head_start()?,
+                Tag::TableRow => backend.table_row_start()?,
+                Tag::TableCell => backend.table_cell_start()?,
+                _ => {}
+            },
+            Event::End(tag_end) => match tag_end {
+                TagEnd::Heading(level) => backend.heading_end(level as u8)?,
+                TagEnd::Paragraph => backend.paragraph_end()?,
+                TagEnd::Strong => backend.strong_end()?,
+                TagEnd::Emphasis => backend.emphasis_end()?,
+                TagEnd::List(_) => backend.list_end()?,
+                TagEnd::Item => backend.list_item_end()?,
+                TagEnd::BlockQuote(_) => backend.block_quote_end()?,
+                TagEnd::Link => backend.link_end()?,
+                TagEnd::Image => {
+                    if let Some((url, alt)) = pending_image.take() {
+                        backend.image(&url, &alt)?;
+                    }
+                }
+                TagEnd::CodeBlock => {
+                    if let Some((language, content)) = code_block.take() {
+                        backend.code_block(language.as_deref(), &content)?;
+                    }
+                }
+                TagEnd::Table => backend.table_end()?,
+                TagEnd::TableHead => backend.table_head_end()?,
+                TagEnd::TableRow => backend.table_row_end()?,
+                TagEnd::TableCell => backend.table_cell_end()?,
+                _ => {}
+            },
+            Event::Text(text) => {
+                if let Some((_, content)) = code_block.as_mut() {
+                    content.push_str(&text);
+                } else if let Some((_, alt)) = pending_image.as_mut() {
+                    alt.push_str(&text);
+                } else {
+                    backend.text(&text)?;
+                }
+            }
+            Event::Code(code) => backend.inline_code(&code)?,
+            Event::SoftBreak => backend.soft_break()?,
+            Event::HardBreak => backend.hard_break()?,
+            Event::Rule => backend.horizontal_rule()?,
+            Event::TaskListMarker(checked) => backend.task_marker(checked)?,
+            _ => {}
+        }
+    }
+
+    backend.finish()
+}
+
+/// The current ANSI terminal behavior, re-expressed as a [`Backend`] by
+/// delegating every method to an inner [`MarkdownRenderer`].
+pub struct TerminalBackend<W: Write = Stdout> {
+    renderer: MarkdownRenderer<W>,
+}
+
+impl TerminalBackend<Stdout> {
+    pub fn new() -> Self {
+        Self {
+            renderer: MarkdownRenderer::new(),
+        }
+    }
+}
+
+impl Default for TerminalBackend<Stdout> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write> TerminalBackend<W> {
+    pub fn with_output(output: BufferedOutput<W>) -> Self {
+        Self {
+            renderer: MarkdownRenderer::with_output(output),
+        }
+    }
+}
+
+impl<W: Write> Backend for TerminalBackend<W> {
+    fn heading_start(&mut self, level: u8) -> Result<()> {
+        self.renderer.render_heading_start(level)
+    }
+
+    fn heading_end(&mut self, _level: u8) -> Result<()> {
+        self.renderer.render_heading_end()
+    }
+
+    fn paragraph_end(&mut self) -> Result<()> {
+        self.renderer.output.newline()
+    }
+
+    fn strong_start(&mut self) -> Result<()> {
+        self.renderer.set_strong_emphasis(true);
+        Ok(())
+    }
+
+    fn strong_end(&mut self) -> Result<()> {
+        self.renderer.set_strong_emphasis(false);
+        Ok(())
+    }
+
+    fn emphasis_start(&mut self) -> Result<()> {
+        self.renderer.set_italic_emphasis(true);
+        Ok(())
+    }
+
+    fn emphasis_end(&mut self) -> Result<()> {
+        self.renderer.set_italic_emphasis(false);
+        Ok(())
+    }
+
+    fn list_start(&mut self, start: Option<u64>) -> Result<()> {
+        if !self.renderer.state.list_stack.is_empty() {
+            self.renderer.output.newline()?;
+        }
+        self.renderer.push_list(start);
+        Ok(())
+    }
+
+    fn list_end(&mut self) -> Result<()> {
+        self.renderer.pop_list();
+        if self.renderer.state.list_stack.is_empty() {
+            self.renderer.output.newline()?;
+        }
+        Ok(())
+    }
+
+    fn list_item_start(&mut self) -> Result<()> {
+        self.renderer.render_list_item()
+    }
+
+    fn block_quote_start(&mut self) -> Result<()> {
+        self.renderer.render_blockquote_start()
+    }
+
+    fn block_quote_end(&mut self) -> Result<()> {
+        self.renderer.output.newline()
+    }
+
+    fn link_start(&mut self, url: &str) -> Result<()> {
+        self.renderer.set_link(url.to_string());
+        Ok(())
+    }
+
+    fn link_end(&mut self) -> Result<()> {
+        self.renderer.render_link()
+    }
+
+    fn image(&mut self, url: &str, alt: &str) -> Result<()> {
+        self.renderer.set_image(url.to_string());
+        if let Some(image) = self.renderer.get_image_mut() {
+            image.alt_text = alt.to_string();
+        }
+        self.renderer.render_image()
+    }
+
+    fn code_block(&mut self, language: Option<&str>, content: &str) -> Result<()> {
+        let code_block = CodeBlockState {
+            language: language.map(String::from),
+            content: content.to_string(),
+        };
+        self.renderer.render_code_block(&code_block)
+    }
+
+    fn inline_code(&mut self, code: &str) -> Result<()> {
+        self.renderer.render_inline_code(code)
+    }
+
+    fn horizontal_rule(&mut self) -> Result<()> {
+        self.renderer.render_horizontal_rule()
+    }
+
+    fn task_marker(&mut self, checked: bool) -> Result<()> {
+        self.renderer.render_task_marker(checked)
+    }
+
+    fn table_start(&mut self, alignments: &[Alignment]) -> Result<()> {
+        self.renderer.set_table(alignments.to_vec());
+        Ok(())
+    }
+
+    fn table_end(&mut self) -> Result<()> {
+        if let Some(table) = self.renderer.get_table() {
+            self.renderer.render_formatted_table(&table)?;
+        }
+        self.renderer.clear_table();
+        self.renderer.output.newline()
+    }
+
+    fn table_head_end(&mut self) -> Result<()> {
+        if let Some(table) = self.renderer.get_table_mut() {
+            table.headers = table.current_row.clone();
+            table.current_row.clear();
+            table.is_header = false;
+        }
+        Ok(())
+    }
+
+    fn table_row_start(&mut self) -> Result<()> {
+        if let Some(table) = self.renderer.get_table_mut() {
+            table.current_row.clear();
+        }
+        Ok(())
+    }
+
+    fn table_row_end(&mut self) -> Result<()> {
+        if let Some(table) = self.renderer.get_table_mut()
+            && !table.current_row.is_empty()
+        {
+            table.rows.push(table.current_row.clone());
+            table.current_row.clear();
+        }
+        Ok(())
+    }
+
+    fn table_cell_start(&mut self) -> Result<()> {
+        if let Some(table) = self.renderer.get_table_mut() {
+            table.current_row.push(String::new());
+        }
+        Ok(())
+    }
+
+    fn text(&mut self, text: &str) -> Result<()> {
+        let decoded = decode_html_entities(text);
+        if !self.renderer.add_text_to_state(&decoded) {
+            self.renderer.render_styled_text(&decoded);
+        }
+        Ok(())
+    }
+
+    fn soft_break(&mut self) -> Result<()> {
+        self.renderer.output.write(" ")
+    }
+
+    fn hard_break(&mut self) -> Result<()> {
+        self.renderer.output.newline()
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        self.renderer.output.flush()
+    }
+}
+
+/// Renders the same event stream as semantic HTML5, escaping text and href
+/// attributes instead of emitting ANSI escapes.
+pub struct HtmlBackend<W: Write> {
+    writer: W,
+    list_stack: Vec<bool>,
+    in_table_head: bool,
+}
+
+impl<W: Write> HtmlBackend<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            list_stack: Vec::new(),
+            in_table_head: false,
+        }
+    }
+
+    fn emit(&mut self, text: &str) -> Result<()> {
+        self.writer.write_all(text.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Backend for HtmlBackend<W> {
+    fn heading_start(&mut self, level: u8) -> Result<()> {
+        self.emit(&format!("<h{level}>"))
+    }
+
+    fn heading_end(&mut self, level: u8) -> Result<()> {
+        self.emit(&format!("</h{level}>\n"))
+    }
+
+    fn paragraph_start(&mut self) -> Result<()> {
+        self.emit("<p>")
+    }
+
+    fn paragraph_end(&mut self) -> Result<()> {
+        self.emit("</p>\n")
+    }
+
+    fn strong_start(&mut self) -> Result<()> {
+        self.emit("<strong>")
+    }
+
+    fn strong_end(&mut self) -> Result<()> {
+        self.emit("</strong>")
+    }
+
+    fn emphasis_start(&mut self) -> Result<()> {
+        self.emit("<em>")
+    }
+
+    fn emphasis_end(&mut self) -> Result<()> {
+        self.emit("</em>")
+    }
+
+    fn list_start(&mut self, start: Option<u64>) -> Result<()> {
+        self.list_stack.push(start.is_some());
+        match start {
+            Some(1) | None => self.emit(if start.is_some() { "<ol>\n" } else { "<ul>\n" }),
+            Some(n) => self.emit(&format!("<ol start=\"{n}\">\n")),
+        }
+    }
+
+    fn list_end(&mut self) -> Result<()> {
+        let ordered = self.list_stack.pop().unwrap_or(false);
+        self.emit(if ordered { "</ol>\n" } else { "</ul>\n" })
+    }
+
+    fn list_item_start(&mut self) -> Result<()> {
+        self.emit("<li>")
+    }
+
+    fn list_item_end(&mut self) -> Result<()> {
+        self.emit("</li>\n")
+    }
+
+    fn block_quote_start(&mut self) -> Result<()> {
+        self.emit("<blockquote>\n")
+    }
+
+    fn block_quote_end(&mut self) -> Result<()> {
+        self.emit("</blockquote>\n")
+    }
+
+    fn link_start(&mut self, url: &str) -> Result<()> {
+        self.emit(&format!("<a href=\"{}\">", escape_html(url)))
+    }
+
+    fn link_end(&mut self) -> Result<()> {
+        self.emit("</a>")
+    }
+
+    fn image(&mut self, url: &str, alt: &str) -> Result<()> {
+        self.emit(&format!(
+            "<img src=\"{}\" alt=\"{}\">",
+            escape_html(url),
+            escape_html(alt)
+        ))
+    }
+
+    fn code_block(&mut self, language: Option<&str>, content: &str) -> Result<()> {
+        let class = language
+            .map(|lang| format!(" class=\"language-{}\"", escape_html(lang)))
+            .unwrap_or_default();
+        self.emit(&format!(
+            "<pre><code{class}>{}</code></pre>\n",
+            escape_html(content)
+        ))
+    }
+
+    fn inline_code(&mut self, code: &str) -> Result<()> {
+        self.emit(&format!("<code>{}</code>", escape_html(code)))
+    }
+
+    fn horizontal_rule(&mut self) -> Result<()> {
+        self.emit("<hr>\n")
+    }
+
+    fn task_marker(&mut self, checked: bool) -> Result<()> {
+        let checked_attr = if checked { " checked" } else { "" };
+        self.emit(&format!("<input type=\"checkbox\" disabled{checked_attr}> "))
+    }
+
+    fn table_start(&mut self, _alignments: &[Alignment]) -> Result<()> {
+        self.emit("<table>\n")
+    }
+
+    fn table_end(&mut self) -> Result<()> {
+        self.emit("</table>\n")
+    }
+
+    fn table_head_start(&mut self) -> Result<()> {
+        self.in_table_head = true;
+        self.emit("<thead>\n")
+    }
+
+    fn table_head_end(&mut self) -> Result<()> {
+        self.in_table_head = false;
+        self.emit("</thead>\n<tbody>\n")
+    }
+
+    fn table_row_start(&mut self) -> Result<()> {
+        self.emit("<tr>")
+    }
+
+    fn table_row_end(&mut self) -> Result<()> {
+        self.emit("</tr>\n")
+    }
+
+    fn table_cell_start(&mut self) -> Result<()> {
+        self.emit(if self.in_table_head { "<th>" } else { "<td>" })
+    }
+
+    fn table_cell_end(&mut self) -> Result<()> {
+        self.emit(if self.in_table_head { "</th>" } else { "</td>" })
+    }
+
+    fn text(&mut self, text: &str) -> Result<()> {
+        self.emit(&escape_html(text))
+    }
+
+    fn soft_break(&mut self) -> Result<()> {
+        self.emit(" ")
+    }
+
+    fn hard_break(&mut self) -> Result<()> {
+        self.emit("<br>\n")
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Strips all styling and emits readable plain text — blank lines between
+/// blocks, `- `/`1. ` list markers, `[x] `/`[ ] ` task markers, and fenced
+/// code content verbatim — so callers piping the output elsewhere, or test
+/// helpers asserting on rendered text, don't have to scrape ANSI escapes
+/// first.
+pub struct PlainTextBackend<W: Write> {
+    writer: W,
+    /// One entry per open list, `Some(next_number)` for an ordered list or
+    /// `None` for an unordered one.
+    list_stack: Vec<Option<u64>>,
+}
+
+impl<W: Write> PlainTextBackend<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            list_stack: Vec::new(),
+        }
+    }
+
+    fn emit(&mut self, text: &str) -> Result<()> {
+        self.writer.write_all(text.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Backend for PlainTextBackend<W> {
+    fn heading_end(&mut self, _level: u8) -> Result<()> {
+        self.emit("\n\n")
+    }
+
+    fn paragraph_end(&mut self) -> Result<()> {
+        self.emit("\n\n")
+    }
+
+    fn list_start(&mut self, start: Option<u64>) -> Result<()> {
+        self.list_stack.push(start);
+        Ok(())
+    }
+
+    fn list_end(&mut self) -> Result<()> {
+        self.list_stack.pop();
+        if self.list_stack.is_empty() {
+            self.emit("\n")
+        } else {
+            Ok(())
+        }
+    }
+
+    fn list_item_start(&mut self) -> Result<()> {
+        match self.list_stack.last_mut() {
+            Some(Some(number)) => {
+                let marker = format!("{number}. ");
+                *number += 1;
+                self.emit(&marker)
+            }
+            _ => self.emit("- "),
+        }
+    }
+
+    fn list_item_end(&mut self) -> Result<()> {
+        self.emit("\n")
+    }
+
+    fn block_quote_end(&mut self) -> Result<()> {
+        self.emit("\n")
+    }
+
+    fn image(&mut self, _url: &str, alt: &str) -> Result<()> {
+        self.emit(alt)
+    }
+
+    fn code_block(&mut self, _language: Option<&str>, content: &str) -> Result<()> {
+        self.emit(content)?;
+        self.emit("\n")
+    }
+
+    fn inline_code(&mut self, code: &str) -> Result<()> {
+        self.emit(code)
+    }
+
+    fn horizontal_rule(&mut self) -> Result<()> {
+        self.emit("\n")
+    }
+
+    fn task_marker(&mut self, checked: bool) -> Result<()> {
+        self.emit(if checked { "[x] " } else { "[ ] " })
+    }
+
+    fn table_row_end(&mut self) -> Result<()> {
+        self.emit("\n")
+    }
+
+    fn table_cell_end(&mut self) -> Result<()> {
+        self.emit(" ")
+    }
+
+    fn table_end(&mut self) -> Result<()> {
+        self.emit("\n")
+    }
+
+    fn text(&mut self, text: &str) -> Result<()> {
+        self.emit(&decode_html_entities(text))
+    }
+
+    fn soft_break(&mut self) -> Result<()> {
+        self.emit(" ")
+    }
+
+    fn hard_break(&mut self) -> Result<()> {
+        self.emit("\n")
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_html_escapes_special_characters() {
+        assert_eq!(
+            escape_html("<script>\"a\" & 'b'</script>"),
+            "&lt;script&gt;&quot;a&quot; &amp; &#39;b&#39;&lt;/script&gt;"
+        );
+    }
+
+    #[test]
+    fn test_html_backend_renders_heading_and_paragraph() {
+        let mut output = Vec::new();
+        {
+            let mut backend = HtmlBackend::new(&mut output);
+            render_with_backend("# Title\n\nBody text.\n", Options::empty(), &mut backend)
+                .unwrap();
+        }
+        let html = String::from_utf8(output).unwrap();
+        assert_eq!(html, "<h1>Title</h1>\n<p>Body text.</p>\n");
+    }
+
+    #[test]
+    fn test_html_backend_escapes_link_text_and_href() {
+        let mut output = Vec::new();
+        {
+            let mut backend = HtmlBackend::new(&mut output);
+            render_with_backend(
+                "[A & B](http://example.com?x=1&y=2)\n",
+                Options::empty(),
+                &mut backend,
+            )
+            .unwrap();
+        }
+        let html = String::from_utf8(output).unwrap();
+        assert!(html.contains("href=\"http://example.com?x=1&amp;y=2\""));
+        assert!(html.contains("A &amp; B"));
+    }
+
+    #[test]
+    fn test_html_backend_renders_fenced_code_block_with_language_class() {
+        let mut output = Vec::new();
+        {
+            let mut backend = HtmlBackend::new(&mut output);
+            render_with_backend(
+                "```rust\nfn main() {}\n```\n",
+                Options::empty(),
+                &mut backend,
+            )
+            .unwrap();
+        }
+        let html = String::from_utf8(output).unwrap();
+        assert!(html.contains("<pre><code class=\"language-rust\">"));
+        assert!(html.contains("fn main() {}"));
+    }
+
+    #[test]
+    fn test_html_backend_renders_list() {
+        let mut output = Vec::new();
+        {
+            let mut backend = HtmlBackend::new(&mut output);
+            render_with_backend("- one\n- two\n", Options::empty(), &mut backend).unwrap();
+        }
+        let html = String::from_utf8(output).unwrap();
+        assert!(html.starts_with("<ul>\n<li>one</li>\n<li>two</li>\n</ul>\n"));
+    }
+
+    #[test]
+    fn test_plain_text_backend_strips_emphasis_and_strong() {
+        let mut output = Vec::new();
+        {
+            let mut backend = PlainTextBackend::new(&mut output);
+            render_with_backend(
+                "# Title\n\nSome **bold** and *italic* text.\n",
+                Options::empty(),
+                &mut backend,
+            )
+            .unwrap();
+        }
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text, "Title\n\nSome bold and italic text.\n\n");
+    }
+
+    #[test]
+    fn test_plain_text_backend_renders_ordered_and_unordered_lists() {
+        let mut output = Vec::new();
+        {
+            let mut backend = PlainTextBackend::new(&mut output);
+            render_with_backend("1. one\n2. two\n", Options::empty(), &mut backend).unwrap();
+        }
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text, "1. one\n2. two\n\n");
+
+        let mut output = Vec::new();
+        {
+            let mut backend = PlainTextBackend::new(&mut output);
+            render_with_backend("- one\n- two\n", Options::empty(), &mut backend).unwrap();
+        }
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text, "- one\n- two\n\n");
+    }
+
+    #[test]
+    fn test_plain_text_backend_renders_link_and_image_as_their_text() {
+        let mut output = Vec::new();
+        {
+            let mut backend = PlainTextBackend::new(&mut output);
+            render_with_backend(
+                "[link text](http://example.com) and ![alt text](http://example.com/img.png)\n",
+                Options::empty(),
+                &mut backend,
+            )
+            .unwrap();
+        }
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text, "link text and alt text\n\n");
+    }
+
+    #[test]
+    fn test_plain_text_backend_renders_fenced_code_block_verbatim() {
+        let mut output = Vec::new();
+        {
+            let mut backend = PlainTextBackend::new(&mut output);
+            render_with_backend("```rust\nfn main() {}\n```\n", Options::empty(), &mut backend)
+                .unwrap();
+        }
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text, "fn main() {}\n\n");
+    }
+
+    #[test]
+    fn test_plain_text_backend_renders_task_markers() {
+        let mut options = Options::empty();
+        options.insert(Options::ENABLE_TASKLISTS);
+        let mut output = Vec::new();
+        {
+            let mut backend = PlainTextBackend::new(&mut output);
+            render_with_backend("- [x] done\n- [ ] todo\n", options, &mut backend).unwrap();
+        }
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text, "- [x] done\n- [ ] todo\n\n");
+    }
+}
@@ -6,6 +6,8 @@ const DEFAULT_BUFFER_SIZE: usize = 8192;
 
 pub struct BufferedOutput<W: Write> {
     writer: BufWriter<W>,
+    capture: Option<String>,
+    lines_written: usize,
 }
 
 impl<W: Write> BufferedOutput<W> {
@@ -16,24 +18,66 @@ impl<W: Write> BufferedOutput<W> {
     pub fn with_capacity(capacity: usize, writer: W) -> Self {
         Self {
             writer: BufWriter::with_capacity(capacity, writer),
+            capture: None,
+            lines_written: 0,
         }
     }
 
+    /// Redirects subsequent `write`/`writeln`/`newline` calls into an
+    /// in-memory buffer instead of the underlying writer, until
+    /// `stop_capture` is called. Used to collect rendered output (e.g. a
+    /// footnote definition's body) for later placement instead of writing
+    /// it in place.
+    pub fn start_capture(&mut self) {
+        self.capture = Some(String::new());
+    }
+
+    /// Stops redirecting writes and returns everything captured since
+    /// `start_capture`. Returns an empty string if capture was never
+    /// started.
+    pub fn stop_capture(&mut self) -> String {
+        self.capture.take().unwrap_or_default()
+    }
+
     pub fn writeln(&mut self, content: &str) -> Result<()> {
+        if let Some(buffer) = self.capture.as_mut() {
+            buffer.push_str(content);
+            buffer.push('\n');
+            return Ok(());
+        }
         writeln!(self.writer, "{}", content)?;
+        self.lines_written += content.matches('\n').count() + 1;
         Ok(())
     }
 
     pub fn write(&mut self, content: &str) -> Result<()> {
+        if let Some(buffer) = self.capture.as_mut() {
+            buffer.push_str(content);
+            return Ok(());
+        }
         write!(self.writer, "{}", content)?;
+        self.lines_written += content.matches('\n').count();
         Ok(())
     }
 
     pub fn newline(&mut self) -> Result<()> {
+        if let Some(buffer) = self.capture.as_mut() {
+            buffer.push('\n');
+            return Ok(());
+        }
         writeln!(self.writer)?;
+        self.lines_written += 1;
         Ok(())
     }
 
+    /// Number of newlines written to the underlying writer so far (writes
+    /// redirected by [`Self::start_capture`] don't count). Used by
+    /// [`crate::MarkdownRenderer::render_content_with_line_map`] to note
+    /// which output line each source event's content landed on.
+    pub fn line_count(&self) -> usize {
+        self.lines_written
+    }
+
     pub fn flush(&mut self) -> Result<()> {
         self.writer.flush()?;
         Ok(())
@@ -124,6 +168,44 @@ mod tests {
         assert_eq!(result, "Hello, World!\n");
     }
 
+    #[test]
+    fn test_capture_redirects_writes_away_from_the_underlying_writer() {
+        let (mock_writer, buffer_ref) = MockWriter::new();
+        let mut output = BufferedOutput::new(mock_writer);
+
+        output.write("before ").unwrap();
+        output.start_capture();
+        output.write("captured").unwrap();
+        output.newline().unwrap();
+        let captured = output.stop_capture();
+        output.writeln("after").unwrap();
+        output.flush().unwrap();
+
+        assert_eq!(captured, "captured\n");
+        let result = String::from_utf8_lossy(&buffer_ref.lock().unwrap());
+        assert_eq!(result, "before after\n");
+    }
+
+    #[test]
+    fn test_line_count_tracks_newlines_written_and_ignores_captured_writes() {
+        let (mock_writer, _buffer_ref) = MockWriter::new();
+        let mut output = BufferedOutput::new(mock_writer);
+
+        output.writeln("first").unwrap();
+        assert_eq!(output.line_count(), 1);
+
+        output.write("no newline yet").unwrap();
+        assert_eq!(output.line_count(), 1);
+
+        output.newline().unwrap();
+        assert_eq!(output.line_count(), 2);
+
+        output.start_capture();
+        output.writeln("captured, shouldn't count").unwrap();
+        output.stop_capture();
+        assert_eq!(output.line_count(), 2);
+    }
+
     #[test]
     fn test_buffered_output_with_capacity() {
         let (mock_writer, buffer_ref) = MockWriter::new();
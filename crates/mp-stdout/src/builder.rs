@@ -2,9 +2,9 @@ use std::io::{Stdout, Write};
 
 use pulldown_cmark::Options;
 
-use mp_core::theme::SolarizedOsaka;
+use mp_core::theme::{MarkdownTheme, SolarizedOsaka, Theme};
 
-use crate::{BufferedOutput, MarkdownRenderer, RenderConfig, RenderState};
+use crate::{BufferedOutput, ColorMode, HtmlRenderer, MarkdownRenderer, RenderConfig, RenderState};
 
 /// # Example
 /// ```
@@ -19,7 +19,7 @@ use crate::{BufferedOutput, MarkdownRenderer, RenderConfig, RenderState};
 ///     .build();
 /// ```
 pub struct RendererBuilder<W: Write = Stdout> {
-    theme: Option<SolarizedOsaka>,
+    theme: Option<Box<dyn MarkdownTheme>>,
     options: Option<Options>,
     config: Option<RenderConfig>,
     writer: Option<W>,
@@ -28,6 +28,10 @@ pub struct RendererBuilder<W: Write = Stdout> {
     enable_tables: bool,
     enable_tasklists: bool,
     enable_footnotes: bool,
+    toc: bool,
+    syntax_highlight: bool,
+    syntax_theme: Option<String>,
+    color_mode: Option<ColorMode>,
 }
 
 impl Default for RendererBuilder<Stdout> {
@@ -48,11 +52,18 @@ impl RendererBuilder<Stdout> {
             enable_tables: true,
             enable_tasklists: true,
             enable_footnotes: true,
+            toc: false,
+            syntax_highlight: true,
+            syntax_theme: None,
+            color_mode: None,
         }
     }
 
-    pub fn build(self) -> MarkdownRenderer<Stdout> {
+    pub fn build(mut self) -> MarkdownRenderer<Stdout> {
         let options = self.build_options();
+        let highlighter = self.build_highlighter();
+        let config = self.build_config();
+        let theme = self.take_theme();
 
         let output = if let Some(size) = self.buffer_size {
             BufferedOutput::stdout_with_capacity(size)
@@ -61,13 +72,26 @@ impl RendererBuilder<Stdout> {
         };
 
         MarkdownRenderer {
-            theme: self.theme.unwrap_or_default(),
+            theme,
             state: RenderState::default(),
             options,
-            config: self.config.unwrap_or_default(),
+            config,
             output,
+            highlighter,
+            handler: std::rc::Rc::new(crate::handler::DefaultHandler),
+            footnotes: crate::footnotes::FootnoteCollector::default(),
+            transform: None,
         }
     }
+
+    /// Like [`Self::build`], but targets a standalone HTML document instead
+    /// of the terminal, using the same `Options` this builder would give a
+    /// terminal renderer.
+    pub fn build_html(mut self) -> HtmlRenderer<Stdout> {
+        let options = self.build_options();
+        let theme = self.take_theme();
+        HtmlRenderer::new(theme, options)
+    }
 }
 
 impl<W: Write> RendererBuilder<W> {
@@ -82,6 +106,25 @@ impl<W: Write> RendererBuilder<W> {
             enable_tables: true,
             enable_tasklists: true,
             enable_footnotes: true,
+            toc: false,
+            syntax_highlight: true,
+            syntax_theme: None,
+            color_mode: None,
+        }
+    }
+
+    fn build_config(&self) -> RenderConfig {
+        let mut config = self.config.clone().unwrap_or_default();
+        config.toc = self.toc;
+        config.syntax_highlight = self.syntax_highlight;
+        config.color_mode = self.color_mode.unwrap_or_else(ColorMode::detect);
+        config
+    }
+
+    fn build_highlighter(&self) -> crate::highlight::SyntaxHighlighter {
+        match &self.syntax_theme {
+            Some(theme_name) => crate::highlight::SyntaxHighlighter::with_theme(theme_name),
+            None => crate::highlight::SyntaxHighlighter::new(),
         }
     }
 
@@ -104,11 +147,35 @@ impl<W: Write> RendererBuilder<W> {
         })
     }
 
-    pub fn theme(mut self, theme: SolarizedOsaka) -> Self {
-        self.theme = Some(theme);
+    pub fn theme(mut self, theme: impl MarkdownTheme + 'static) -> Self {
+        self.theme = Some(Box::new(theme));
         self
     }
 
+    /// Selects a bundled preset by name (see [`mp_core::theme::THEME_NAMES`]),
+    /// falling back to the default theme for an unrecognized name.
+    pub fn theme_by_name(mut self, name: &str) -> Self {
+        self.theme = Theme::preset(name);
+        self
+    }
+
+    /// Loads a custom theme from a TOML file (see [`mp_core::theme::TomlTheme`]),
+    /// layered over the default theme for any role the file leaves unset.
+    /// Falls back to the default theme if the file can't be read or parsed.
+    pub fn theme_from_toml(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.theme = mp_core::theme::TomlTheme::from_path(path, Box::new(SolarizedOsaka))
+            .ok()
+            .map(|theme| Box::new(theme) as Box<dyn MarkdownTheme>);
+        self
+    }
+
+    /// Consumes the configured theme, falling back to [`SolarizedOsaka`].
+    fn take_theme(&mut self) -> Box<dyn MarkdownTheme> {
+        self.theme
+            .take()
+            .unwrap_or_else(|| Box::new(SolarizedOsaka))
+    }
+
     pub fn config(mut self, config: RenderConfig) -> Self {
         self.config = Some(config);
         self
@@ -139,13 +206,47 @@ impl<W: Write> RendererBuilder<W> {
         self
     }
 
+    /// Emit a table of contents built from the document's headings before
+    /// the rendered body.
+    pub fn toc(mut self, enable: bool) -> Self {
+        self.toc = enable;
+        self
+    }
+
+    /// Colorize fenced code blocks via `syntect`. Disable for non-TTY
+    /// writers, where the uniform `TextStyle::Code` fallback is preferable.
+    pub fn syntax_highlight(mut self, enable: bool) -> Self {
+        self.syntax_highlight = enable;
+        self
+    }
+
+    /// Picks the `syntect` theme fenced code blocks are highlighted with
+    /// (one of the names `syntect::highlighting::ThemeSet::load_defaults`
+    /// bundles, e.g. `"base16-ocean.dark"`). Falls back to that default for
+    /// an unrecognized name.
+    pub fn syntax_theme(mut self, theme_name: impl Into<String>) -> Self {
+        self.syntax_theme = Some(theme_name.into());
+        self
+    }
+
+    /// Overrides the renderer's [`ColorMode`], which governs how much color
+    /// styled output carries (truecolor, downsampled 256/16-color, or none).
+    /// Falls back to [`ColorMode::detect`] if never called.
+    pub fn color_mode(mut self, mode: ColorMode) -> Self {
+        self.color_mode = Some(mode);
+        self
+    }
+
     pub fn options(mut self, options: Options) -> Self {
         self.options = Some(options);
         self
     }
 
-    pub fn build_with_writer(self) -> MarkdownRenderer<W> {
+    pub fn build_with_writer(mut self) -> MarkdownRenderer<W> {
         let options = self.build_options();
+        let highlighter = self.build_highlighter();
+        let config = self.build_config();
+        let theme = self.take_theme();
 
         let writer = self
             .writer
@@ -157,13 +258,28 @@ impl<W: Write> RendererBuilder<W> {
         };
 
         MarkdownRenderer {
-            theme: self.theme.unwrap_or_default(),
+            theme,
             state: RenderState::default(),
             options,
-            config: self.config.unwrap_or_default(),
+            config,
             output,
+            highlighter,
+            handler: std::rc::Rc::new(crate::handler::DefaultHandler),
+            footnotes: crate::footnotes::FootnoteCollector::default(),
+            transform: None,
         }
     }
+
+    /// Like [`Self::build_with_writer`], but targets a standalone HTML
+    /// document instead of the terminal.
+    pub fn build_html_with_writer(mut self) -> HtmlRenderer<W> {
+        let options = self.build_options();
+        let theme = self.take_theme();
+        let writer = self
+            .writer
+            .expect("Writer must be provided via with_writer()");
+        HtmlRenderer::with_writer(theme, options, writer)
+    }
 }
 
 #[cfg(test)]
@@ -207,6 +323,74 @@ mod tests {
         assert_eq!(renderer.config.table_separator, config.table_separator);
     }
 
+    #[test]
+    fn test_builder_with_toc_enabled() {
+        let renderer = RendererBuilder::new().toc(true).build();
+        assert!(renderer.config.toc);
+    }
+
+    #[test]
+    fn test_builder_with_syntax_highlight_disabled() {
+        let renderer = RendererBuilder::new().syntax_highlight(false).build();
+        assert!(!renderer.config.syntax_highlight);
+    }
+
+    #[test]
+    fn test_builder_with_syntax_theme() {
+        let renderer = RendererBuilder::new().syntax_theme("base16-eighties.dark").build();
+        assert!(renderer.config.syntax_highlight);
+    }
+
+    #[test]
+    fn test_builder_with_color_mode() {
+        let renderer = RendererBuilder::new()
+            .color_mode(ColorMode::Ansi256)
+            .build();
+        assert_eq!(renderer.config.color_mode, ColorMode::Ansi256);
+    }
+
+    #[test]
+    fn test_builder_color_mode_defaults_to_detect() {
+        let renderer = RendererBuilder::new().build();
+        assert_eq!(renderer.config.color_mode, ColorMode::detect());
+    }
+
+    #[test]
+    fn test_builder_with_named_theme() {
+        let renderer = RendererBuilder::new().theme_by_name("dracula").build();
+        assert_eq!(
+            renderer.theme.text_style().color,
+            mp_core::theme::Dracula.text_style().color
+        );
+    }
+
+    #[test]
+    fn test_builder_with_unknown_theme_name_falls_back_to_default() {
+        let renderer = RendererBuilder::new().theme_by_name("not-a-theme").build();
+        assert_eq!(
+            renderer.theme.text_style().color,
+            SolarizedOsaka.text_style().color
+        );
+    }
+
+    #[test]
+    fn test_builder_with_theme_from_toml() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("theme.toml");
+        std::fs::write(&path, "[text]\ncolor = \"#ff00ff\"\n").unwrap();
+
+        let renderer = RendererBuilder::new().theme_from_toml(&path).build();
+
+        assert_eq!(
+            renderer.theme.text_style().color,
+            mp_core::theme::ThemeColor {
+                r: 255,
+                g: 0,
+                b: 255
+            }
+        );
+    }
+
     #[test]
     fn test_builder_with_custom_writer() {
         let writer = Vec::with_capacity(256);
@@ -217,4 +401,16 @@ mod tests {
         assert!(!renderer.options.contains(Options::ENABLE_TABLES));
         assert!(renderer.options.contains(Options::ENABLE_STRIKETHROUGH));
     }
+
+    #[test]
+    fn test_build_html_with_writer_renders_a_standalone_document() {
+        let mut output = Vec::new();
+        let mut renderer = RendererBuilder::with_writer(&mut output).build_html_with_writer();
+        renderer.render_content("# Title\n").unwrap();
+        drop(renderer);
+
+        let html = String::from_utf8(output).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h1>Title</h1>"));
+    }
 }
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::theme_adapter::ColorMode;
+
 #[derive(Debug, Clone)]
 pub struct RenderConfig {
     pub indent_width: usize,
@@ -7,6 +9,25 @@ pub struct RenderConfig {
     pub table_separator: &'static str,
 
     pub table_alignment: TableAlignmentConfig,
+
+    /// Emit a table of contents, built from the document's headings, before
+    /// the rendered body.
+    pub toc: bool,
+
+    /// Maximum display width, in columns, a rendered table may use before
+    /// its cells are wrapped across multiple physical lines. `None` leaves
+    /// tables unwrapped regardless of terminal width.
+    pub max_table_width: Option<usize>,
+
+    /// Colorize fenced code blocks with the renderer's `syntect`-backed
+    /// highlighter. Off falls back to the uniform `TextStyle::Code`
+    /// coloring, useful for non-TTY writers.
+    pub syntax_highlight: bool,
+
+    /// How much color [`crate::theme_adapter::styled_text`] is allowed to
+    /// emit, independent of `syntax_highlight`. Defaults to
+    /// [`ColorMode::detect`], so piped/non-TTY output degrades gracefully.
+    pub color_mode: ColorMode,
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +44,10 @@ impl Default for RenderConfig {
             indent_width: 2,
             table_separator: "|",
             table_alignment: TableAlignmentConfig::default(),
+            toc: false,
+            max_table_width: None,
+            syntax_highlight: true,
+            color_mode: ColorMode::detect(),
         }
     }
 }
@@ -73,6 +98,8 @@ mod tests {
         let config = RenderConfig::default();
         assert_eq!(config.indent_width, 2);
         assert_eq!(config.table_separator, "|");
+        assert!(config.syntax_highlight);
+        assert_eq!(config.color_mode, ColorMode::detect());
     }
 
     #[test]
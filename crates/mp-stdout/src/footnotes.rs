@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+/// Tracks footnote references and definitions seen while rendering, so a
+/// "Notes" section listing every definition by its first-seen-order number
+/// can be emitted once at the end of the document instead of wherever
+/// pulldown-cmark happens to emit the definition.
+#[derive(Debug, Default)]
+pub(crate) struct FootnoteCollector {
+    order: Vec<String>,
+    numbers: HashMap<String, usize>,
+    definitions: HashMap<String, String>,
+    pending: Option<String>,
+}
+
+impl FootnoteCollector {
+    /// Registers a reference to `label`, assigning it the next number the
+    /// first time it's seen, and returns that number either way.
+    pub(crate) fn reference(&mut self, label: &str) -> usize {
+        if let Some(&number) = self.numbers.get(label) {
+            return number;
+        }
+        let number = self.order.len() + 1;
+        self.order.push(label.to_string());
+        self.numbers.insert(label.to_string(), number);
+        number
+    }
+
+    /// Marks `label` as the definition currently being captured; its
+    /// rendered body is recorded once `finish_definition` is called.
+    pub(crate) fn begin_definition(&mut self, label: &str) {
+        self.reference(label);
+        self.pending = Some(label.to_string());
+    }
+
+    /// Stores `body` as the rendered content for whichever definition
+    /// `begin_definition` most recently started.
+    pub(crate) fn finish_definition(&mut self, body: String) {
+        if let Some(label) = self.pending.take() {
+            self.definitions.insert(label, body);
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Every footnote in assigned-number order, with its rendered body if
+    /// one was ever defined (`None` for a reference that was never backed
+    /// by a matching `[^label]: ...` definition).
+    pub(crate) fn entries(&self) -> Vec<(usize, String, Option<String>)> {
+        self.order
+            .iter()
+            .enumerate()
+            .map(|(i, label)| (i + 1, label.clone(), self.definitions.get(label).cloned()))
+            .collect()
+    }
+}
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// Converts `number` to Unicode superscript digits for an inline marker,
+/// e.g. `12` -> `"¹²"`.
+pub(crate) fn to_superscript(number: usize) -> String {
+    number
+        .to_string()
+        .chars()
+        .map(|digit| SUPERSCRIPT_DIGITS[digit.to_digit(10).unwrap_or(0) as usize])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_assigns_numbers_in_first_seen_order() {
+        let mut footnotes = FootnoteCollector::default();
+        assert_eq!(footnotes.reference("b"), 1);
+        assert_eq!(footnotes.reference("a"), 2);
+        assert_eq!(footnotes.reference("b"), 1);
+    }
+
+    #[test]
+    fn test_definition_never_referenced_still_gets_a_number() {
+        let mut footnotes = FootnoteCollector::default();
+        footnotes.begin_definition("a");
+        footnotes.finish_definition("body".to_string());
+
+        let entries = footnotes.entries();
+        assert_eq!(entries, vec![(1, "a".to_string(), Some("body".to_string()))]);
+    }
+
+    #[test]
+    fn test_reference_with_no_definition_has_none_body() {
+        let mut footnotes = FootnoteCollector::default();
+        footnotes.reference("missing");
+
+        let entries = footnotes.entries();
+        assert_eq!(entries, vec![(1, "missing".to_string(), None)]);
+    }
+
+    #[test]
+    fn test_to_superscript_handles_multiple_digits() {
+        assert_eq!(to_superscript(1), "¹");
+        assert_eq!(to_superscript(12), "¹²");
+    }
+}
@@ -1,4 +1,5 @@
 use std::io::Write;
+use std::rc::Rc;
 
 use anyhow::Result;
 use pulldown_cmark::{Alignment, Event, Tag, TagEnd};
@@ -8,11 +9,14 @@ use mp_core::theme::MarkdownTheme;
 
 use super::{
     MarkdownRenderer,
+    handler::RenderHandler,
     state::{CodeBlockState, ContentType},
     styling::TextStyle,
 };
+use crate::footnotes;
 use crate::output::{ElementKind, ElementPhase, OutputType, TableVariant};
 use crate::theme_adapter::CrosstermAdapter;
+use crate::width;
 
 impl<W: Write> MarkdownRenderer<W> {
     pub fn process_event(&mut self, event: Event) -> Result<()> {
@@ -26,10 +30,7 @@ impl<W: Write> MarkdownRenderer<W> {
             Event::HardBreak => self.handle_content(ContentType::HardBreak),
             Event::Rule => self.handle_content(ContentType::Rule),
             Event::TaskListMarker(checked) => self.handle_content(ContentType::TaskMarker(checked)),
-            Event::FootnoteReference(label) => {
-                let footnote_text = format!("[^{}]", label.as_ref());
-                self.handle_content(ContentType::Text(&footnote_text))
-            }
+            Event::FootnoteReference(label) => self.render_footnote_reference(label.as_ref()),
             _ => Ok(()),
         }
     }
@@ -42,6 +43,7 @@ impl<W: Write> MarkdownRenderer<W> {
             Tag::Paragraph => self.handle_element(ElementKind::Paragraph, ElementPhase::Start)?,
             Tag::Strong => self.set_strong_emphasis(true),
             Tag::Emphasis => self.set_italic_emphasis(true),
+            Tag::Strikethrough => self.set_strikethrough(true),
             Tag::Link { dest_url, .. } => self.set_link(dest_url.as_ref().to_owned()),
             Tag::List(start) => self.handle_list_start(start),
             Tag::Item => self.handle_element(ElementKind::ListItem, ElementPhase::Start)?,
@@ -66,8 +68,8 @@ impl<W: Write> MarkdownRenderer<W> {
             }
             Tag::Image { dest_url, .. } => self.set_image(dest_url.as_ref().to_owned()),
             Tag::FootnoteDefinition(label) => {
-                self.output.newline().ok();
-                self.output.write(&format!("[{}]: ", label.as_ref())).ok();
+                self.footnotes.begin_definition(label.as_ref());
+                self.output.start_capture();
             }
             _ => {}
         }
@@ -82,6 +84,7 @@ impl<W: Write> MarkdownRenderer<W> {
             TagEnd::Paragraph => self.handle_element(ElementKind::Paragraph, ElementPhase::End)?,
             TagEnd::Strong => self.set_strong_emphasis(false),
             TagEnd::Emphasis => self.set_italic_emphasis(false),
+            TagEnd::Strikethrough => self.set_strikethrough(false),
             TagEnd::Link => self.print_output(OutputType::Link)?,
             TagEnd::List(_) => self.handle_list_end(),
             TagEnd::Item => self.handle_element(ElementKind::ListItem, ElementPhase::End)?,
@@ -101,7 +104,8 @@ impl<W: Write> MarkdownRenderer<W> {
             }
             TagEnd::Image => self.print_output(OutputType::Image)?,
             TagEnd::FootnoteDefinition => {
-                self.output.newline().ok();
+                let body = self.output.stop_capture();
+                self.footnotes.finish_definition(body);
             }
             _ => {}
         }
@@ -109,7 +113,14 @@ impl<W: Write> MarkdownRenderer<W> {
     }
 
     fn handle_element(&mut self, kind: ElementKind, phase: ElementPhase) -> Result<()> {
-        self.print_output(OutputType::Element { kind, phase })
+        let handler = Rc::clone(&self.handler);
+        match kind {
+            ElementKind::Heading(level) => handler.heading(self, level, phase),
+            ElementKind::Paragraph => handler.paragraph(self, phase),
+            ElementKind::ListItem => handler.list_item(self, phase),
+            ElementKind::BlockQuote => handler.block_quote(self, phase),
+            ElementKind::Table(variant) => handler.table_variant(self, variant),
+        }
     }
 
     fn handle_list_start(&mut self, start: Option<u64>) {
@@ -202,18 +213,28 @@ impl<W: Write> MarkdownRenderer<W> {
     }
 
     pub fn print_output(&mut self, output_type: OutputType) -> Result<()> {
+        let handler = Rc::clone(&self.handler);
         match output_type {
-            OutputType::Element { kind, phase } => self.handle_element_output(kind, phase),
-            OutputType::HorizontalRule => self.render_horizontal_rule(),
-            OutputType::InlineCode { ref code } => self.render_inline_code(code),
-            OutputType::TaskMarker { checked } => self.render_task_marker(checked),
-            OutputType::Link => self.render_link(),
-            OutputType::Image => self.render_image(),
-            OutputType::CodeBlock => self.render_code_block_output(),
+            OutputType::Element { kind, phase } => self.handle_element(kind, phase),
+            OutputType::HorizontalRule => handler.horizontal_rule(self),
+            OutputType::InlineCode { ref code } => handler.inline_code(self, code),
+            OutputType::TaskMarker { checked } => handler.task_marker(self, checked),
+            OutputType::Link => match self.get_link() {
+                Some(link) => handler.link(self, &link),
+                None => Ok(()),
+            },
+            OutputType::Image => match self.get_image() {
+                Some(image) => handler.image(self, &image),
+                None => Ok(()),
+            },
+            OutputType::CodeBlock => match self.get_code_block() {
+                Some(code_block) => handler.code_block(self, &code_block),
+                None => Ok(()),
+            },
         }
     }
 
-    fn render_horizontal_rule(&mut self) -> Result<()> {
+    pub(crate) fn render_horizontal_rule(&mut self) -> Result<()> {
         let line = self.config.create_horizontal_rule();
         let styled_line = format!("{}", self.apply_text_style(&line, TextStyle::Delimiter));
         self.output.writeln("")?;
@@ -222,13 +243,13 @@ impl<W: Write> MarkdownRenderer<W> {
         Ok(())
     }
 
-    fn render_inline_code(&mut self, code: &str) -> Result<()> {
+    pub(crate) fn render_inline_code(&mut self, code: &str) -> Result<()> {
         let styled_code = format!("{}", self.apply_text_style(code, TextStyle::CodeBlock));
         self.output.write(&styled_code)?;
         Ok(())
     }
 
-    fn render_task_marker(&mut self, checked: bool) -> Result<()> {
+    pub(crate) fn render_task_marker(&mut self, checked: bool) -> Result<()> {
         let marker = if checked { "[x] " } else { "[ ] " };
         let list_marker_style = self.theme.list_marker_style();
         let styled_marker =
@@ -237,7 +258,7 @@ impl<W: Write> MarkdownRenderer<W> {
         Ok(())
     }
 
-    fn render_link(&mut self) -> Result<()> {
+    pub(crate) fn render_link(&mut self) -> Result<()> {
         if let Some(link) = self.get_link() {
             if !link.text.is_empty() {
                 let hyperlink = format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", link.url, link.text);
@@ -258,7 +279,7 @@ impl<W: Write> MarkdownRenderer<W> {
         Ok(())
     }
 
-    fn render_image(&mut self) -> Result<()> {
+    pub(crate) fn render_image(&mut self) -> Result<()> {
         if let Some(image) = self.get_image() {
             let display_text = if !image.alt_text.is_empty() {
                 format!("[{}]", image.alt_text)
@@ -293,35 +314,62 @@ impl<W: Write> MarkdownRenderer<W> {
         Ok(())
     }
 
-    fn render_code_block_output(&mut self) -> Result<()> {
-        if let Some(code_block) = self.get_code_block() {
-            self.clear_code_block();
-            self.render_code_block(&code_block)?;
+    /// Renders a `[^label]` reference as a clickable superscript marker,
+    /// linked (via OSC-8) to its entry in the "Notes" section rendered at
+    /// the end of the document; the definition itself is captured rather
+    /// than written here. See [`MarkdownRenderer::render_footnotes_section`].
+    fn render_footnote_reference(&mut self, label: &str) -> Result<()> {
+        let number = self.footnotes.reference(label);
+        let marker = footnotes::to_superscript(number);
+        let linked = format!("\x1b]8;;#fn-{label}\x1b\\{marker}\x1b]8;;\x1b\\");
+        self.output.write(&linked)
+    }
+
+    /// Emits a delimiter rule followed by a "Notes" section listing every
+    /// footnote definition by its first-seen-order number, each with a
+    /// back-reference (OSC-8) to `#fnref-<label>`. A reference with no
+    /// matching `[^label]: ...` definition is still listed, marked
+    /// undefined, rather than dropped.
+    pub(crate) fn render_footnotes_section(&mut self) -> Result<()> {
+        let line = self.config.create_horizontal_rule();
+        let styled_line = format!("{}", self.apply_text_style(&line, TextStyle::Delimiter));
+        self.output.newline()?;
+        self.output.writeln(&styled_line)?;
+
+        let heading_style = self.theme.heading_style(2);
+        let styled_heading = self.create_styled_text(
+            "## Notes",
+            heading_style.color.to_crossterm_color(),
+            heading_style.bold,
+            false,
+            heading_style.underline,
+        );
+        self.output.writeln(&styled_heading)?;
+        self.output.newline()?;
+
+        for (number, label, body) in self.footnotes.entries() {
+            self.output.write(&format!("{number}. "))?;
+            match body.as_deref().map(str::trim) {
+                Some(body) if !body.is_empty() => self.output.write(body)?,
+                _ => self.output.write("(undefined)")?,
+            }
+            self.output.write(" ")?;
+            let back_reference = format!("\x1b]8;;#fnref-{label}\x1b\\\u{21a9}\x1b]8;;\x1b\\");
+            self.output.writeln(&back_reference)?;
         }
+
         Ok(())
     }
 
-    fn handle_element_output(&mut self, kind: ElementKind, phase: ElementPhase) -> Result<()> {
-        match (kind, phase) {
-            (ElementKind::Heading(level), ElementPhase::Start) => {
-                self.render_heading_start(level)?
-            }
-            (ElementKind::Heading(_), ElementPhase::End) => self.render_heading_end()?,
-            (ElementKind::Paragraph, ElementPhase::Start) => {}
-            (ElementKind::Paragraph, ElementPhase::End) => self.output.newline()?,
-            (ElementKind::ListItem, ElementPhase::Start) => self.render_list_item()?,
-            (ElementKind::ListItem, ElementPhase::End) => {}
-            (ElementKind::BlockQuote, ElementPhase::Start) => self.render_blockquote_start()?,
-            (ElementKind::BlockQuote, ElementPhase::End) => self.output.newline()?,
-            (ElementKind::Table(variant), ElementPhase::Start) => {
-                self.handle_table_variant(variant)?
-            }
-            _ => {}
+    pub(crate) fn render_code_block_output(&mut self) -> Result<()> {
+        if let Some(code_block) = self.get_code_block() {
+            self.clear_code_block();
+            self.render_code_block(&code_block)?;
         }
         Ok(())
     }
 
-    fn render_heading_start(&mut self, level: u8) -> Result<()> {
+    pub(crate) fn render_heading_start(&mut self, level: u8) -> Result<()> {
         let heading_style = self.theme.heading_style(level);
         let prefix = "#".repeat(level as usize);
         let styled_prefix = self.create_styled_text(
@@ -336,13 +384,13 @@ impl<W: Write> MarkdownRenderer<W> {
         Ok(())
     }
 
-    fn render_heading_end(&mut self) -> Result<()> {
+    pub(crate) fn render_heading_end(&mut self) -> Result<()> {
         self.output.newline()?;
         self.output.newline()?;
         Ok(())
     }
 
-    fn render_list_item(&mut self) -> Result<()> {
+    pub(crate) fn render_list_item(&mut self) -> Result<()> {
         let indent_level = self.state.list_stack.len().saturating_sub(1);
         let indent = self.config.create_indent(indent_level);
         self.output.write(&indent)?;
@@ -369,7 +417,7 @@ impl<W: Write> MarkdownRenderer<W> {
         Ok(())
     }
 
-    fn render_blockquote_start(&mut self) -> Result<()> {
+    pub(crate) fn render_blockquote_start(&mut self) -> Result<()> {
         let quote_style = self.theme.code_style();
         let styled_marker = self.create_styled_text(
             "> ",
@@ -382,7 +430,7 @@ impl<W: Write> MarkdownRenderer<W> {
         Ok(())
     }
 
-    fn handle_table_variant(&mut self, variant: TableVariant) -> Result<()> {
+    pub(crate) fn handle_table_variant(&mut self, variant: TableVariant) -> Result<()> {
         match variant {
             TableVariant::HeadStart => {}
             TableVariant::HeadEnd => {
@@ -404,11 +452,18 @@ impl<W: Write> MarkdownRenderer<W> {
         Ok(())
     }
 
-    pub(super) fn render_code_content(&mut self, content: &str) -> Result<()> {
-        let styled_lines: Vec<String> = content
-            .lines()
-            .map(|line| self.create_styled_code_line(line))
-            .collect();
+    pub(super) fn render_code_content(&mut self, content: &str, language: Option<&str>) -> Result<()> {
+        let highlighted = self
+            .config
+            .syntax_highlight
+            .then(|| self.highlighter.highlight(content, language))
+            .flatten();
+        let styled_lines = highlighted.unwrap_or_else(|| {
+            content
+                .lines()
+                .map(|line| self.create_styled_code_line(line))
+                .collect()
+        });
 
         for styled_line in styled_lines {
             self.output.writeln(&styled_line)?;
@@ -429,50 +484,52 @@ impl<W: Write> MarkdownRenderer<W> {
 
         self.output.newline()?;
         self.output.writeln(&styled_fence)?;
-        self.render_code_content(&code_block.content)?;
+        self.render_code_content(&code_block.content, code_block.language.as_deref())?;
         self.output.writeln(&styled_fence)?;
         self.output.newline()?;
         Ok(())
     }
 
-    fn render_formatted_table(&mut self, table: &crate::state::TableState) -> Result<()> {
+    pub(crate) fn render_formatted_table(&mut self, table: &crate::state::TableState) -> Result<()> {
         let mut column_widths = vec![0; table.alignments.len()];
 
         for (i, header) in table.headers.iter().enumerate() {
             if i < column_widths.len() {
-                column_widths[i] = column_widths[i].max(header.len());
+                column_widths[i] = column_widths[i].max(width::display_width(header));
             }
         }
 
         for row in &table.rows {
             for (i, cell) in row.iter().enumerate() {
                 if i < column_widths.len() {
-                    column_widths[i] = column_widths[i].max(cell.len());
+                    column_widths[i] = column_widths[i].max(width::display_width(cell));
                 }
             }
         }
 
+        const MIN_COLUMN_WIDTH: usize = 3;
+        if let Some(max_width) = self.config.max_table_width {
+            let separator_width = width::display_width(self.config.table_separator);
+            let overhead = separator_width + column_widths.len() * (2 + separator_width);
+            let available = max_width.saturating_sub(overhead);
+            column_widths = width::shrink_to_fit(&column_widths, available, MIN_COLUMN_WIDTH);
+        }
+
         if !table.headers.is_empty() {
-            let mut output = String::new();
-            output.push_str(self.config.table_separator);
-            for (i, header) in table.headers.iter().enumerate() {
-                let width = column_widths.get(i).copied().unwrap_or(0);
-                output.push(' ');
-                output.push_str(&format!("{:<width$}", header, width = width));
-                output.push(' ');
-                output.push_str(self.config.table_separator);
+            let header_alignments = vec![Alignment::Left; column_widths.len()];
+            for line in self.format_table_row(&table.headers, &column_widths, &header_alignments) {
+                self.output.writeln(&line)?;
             }
-            self.output.writeln(&output)?;
 
             let mut sep_output = String::new();
             sep_output.push_str(self.config.table_separator);
             for (i, alignment) in table.alignments.iter().enumerate() {
-                let width = column_widths.get(i).copied().unwrap_or(3).max(3);
+                let col_width = column_widths.get(i).copied().unwrap_or(MIN_COLUMN_WIDTH).max(MIN_COLUMN_WIDTH);
                 let separator = match alignment {
-                    Alignment::Left => format!(":{}", "-".repeat(width - 1)),
-                    Alignment::Center => format!(":{}:", "-".repeat(width - 2)),
-                    Alignment::Right => format!("{}:", "-".repeat(width - 1)),
-                    Alignment::None => "-".repeat(width),
+                    Alignment::Left => format!(":{}", "-".repeat(col_width - 1)),
+                    Alignment::Center => format!(":{}:", "-".repeat(col_width - 2)),
+                    Alignment::Right => format!("{}:", "-".repeat(col_width - 1)),
+                    Alignment::None => "-".repeat(col_width),
                 };
                 sep_output.push(' ');
                 sep_output.push_str(&separator);
@@ -483,26 +540,54 @@ impl<W: Write> MarkdownRenderer<W> {
         }
 
         for row in &table.rows {
-            let mut output = String::new();
-            output.push_str(self.config.table_separator);
-            for (i, cell) in row.iter().enumerate() {
-                let width = column_widths.get(i).copied().unwrap_or(0);
-                let alignment = table.alignments.get(i).unwrap_or(&Alignment::None);
-                let formatted_cell = match alignment {
-                    Alignment::Left | Alignment::None => format!("{:<width$}", cell, width = width),
-                    Alignment::Center => format!("{:^width$}", cell, width = width),
-                    Alignment::Right => format!("{:>width$}", cell, width = width),
-                };
-                output.push(' ');
-                output.push_str(&formatted_cell);
-                output.push(' ');
-                output.push_str(self.config.table_separator);
+            for line in self.format_table_row(row, &column_widths, &table.alignments) {
+                self.output.writeln(&line)?;
             }
-            self.output.writeln(&output)?;
         }
 
         Ok(())
     }
+
+    /// Renders one logical table row as one or more physical output lines,
+    /// wrapping each cell to its column width on word boundaries so that
+    /// cells needing multiple lines don't throw off column alignment.
+    fn format_table_row(
+        &self,
+        cells: &[String],
+        column_widths: &[usize],
+        alignments: &[Alignment],
+    ) -> Vec<String> {
+        let wrapped_cells: Vec<Vec<String>> = cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                let col_width = column_widths.get(i).copied().unwrap_or(0);
+                width::wrap_text(cell, col_width)
+            })
+            .collect();
+
+        let line_count = wrapped_cells.iter().map(Vec::len).max().unwrap_or(1);
+
+        (0..line_count)
+            .map(|line_index| {
+                let mut output = String::new();
+                output.push_str(self.config.table_separator);
+                for (i, col_width) in column_widths.iter().enumerate() {
+                    let text = wrapped_cells
+                        .get(i)
+                        .and_then(|lines| lines.get(line_index))
+                        .map(String::as_str)
+                        .unwrap_or("");
+                    let alignment = alignments.get(i).copied().unwrap_or(Alignment::None);
+                    output.push(' ');
+                    output.push_str(&width::pad_display(text, *col_width, alignment));
+                    output.push(' ');
+                    output.push_str(self.config.table_separator);
+                }
+                output
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -547,6 +632,13 @@ mod tests {
         MarkdownRenderer::with_output(output)
     }
 
+    fn create_renderer_with_buffer() -> (MarkdownRenderer<MockWriter>, Arc<Mutex<Vec<u8>>>) {
+        let buffer = Arc::new(Mutex::new(Vec::with_capacity(1024)));
+        let mock_writer = MockWriter::new_with_buffer(buffer.clone());
+        let output = BufferedOutput::new(mock_writer);
+        (MarkdownRenderer::with_output(output), buffer)
+    }
+
     #[rstest]
     #[case(OutputType::Element { kind: ElementKind::Heading(1), phase: ElementPhase::Start })]
     #[case(OutputType::Element { kind: ElementKind::Heading(1), phase: ElementPhase::End })]
@@ -717,6 +809,29 @@ mod tests {
         assert!(renderer.state.emphasis.italic);
     }
 
+    #[test]
+    fn test_strikethrough_tag_toggles_state_and_combines_with_emphasis() {
+        let (mut renderer, buffer) = create_renderer_with_buffer();
+        renderer
+            .process_event(Event::Start(Tag::Strikethrough))
+            .unwrap();
+        assert!(renderer.state.emphasis.strikethrough);
+
+        renderer
+            .process_event(Event::Start(Tag::Strong))
+            .unwrap();
+        renderer
+            .process_event(Event::Text("gone".into()))
+            .unwrap();
+        renderer.output.flush().unwrap();
+
+        let rendered = String::from_utf8_lossy(&buffer.lock().unwrap()).into_owned();
+        assert!(rendered.contains("gone"));
+
+        renderer.process_event(Event::End(TagEnd::Strikethrough)).unwrap();
+        assert!(!renderer.state.emphasis.strikethrough);
+    }
+
     #[test]
     fn test_active_element_transitions() {
         let mut renderer = create_renderer();
@@ -736,4 +851,65 @@ mod tests {
         renderer.clear_active_element();
         assert!(renderer.state.active_element.is_none());
     }
+
+    #[test]
+    fn test_footnote_reference_renders_a_superscript_marker() {
+        let (mut renderer, buffer) = create_renderer_with_buffer();
+        renderer
+            .process_event(Event::FootnoteReference("note".into()))
+            .unwrap();
+        renderer.output.flush().unwrap();
+
+        let rendered = String::from_utf8_lossy(&buffer.lock().unwrap()).into_owned();
+        assert!(rendered.contains('¹'));
+    }
+
+    #[test]
+    fn test_footnote_definition_is_captured_not_written_in_place() {
+        let (mut renderer, buffer) = create_renderer_with_buffer();
+        renderer
+            .process_event(Event::Start(Tag::FootnoteDefinition("note".into())))
+            .unwrap();
+        renderer
+            .process_event(Event::Text("the body".into()))
+            .unwrap();
+        renderer
+            .process_event(Event::End(TagEnd::FootnoteDefinition))
+            .unwrap();
+        renderer.output.flush().unwrap();
+
+        assert!(buffer.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_render_formatted_table_pads_cells_per_column_alignment() {
+        let (mut renderer, buffer) = create_renderer_with_buffer();
+        renderer
+            .render_content(
+                "| Left | Center | Right |\n|:---|:---:|---:|\n| a | b | c |\n",
+            )
+            .unwrap();
+        renderer.output.flush().unwrap();
+
+        let rendered = String::from_utf8_lossy(&buffer.lock().unwrap()).into_owned();
+        let data_row = rendered
+            .lines()
+            .find(|line| line.contains("| a "))
+            .expect("data row present");
+
+        assert_eq!(data_row, "| a    |   b    |     c |");
+    }
+
+    #[test]
+    fn test_footnotes_section_lists_undefined_references() {
+        let (mut renderer, buffer) = create_renderer_with_buffer();
+        renderer
+            .process_event(Event::FootnoteReference("missing".into()))
+            .unwrap();
+        renderer.render_footnotes_section().unwrap();
+        renderer.output.flush().unwrap();
+
+        let rendered = String::from_utf8_lossy(&buffer.lock().unwrap()).into_owned();
+        assert!(rendered.contains("(undefined)"));
+    }
 }
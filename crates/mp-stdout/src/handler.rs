@@ -0,0 +1,258 @@
+use std::io::Write;
+
+use anyhow::Result;
+
+use crate::MarkdownRenderer;
+use crate::output::{ElementPhase, TableVariant};
+use crate::state::{CodeBlockState, ImageState, LinkState};
+
+/// Per-element rendering hooks dispatched from `process_event`, mirroring
+/// orgize's `HtmlHandler` pattern: override only the elements you care
+/// about and every other method falls back to [`DefaultHandler`], turning
+/// `MarkdownRenderer` into a reusable engine instead of a fixed pipeline.
+/// This is the extension point for a library consumer who wants, say,
+/// custom link decoration or a callout/admonition renderer without
+/// patching the core `handle_tag_start`/`handle_tag_end`/`handle_content`
+/// match arms — just implement the one method that matters and install it
+/// with [`MarkdownRenderer::set_handler`].
+///
+/// Overriding `link`/`image`/`code_block` and not delegating to the default
+/// means the implementor is responsible for calling the matching
+/// `clear_*`/`clear_active_element` method once it has read the state it
+/// needs, the same way the default handler does.
+pub trait RenderHandler<W: Write> {
+    fn heading(
+        &self,
+        renderer: &mut MarkdownRenderer<W>,
+        level: u8,
+        phase: ElementPhase,
+    ) -> Result<()> {
+        DefaultHandler.heading(renderer, level, phase)
+    }
+
+    fn paragraph(&self, renderer: &mut MarkdownRenderer<W>, phase: ElementPhase) -> Result<()> {
+        DefaultHandler.paragraph(renderer, phase)
+    }
+
+    fn list_item(&self, renderer: &mut MarkdownRenderer<W>, phase: ElementPhase) -> Result<()> {
+        DefaultHandler.list_item(renderer, phase)
+    }
+
+    fn block_quote(&self, renderer: &mut MarkdownRenderer<W>, phase: ElementPhase) -> Result<()> {
+        DefaultHandler.block_quote(renderer, phase)
+    }
+
+    fn table_variant(
+        &self,
+        renderer: &mut MarkdownRenderer<W>,
+        variant: TableVariant,
+    ) -> Result<()> {
+        DefaultHandler.table_variant(renderer, variant)
+    }
+
+    fn horizontal_rule(&self, renderer: &mut MarkdownRenderer<W>) -> Result<()> {
+        DefaultHandler.horizontal_rule(renderer)
+    }
+
+    fn inline_code(&self, renderer: &mut MarkdownRenderer<W>, code: &str) -> Result<()> {
+        DefaultHandler.inline_code(renderer, code)
+    }
+
+    fn task_marker(&self, renderer: &mut MarkdownRenderer<W>, checked: bool) -> Result<()> {
+        DefaultHandler.task_marker(renderer, checked)
+    }
+
+    fn link(&self, renderer: &mut MarkdownRenderer<W>, link: &LinkState) -> Result<()> {
+        DefaultHandler.link(renderer, link)
+    }
+
+    fn image(&self, renderer: &mut MarkdownRenderer<W>, image: &ImageState) -> Result<()> {
+        DefaultHandler.image(renderer, image)
+    }
+
+    fn code_block(
+        &self,
+        renderer: &mut MarkdownRenderer<W>,
+        code_block: &CodeBlockState,
+    ) -> Result<()> {
+        DefaultHandler.code_block(renderer, code_block)
+    }
+}
+
+/// The built-in terminal rendering behavior. Every [`RenderHandler`] method
+/// delegates here unless a caller overrides it.
+pub struct DefaultHandler;
+
+impl<W: Write> RenderHandler<W> for DefaultHandler {
+    fn heading(
+        &self,
+        renderer: &mut MarkdownRenderer<W>,
+        level: u8,
+        phase: ElementPhase,
+    ) -> Result<()> {
+        match phase {
+            ElementPhase::Start => renderer.render_heading_start(level),
+            ElementPhase::End => renderer.render_heading_end(),
+        }
+    }
+
+    fn paragraph(&self, renderer: &mut MarkdownRenderer<W>, phase: ElementPhase) -> Result<()> {
+        match phase {
+            ElementPhase::Start => Ok(()),
+            ElementPhase::End => renderer.output.newline(),
+        }
+    }
+
+    fn list_item(&self, renderer: &mut MarkdownRenderer<W>, phase: ElementPhase) -> Result<()> {
+        match phase {
+            ElementPhase::Start => renderer.render_list_item(),
+            ElementPhase::End => Ok(()),
+        }
+    }
+
+    fn block_quote(&self, renderer: &mut MarkdownRenderer<W>, phase: ElementPhase) -> Result<()> {
+        match phase {
+            ElementPhase::Start => renderer.render_blockquote_start(),
+            ElementPhase::End => renderer.output.newline(),
+        }
+    }
+
+    fn table_variant(
+        &self,
+        renderer: &mut MarkdownRenderer<W>,
+        variant: TableVariant,
+    ) -> Result<()> {
+        renderer.handle_table_variant(variant)
+    }
+
+    fn horizontal_rule(&self, renderer: &mut MarkdownRenderer<W>) -> Result<()> {
+        renderer.render_horizontal_rule()
+    }
+
+    fn inline_code(&self, renderer: &mut MarkdownRenderer<W>, code: &str) -> Result<()> {
+        renderer.render_inline_code(code)
+    }
+
+    fn task_marker(&self, renderer: &mut MarkdownRenderer<W>, checked: bool) -> Result<()> {
+        renderer.render_task_marker(checked)
+    }
+
+    fn link(&self, renderer: &mut MarkdownRenderer<W>, _link: &LinkState) -> Result<()> {
+        renderer.render_link()
+    }
+
+    fn image(&self, renderer: &mut MarkdownRenderer<W>, _image: &ImageState) -> Result<()> {
+        renderer.render_image()
+    }
+
+    fn code_block(
+        &self,
+        renderer: &mut MarkdownRenderer<W>,
+        _code_block: &CodeBlockState,
+    ) -> Result<()> {
+        renderer.render_code_block_output()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffered_output::BufferedOutput;
+    use crate::output::{ElementKind, OutputType};
+    use std::sync::{Arc, Mutex};
+
+    struct MockWriter {
+        buffer: Arc<Mutex<Vec<u8>>>,
+    }
+
+    impl Write for MockWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.buffer.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn create_renderer() -> (MarkdownRenderer<MockWriter>, Arc<Mutex<Vec<u8>>>) {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let writer = MockWriter {
+            buffer: buffer.clone(),
+        };
+        (MarkdownRenderer::with_output(BufferedOutput::new(writer)), buffer)
+    }
+
+    struct ShoutingHandler;
+
+    impl<W: Write> RenderHandler<W> for ShoutingHandler {
+        fn inline_code(&self, renderer: &mut MarkdownRenderer<W>, code: &str) -> Result<()> {
+            renderer.output.write(&code.to_uppercase())?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_default_handler_renders_inline_code() {
+        let (mut renderer, buffer) = create_renderer();
+        renderer
+            .print_output(OutputType::InlineCode {
+                code: "hi".to_string(),
+            })
+            .unwrap();
+        renderer.output.flush().unwrap();
+        assert!(!buffer.lock().unwrap().is_empty());
+    }
+
+    struct CalloutLinkHandler;
+
+    impl<W: Write> RenderHandler<W> for CalloutLinkHandler {
+        fn link(&self, renderer: &mut MarkdownRenderer<W>, link: &LinkState) -> Result<()> {
+            renderer.output.write(&format!("<<{}>>", link.url))?;
+            renderer.clear_link();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_custom_handler_can_decorate_links_while_deferring_everything_else() {
+        let (mut renderer, buffer) = create_renderer();
+        renderer.set_handler(std::rc::Rc::new(CalloutLinkHandler));
+        renderer.set_link("https://example.com".to_string());
+
+        renderer.print_output(OutputType::Link).unwrap();
+        renderer
+            .print_output(OutputType::Element {
+                kind: ElementKind::Paragraph,
+                phase: ElementPhase::End,
+            })
+            .unwrap();
+        renderer.output.flush().unwrap();
+
+        let rendered = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+        assert_eq!(rendered, "<<https://example.com>>\n");
+    }
+
+    #[test]
+    fn test_custom_handler_overrides_single_element() {
+        let (mut renderer, buffer) = create_renderer();
+        renderer.set_handler(std::rc::Rc::new(ShoutingHandler));
+
+        renderer
+            .print_output(OutputType::InlineCode {
+                code: "hi".to_string(),
+            })
+            .unwrap();
+        renderer
+            .print_output(OutputType::Element {
+                kind: ElementKind::Paragraph,
+                phase: ElementPhase::End,
+            })
+            .unwrap();
+        renderer.output.flush().unwrap();
+
+        let rendered = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+        assert!(rendered.contains("HI"));
+    }
+}
@@ -0,0 +1,97 @@
+use crossterm::style::{Color, Stylize};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Style as SyntectStyle, Theme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+/// Wraps `syntect`'s bundled syntax and theme databases to color fenced code
+/// blocks by their fence's language hint, one ANSI-styled line at a time.
+pub(crate) struct SyntaxHighlighter {
+    syntax_set: SyntaxSet,
+    theme: Theme,
+}
+
+impl SyntaxHighlighter {
+    pub(crate) fn new() -> Self {
+        Self::with_theme("base16-ocean.dark")
+    }
+
+    /// Like [`Self::new`], but highlighting with the named theme instead
+    /// (one of the names `syntect::highlighting::ThemeSet::load_defaults`
+    /// bundles).
+    pub(crate) fn with_theme(theme_name: &str) -> Self {
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let theme_set = ThemeSet::load_defaults();
+        let theme = theme_set
+            .themes
+            .get(theme_name)
+            .cloned()
+            .unwrap_or_else(|| theme_set.themes["base16-ocean.dark"].clone());
+        Self { syntax_set, theme }
+    }
+
+    /// Highlight `content` as `language`, returning one ANSI-colored string
+    /// per source line. Returns `None` when `language` has no known syntax,
+    /// so callers can fall back to the uniform `TextStyle::Code` coloring.
+    pub(crate) fn highlight(&self, content: &str, language: Option<&str>) -> Option<Vec<String>> {
+        let language = language?;
+        let syntax = self
+            .syntax_set
+            .find_syntax_by_token(language)
+            .or_else(|| self.syntax_set.find_syntax_by_extension(language))?;
+
+        let mut highlighter = HighlightLines::new(syntax, &self.theme);
+        let mut lines = Vec::new();
+        for line in LinesWithEndings::from(content) {
+            let ranges = highlighter.highlight_line(line, &self.syntax_set).ok()?;
+            lines.push(render_ranges(&ranges));
+        }
+        Some(lines)
+    }
+}
+
+fn render_ranges(ranges: &[(SyntectStyle, &str)]) -> String {
+    ranges
+        .iter()
+        .map(|(style, text)| {
+            let color = Color::Rgb {
+                r: style.foreground.r,
+                g: style.foreground.g,
+                b: style.foreground.b,
+            };
+            format!("{}", text.trim_end_matches('\n').with(color))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_known_language_splits_into_lines() {
+        let highlighter = SyntaxHighlighter::new();
+        let result = highlighter.highlight("fn main() {\n    1;\n}", Some("rust"));
+        let lines = result.expect("rust is a bundled syntax");
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_with_theme_falls_back_to_default_for_an_unknown_name() {
+        let highlighter = SyntaxHighlighter::with_theme("not-a-real-theme");
+        let result = highlighter.highlight("fn main() {}", Some("rust"));
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn test_highlight_unknown_language_returns_none() {
+        let highlighter = SyntaxHighlighter::new();
+        assert!(highlighter.highlight("whatever", Some("not-a-real-language")).is_none());
+    }
+
+    #[test]
+    fn test_highlight_no_language_returns_none() {
+        let highlighter = SyntaxHighlighter::new();
+        assert!(highlighter.highlight("plain text", None).is_none());
+    }
+}
@@ -0,0 +1,111 @@
+use std::io::{Stdout, Write};
+use std::path::Path;
+
+use anyhow::Result;
+use pulldown_cmark::Options;
+
+use mp_core::theme::MarkdownTheme;
+
+use crate::backend::{HtmlBackend, render_with_backend};
+
+/// Renders to a standalone HTML document instead of a terminal: the same
+/// [`HtmlBackend`] used for the body, wrapped in a minimal `<html>` shell
+/// whose inline `<style>` block is derived from the active theme's colors,
+/// so a shared preview looks like the themed terminal output without
+/// needing a stylesheet of its own.
+pub struct HtmlRenderer<W: Write = Stdout> {
+    theme: Box<dyn MarkdownTheme>,
+    options: Options,
+    writer: W,
+}
+
+impl HtmlRenderer<Stdout> {
+    pub(crate) fn new(theme: Box<dyn MarkdownTheme>, options: Options) -> Self {
+        Self::with_writer(theme, options, std::io::stdout())
+    }
+}
+
+impl<W: Write> HtmlRenderer<W> {
+    pub(crate) fn with_writer(theme: Box<dyn MarkdownTheme>, options: Options, writer: W) -> Self {
+        Self {
+            theme,
+            options,
+            writer,
+        }
+    }
+
+    pub fn render_file(&mut self, path: &Path) -> Result<()> {
+        let content = crate::read_markdown_file(path)?;
+        self.render_content(&content)
+    }
+
+    pub fn render_content(&mut self, content: &str) -> Result<()> {
+        self.writer
+            .write_all(document_head(&self.theme).as_bytes())?;
+        {
+            let mut backend = HtmlBackend::new(&mut self.writer);
+            render_with_backend(content, self.options, &mut backend)?;
+        }
+        self.writer.write_all(DOCUMENT_FOOTER.as_bytes())?;
+        Ok(())
+    }
+}
+
+const DOCUMENT_FOOTER: &str = "</body>\n</html>\n";
+
+/// Builds the `<html>`/`<head>` opening and inline `<style>` block, with
+/// colors pulled from `theme` so the exported document approximates the
+/// same palette as the terminal preview.
+fn document_head(theme: &dyn MarkdownTheme) -> String {
+    let background = theme.status_background_color().to_hex();
+    let text = theme.text_style().color.to_hex();
+    let heading = theme.heading_style(1).color.to_hex();
+    let link = theme.link_style().color.to_hex();
+    let code = theme.code_style().color.to_hex();
+    let code_background = theme.code_background().to_hex();
+    let delimiter = theme.delimiter_style().color.to_hex();
+
+    format!(
+        "<!DOCTYPE html>\n\
+<html lang=\"en\">\n\
+<head>\n\
+<meta charset=\"utf-8\">\n\
+<style>\n\
+body {{ background: {background}; color: {text}; font-family: sans-serif; \
+max-width: 48rem; margin: 2rem auto; padding: 0 1rem; line-height: 1.5; }}\n\
+h1, h2, h3, h4, h5, h6 {{ color: {heading}; }}\n\
+a {{ color: {link}; }}\n\
+code {{ background: {code_background}; color: {code}; padding: 0.1em 0.3em; border-radius: 3px; }}\n\
+pre code {{ display: block; padding: 0.75em; }}\n\
+blockquote {{ border-left: 3px solid {delimiter}; margin-left: 0; padding-left: 1em; color: {text}; }}\n\
+</style>\n\
+</head>\n\
+<body>\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mp_core::theme::SolarizedOsaka;
+
+    #[test]
+    fn test_render_content_wraps_body_in_document_shell() {
+        let mut output = Vec::new();
+        let mut renderer =
+            HtmlRenderer::with_writer(Box::new(SolarizedOsaka), Options::empty(), &mut output);
+        renderer.render_content("# Title\n").unwrap();
+
+        let html = String::from_utf8(output).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h1>Title</h1>"));
+        assert!(html.ends_with("</body>\n</html>\n"));
+    }
+
+    #[test]
+    fn test_document_head_includes_theme_colors() {
+        let head = document_head(&SolarizedOsaka);
+        assert!(head.contains(&SolarizedOsaka.heading_style(1).color.to_hex()));
+        assert!(head.contains(&SolarizedOsaka.link_style().color.to_hex()));
+    }
+}
@@ -1,11 +1,12 @@
 use std::fs::{self, File};
 use std::io::{Read, Stdout, Write};
 use std::path::Path;
+use std::rc::Rc;
 
 use anyhow::{Context, Result};
-use pulldown_cmark::{Options, Parser};
+use pulldown_cmark::{Event, Options, Parser};
 
-use mp_core::theme::SolarizedOsaka;
+use mp_core::theme::{MarkdownTheme, SolarizedOsaka};
 use mp_core::utils::normalize_line_endings;
 
 pub mod buffered_output;
@@ -13,26 +14,49 @@ pub mod builder;
 pub mod output;
 pub mod state;
 
+mod backend;
 mod config;
+mod footnotes;
 mod formatting;
+mod handler;
+mod highlight;
+mod html_document;
+mod line_map;
 mod styling;
 mod table_builder;
 mod theme_adapter;
+mod toc;
+mod width;
 
+pub use backend::{Backend, HtmlBackend, PlainTextBackend, TerminalBackend, render_with_backend};
 pub use builder::RendererBuilder;
 pub use config::RenderConfig;
+pub use handler::{DefaultHandler, RenderHandler};
+pub use html_document::HtmlRenderer;
+pub use line_map::LineMap;
 pub use state::{ActiveElement, RenderState};
 pub use styling::TextStyle;
 pub use table_builder::{Table, TableBuilder};
+pub use theme_adapter::ColorMode;
 
 pub use self::buffered_output::BufferedOutput;
 
+/// A map/filter hook over the `Event` stream pulldown-cmark produces,
+/// applied lazily between parsing and [`MarkdownRenderer::process_event`].
+/// Returning `None` drops the event; returning `Some` with a different
+/// event substitutes it. See [`MarkdownRenderer::with_event_transform`].
+pub type EventTransform = dyn for<'a> FnMut(Event<'a>) -> Option<Event<'a>>;
+
 pub struct MarkdownRenderer<W: Write = Stdout> {
-    pub theme: SolarizedOsaka,
+    pub theme: Box<dyn MarkdownTheme>,
     pub state: RenderState,
     pub options: Options,
     pub config: RenderConfig,
     pub output: BufferedOutput<W>,
+    highlighter: highlight::SyntaxHighlighter,
+    handler: Rc<dyn RenderHandler<W>>,
+    footnotes: footnotes::FootnoteCollector,
+    transform: Option<Box<EventTransform>>,
 }
 
 impl Default for MarkdownRenderer<Stdout> {
@@ -56,14 +80,50 @@ impl<W: Write> MarkdownRenderer<W> {
         options.insert(Options::ENABLE_FOOTNOTES);
 
         Self {
-            theme: SolarizedOsaka,
+            theme: Box::new(SolarizedOsaka),
             state: RenderState::default(),
             options,
             config: RenderConfig::default(),
             output,
+            highlighter: highlight::SyntaxHighlighter::new(),
+            handler: Rc::new(DefaultHandler),
+            footnotes: footnotes::FootnoteCollector::default(),
+            transform: None,
         }
     }
 
+    /// Replace the per-element rendering hooks. See [`RenderHandler`].
+    pub fn set_handler(&mut self, handler: Rc<dyn RenderHandler<W>>) {
+        self.handler = handler;
+    }
+
+    /// Builder-style variant of [`Self::set_handler`].
+    pub fn with_handler(mut self, handler: Rc<dyn RenderHandler<W>>) -> Self {
+        self.set_handler(handler);
+        self
+    }
+
+    /// Installs a map/filter hook over the `Event` stream, applied lazily
+    /// between the parser and [`Self::process_event`]. Useful for
+    /// link-rewriting (relativizing paths, `http` -> `https`), stripping
+    /// raw HTML, or injecting synthetic events, all without touching
+    /// renderer internals.
+    pub fn set_event_transform<F>(&mut self, transform: F)
+    where
+        F: for<'a> FnMut(Event<'a>) -> Option<Event<'a>> + 'static,
+    {
+        self.transform = Some(Box::new(transform));
+    }
+
+    /// Builder-style variant of [`Self::set_event_transform`].
+    pub fn with_event_transform<F>(mut self, transform: F) -> Self
+    where
+        F: for<'a> FnMut(Event<'a>) -> Option<Event<'a>> + 'static,
+    {
+        self.set_event_transform(transform);
+        self
+    }
+
     pub fn render_file(&mut self, path: &Path) -> Result<()> {
         let content = read_markdown_file(path)
             .with_context(|| format!("Failed to read markdown file: {}", path.display()))?;
@@ -71,17 +131,76 @@ impl<W: Write> MarkdownRenderer<W> {
     }
 
     pub fn render_content(&mut self, content: &str) -> Result<()> {
+        if self.config.toc {
+            self.render_toc(content)?;
+        }
+
         let parser = Parser::new_ext(content, self.options);
 
         for event in parser {
-            self.process_event(event)?;
+            let event = match self.transform.as_mut() {
+                Some(transform) => transform(event),
+                None => Some(event),
+            };
+
+            if let Some(event) = event {
+                self.process_event(event)?;
+            }
         }
 
         self.flush()?;
+
+        if !self.footnotes.is_empty() {
+            self.render_footnotes_section()?;
+        }
+
         self.output.flush()?;
         Ok(())
     }
 
+    /// Like [`Self::render_content`], but also returns a [`LineMap`]
+    /// recording which source byte range produced each output line. Meant
+    /// for editor scroll-sync: translating a cursor position in the source
+    /// Markdown to its rendered line, and vice versa, to highlight the
+    /// element under the cursor.
+    pub fn render_content_with_line_map(&mut self, content: &str) -> Result<LineMap> {
+        if self.config.toc {
+            self.render_toc(content)?;
+        }
+
+        let mut line_map = LineMap::default();
+        let mut last_line = self.output.line_count();
+
+        for (event, range) in Parser::new_ext(content, self.options).into_offset_iter() {
+            self.state.source_range = Some(range.clone());
+
+            let event = match self.transform.as_mut() {
+                Some(transform) => transform(event),
+                None => Some(event),
+            };
+
+            if let Some(event) = event {
+                self.process_event(event)?;
+            }
+
+            let current_line = self.output.line_count();
+            for line in last_line..current_line {
+                line_map.push(range.clone(), line);
+            }
+            last_line = current_line;
+        }
+
+        self.state.source_range = None;
+        self.flush()?;
+
+        if !self.footnotes.is_empty() {
+            self.render_footnotes_section()?;
+        }
+
+        self.output.flush()?;
+        Ok(line_map)
+    }
+
     pub fn flush(&mut self) -> Result<()> {
         if let Some(code_block) = self.get_code_block() {
             self.clear_active_element();
@@ -90,6 +209,24 @@ impl<W: Write> MarkdownRenderer<W> {
         Ok(())
     }
 
+    /// Writes a table of contents built from `content`'s headings, followed
+    /// by a blank line. Does nothing if `content` has no headings. Called
+    /// automatically by [`Self::render_content`] when [`RenderConfig::toc`]
+    /// is set, but also exposed here for emitting the TOC on demand.
+    pub fn render_toc(&mut self, content: &str) -> Result<()> {
+        let headings = toc::collect_headings(content, self.options);
+        if headings.is_empty() {
+            return Ok(());
+        }
+        self.output.write(&toc::render_toc(
+            &headings,
+            self.config.indent_width,
+            self.theme.as_ref(),
+            self.config.color_mode,
+        ))?;
+        self.output.newline()
+    }
+
     pub fn set_strong_emphasis(&mut self, value: bool) {
         self.state.emphasis.strong = value;
     }
@@ -98,6 +235,10 @@ impl<W: Write> MarkdownRenderer<W> {
         self.state.emphasis.italic = value;
     }
 
+    pub fn set_strikethrough(&mut self, value: bool) {
+        self.state.emphasis.strikethrough = value;
+    }
+
     pub fn set_link(&mut self, url: String) {
         self.state.active_element = Some(ActiveElement::Link(state::LinkState {
             text: String::new(),
@@ -275,7 +416,7 @@ fn read_markdown_file(path: &Path) -> Result<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use pulldown_cmark::Options;
+    use pulldown_cmark::{Options, Tag};
     use rstest::rstest;
     use std::io::Write;
     use std::sync::{Arc, Mutex};
@@ -363,6 +504,13 @@ fn main() {
         MarkdownRenderer::with_output(output)
     }
 
+    fn create_renderer_with_buffer() -> (MarkdownRenderer<MockWriter>, Arc<Mutex<Vec<u8>>>) {
+        let buffer = Arc::new(Mutex::new(Vec::with_capacity(1024)));
+        let mock_writer = MockWriter::new_with_buffer(buffer.clone());
+        let output = BufferedOutput::new(mock_writer);
+        (MarkdownRenderer::with_output(output), buffer)
+    }
+
     fn assert_render_success(content: &str) {
         let mut renderer = create_renderer();
         let result = renderer.render_content(content);
@@ -540,4 +688,76 @@ fn main() {
     fn test_complex_markdown() {
         assert_render_success(test_data::COMPLEX_MARKDOWN);
     }
+
+    #[test]
+    fn test_render_content_with_line_map_resolves_offsets_both_ways() {
+        let (mut renderer, _buffer) = create_renderer_with_buffer();
+        let content = "# Heading\n\nSecond paragraph\n";
+
+        let line_map = renderer.render_content_with_line_map(content).unwrap();
+
+        let heading_offset = content.find("Heading").unwrap();
+        let heading_line = line_map
+            .line_for_offset(heading_offset)
+            .expect("heading produced a line");
+
+        let paragraph_offset = content.find("Second").unwrap();
+        let paragraph_line = line_map
+            .line_for_offset(paragraph_offset)
+            .expect("paragraph produced a line");
+
+        assert!(paragraph_line > heading_line);
+        assert!(
+            line_map
+                .source_range_for_line(heading_line)
+                .unwrap()
+                .contains(&heading_offset)
+        );
+    }
+
+    #[test]
+    fn test_event_transform_can_drop_raw_html() {
+        let (mut renderer, buffer) = create_renderer_with_buffer();
+        renderer.set_event_transform(|event| match event {
+            Event::Html(_) => None,
+            other => Some(other),
+        });
+
+        renderer
+            .render_content("<script>alert(1)</script>\n\nkept text")
+            .unwrap();
+
+        let rendered = String::from_utf8_lossy(&buffer.lock().unwrap()).into_owned();
+        assert!(!rendered.contains("script"));
+        assert!(rendered.contains("kept text"));
+    }
+
+    #[test]
+    fn test_event_transform_can_rewrite_link_destinations() {
+        let (mut renderer, buffer) = create_renderer_with_buffer();
+        renderer.set_event_transform(|event| {
+            let event = match event {
+                Event::Start(Tag::Link {
+                    link_type,
+                    dest_url,
+                    title,
+                    id,
+                }) => Event::Start(Tag::Link {
+                    link_type,
+                    dest_url: dest_url.replace("http://", "https://").into(),
+                    title,
+                    id,
+                }),
+                other => other,
+            };
+            Some(event)
+        });
+
+        renderer
+            .render_content("[example](http://example.com)")
+            .unwrap();
+
+        let rendered = String::from_utf8_lossy(&buffer.lock().unwrap()).into_owned();
+        assert!(rendered.contains("https://example.com"));
+    }
 }
@@ -0,0 +1,61 @@
+use std::ops::Range;
+
+/// Maps rendered output lines to the source byte ranges that produced them,
+/// built by [`crate::MarkdownRenderer::render_content_with_line_map`] to
+/// support editor scroll-sync: translating a cursor position in the source
+/// Markdown to its rendered line, and a rendered line back to the source
+/// range that produced it.
+#[derive(Debug, Default, Clone)]
+pub struct LineMap {
+    entries: Vec<(Range<usize>, usize)>,
+}
+
+impl LineMap {
+    pub(crate) fn push(&mut self, range: Range<usize>, line: usize) {
+        self.entries.push((range, line));
+    }
+
+    /// The output line produced by the source event covering `offset`, if
+    /// any. When more than one event covers the same line, the first
+    /// recorded match wins.
+    pub fn line_for_offset(&self, offset: usize) -> Option<usize> {
+        self.entries
+            .iter()
+            .find(|(range, _)| range.contains(&offset))
+            .map(|(_, line)| *line)
+    }
+
+    /// The source range that produced `line`, if any.
+    pub fn source_range_for_line(&self, line: usize) -> Option<Range<usize>> {
+        self.entries
+            .iter()
+            .find(|(_, produced_line)| *produced_line == line)
+            .map(|(range, _)| range.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_for_offset_finds_the_covering_entry() {
+        let mut map = LineMap::default();
+        map.push(0..5, 0);
+        map.push(5..12, 1);
+
+        assert_eq!(map.line_for_offset(2), Some(0));
+        assert_eq!(map.line_for_offset(8), Some(1));
+        assert_eq!(map.line_for_offset(100), None);
+    }
+
+    #[test]
+    fn test_source_range_for_line_finds_the_matching_entry() {
+        let mut map = LineMap::default();
+        map.push(0..5, 0);
+        map.push(5..12, 1);
+
+        assert_eq!(map.source_range_for_line(1), Some(5..12));
+        assert_eq!(map.source_range_for_line(99), None);
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use pulldown_cmark::Alignment;
 
 pub enum ContentType<'a> {
@@ -14,6 +16,7 @@ pub enum ContentType<'a> {
 pub struct EmphasisState {
     pub strong: bool,
     pub italic: bool,
+    pub strikethrough: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -62,6 +65,11 @@ pub struct RenderState {
     pub active_element: Option<ActiveElement>,
 
     pub list_stack: Vec<ListType>,
+
+    /// The source byte range of the event currently being processed, set by
+    /// [`crate::MarkdownRenderer::render_content_with_line_map`]. `None`
+    /// outside that rendering mode.
+    pub source_range: Option<Range<usize>>,
 }
 
 impl ActiveElement {
@@ -26,19 +26,23 @@ impl<W: Write> MarkdownRenderer<W> {
     pub fn apply_text_style(&self, text: &str, style: TextStyle) -> StyledContent<String> {
         use crate::theme_adapter::{styled_text, styled_text_with_bg};
 
+        let mode = self.config.color_mode;
+
         match style {
-            TextStyle::Normal => styled_text(text, &self.theme.text_style()),
-            TextStyle::Strong => styled_text(text, &self.theme.strong_style()),
-            TextStyle::Emphasis => styled_text(text, &self.theme.emphasis_style()),
-            TextStyle::Code => styled_text(text, &self.theme.code_style()),
-            TextStyle::Link => styled_text(text, &self.theme.link_style()),
-            TextStyle::Heading(level) => styled_text(text, &self.theme.heading_style(level)),
-            TextStyle::ListMarker => styled_text(text, &self.theme.list_marker_style()),
-            TextStyle::Delimiter => styled_text(text, &self.theme.delimiter_style()),
+            TextStyle::Normal => styled_text(text, &self.theme.text_style(), mode),
+            TextStyle::Strong => styled_text(text, &self.theme.strong_style(), mode),
+            TextStyle::Emphasis => styled_text(text, &self.theme.emphasis_style(), mode),
+            TextStyle::Code => styled_text(text, &self.theme.code_style(), mode),
+            TextStyle::Link => styled_text(text, &self.theme.link_style(), mode),
+            TextStyle::Heading(level) => {
+                styled_text(text, &self.theme.heading_style(level), mode)
+            }
+            TextStyle::ListMarker => styled_text(text, &self.theme.list_marker_style(), mode),
+            TextStyle::Delimiter => styled_text(text, &self.theme.delimiter_style(), mode),
             TextStyle::CodeBlock => {
                 let style = self.theme.code_style();
                 let bg = self.theme.code_background();
-                styled_text_with_bg(text, &style, &bg)
+                styled_text_with_bg(text, &style, &bg, mode)
             }
             TextStyle::Custom { color, bold } => {
                 let theme_color = match color {
@@ -54,8 +58,12 @@ impl<W: Write> MarkdownRenderer<W> {
                     bold,
                     italic: false,
                     underline: false,
+                    bg: None,
+                    reverse: false,
+                    strikethrough: false,
+                    dim: false,
                 };
-                styled_text(text, &theme_style)
+                styled_text(text, &theme_style, mode)
             }
         }
     }
@@ -75,9 +83,46 @@ impl<W: Write> MarkdownRenderer<W> {
         } else {
             TextStyle::Normal
         };
+
+        if self.state.emphasis.strikethrough {
+            let mut theme_style = self.theme_style_for(style);
+            theme_style.strikethrough = true;
+            use crate::theme_adapter::styled_text;
+            return Cow::Owned(format!(
+                "{}",
+                styled_text(text, &theme_style, self.config.color_mode)
+            ));
+        }
+
         Cow::Owned(format!("{}", self.apply_text_style(text, style)))
     }
 
+    /// Resolves a [`TextStyle`] to the underlying [`mp_core::theme::ThemeStyle`]
+    /// it delegates to, so callers (like strikethrough composition above) can
+    /// tweak an individual attribute without losing the role's color/weight.
+    fn theme_style_for(&self, style: TextStyle) -> mp_core::theme::ThemeStyle {
+        match style {
+            TextStyle::Normal => self.theme.text_style(),
+            TextStyle::Strong => self.theme.strong_style(),
+            TextStyle::Emphasis => self.theme.emphasis_style(),
+            TextStyle::Code => self.theme.code_style(),
+            TextStyle::Link => self.theme.link_style(),
+            TextStyle::Heading(level) => self.theme.heading_style(level),
+            TextStyle::ListMarker => self.theme.list_marker_style(),
+            TextStyle::Delimiter => self.theme.delimiter_style(),
+            TextStyle::CodeBlock => self.theme.code_style(),
+            TextStyle::Custom { color, bold } => {
+                let mut style = self.theme.text_style();
+                style.color = match color {
+                    Color::Rgb { r, g, b } => ThemeColor { r, g, b },
+                    _ => style.color,
+                };
+                style.bold = bold;
+                style
+            }
+        }
+    }
+
     pub fn create_styled_marker(
         &self,
         marker: &str,
@@ -155,9 +200,16 @@ impl<W: Write> MarkdownRenderer<W> {
             bold,
             italic,
             underline,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
         use crate::theme_adapter::styled_text;
-        Cow::Owned(format!("{}", styled_text(text, &theme_style)))
+        Cow::Owned(format!(
+            "{}",
+            styled_text(text, &theme_style, self.config.color_mode)
+        ))
     }
 
     pub fn create_styled_code_line(&self, line: &str) -> String {
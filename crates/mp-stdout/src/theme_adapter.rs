@@ -1,22 +1,104 @@
 use crossterm::style::{Attribute, Color, StyledContent, Stylize};
 
+use mp_core::theme::color::{quantize_to_ansi16, quantize_to_ansi256};
 use mp_core::theme::{ThemeAdapter, ThemeColor, ThemeStyle};
 
-pub struct CrosstermThemeAdapter;
+/// How much color output [`CrosstermThemeAdapter`] is allowed to produce,
+/// independent of the active [`mp_core::theme::MarkdownTheme`]'s colors
+/// themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    /// 24-bit RGB, passed through untouched.
+    TrueColor,
+    /// Downsampled to the standard 256-entry xterm palette via
+    /// [`quantize_to_ansi256`].
+    Ansi256,
+    /// Downsampled to the 16 standard system colors via
+    /// [`quantize_to_ansi16`].
+    Ansi16,
+    /// No color or style attributes at all; [`CrosstermThemeAdapter::to_style`]
+    /// returns plain, unescaped text.
+    None,
+}
+
+impl ColorMode {
+    /// Probes the environment and tty state the way most CLIs do: a piped
+    /// (non-tty) stdout, `NO_COLOR`, or `CLICOLOR=0` all disable color
+    /// output; `CLICOLOR_FORCE` (set to anything but `0`) overrides those
+    /// and forces it back on. Otherwise, depth is picked from `COLORTERM`
+    /// (`truecolor`/`24bit` → [`ColorMode::TrueColor`]) and `TERM` (a
+    /// `256color` terminal gets [`ColorMode::Ansi256`], a plain one gets
+    /// [`ColorMode::Ansi16`], `dumb`/unset gets [`ColorMode::None`]).
+    pub fn detect() -> Self {
+        use std::io::IsTerminal;
+
+        let forced_on = matches!(std::env::var("CLICOLOR_FORCE"), Ok(val) if val != "0");
+        if !forced_on {
+            if !std::io::stdout().is_terminal() {
+                return ColorMode::None;
+            }
+            if std::env::var_os("NO_COLOR").is_some() {
+                return ColorMode::None;
+            }
+            if matches!(std::env::var("CLICOLOR"), Ok(val) if val == "0") {
+                return ColorMode::None;
+            }
+        }
+
+        if matches!(std::env::var("COLORTERM").as_deref(), Ok("truecolor" | "24bit")) {
+            return ColorMode::TrueColor;
+        }
+        match std::env::var("TERM").unwrap_or_default().as_str() {
+            "" | "dumb" => ColorMode::None,
+            term if term.contains("256color") => ColorMode::Ansi256,
+            _ => ColorMode::Ansi16,
+        }
+    }
+}
+
+pub struct CrosstermThemeAdapter {
+    mode: ColorMode,
+}
+
+impl CrosstermThemeAdapter {
+    pub fn new(mode: ColorMode) -> Self {
+        Self { mode }
+    }
+
+    /// Builds an adapter whose [`ColorMode`] is auto-detected via
+    /// [`ColorMode::detect`].
+    pub fn auto() -> Self {
+        Self::new(ColorMode::detect())
+    }
+}
+
+impl Default for CrosstermThemeAdapter {
+    fn default() -> Self {
+        Self::auto()
+    }
+}
 
 impl ThemeAdapter for CrosstermThemeAdapter {
     type Color = Color;
     type Style = StyledContent<String>;
 
     fn to_color(&self, color: &ThemeColor) -> Self::Color {
-        Color::Rgb {
-            r: color.r,
-            g: color.g,
-            b: color.b,
+        match self.mode {
+            ColorMode::TrueColor => Color::Rgb {
+                r: color.r,
+                g: color.g,
+                b: color.b,
+            },
+            ColorMode::Ansi256 => Color::AnsiValue(quantize_to_ansi256(color)),
+            ColorMode::Ansi16 => Color::AnsiValue(quantize_to_ansi16(color)),
+            ColorMode::None => Color::Reset,
         }
     }
 
     fn to_style(&self, style: &ThemeStyle) -> Self::Style {
+        if self.mode == ColorMode::None {
+            return String::new().stylize();
+        }
         apply_style_attributes(String::new().with(self.to_color(&style.color)), style)
     }
 }
@@ -27,7 +109,7 @@ pub trait CrosstermAdapter {
 
 impl CrosstermAdapter for ThemeColor {
     fn to_crossterm_color(&self) -> Color {
-        let adapter = CrosstermThemeAdapter;
+        let adapter = CrosstermThemeAdapter::new(ColorMode::TrueColor);
         adapter.to_color(self)
     }
 }
@@ -45,11 +127,24 @@ fn apply_style_attributes(
     if style.underline {
         styled = styled.attribute(Attribute::Underlined);
     }
+    if style.strikethrough {
+        styled = styled.attribute(Attribute::CrossedOut);
+    }
     styled
 }
 
-pub fn styled_text<S: AsRef<str>>(text: S, style: &ThemeStyle) -> StyledContent<String> {
-    let adapter = CrosstermThemeAdapter;
+/// Styles `text` per `style`, downsampling the color to `mode`. Under
+/// [`ColorMode::None`], returns `text` with no color or attributes applied
+/// at all, so piped output stays plain.
+pub fn styled_text<S: AsRef<str>>(
+    text: S,
+    style: &ThemeStyle,
+    mode: ColorMode,
+) -> StyledContent<String> {
+    if mode == ColorMode::None {
+        return text.as_ref().to_string().stylize();
+    }
+    let adapter = CrosstermThemeAdapter::new(mode);
     let styled = text
         .as_ref()
         .to_string()
@@ -57,12 +152,17 @@ pub fn styled_text<S: AsRef<str>>(text: S, style: &ThemeStyle) -> StyledContent<
     apply_style_attributes(styled, style)
 }
 
+/// Like [`styled_text`], but also paints `bg` behind the text.
 pub fn styled_text_with_bg<S: AsRef<str>>(
     text: S,
     style: &ThemeStyle,
     bg: &ThemeColor,
+    mode: ColorMode,
 ) -> StyledContent<String> {
-    let adapter = CrosstermThemeAdapter;
+    if mode == ColorMode::None {
+        return text.as_ref().to_string().stylize();
+    }
+    let adapter = CrosstermThemeAdapter::new(mode);
     let styled = text
         .as_ref()
         .to_string()
@@ -100,8 +200,12 @@ mod tests {
             bold: true,
             italic: false,
             underline: true,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
-        let styled = styled_text("test", &style);
+        let styled = styled_text("test", &style, ColorMode::TrueColor);
         let formatted = format!("{}", styled);
         assert!(formatted.contains("test"));
     }
@@ -113,8 +217,12 @@ mod tests {
             bold: false,
             italic: true,
             underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
-        let styled = styled_text("hello", &style);
+        let styled = styled_text("hello", &style, ColorMode::TrueColor);
         let formatted = format!("{}", styled);
         assert!(formatted.contains("hello"));
     }
@@ -130,10 +238,80 @@ mod tests {
             bold: true,
             italic: false,
             underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
         let bg = ThemeColor { r: 0, g: 0, b: 0 };
-        let styled = styled_text_with_bg("text", &style, &bg);
+        let styled = styled_text_with_bg("text", &style, &bg, ColorMode::TrueColor);
         let formatted = format!("{}", styled);
         assert!(formatted.contains("text"));
     }
+
+    #[test]
+    fn test_to_color_downsamples_per_mode() {
+        let color = ThemeColor {
+            r: 200,
+            g: 40,
+            b: 40,
+        };
+
+        assert!(matches!(
+            CrosstermThemeAdapter::new(ColorMode::TrueColor).to_color(&color),
+            Color::Rgb { r: 200, g: 40, b: 40 }
+        ));
+        assert!(matches!(
+            CrosstermThemeAdapter::new(ColorMode::Ansi256).to_color(&color),
+            Color::AnsiValue(_)
+        ));
+        assert!(matches!(
+            CrosstermThemeAdapter::new(ColorMode::Ansi16).to_color(&color),
+            Color::AnsiValue(index) if index < 16
+        ));
+    }
+
+    #[test]
+    fn test_styled_text_under_none_mode_has_no_escapes() {
+        let style = ThemeStyle {
+            color: ThemeColor { r: 255, g: 0, b: 0 },
+            bold: true,
+            italic: false,
+            underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
+        };
+        let styled = styled_text("plain", &style, ColorMode::None);
+        assert_eq!(format!("{}", styled), "plain");
+    }
+
+    #[test]
+    fn test_color_mode_detect_respects_no_color() {
+        // SAFETY: test runs single-threaded within this module
+        unsafe {
+            std::env::set_var("NO_COLOR", "1");
+        }
+        assert_eq!(ColorMode::detect(), ColorMode::None);
+        unsafe {
+            std::env::remove_var("NO_COLOR");
+        }
+    }
+
+    #[test]
+    fn test_color_mode_detect_clicolor_force_overrides_no_color() {
+        // SAFETY: test runs single-threaded within this module
+        unsafe {
+            std::env::set_var("NO_COLOR", "1");
+            std::env::set_var("CLICOLOR_FORCE", "1");
+            std::env::set_var("COLORTERM", "truecolor");
+        }
+        assert_eq!(ColorMode::detect(), ColorMode::TrueColor);
+        unsafe {
+            std::env::remove_var("NO_COLOR");
+            std::env::remove_var("CLICOLOR_FORCE");
+            std::env::remove_var("COLORTERM");
+        }
+    }
 }
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use mp_core::theme::MarkdownTheme;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+use crate::theme_adapter::{ColorMode, styled_text};
+
+/// One heading collected from the document, nested under its nearest
+/// lower-level ancestor.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TocEntry {
+    pub(crate) id: String,
+    pub(crate) text: String,
+    pub(crate) children: Vec<TocEntry>,
+}
+
+/// Parse `content` once, collecting every heading into a table of contents.
+///
+/// Nesting is built with a stack of `(level, entries)`, one frame per open
+/// heading: an incoming heading pops every frame whose level is >= its own
+/// (closing out same-or-deeper headings), then is appended to whatever frame
+/// is left on top before pushing its own frame for grandchildren. This keeps
+/// headings correctly nested even when levels skip, e.g. H1 followed by H3.
+pub(crate) fn collect_headings(content: &str, options: Options) -> Vec<TocEntry> {
+    let mut slugs: HashMap<String, usize> = HashMap::new();
+    let mut stack: Vec<(u8, Vec<TocEntry>)> = vec![(0, Vec::new())];
+    let mut current_level: Option<u8> = None;
+    let mut current_text = String::new();
+
+    for event in Parser::new_ext(content, options) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                current_level = Some(level as u8);
+                current_text.clear();
+            }
+            Event::Text(text) if current_level.is_some() => current_text.push_str(&text),
+            Event::Code(code) if current_level.is_some() => current_text.push_str(&code),
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(level) = current_level.take() {
+                    let id = unique_slug(&mut slugs, &current_text);
+                    push_heading(&mut stack, level, id, current_text.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    while stack.len() > 1 {
+        close_top_frame(&mut stack);
+    }
+    stack.pop().map(|(_, entries)| entries).unwrap_or_default()
+}
+
+fn push_heading(stack: &mut Vec<(u8, Vec<TocEntry>)>, level: u8, id: String, text: String) {
+    while stack.len() > 1 && stack.last().map(|(l, _)| *l).unwrap_or(0) >= level {
+        close_top_frame(stack);
+    }
+    if let Some((_, entries)) = stack.last_mut() {
+        entries.push(TocEntry {
+            id,
+            text,
+            children: Vec::new(),
+        });
+    }
+    stack.push((level, Vec::new()));
+}
+
+fn close_top_frame(stack: &mut Vec<(u8, Vec<TocEntry>)>) {
+    let Some((_, entries)) = stack.pop() else {
+        return;
+    };
+    if let Some((_, parent_entries)) = stack.last_mut()
+        && let Some(parent_entry) = parent_entries.last_mut()
+    {
+        parent_entry.children = entries;
+    }
+}
+
+fn unique_slug(seen: &mut HashMap<String, usize>, text: &str) -> String {
+    let base = slugify(text);
+    let count = seen.entry(base.clone()).or_insert(0);
+    let id = if *count == 0 {
+        base
+    } else {
+        format!("{base}-{count}")
+    };
+    *count += 1;
+    id
+}
+
+/// Lowercase, spaces→hyphens, punctuation stripped.
+fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    let mut last_was_hyphen = true;
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            slug.push(ch.to_ascii_lowercase());
+            last_was_hyphen = false;
+        } else if !last_was_hyphen {
+            slug.push('-');
+            last_was_hyphen = true;
+        }
+    }
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
+/// Render a nested TOC as indented, OSC-8 linked entries (the same
+/// hyperlink escape `render_link` already emits for Markdown links), with
+/// the `- ` marker styled like any other list marker.
+pub(crate) fn render_toc(
+    entries: &[TocEntry],
+    indent_width: usize,
+    theme: &dyn MarkdownTheme,
+    mode: ColorMode,
+) -> String {
+    let mut out = String::new();
+    render_entries(entries, 0, indent_width, theme, mode, &mut out);
+    out
+}
+
+fn render_entries(
+    entries: &[TocEntry],
+    depth: usize,
+    indent_width: usize,
+    theme: &dyn MarkdownTheme,
+    mode: ColorMode,
+    out: &mut String,
+) {
+    for entry in entries {
+        out.push_str(&" ".repeat(indent_width * depth));
+        out.push_str(&styled_text("-", &theme.list_marker_style(), mode).to_string());
+        out.push(' ');
+        out.push_str(&format!(
+            "\x1b]8;;#{}\x1b\\{}\x1b]8;;\x1b\\",
+            entry.id, entry.text
+        ));
+        out.push('\n');
+        render_entries(&entry.children, depth + 1, indent_width, theme, mode, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mp_core::theme::SolarizedOsaka;
+
+    #[test]
+    fn test_collect_headings_flat() {
+        let headings = collect_headings("# One\n\n# Two\n", Options::empty());
+        assert_eq!(headings.len(), 2);
+        assert_eq!(headings[0].id, "one");
+        assert_eq!(headings[1].id, "two");
+    }
+
+    #[test]
+    fn test_collect_headings_nests_skipped_levels() {
+        let headings = collect_headings("# H1\n\n### H3\n\n## H2\n", Options::empty());
+        assert_eq!(headings.len(), 1);
+        let h1 = &headings[0];
+        assert_eq!(h1.children.len(), 2);
+        assert_eq!(h1.children[0].text, "H3");
+        assert_eq!(h1.children[1].text, "H2");
+    }
+
+    #[test]
+    fn test_duplicate_headings_get_suffixed_ids() {
+        let headings = collect_headings("# Intro\n\n# Intro\n", Options::empty());
+        assert_eq!(headings[0].id, "intro");
+        assert_eq!(headings[1].id, "intro-1");
+    }
+
+    #[test]
+    fn test_slugify_strips_punctuation() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+        assert_eq!(slugify("  Leading/Trailing  "), "leading-trailing");
+    }
+
+    #[test]
+    fn test_render_toc_indents_by_depth() {
+        let headings = collect_headings("# H1\n\n## H2\n", Options::empty());
+        let rendered = render_toc(&headings, 2, &SolarizedOsaka, ColorMode::None);
+        assert!(rendered.contains("- \x1b]8;;#h1\x1b\\H1\x1b]8;;\x1b\\"));
+        assert!(rendered.contains("  - \x1b]8;;#h2\x1b\\H2\x1b]8;;\x1b\\"));
+    }
+
+    #[test]
+    fn test_render_toc_styles_the_marker() {
+        let headings = collect_headings("# H1\n", Options::empty());
+        let rendered = render_toc(&headings, 2, &SolarizedOsaka, ColorMode::TrueColor);
+        assert_ne!(
+            rendered,
+            render_toc(&headings, 2, &SolarizedOsaka, ColorMode::None)
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use unicode_width::UnicodeWidthStr;
+
+static ANSI_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\x1b\[[0-9;]*[mGKHF]").unwrap());
+
+/// Display width of `text` in terminal columns: SGR/cursor escape sequences
+/// are ignored and wide glyphs (CJK, emoji, ...) count as two columns, so
+/// this lines up with what a terminal actually renders unlike `str::len()`.
+pub(crate) fn display_width(text: &str) -> usize {
+    ANSI_REGEX.replace_all(text, "").width()
+}
+
+/// Pads `text` to `width` display columns according to `alignment`, padding
+/// by columns rather than bytes so CJK/emoji content still lines up.
+pub(crate) fn pad_display(text: &str, width: usize, alignment: pulldown_cmark::Alignment) -> String {
+    use pulldown_cmark::Alignment;
+
+    let padding = width.saturating_sub(display_width(text));
+    match alignment {
+        Alignment::Right => format!("{}{text}", " ".repeat(padding)),
+        Alignment::Center => {
+            let left = padding / 2;
+            let right = padding - left;
+            format!("{}{text}{}", " ".repeat(left), " ".repeat(right))
+        }
+        Alignment::Left | Alignment::None => format!("{text}{}", " ".repeat(padding)),
+    }
+}
+
+/// Wraps `text` into lines that each fit within `width` display columns,
+/// splitting on word boundaries. A single word wider than `width` is kept
+/// whole on its own line rather than split mid-word.
+pub(crate) fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    if width == 0 || text.is_empty() {
+        return vec![text.to_string()];
+    }
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for word in text.split_whitespace() {
+        let word_width = display_width(word);
+        let separator_width = if current.is_empty() { 0 } else { 1 };
+
+        if current_width + separator_width + word_width > width && !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+            current_width = 0;
+        }
+
+        if !current.is_empty() {
+            current.push(' ');
+            current_width += 1;
+        }
+        current.push_str(word);
+        current_width += word_width;
+    }
+
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+}
+
+/// Shrinks `widths` so they sum to at most `available`, distributing the
+/// reduction proportionally to each column's share of the total, while
+/// never shrinking a column below `min_width`.
+pub(crate) fn shrink_to_fit(widths: &[usize], available: usize, min_width: usize) -> Vec<usize> {
+    let total: usize = widths.iter().sum();
+    if total == 0 || total <= available {
+        return widths.to_vec();
+    }
+
+    widths
+        .iter()
+        .map(|&width| {
+            let scaled = (width * available) / total;
+            scaled.max(min_width.min(width))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulldown_cmark::Alignment;
+
+    #[test]
+    fn test_display_width_ignores_ansi_escapes() {
+        let styled = "\x1b[31mhello\x1b[0m";
+        assert_eq!(display_width(styled), 5);
+    }
+
+    #[test]
+    fn test_display_width_counts_wide_glyphs_as_two() {
+        assert_eq!(display_width("日本語"), 6);
+    }
+
+    #[test]
+    fn test_pad_display_left_aligns_by_columns() {
+        assert_eq!(pad_display("ab", 5, Alignment::Left), "ab   ");
+    }
+
+    #[test]
+    fn test_pad_display_accounts_for_wide_glyphs() {
+        assert_eq!(pad_display("日", 3, Alignment::Left), "日 ");
+    }
+
+    #[test]
+    fn test_wrap_text_splits_on_word_boundaries() {
+        let wrapped = wrap_text("the quick brown fox", 10);
+        assert_eq!(wrapped, vec!["the quick", "brown fox"]);
+    }
+
+    #[test]
+    fn test_wrap_text_keeps_overlong_word_whole() {
+        let wrapped = wrap_text("supercalifragilisticexpialidocious", 10);
+        assert_eq!(wrapped, vec!["supercalifragilisticexpialidocious"]);
+    }
+
+    #[test]
+    fn test_shrink_to_fit_leaves_widths_under_available_untouched() {
+        assert_eq!(shrink_to_fit(&[3, 4], 20, 3), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_shrink_to_fit_distributes_reduction_proportionally() {
+        assert_eq!(shrink_to_fit(&[10, 30], 20, 3), vec![5, 15]);
+    }
+}
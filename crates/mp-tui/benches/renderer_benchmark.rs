@@ -1,6 +1,5 @@
-use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
 use mp_tui::renderer::MarkdownWidget as RendererStandard;
-use mp_tui::renderer_optimized::MarkdownWidget as RendererOptimized;
 
 const SMALL_MD: &str = r#"
 # Small Test Document
@@ -143,63 +142,5 @@ fn benchmark_renderer_standard(c: &mut Criterion) {
     group.finish();
 }
 
-fn benchmark_renderer_optimized(c: &mut Criterion) {
-    let mut group = c.benchmark_group("renderer_optimized");
-
-    group.bench_function("small", |b| {
-        b.iter(|| {
-            let _widget = RendererOptimized::new(black_box(SMALL_MD.to_string()));
-        });
-    });
-
-    group.bench_function("medium", |b| {
-        b.iter(|| {
-            let _widget = RendererOptimized::new(black_box(MEDIUM_MD.to_string()));
-        });
-    });
-
-    let large_md = generate_large_md();
-    group.bench_function("large", |b| {
-        b.iter(|| {
-            let _widget = RendererOptimized::new(black_box(large_md.clone()));
-        });
-    });
-
-    group.finish();
-}
-
-fn benchmark_comparison(c: &mut Criterion) {
-    let mut group = c.benchmark_group("renderer_comparison");
-
-    for (name, content) in &[
-        ("small", SMALL_MD.to_string()),
-        ("medium", MEDIUM_MD.to_string()),
-        ("large", generate_large_md()),
-    ] {
-        group.bench_with_input(BenchmarkId::new("standard", name), content, |b, content| {
-            b.iter(|| {
-                let _widget = RendererStandard::new(black_box(content.clone()));
-            });
-        });
-
-        group.bench_with_input(
-            BenchmarkId::new("optimized", name),
-            content,
-            |b, content| {
-                b.iter(|| {
-                    let _widget = RendererOptimized::new(black_box(content.clone()));
-                });
-            },
-        );
-    }
-
-    group.finish();
-}
-
-criterion_group!(
-    benches,
-    benchmark_renderer_standard,
-    benchmark_renderer_optimized,
-    benchmark_comparison
-);
+criterion_group!(benches, benchmark_renderer_standard);
 criterion_main!(benches);
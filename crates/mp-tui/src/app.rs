@@ -5,6 +5,14 @@ use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
 use ratatui::DefaultTerminal;
 
+use mp_core::FinderConfig;
+
+use mp_core::theme::Theme;
+
+use crate::bookmarks::Bookmarks;
+use crate::file_finder::FileFinder;
+use crate::theme_config::ThemeConfig;
+use crate::watcher::{FileWatcher, WatchSignal};
 use crate::widgets::{FileTreeWidget, PreviewWidget, StatusBar, StatusMode};
 
 pub struct App {
@@ -14,28 +22,77 @@ pub struct App {
     pub focus: AppFocus,
     pub should_quit: bool,
     pub show_help: bool,
+    pub preview_zoomed: bool,
+    pub show_bookmarks: bool,
+    pub show_toc: bool,
+    pub summary_mode: bool,
+    file_finder: FileFinder,
+    watcher: FileWatcher,
+    bookmarks: Bookmarks,
+    bookmark_prefix: Option<BookmarkPrefix>,
+    toc_selected_index: usize,
+    theme_picker_active: bool,
+    theme_before_picker: String,
 }
 
+/// Byte budget the preview is capped to while [`App::summary_mode`] is on,
+/// picked to give a large document a fast first paint without being so
+/// small the summary is useless.
+const SUMMARY_BYTE_BUDGET: usize = 40 * 1024;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AppFocus {
     FileTree,
     Preview,
 }
 
+/// Which prefix key (if any) is awaiting its following letter: `m` to
+/// bookmark the current path, `'` to jump to one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BookmarkPrefix {
+    Set,
+    Jump,
+}
+
 impl App {
     pub fn new() -> Result<Self> {
         let current_dir = std::env::current_dir()?;
-        let mut file_tree = FileTreeWidget::new(current_dir);
+        let mut file_tree = FileTreeWidget::new(current_dir.clone());
 
         file_tree.root.is_expanded = true;
 
+        let mut watcher = FileWatcher::new()?;
+        // Not being able to watch the tree root just means the file list
+        // won't auto-refresh; the active file is still watched individually
+        // once one is loaded below.
+        let _ = watcher.watch_root(&current_dir);
+
+        let theme_name = ThemeConfig::load()
+            .theme_name()
+            .unwrap_or("solarized-dark")
+            .to_string();
+
+        let mut preview = PreviewWidget::new();
+        preview.set_theme(&theme_name);
+
         let mut app = Self {
             file_tree,
-            preview: PreviewWidget::new(),
-            status_bar: StatusBar::new(),
+            preview,
+            status_bar: StatusBar::with_theme(&theme_name),
             focus: AppFocus::FileTree,
             should_quit: false,
             show_help: false,
+            preview_zoomed: false,
+            show_bookmarks: false,
+            show_toc: false,
+            summary_mode: false,
+            file_finder: FileFinder::new(),
+            watcher,
+            bookmarks: Bookmarks::load(),
+            bookmark_prefix: None,
+            toc_selected_index: 0,
+            theme_picker_active: false,
+            theme_before_picker: theme_name,
         };
 
         app.load_selected_file()?;
@@ -55,6 +112,20 @@ impl App {
                 }
                 terminal.draw(|frame| self.draw(frame))?;
             }
+
+            match self.watcher.poll_reload() {
+                WatchSignal::FileChanged => {
+                    self.reload_current_file()?;
+                    terminal.draw(|frame| self.draw(frame))?;
+                }
+                WatchSignal::TreeChanged => {
+                    self.file_tree.reload();
+                    self.status_bar
+                        .set_message("File tree refreshed: changes detected on disk");
+                    terminal.draw(|frame| self.draw(frame))?;
+                }
+                WatchSignal::None => {}
+            }
         }
         Ok(())
     }
@@ -66,10 +137,177 @@ impl App {
             return Ok(false);
         }
 
+        if self.file_finder.active {
+            match key.code {
+                KeyCode::Esc => {
+                    self.file_finder.close();
+                    self.status_bar.set_mode(StatusMode::Normal);
+                }
+                KeyCode::Enter => {
+                    if let Some(path) = self.file_finder.selected_path() {
+                        self.file_finder.close();
+                        self.status_bar.set_mode(StatusMode::Normal);
+                        self.open_path(&path)?;
+                        self.focus = AppFocus::Preview;
+                    }
+                }
+                KeyCode::Down => self.file_finder.move_selection_down(),
+                KeyCode::Up => self.file_finder.move_selection_up(),
+                KeyCode::Backspace => self.file_finder.pop_char(),
+                KeyCode::Char(c) => self.file_finder.push_char(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+
+        if self.preview.search_mode {
+            match key.code {
+                KeyCode::Esc => {
+                    self.preview.cancel_search();
+                    self.status_bar.clear_search_query();
+                    self.status_bar.set_mode(StatusMode::Normal);
+                }
+                KeyCode::Enter => {
+                    self.preview.search_mode = false;
+                    self.status_bar.set_mode(StatusMode::Normal);
+                }
+                KeyCode::Backspace => {
+                    self.preview.remove_search_char();
+                    self.sync_preview_search_status();
+                }
+                KeyCode::Char(c) => {
+                    self.preview.add_search_char(c);
+                    self.sync_preview_search_status();
+                }
+                _ => {}
+            }
+            return Ok(false);
+        }
+
+        if let Some(prefix) = self.bookmark_prefix {
+            match key.code {
+                KeyCode::Esc => {
+                    self.bookmark_prefix = None;
+                    self.show_bookmarks = false;
+                    self.status_bar.clear_message();
+                }
+                KeyCode::Char(c) => {
+                    self.bookmark_prefix = None;
+                    self.show_bookmarks = false;
+                    match prefix {
+                        BookmarkPrefix::Set => {
+                            if let Some(path) = self.file_tree.get_selected_file() {
+                                self.bookmarks.set(c, path);
+                                if let Err(e) = self.bookmarks.save() {
+                                    self.status_bar
+                                        .set_error(format!("Failed to save bookmarks: {}", e));
+                                } else {
+                                    self.status_bar.set_message(format!("Bookmarked '{}'", c));
+                                }
+                            } else {
+                                self.status_bar
+                                    .set_error("No file selected to bookmark".to_string());
+                            }
+                        }
+                        BookmarkPrefix::Jump => {
+                            if let Some(path) = self.bookmarks.get(c).map(|p| p.to_path_buf()) {
+                                if self.file_tree.select_path(&path) {
+                                    self.load_selected_file()?;
+                                    self.focus = AppFocus::Preview;
+                                } else {
+                                    self.status_bar
+                                        .set_error(format!("Bookmark '{}' no longer exists", c));
+                                }
+                            } else {
+                                self.status_bar.set_error(format!("No bookmark '{}'", c));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+            return Ok(false);
+        }
+
+        if self.show_toc {
+            let headings = self.preview.headings();
+            match key.code {
+                KeyCode::Esc => {
+                    self.show_toc = false;
+                    self.status_bar.set_mode(StatusMode::Normal);
+                    self.status_bar.clear_toc_heading();
+                }
+                KeyCode::Enter => {
+                    if let Some(heading) = headings.get(self.toc_selected_index) {
+                        let line = heading.line;
+                        self.preview.jump_to_line(line);
+                    }
+                    self.show_toc = false;
+                    self.status_bar.set_mode(StatusMode::Normal);
+                    self.status_bar.clear_toc_heading();
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if !headings.is_empty() {
+                        self.toc_selected_index = (self.toc_selected_index + 1) % headings.len();
+                        self.status_bar
+                            .set_toc_heading(headings[self.toc_selected_index].text.clone());
+                    }
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    if !headings.is_empty() {
+                        self.toc_selected_index = if self.toc_selected_index == 0 {
+                            headings.len() - 1
+                        } else {
+                            self.toc_selected_index - 1
+                        };
+                        self.status_bar
+                            .set_toc_heading(headings[self.toc_selected_index].text.clone());
+                    }
+                }
+                _ => {}
+            }
+            return Ok(false);
+        }
+
+        if self.theme_picker_active {
+            match key.code {
+                KeyCode::Esc => {
+                    let previous = self.theme_before_picker.clone();
+                    self.apply_theme(&previous);
+                    self.theme_picker_active = false;
+                    self.status_bar.set_mode(StatusMode::Normal);
+                    self.status_bar.clear_message();
+                }
+                KeyCode::Enter => {
+                    let name = self.status_bar.theme_name().to_string();
+                    if let Err(e) = ThemeConfig::save(&name) {
+                        self.status_bar
+                            .set_error(format!("Failed to save theme: {}", e));
+                    } else {
+                        self.status_bar
+                            .set_message(format!("Theme set to {}", name));
+                    }
+                    self.theme_picker_active = false;
+                    self.status_bar.set_mode(StatusMode::Normal);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    let next = Theme::next_preset_name(self.status_bar.theme_name()).to_string();
+                    self.apply_theme(&next);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    let prev = Theme::prev_preset_name(self.status_bar.theme_name()).to_string();
+                    self.apply_theme(&prev);
+                }
+                _ => {}
+            }
+            return Ok(false);
+        }
+
         if self.file_tree.search_mode {
             match key.code {
                 KeyCode::Esc => {
                     self.file_tree.cancel_search();
+                    self.status_bar.clear_search_query();
                     self.status_bar.set_mode(StatusMode::Normal);
                 }
                 KeyCode::Enter => {
@@ -79,9 +317,11 @@ impl App {
                 }
                 KeyCode::Backspace => {
                     self.file_tree.remove_search_char();
+                    self.sync_file_tree_search_status();
                 }
                 KeyCode::Char(c) => {
                     self.file_tree.add_search_char(c);
+                    self.sync_file_tree_search_status();
                 }
                 _ => {}
             }
@@ -90,6 +330,7 @@ impl App {
 
         match (self.focus, key.code, key.modifiers) {
             (_, KeyCode::Char('q'), _) | (_, KeyCode::Esc, _) => {
+                let _ = self.bookmarks.save();
                 self.should_quit = true;
                 return Ok(true);
             }
@@ -100,9 +341,70 @@ impl App {
                     "Help: Use arrow keys to navigate, Enter to expand/select, Tab to switch focus",
                 );
             }
-            (_, KeyCode::Char('/'), _) => {
+            (_, KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                self.file_finder.open(FinderConfig::default());
+                self.status_bar.set_mode(StatusMode::Search);
+            }
+            (AppFocus::Preview, KeyCode::Char('/'), _) => {
+                self.preview.start_search();
+                self.status_bar.set_mode(StatusMode::Search);
+                self.sync_preview_search_status();
+            }
+            (AppFocus::FileTree, KeyCode::Char('/'), _) => {
                 self.file_tree.start_search();
                 self.status_bar.set_mode(StatusMode::Search);
+                self.sync_file_tree_search_status();
+            }
+            (_, KeyCode::Char('m'), _) => {
+                self.bookmark_prefix = Some(BookmarkPrefix::Set);
+                self.status_bar
+                    .set_message("Bookmark: press a key to label the selected file");
+            }
+            (_, KeyCode::Char('\''), _) => {
+                self.bookmark_prefix = Some(BookmarkPrefix::Jump);
+                self.show_bookmarks = true;
+                self.status_bar.set_message("Jump: press a bookmark's key");
+            }
+            (_, KeyCode::Char('t'), _) => {
+                if self.preview.headings().is_empty() {
+                    self.status_bar.set_message("No headings in this document");
+                } else {
+                    self.show_toc = true;
+                    self.toc_selected_index = 0;
+                    self.status_bar.set_mode(StatusMode::Toc);
+                    self.status_bar
+                        .set_toc_heading(self.preview.headings()[0].text.clone());
+                }
+            }
+            (_, KeyCode::Char('T'), _) => {
+                self.theme_before_picker = self.status_bar.theme_name().to_string();
+                self.theme_picker_active = true;
+                self.status_bar.set_mode(StatusMode::Theme);
+                self.status_bar.set_message(
+                    "Theme: \u{2191}/\u{2193} to cycle, Enter to confirm, Esc to cancel",
+                );
+            }
+            (_, KeyCode::Char('S'), _) => {
+                self.summary_mode = !self.summary_mode;
+                self.preview.set_byte_budget(if self.summary_mode {
+                    Some(SUMMARY_BYTE_BUDGET)
+                } else {
+                    None
+                });
+                self.status_bar.set_message(if self.summary_mode {
+                    "Summary mode on: showing a truncated preview"
+                } else {
+                    "Summary mode off: showing the full document"
+                });
+            }
+            (_, KeyCode::Char('z'), _) => {
+                self.preview_zoomed = !self.preview_zoomed;
+                self.status_bar.set_zoomed(self.preview_zoomed);
+                self.status_bar.set_message(if self.preview_zoomed {
+                    "Preview zoomed"
+                } else {
+                    "Preview unzoomed"
+                });
             }
             (_, KeyCode::Tab, _) => {
                 self.focus = match self.focus {
@@ -160,6 +462,12 @@ impl App {
             | (AppFocus::Preview, KeyCode::Char('G'), KeyModifiers::SHIFT) => {
                 self.preview.scroll_to_bottom();
             }
+            (AppFocus::Preview, KeyCode::Char('n'), _) => {
+                self.preview.next_match();
+            }
+            (AppFocus::Preview, KeyCode::Char('N'), KeyModifiers::SHIFT) => {
+                self.preview.prev_match();
+            }
 
             _ => {}
         }
@@ -167,17 +475,106 @@ impl App {
         Ok(false)
     }
 
+    /// Switches the active theme live, re-theming both the status bar and
+    /// the already-rendered preview in place. Used by the theme picker mode
+    /// to preview each preset as the user cycles through them.
+    fn apply_theme(&mut self, name: &str) {
+        self.status_bar.set_theme(name);
+        self.preview.set_theme(name);
+        self.status_bar.set_message(format!(
+            "Theme: {} (\u{2191}/\u{2193} to cycle, Enter to confirm, Esc to cancel)",
+            name
+        ));
+    }
+
+    /// Mirrors the content search's query and match count onto the status
+    /// bar, so it updates on every keystroke while the user is typing.
+    fn sync_preview_search_status(&mut self) {
+        self.status_bar.set_search_query(&self.preview.search_query);
+        let total = self.preview.markdown_state.search_matches.len();
+        let index = self.preview.markdown_state.active_match;
+        self.status_bar.set_match_counts(index, total);
+    }
+
+    /// Mirrors the file tree's fuzzy search query and filtered result count
+    /// onto the status bar, so it updates on every keystroke while the user
+    /// is typing.
+    fn sync_file_tree_search_status(&mut self) {
+        self.status_bar
+            .set_search_query(&self.file_tree.search_query);
+        let total = self.file_tree.get_filtered_list().len();
+        let index = if self.file_tree.search_query.is_empty() {
+            None
+        } else {
+            Some(self.file_tree.selected_index)
+        };
+        self.status_bar.set_match_counts(index, total);
+    }
+
     fn load_selected_file(&mut self) -> Result<()> {
         if let Some(path) = self.file_tree.get_selected_file() {
+            self.open_path(&path)?;
+        }
+        Ok(())
+    }
+
+    /// Loads an arbitrary markdown file into the preview, independent of the
+    /// file tree's current selection. Used by [`Self::load_selected_file`]
+    /// and by the Ctrl-P file finder, which can jump to a file anywhere
+    /// under the root regardless of which tree nodes are expanded.
+    fn open_path(&mut self, path: &std::path::Path) -> Result<()> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => {
+                self.preview
+                    .set_base_dir(path.parent().map(|dir| dir.to_path_buf()));
+                self.preview.set_content(Arc::new(content));
+                self.preview
+                    .set_file_modified(std::fs::metadata(path).and_then(|m| m.modified()).ok());
+                self.status_bar.set_file(path);
+                self.status_bar.clear_message();
+                if let Err(e) = self.watcher.watch_file(path) {
+                    self.status_bar
+                        .set_error(format!("Failed to watch file: {}", e));
+                }
+            }
+            Err(e) => {
+                self.status_bar
+                    .set_error(format!("Failed to load file: {}", e));
+            }
+        }
+        Ok(())
+    }
+
+    /// Re-reads the currently open file after [`FileWatcher`] reports a
+    /// debounced change, preserving the scroll position (clamped to the
+    /// reloaded document's new line count) instead of resetting it to the
+    /// top the way [`Self::load_selected_file`] does for a fresh selection.
+    fn reload_current_file(&mut self) -> Result<()> {
+        if let Some(path) = self.status_bar.file_path.clone().map(std::path::PathBuf::from) {
             match std::fs::read_to_string(&path) {
                 Ok(content) => {
+                    let previous_scroll = self.preview.scroll_offset;
                     self.preview.set_content(Arc::new(content));
-                    self.status_bar.set_file(&path);
-                    self.status_bar.clear_message();
+                    self.preview.set_file_modified(
+                        std::fs::metadata(&path).and_then(|m| m.modified()).ok(),
+                    );
+
+                    let line_count = self
+                        .preview
+                        .markdown_widget
+                        .as_ref()
+                        .map(|widget| widget.line_count())
+                        .unwrap_or(0);
+                    let clamped_scroll = previous_scroll.min(line_count.saturating_sub(1) as u16);
+                    self.preview.scroll_offset = clamped_scroll;
+                    self.preview.markdown_state.scroll_offset = clamped_scroll;
+
+                    self.status_bar
+                        .set_message("Reloaded: file changed on disk");
                 }
                 Err(e) => {
                     self.status_bar
-                        .set_error(format!("Failed to load file: {}", e));
+                        .set_error(format!("Failed to reload file: {}", e));
                 }
             }
         }
@@ -192,20 +589,46 @@ impl App {
             .constraints([Constraint::Min(0), Constraint::Length(1)])
             .split(frame.area());
 
-        let content_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
-            .split(main_chunks[0]);
-
-        self.file_tree
-            .render(frame, content_chunks[0], self.focus == AppFocus::FileTree);
-        self.preview
-            .render(frame, content_chunks[1], self.focus == AppFocus::Preview);
+        if self.preview_zoomed {
+            self.preview.render(frame, main_chunks[0], true);
+        } else {
+            let content_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+                .split(main_chunks[0]);
+
+            self.file_tree
+                .render(frame, content_chunks[0], self.focus == AppFocus::FileTree);
+            self.preview
+                .render(frame, content_chunks[1], self.focus == AppFocus::Preview);
+        }
+        self.status_bar.set_code_language(
+            self.preview
+                .code_block_language_at_cursor()
+                .map(str::to_string),
+        );
+        self.status_bar.set_truncation(
+            self.preview
+                .is_truncated()
+                .then(|| self.preview.truncation_progress()),
+        );
         self.status_bar.render(frame, main_chunks[1]);
 
         if self.show_help {
             self.render_help(frame);
         }
+
+        if self.show_bookmarks {
+            self.render_bookmarks(frame);
+        }
+
+        if self.show_toc {
+            self.render_toc(frame);
+        }
+
+        if self.file_finder.active {
+            self.file_finder.render(frame, frame.area());
+        }
     }
 
     fn render_help(&self, frame: &mut ratatui::Frame) {
@@ -264,6 +687,12 @@ impl App {
             Line::from("  PgDn/^f - Page down"),
             Line::from("  Home/g  - Go to top"),
             Line::from("  End/G   - Go to bottom"),
+            Line::from("  z       - Toggle full-screen zoom"),
+            Line::from("  /       - Search within the document"),
+            Line::from("  n/N     - Jump to next/previous match"),
+            Line::from("  t       - Jump to heading (table of contents)"),
+            Line::from("  T       - Pick a theme (\u{2191}/\u{2193} to cycle, Enter to confirm)"),
+            Line::from("  S       - Toggle summary mode (truncated preview for large files)"),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "General:",
@@ -271,10 +700,15 @@ impl App {
                     .fg(Color::Rgb(38, 139, 210))
                     .add_modifier(Modifier::BOLD),
             )]),
-            Line::from("  /       - Search files"),
+            Line::from("  /       - Search files (in the file tree)"),
+            Line::from("  ^p      - Find file anywhere in the tree"),
+            Line::from("  m <key> - Bookmark the selected file"),
+            Line::from("  ' <key> - Jump to a bookmark"),
             Line::from("  ?       - Show this help"),
             Line::from("  q/Esc   - Quit"),
             Line::from(""),
+            Line::from("Files are reloaded automatically when changed on disk."),
+            Line::from(""),
             Line::from(vec![Span::styled(
                 "Press any key to close",
                 Style::default()
@@ -296,6 +730,159 @@ impl App {
         frame.render_widget(Clear, popup_area);
         frame.render_widget(help, popup_area);
     }
+
+    fn render_bookmarks(&self, frame: &mut ratatui::Frame) {
+        use ratatui::{
+            layout::{Alignment, Constraint, Direction, Layout},
+            style::{Color, Modifier, Style},
+            text::{Line, Span},
+            widgets::{Block, Borders, Clear, Paragraph},
+        };
+
+        let area = frame.area();
+        let popup_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(30),
+                Constraint::Percentage(40),
+                Constraint::Percentage(30),
+            ])
+            .split(area)[1];
+
+        let popup_area = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(20),
+                Constraint::Percentage(60),
+                Constraint::Percentage(20),
+            ])
+            .split(popup_area)[1];
+
+        let mut entries: Vec<(char, &std::path::Path)> = self.bookmarks.iter().collect();
+        entries.sort_by_key(|(key, _)| *key);
+
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                "Bookmarks",
+                Style::default()
+                    .fg(Color::Rgb(181, 137, 0))
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(""),
+        ];
+
+        if entries.is_empty() {
+            lines.push(Line::from(
+                "No bookmarks yet \u{2014} press m then a key to add one.",
+            ));
+        } else {
+            for (key, path) in entries {
+                lines.push(Line::from(format!("  {}  {}", key, path.display())));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled(
+            "Press a key to jump, Esc to cancel",
+            Style::default()
+                .fg(Color::Rgb(88, 110, 117))
+                .add_modifier(Modifier::ITALIC),
+        )]));
+
+        let popup = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title("Jump to Bookmark")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Rgb(38, 139, 210))),
+            )
+            .style(Style::default().bg(Color::Rgb(7, 54, 66)))
+            .alignment(Alignment::Left);
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(popup, popup_area);
+    }
+
+    fn render_toc(&self, frame: &mut ratatui::Frame) {
+        use ratatui::{
+            layout::{Alignment, Constraint, Direction, Layout},
+            style::{Color, Modifier, Style},
+            text::{Line, Span},
+            widgets::{Block, Borders, Clear, Paragraph},
+        };
+
+        let area = frame.area();
+        let popup_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(20),
+                Constraint::Percentage(60),
+                Constraint::Percentage(20),
+            ])
+            .split(area)[1];
+
+        let popup_area = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(20),
+                Constraint::Percentage(60),
+                Constraint::Percentage(20),
+            ])
+            .split(popup_area)[1];
+
+        let headings = self.preview.headings();
+
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                "Table of Contents",
+                Style::default()
+                    .fg(Color::Rgb(181, 137, 0))
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(""),
+        ];
+
+        if headings.is_empty() {
+            lines.push(Line::from("No headings in this document."));
+        } else {
+            for (index, heading) in headings.iter().enumerate() {
+                let indent = "  ".repeat((heading.level as usize).saturating_sub(1));
+                let text = format!("{}{}", indent, heading.text);
+                if index == self.toc_selected_index {
+                    lines.push(Line::from(vec![Span::styled(
+                        text,
+                        Style::default()
+                            .fg(Color::Rgb(7, 54, 66))
+                            .bg(Color::Rgb(38, 139, 210))
+                            .add_modifier(Modifier::BOLD),
+                    )]));
+                } else {
+                    lines.push(Line::from(text));
+                }
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled(
+            "\u{2191}/\u{2193} to move, Enter to jump, Esc to cancel",
+            Style::default()
+                .fg(Color::Rgb(88, 110, 117))
+                .add_modifier(Modifier::ITALIC),
+        )]));
+
+        let popup = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title("Jump to Heading")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Rgb(38, 139, 210))),
+            )
+            .style(Style::default().bg(Color::Rgb(7, 54, 66)))
+            .alignment(Alignment::Left);
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(popup, popup_area);
+    }
 }
 
 #[cfg(test)]
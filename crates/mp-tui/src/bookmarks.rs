@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+
+/// Single-key-label bookmarks for files and directories, persisted to a TOML
+/// file under the user's config directory so they survive across sessions.
+/// Ports the bookmarks concept from hunter into this crate.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Bookmarks {
+    paths: BTreeMap<char, PathBuf>,
+}
+
+impl Bookmarks {
+    /// Loads the bookmark store from disk, falling back to an empty store if
+    /// it doesn't exist yet or can't be parsed.
+    pub fn load() -> Self {
+        match bookmarks_file() {
+            Some(path) => std::fs::read_to_string(path)
+                .ok()
+                .and_then(|contents| toml::from_str(&contents).ok())
+                .unwrap_or_default(),
+            None => Self::default(),
+        }
+    }
+
+    /// Writes the bookmark store to disk, creating the config directory if
+    /// it doesn't exist yet.
+    pub fn save(&self) -> Result<()> {
+        let path = bookmarks_file().context("could not determine config directory")?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let contents = toml::to_string_pretty(self).context("failed to serialize bookmarks")?;
+        std::fs::write(&path, contents)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn set(&mut self, key: char, path: PathBuf) {
+        self.paths.insert(key, path);
+    }
+
+    pub fn get(&self, key: char) -> Option<&Path> {
+        self.paths.get(&key).map(PathBuf::as_path)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (char, &Path)> {
+        self.paths.iter().map(|(&key, path)| (key, path.as_path()))
+    }
+}
+
+fn bookmarks_file() -> Option<PathBuf> {
+    ProjectDirs::from("", "", "markdown-preview")
+        .map(|dirs| dirs.config_dir().join("bookmarks.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_get_round_trip() {
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.set('a', PathBuf::from("/tmp/notes.md"));
+
+        assert_eq!(bookmarks.get('a'), Some(Path::new("/tmp/notes.md")));
+        assert_eq!(bookmarks.get('b'), None);
+    }
+
+    #[test]
+    fn test_iter_visits_every_bookmark() {
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.set('a', PathBuf::from("/tmp/a.md"));
+        bookmarks.set('b', PathBuf::from("/tmp/b.md"));
+
+        let mut seen: Vec<char> = bookmarks.iter().map(|(key, _)| key).collect();
+        seen.sort();
+        assert_eq!(seen, vec!['a', 'b']);
+    }
+
+    #[test]
+    fn test_serializes_round_trip_through_toml() {
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.set('n', PathBuf::from("/tmp/notes.md"));
+
+        let serialized = toml::to_string_pretty(&bookmarks).unwrap();
+        let deserialized: Bookmarks = toml::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.get('n'), Some(Path::new("/tmp/notes.md")));
+    }
+}
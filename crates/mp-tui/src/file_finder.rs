@@ -0,0 +1,235 @@
+use std::path::PathBuf;
+
+use mp_core::FinderConfig;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+};
+
+use crate::fuzzy::{FuzzyMatch, fuzzy_match_dp};
+
+/// Cap on how many ranked results are shown, smaller than the file tree's
+/// own search-result cap of 50.
+const MAX_RESULTS: usize = 20;
+
+/// Ctrl-P popup that fuzzy-finds any markdown file under the tree root,
+/// independent of the file tree's own inline `/` filter which only searches
+/// whatever subset of the tree is currently expanded.
+pub struct FileFinder {
+    pub active: bool,
+    pub query: String,
+    all_files: Vec<PathBuf>,
+    results: Vec<(PathBuf, FuzzyMatch)>,
+    pub selected_index: usize,
+}
+
+impl FileFinder {
+    pub fn new() -> Self {
+        Self {
+            active: false,
+            query: String::new(),
+            all_files: Vec::new(),
+            results: Vec::new(),
+            selected_index: 0,
+        }
+    }
+
+    /// Opens the finder, collecting every markdown file under the root.
+    pub fn open(&mut self, config: FinderConfig) {
+        self.active = true;
+        self.query.clear();
+        self.all_files = mp_core::find_markdown_files(config).unwrap_or_default();
+        self.update_results();
+    }
+
+    pub fn close(&mut self) {
+        self.active = false;
+        self.query.clear();
+        self.results.clear();
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.update_results();
+    }
+
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.update_results();
+    }
+
+    pub fn move_selection_down(&mut self) {
+        if !self.results.is_empty() && self.selected_index < self.results.len() - 1 {
+            self.selected_index += 1;
+        }
+    }
+
+    pub fn move_selection_up(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    pub fn selected_path(&self) -> Option<PathBuf> {
+        self.results
+            .get(self.selected_index)
+            .map(|(path, _)| path.clone())
+    }
+
+    /// Re-ranks `all_files` against the current query, sorting by descending
+    /// score and breaking ties by shorter path, then pre-selects the best
+    /// match.
+    fn update_results(&mut self) {
+        let mut ranked: Vec<(PathBuf, FuzzyMatch)> = self
+            .all_files
+            .iter()
+            .filter_map(|path| {
+                let candidate = path.to_string_lossy().into_owned();
+                fuzzy_match_dp(&self.query, &candidate).map(|matched| (path.clone(), matched))
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| {
+            b.1.score
+                .cmp(&a.1.score)
+                .then_with(|| a.0.as_os_str().len().cmp(&b.0.as_os_str().len()))
+        });
+        ranked.truncate(MAX_RESULTS);
+
+        self.results = ranked;
+        self.selected_index = 0;
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(area, 60, 60);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(popup_area);
+
+        let query_line = Paragraph::new(format!("> {}", self.query)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Find File")
+                .border_style(Style::default().fg(Color::Rgb(38, 139, 210))),
+        );
+
+        let items: Vec<ListItem> = self
+            .results
+            .iter()
+            .enumerate()
+            .map(|(index, (path, _))| {
+                let style = if index == self.selected_index {
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Rgb(38, 139, 210))
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Rgb(131, 148, 150))
+                };
+                ListItem::new(Line::from(Span::styled(path.display().to_string(), style)))
+            })
+            .collect();
+
+        let results = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Rgb(38, 139, 210))),
+        );
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(query_line, layout[0]);
+        frame.render_widget(results, layout[1]);
+    }
+}
+
+impl Default for FileFinder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn centered_rect(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area)[1];
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical)[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn finder_in(dir: &std::path::Path) -> FileFinder {
+        let mut finder = FileFinder::new();
+        let config = FinderConfig::default();
+        finder.all_files =
+            mp_core::find_markdown_files_in_dir(dir.to_str().unwrap(), config).unwrap_or_default();
+        finder.update_results();
+        finder
+    }
+
+    #[test]
+    fn test_open_collects_and_ranks_all_markdown_files() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("readme.md"), "").unwrap();
+        fs::write(temp_dir.path().join("notes.md"), "").unwrap();
+
+        let mut finder = finder_in(temp_dir.path());
+        finder.push_char('r');
+        finder.push_char('e');
+
+        let selected = finder.selected_path().unwrap();
+        assert!(selected.to_string_lossy().contains("readme"));
+    }
+
+    #[test]
+    fn test_navigation_stays_within_bounds() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a.md"), "").unwrap();
+        fs::write(temp_dir.path().join("b.md"), "").unwrap();
+
+        let mut finder = finder_in(temp_dir.path());
+        assert_eq!(finder.selected_index, 0);
+
+        finder.move_selection_up();
+        assert_eq!(finder.selected_index, 0);
+
+        finder.move_selection_down();
+        assert_eq!(finder.selected_index, 1);
+
+        finder.move_selection_down();
+        assert_eq!(finder.selected_index, 1);
+    }
+
+    #[test]
+    fn test_close_clears_query_and_results() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a.md"), "").unwrap();
+
+        let mut finder = finder_in(temp_dir.path());
+        finder.push_char('a');
+        finder.close();
+
+        assert!(!finder.active);
+        assert!(finder.query.is_empty());
+        assert!(finder.selected_path().is_none());
+    }
+}
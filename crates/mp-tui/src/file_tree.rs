@@ -1,15 +1,52 @@
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use mp_core::theme::DefaultTheme;
+
+use crate::fuzzy::{FuzzyMatch, fuzzy_match};
+use crate::theme_adapter::RoleStyles;
 use crate::tree_builder::{DefaultTreeBuilder, TreeBuilder};
 use mp_core::{FileTreeNode, FinderConfig};
 use ratatui::{
     Frame,
     layout::Rect,
     style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem},
 };
 
+/// Cap on how many fuzzy-ranked entries are shown while searching, so a
+/// large tree doesn't render a list nobody can page through.
+const MAX_SEARCH_RESULTS: usize = 50;
+
+/// Nerd-font glyphs used to decorate file-tree rows by node kind.
+struct TreeIcons;
+
+impl TreeIcons {
+    const FOLDER_OPEN: &'static str = "\u{f115} ";
+    const FOLDER_CLOSED: &'static str = "\u{f114} ";
+    const MARKDOWN_FILE: &'static str = "\u{f48a} ";
+    const GENERIC_FILE: &'static str = "\u{f15b} ";
+
+    fn for_node(node: &DisplayNode) -> &'static str {
+        if node.is_dir {
+            if node.is_expanded {
+                Self::FOLDER_OPEN
+            } else {
+                Self::FOLDER_CLOSED
+            }
+        } else if node
+            .path
+            .extension()
+            .is_some_and(|ext| ext == "md" || ext == "markdown")
+        {
+            Self::MARKDOWN_FILE
+        } else {
+            Self::GENERIC_FILE
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct DisplayNode {
     pub path: PathBuf,
@@ -29,6 +66,7 @@ pub struct FileTreeWidget {
     pub scroll_offset: usize,
     finder_config: FinderConfig,
     tree_builder: Box<dyn TreeBuilder>,
+    role_styles: RoleStyles,
 }
 
 impl FileTreeWidget {
@@ -45,6 +83,8 @@ impl FileTreeWidget {
                 name: "Current Directory".to_string(),
                 is_dir: true,
                 children: Vec::new(),
+                size: None,
+                modified: None,
             }
         });
 
@@ -57,6 +97,7 @@ impl FileTreeWidget {
             scroll_offset: 0,
             finder_config,
             tree_builder,
+            role_styles: RoleStyles::new(&DefaultTheme),
         };
 
         // Initialize display nodes with root expanded
@@ -123,19 +164,43 @@ impl FileTreeWidget {
 
     /// Get the filtered list of display nodes based on search query
     pub fn get_filtered_list(&self) -> Vec<(usize, &DisplayNode)> {
+        self.ranked_matches()
+            .into_iter()
+            .map(|(index, node, _)| (index, node))
+            .collect()
+    }
+
+    /// Fuzzy-rank the display nodes against the current search query,
+    /// keeping the top [`MAX_SEARCH_RESULTS`] matches sorted by descending
+    /// score. Returns every node, unranked, when there's no active query.
+    fn ranked_matches(&self) -> Vec<(usize, &DisplayNode, Option<FuzzyMatch>)> {
         if self.search_query.is_empty() {
-            self.display_nodes.iter().enumerate().collect()
-        } else {
-            let query = self.search_query.to_lowercase();
-            self.display_nodes
+            return self
+                .display_nodes
                 .iter()
                 .enumerate()
-                .filter(|(_, node)| {
-                    node.name.to_lowercase().contains(&query)
-                        || node.path.to_string_lossy().to_lowercase().contains(&query)
-                })
-                .collect()
+                .map(|(index, node)| (index, node, None))
+                .collect();
         }
+
+        let mut ranked: Vec<(usize, &DisplayNode, FuzzyMatch)> = self
+            .display_nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, node)| {
+                let best = fuzzy_match(&self.search_query, &node.name)
+                    .or_else(|| fuzzy_match(&self.search_query, &node.path.to_string_lossy()));
+                best.map(|matched| (index, node, matched))
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| b.2.score.cmp(&a.2.score));
+        ranked.truncate(MAX_SEARCH_RESULTS);
+
+        ranked
+            .into_iter()
+            .map(|(index, node, matched)| (index, node, Some(matched)))
+            .collect()
     }
 
     pub fn toggle_selected(&mut self) {
@@ -193,6 +258,31 @@ impl FileTreeWidget {
         })
     }
 
+    /// Expands every ancestor directory of `path` and selects it, so the
+    /// tree can jump straight to an arbitrary path regardless of which
+    /// nodes are currently expanded (used by bookmark jumps). Returns
+    /// `false` if `path` isn't part of the tree.
+    pub fn select_path(&mut self, path: &Path) -> bool {
+        let mut expanded_paths: HashSet<PathBuf> = self
+            .display_nodes
+            .iter()
+            .filter(|n| n.is_expanded)
+            .map(|n| n.path.clone())
+            .collect();
+        expanded_paths.extend(path.ancestors().skip(1).map(Path::to_path_buf));
+
+        self.display_nodes.clear();
+        self.add_node_to_display(&self.tree_data.clone(), 0, true, &expanded_paths);
+
+        match self.display_nodes.iter().position(|n| n.path == path) {
+            Some(index) => {
+                self.selected_index = index;
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn start_search(&mut self) {
         self.search_mode = true;
         self.search_query.clear();
@@ -245,46 +335,62 @@ impl FileTreeWidget {
 
     /// Render the file tree widget
     pub fn render(&self, frame: &mut Frame, area: Rect, is_focused: bool) {
-        let filtered = self.get_filtered_list();
+        let ranked = self.ranked_matches();
+        let selected_path = ranked
+            .get(self.selected_index)
+            .map(|(_, node, _)| node.path.clone());
 
-        let items: Vec<ListItem> = filtered
+        let items: Vec<ListItem> = ranked
             .iter()
-            .map(|(_, node)| {
+            .map(|(_, node, matched)| {
                 let indent = "  ".repeat(node.depth);
-                let icon = if node.is_dir {
-                    if node.has_children {
-                        if node.is_expanded { "▼ " } else { "▶ " }
-                    } else {
-                        "○ "
-                    }
+                // The expansion arrow shows whether a directory is open or
+                // closed; the icon next to it shows what kind of node it is.
+                let expansion_indicator = if node.is_dir && node.has_children {
+                    if node.is_expanded { "▼ " } else { "▶ " }
                 } else {
-                    "• "
+                    "  "
                 };
+                let icon = TreeIcons::for_node(node);
 
-                let style = if filtered
-                    .get(self.selected_index)
-                    .map(|(_, n)| n.path == node.path)
-                    .unwrap_or(false)
-                {
+                let is_selected = selected_path.as_ref() == Some(&node.path);
+                let base_style = if is_selected {
                     Style::default()
                         .fg(Color::Black)
                         .bg(Color::Rgb(38, 139, 210))
                         .add_modifier(Modifier::BOLD)
-                } else if !self.search_query.is_empty()
-                    && node
-                        .name
-                        .to_lowercase()
-                        .contains(&self.search_query.to_lowercase())
-                {
-                    Style::default()
-                        .fg(Color::Rgb(181, 137, 0))
-                        .add_modifier(Modifier::BOLD)
                 } else {
                     Style::default().fg(Color::Rgb(131, 148, 150))
                 };
+                let icon_style = if is_selected {
+                    base_style
+                } else {
+                    self.role_styles.list_marker
+                };
+
+                let mut spans = vec![
+                    Span::styled(format!("{}{}", indent, expansion_indicator), base_style),
+                    Span::styled(icon, icon_style),
+                ];
+
+                if let Some(matched) = matched {
+                    let matched_indices: HashSet<usize> = matched.indices.iter().copied().collect();
+                    let match_style = base_style
+                        .fg(Color::Rgb(181, 137, 0))
+                        .add_modifier(Modifier::BOLD);
+                    for (char_index, ch) in node.name.chars().enumerate() {
+                        let style = if matched_indices.contains(&char_index) {
+                            match_style
+                        } else {
+                            base_style
+                        };
+                        spans.push(Span::styled(ch.to_string(), style));
+                    }
+                } else {
+                    spans.push(Span::styled(node.name.clone(), base_style));
+                }
 
-                let content = format!("{}{}{}", indent, icon, node.name);
-                ListItem::new(content).style(style)
+                ListItem::new(Line::from(spans))
             })
             .collect();
 
@@ -312,7 +418,7 @@ impl FileTreeWidget {
 
     /// Reload the file tree (useful for refreshing after file system changes)
     pub fn reload(&mut self) {
-        if let Ok(new_tree) = self.tree_builder.build_tree(self.finder_config) {
+        if let Ok(new_tree) = self.tree_builder.build_tree(self.finder_config.clone()) {
             self.tree_data = new_tree;
             self.rebuild_display_nodes();
         }
@@ -443,6 +549,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_select_path_expands_ancestors_and_selects_nested_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().to_path_buf();
+
+        let subdir = path.join("subdir");
+        fs::create_dir(&subdir).unwrap();
+        let nested_file = subdir.join("nested.md");
+        fs::write(&nested_file, "content").unwrap();
+
+        let config = FinderConfig::default();
+        let mock_builder = MockTreeBuilder::from_directory(path.to_str().unwrap(), config).unwrap();
+        let mut widget = FileTreeWidget::with_builder(Box::new(mock_builder), config);
+
+        assert!(widget.select_path(&nested_file));
+        assert_eq!(widget.get_selected_file(), Some(nested_file));
+    }
+
+    #[test]
+    fn test_select_path_returns_false_for_a_path_outside_the_tree() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().to_path_buf();
+        fs::write(path.join("a.md"), "").unwrap();
+
+        let config = FinderConfig::default();
+        let mock_builder = MockTreeBuilder::from_directory(path.to_str().unwrap(), config).unwrap();
+        let mut widget = FileTreeWidget::with_builder(Box::new(mock_builder), config);
+
+        assert!(!widget.select_path(Path::new("/does/not/exist.md")));
+    }
+
     #[test]
     fn test_folder_expansion() {
         let temp_dir = TempDir::new().unwrap();
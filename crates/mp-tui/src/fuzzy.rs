@@ -0,0 +1,246 @@
+/// Subsequence fuzzy matcher backing the file tree's quick-filter search.
+///
+/// Scores the way most fuzzy pickers (fzf, telescope, helix's file picker)
+/// do: consecutive matched characters and matches that land on a "word
+/// boundary" (right after a path separator, `_`, `-`, `.`, or a
+/// lower-to-upper case transition) score higher than an isolated match, and
+/// each unmatched gap between two matches is penalized.
+const CONSECUTIVE_BONUS: i32 = 15;
+const BOUNDARY_BONUS: i32 = 10;
+const GAP_PENALTY: i32 = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzyMatch {
+    pub score: i32,
+    /// Char indices into `candidate` that matched a query character, in order.
+    pub indices: Vec<usize>,
+}
+
+/// Matches `query` as an ordered (case-insensitive) subsequence of
+/// `candidate`, returning the score and matched char indices, or `None` if
+/// `query` isn't a subsequence of `candidate`. An empty `query` matches
+/// everything with a score of `0`.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            indices: Vec::new(),
+        });
+    }
+
+    let query_lower: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let candidate_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut indices = Vec::with_capacity(query_lower.len());
+    let mut score = 0i32;
+    let mut last_match: Option<usize> = None;
+    let mut qi = 0usize;
+
+    for (ci, &lower_ch) in candidate_lower.iter().enumerate() {
+        if qi >= query_lower.len() {
+            break;
+        }
+        if lower_ch != query_lower[qi] {
+            continue;
+        }
+
+        let mut char_score = 1;
+        if is_boundary(&candidate_chars, ci) {
+            char_score += BOUNDARY_BONUS;
+        }
+        match last_match {
+            Some(last) if ci == last + 1 => char_score += CONSECUTIVE_BONUS,
+            Some(last) => char_score -= GAP_PENALTY * (ci - last - 1) as i32,
+            None => {}
+        }
+
+        score += char_score;
+        indices.push(ci);
+        last_match = Some(ci);
+        qi += 1;
+    }
+
+    if qi < query_lower.len() {
+        return None;
+    }
+
+    Some(FuzzyMatch { score, indices })
+}
+
+/// DP-based scorer used by the Ctrl-P file finder, where finding the
+/// globally best alignment across a whole file list matters more than the
+/// tree filter's single greedy pass. Keeps a `query_len x candidate_len`
+/// table where `dp[i][j]` is the best score of an alignment matching the
+/// first `i + 1` query characters and ending with a match at candidate
+/// index `j`; the final score is the max over the last row.
+pub fn fuzzy_match_dp(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            indices: Vec::new(),
+        });
+    }
+
+    let query_lower: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let candidate_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let query_len = query_lower.len();
+    let candidate_len = candidate_chars.len();
+    if candidate_len < query_len {
+        return None;
+    }
+
+    const UNREACHABLE: i32 = i32::MIN / 2;
+    let mut dp = vec![vec![UNREACHABLE; candidate_len]; query_len];
+    let mut back: Vec<Vec<Option<usize>>> = vec![vec![None; candidate_len]; query_len];
+
+    for (j, &lower_ch) in candidate_lower.iter().enumerate() {
+        if lower_ch != query_lower[0] {
+            continue;
+        }
+        dp[0][j] = char_score(&candidate_chars, j);
+    }
+
+    for i in 1..query_len {
+        for (j, &lower_ch) in candidate_lower.iter().enumerate() {
+            if lower_ch != query_lower[i] {
+                continue;
+            }
+
+            let mut best: Option<(usize, i32)> = None;
+            for k in 0..j {
+                if dp[i - 1][k] == UNREACHABLE {
+                    continue;
+                }
+                let gap = (j - k - 1) as i32;
+                let transition = if gap == 0 {
+                    CONSECUTIVE_BONUS
+                } else {
+                    -GAP_PENALTY * gap
+                };
+                let candidate_total = dp[i - 1][k] + transition;
+                if best.is_none_or(|(_, best_total)| candidate_total > best_total) {
+                    best = Some((k, candidate_total));
+                }
+            }
+
+            if let Some((k, total)) = best {
+                dp[i][j] = total + char_score(&candidate_chars, j);
+                back[i][j] = Some(k);
+            }
+        }
+    }
+
+    let (best_j, best_score) = (0..candidate_len)
+        .filter(|&j| dp[query_len - 1][j] != UNREACHABLE)
+        .map(|j| (j, dp[query_len - 1][j]))
+        .max_by_key(|&(_, score)| score)?;
+
+    let mut indices = vec![0usize; query_len];
+    let mut j = best_j;
+    for i in (0..query_len).rev() {
+        indices[i] = j;
+        if i > 0 {
+            j = back[i][j]?;
+        }
+    }
+
+    Some(FuzzyMatch {
+        score: best_score,
+        indices,
+    })
+}
+
+fn char_score(chars: &[char], index: usize) -> i32 {
+    let mut score = 1;
+    if is_boundary(chars, index) {
+        score += BOUNDARY_BONUS;
+    }
+    score
+}
+
+fn is_boundary(chars: &[char], index: usize) -> bool {
+    if index == 0 {
+        return true;
+    }
+    let prev = chars[index - 1];
+    let curr = chars[index];
+    matches!(prev, '/' | '\\' | '_' | '-' | '.') || (prev.is_lowercase() && curr.is_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query_matches_everything_with_zero_score() {
+        let m = fuzzy_match("", "anything.md").unwrap();
+        assert_eq!(m.score, 0);
+        assert!(m.indices.is_empty());
+    }
+
+    #[test]
+    fn test_non_subsequence_does_not_match() {
+        assert!(fuzzy_match("xyz", "readme.md").is_none());
+    }
+
+    #[test]
+    fn test_out_of_order_characters_do_not_match() {
+        assert!(fuzzy_match("dr", "readme.md").is_none());
+    }
+
+    #[test]
+    fn test_consecutive_match_scores_higher_than_scattered_match() {
+        let consecutive = fuzzy_match("rea", "readme.md").unwrap();
+        let scattered = fuzzy_match("rme", "readme.md").unwrap();
+        assert!(consecutive.score > scattered.score);
+    }
+
+    #[test]
+    fn test_word_boundary_match_scores_higher_than_mid_word_match() {
+        let boundary = fuzzy_match("s", "src/status.rs").unwrap();
+        let mid_word = fuzzy_match("t", "src/status.rs").unwrap();
+        assert!(boundary.score > mid_word.score);
+    }
+
+    #[test]
+    fn test_matched_indices_point_at_the_matched_characters() {
+        let m = fuzzy_match("sb", "subdir").unwrap();
+        assert_eq!(m.indices, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_dp_empty_query_matches_everything_with_zero_score() {
+        let m = fuzzy_match_dp("", "anything.md").unwrap();
+        assert_eq!(m.score, 0);
+        assert!(m.indices.is_empty());
+    }
+
+    #[test]
+    fn test_dp_non_subsequence_does_not_match() {
+        assert!(fuzzy_match_dp("xyz", "readme.md").is_none());
+    }
+
+    #[test]
+    fn test_dp_out_of_order_characters_do_not_match() {
+        assert!(fuzzy_match_dp("dr", "readme.md").is_none());
+    }
+
+    #[test]
+    fn test_dp_finds_best_alignment_over_earliest_match() {
+        // A greedy left-to-right matcher would align "rs" to the `r` in "src"
+        // and the first `s` of "status", missing the consecutive,
+        // word-boundary-rich alignment against the ".rs" at the end of the path.
+        let m = fuzzy_match_dp("rs", "src/status.rs").unwrap();
+        assert_eq!(m.indices, vec![11, 12]);
+    }
+
+    #[test]
+    fn test_dp_consecutive_match_scores_higher_than_scattered_match() {
+        let consecutive = fuzzy_match_dp("rea", "readme.md").unwrap();
+        let scattered = fuzzy_match_dp("rme", "readme.md").unwrap();
+        assert!(consecutive.score > scattered.score);
+    }
+}
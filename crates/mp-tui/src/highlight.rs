@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{LazyLock, Mutex};
+
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use syntect::highlighting::{Style as SyntectStyle, Theme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+
+use crate::theme_adapter::RatatuiThemeAdapter;
+
+/// `syntect`'s bundled syntax definitions, parsed once and shared by every
+/// [`CodeHighlighter`] instance rather than reloaded per widget.
+static SYNTAX_SET: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+/// `syntect`'s bundled highlighting themes, loaded once for the same reason
+/// as [`SYNTAX_SET`].
+static THEME_SET: LazyLock<ThemeSet> = LazyLock::new(ThemeSet::load_defaults);
+
+/// Syntax-highlights fenced code blocks for [`crate::renderer::MarkdownWidget`],
+/// backed by `syntect`'s bundled syntax and theme databases. Highlighted
+/// lines are cached per code block (keyed by a hash of its language and
+/// content) so re-rendering during scrolling doesn't re-run the highlighter.
+pub struct CodeHighlighter {
+    theme: Theme,
+    theme_name: String,
+    cache: Mutex<HashMap<u64, Vec<Line<'static>>>>,
+}
+
+impl CodeHighlighter {
+    /// Looks up `theme_name` in the bundled [`THEME_SET`], falling back to
+    /// `base16-ocean.dark` when it isn't found.
+    pub fn new(theme_name: &str) -> Self {
+        let theme = THEME_SET
+            .themes
+            .get(theme_name)
+            .cloned()
+            .unwrap_or_else(|| THEME_SET.themes["base16-ocean.dark"].clone());
+
+        Self {
+            theme,
+            theme_name: theme_name.to_string(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn theme_name(&self) -> &str {
+        &self.theme_name
+    }
+
+    /// Highlights `content` fenced with `language`, falling back to
+    /// `find_syntax_by_first_line` when `language` is absent or unknown, and
+    /// to `fallback_style` applied uniformly when no syntax can be found at
+    /// all. Returns one ratatui [`Line`] per source line.
+    pub fn highlight(
+        &self,
+        language: Option<&str>,
+        content: &str,
+        fallback_style: Style,
+    ) -> Vec<Line<'static>> {
+        let cache_key = Self::cache_key(language, content);
+        if let Some(cached) = self.cache.lock().unwrap().get(&cache_key) {
+            return cached.clone();
+        }
+
+        let syntax = language
+            .and_then(|lang| SYNTAX_SET.find_syntax_by_token(lang))
+            .or_else(|| {
+                content
+                    .lines()
+                    .next()
+                    .and_then(|first_line| SYNTAX_SET.find_syntax_by_first_line(first_line))
+            });
+
+        let lines = match syntax {
+            Some(syntax) => {
+                // A fresh `HighlightLines` per block, so parser/highlight
+                // state never bleeds from one fenced block into the next.
+                let mut highlighter = syntect::easy::HighlightLines::new(syntax, &self.theme);
+                content
+                    .lines()
+                    .map(|line| {
+                        let ranges = highlighter
+                            .highlight_line(line, &SYNTAX_SET)
+                            .unwrap_or_default();
+                        Line::from(
+                            ranges
+                                .into_iter()
+                                .map(|(style, text)| {
+                                    Span::styled(text.to_string(), to_ratatui_style(style))
+                                })
+                                .collect::<Vec<_>>(),
+                        )
+                    })
+                    .collect()
+            }
+            None => content
+                .lines()
+                .map(|line| Line::from(Span::styled(line.to_string(), fallback_style)))
+                .collect(),
+        };
+
+        self.cache.lock().unwrap().insert(cache_key, lines.clone());
+        lines
+    }
+
+    fn cache_key(language: Option<&str>, content: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        language.hash(&mut hasher);
+        content.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Maps a `syntect` per-token style onto a ratatui [`Style`], via
+/// [`RatatuiThemeAdapter`] so foreground/background colors and bold/italic/
+/// underline font-style bits convert the same way theme-driven styles do.
+fn to_ratatui_style(style: SyntectStyle) -> Style {
+    let adapter = RatatuiThemeAdapter;
+    let modifiers = adapter.to_modifier_from_syntect(style.font_style);
+    let mut ratatui_style = Style::default()
+        .fg(adapter.to_color_from_syntect(style.foreground))
+        .bg(adapter.to_color_from_syntect(style.background));
+    if !modifiers.is_empty() {
+        ratatui_style = ratatui_style.add_modifier(modifiers);
+    }
+    ratatui_style
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Modifier};
+    use syntect::highlighting::{Color as SyntectColor, FontStyle as SyntectFontStyle};
+
+    use super::*;
+
+    fn line_text(line: &Line<'_>) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_highlight_known_language_produces_one_line_per_source_line() {
+        let highlighter = CodeHighlighter::new("base16-ocean.dark");
+        let lines = highlighter.highlight(Some("rust"), "fn main() {\n    1;\n}", Style::default());
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_highlight_unknown_language_falls_back_to_flat_style() {
+        let highlighter = CodeHighlighter::new("base16-ocean.dark");
+        let fallback = Style::default().fg(Color::Gray);
+        let lines = highlighter.highlight(Some("not-a-real-language"), "plain text", fallback);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].spans[0].style, fallback);
+    }
+
+    #[test]
+    fn test_highlight_caches_by_content_hash() {
+        let highlighter = CodeHighlighter::new("base16-ocean.dark");
+        let first = highlighter.highlight(Some("rust"), "let x = 1;", Style::default());
+        let second = highlighter.highlight(Some("rust"), "let x = 1;", Style::default());
+        assert_eq!(line_text(&first[0]), line_text(&second[0]));
+    }
+
+    #[test]
+    fn test_theme_name_reports_requested_theme() {
+        let highlighter = CodeHighlighter::new("base16-ocean.dark");
+        assert_eq!(highlighter.theme_name(), "base16-ocean.dark");
+    }
+
+    #[test]
+    fn test_to_ratatui_style_carries_font_style_bits_as_modifiers() {
+        let style = SyntectStyle {
+            foreground: SyntectColor {
+                r: 1,
+                g: 2,
+                b: 3,
+                a: 255,
+            },
+            background: SyntectColor {
+                r: 0,
+                g: 0,
+                b: 0,
+                a: 255,
+            },
+            font_style: SyntectFontStyle::BOLD | SyntectFontStyle::ITALIC,
+        };
+
+        let ratatui_style = to_ratatui_style(style);
+        assert_eq!(ratatui_style.fg, Some(Color::Rgb(1, 2, 3)));
+        assert!(ratatui_style.add_modifier.contains(Modifier::BOLD));
+        assert!(ratatui_style.add_modifier.contains(Modifier::ITALIC));
+        assert!(!ratatui_style.add_modifier.contains(Modifier::UNDERLINED));
+    }
+}
@@ -0,0 +1,117 @@
+use std::path::Path;
+
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+
+/// Glyph used for each rendered pixel cell: its foreground paints the top
+/// source pixel, its background paints the bottom one, so one character row
+/// shows two rows of source pixels (the trick terminal image viewers like
+/// `viu` and `chafa` use).
+const HALF_BLOCK: &str = "\u{2580}";
+
+/// Decodes the image at `path` and renders it as Unicode half-block
+/// characters, downscaled to fit a `max_width` x `max_height` character-cell
+/// grid (i.e. `max_width` x `2 * max_height` source pixels after scaling).
+/// Returns an error description on decode failure so the caller can fall
+/// back to showing the image's alt text instead.
+pub fn render_image(
+    path: &Path,
+    max_width: u16,
+    max_height: u16,
+) -> Result<Vec<Line<'static>>, String> {
+    if max_width == 0 || max_height == 0 {
+        return Err("terminal area too small to render an image".to_string());
+    }
+
+    let image = image::open(path).map_err(|e| e.to_string())?.into_rgba8();
+
+    let (src_width, src_height) = image.dimensions();
+    if src_width == 0 || src_height == 0 {
+        return Err("image has no pixels".to_string());
+    }
+
+    let target_width = max_width as f64;
+    let target_height = max_height as f64 * 2.0;
+    let scale = (target_width / src_width as f64)
+        .min(target_height / src_height as f64)
+        .min(1.0);
+
+    let scaled_width = ((src_width as f64 * scale).round() as u32).max(1);
+    let mut scaled_height = ((src_height as f64 * scale).round() as u32).max(1);
+    if scaled_height % 2 != 0 {
+        scaled_height += 1;
+    }
+
+    let resized = image::imageops::resize(
+        &image,
+        scaled_width,
+        scaled_height,
+        image::imageops::FilterType::Triangle,
+    );
+
+    let rows = scaled_height / 2;
+    let mut lines = Vec::with_capacity(rows as usize);
+    for row in 0..rows {
+        let mut spans = Vec::with_capacity(scaled_width as usize);
+        for col in 0..scaled_width {
+            let top = resized.get_pixel(col, row * 2);
+            let bottom = resized.get_pixel(col, row * 2 + 1);
+            let style = Style::default()
+                .fg(Color::Rgb(top[0], top[1], top[2]))
+                .bg(Color::Rgb(bottom[0], bottom[1], bottom[2]));
+            spans.push(Span::styled(HALF_BLOCK, style));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_test_png(dir: &std::path::Path, width: u32, height: u32) -> std::path::PathBuf {
+        let path = dir.join("test.png");
+        let pixels = vec![255u8; (width * height * 3) as usize];
+        image::save_buffer(&path, &pixels, width, height, image::ColorType::Rgb8).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_render_image_scales_down_to_fit_cell_budget() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_test_png(temp_dir.path(), 200, 100);
+
+        let lines = render_image(&path, 20, 10).unwrap();
+
+        assert!(lines.len() <= 10);
+        assert!(lines[0].spans.len() <= 20);
+    }
+
+    #[test]
+    fn test_render_image_never_upscales_a_small_image() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_test_png(temp_dir.path(), 4, 2);
+
+        let lines = render_image(&path, 60, 20).unwrap();
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].spans.len(), 4);
+    }
+
+    #[test]
+    fn test_render_image_reports_an_error_for_a_missing_file() {
+        let missing = std::path::Path::new("/nonexistent/does-not-exist.png");
+        assert!(render_image(missing, 40, 20).is_err());
+    }
+
+    #[test]
+    fn test_render_image_rejects_a_zero_sized_budget() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_test_png(temp_dir.path(), 10, 10);
+
+        assert!(render_image(&path, 0, 10).is_err());
+    }
+}
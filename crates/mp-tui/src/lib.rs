@@ -4,12 +4,19 @@ use anyhow::Result;
 use mp_core::FinderConfig;
 
 mod app;
+mod bookmarks;
+mod file_finder;
 mod file_tree;
+mod fuzzy;
+mod highlight;
+mod image_render;
 mod preview;
 pub mod renderer;
 mod status_bar;
 mod theme_adapter;
+mod theme_config;
 mod tree_builder;
+mod watcher;
 
 pub use app::{App, AppFocus};
 pub use file_tree::FileTreeWidget;
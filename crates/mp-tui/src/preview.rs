@@ -1,4 +1,6 @@
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use ratatui::{
     Frame,
@@ -7,17 +9,41 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Wrap},
 };
 
-use mp_core::theme::{DefaultTheme, MarkdownTheme, ThemeAdapter};
+use mp_core::theme::{DefaultTheme, MarkdownTheme, SolarizedOsaka, Theme};
 
-use crate::renderer::{MarkdownWidget, MarkdownWidgetState};
-use crate::theme_adapter::RatatuiThemeAdapter;
+use crate::highlight::CodeHighlighter;
+use crate::renderer::{HeadingEntry, MarkdownWidget, MarkdownWidgetState};
+use crate::theme_adapter::RoleStyles;
+
+/// The bundled `syntect` theme code blocks are highlighted with by default,
+/// chosen to blend with the Solarized-like palette used elsewhere in the UI
+/// (e.g. `render_help`'s popup).
+const DEFAULT_CODE_THEME: &str = "Solarized (dark)";
+
+/// Name of the preset the preview's markdown rendering falls back to when
+/// none has been persisted or chosen yet.
+const DEFAULT_THEME_NAME: &str = "solarized-dark";
+
+/// Resolves a preset name to a theme instance, falling back to
+/// `SolarizedOsaka` if the name isn't a recognized bundled preset.
+fn theme_for_name(name: &str) -> Box<dyn MarkdownTheme> {
+    Theme::preset(name).unwrap_or_else(|| Box::new(SolarizedOsaka))
+}
 
 pub struct PreviewWidget {
     pub content: Arc<String>,
     pub scroll_offset: u16,
     pub markdown_widget: Option<MarkdownWidget>,
     pub markdown_state: MarkdownWidgetState,
-    theme: DefaultTheme,
+    pub search_mode: bool,
+    pub search_query: String,
+    role_styles: RoleStyles,
+    highlighter: Arc<CodeHighlighter>,
+    file_modified: Option<SystemTime>,
+    base_dir: Option<PathBuf>,
+    images_enabled: bool,
+    theme_name: String,
+    byte_budget: Option<usize>,
 }
 
 impl Default for PreviewWidget {
@@ -27,7 +53,15 @@ impl Default for PreviewWidget {
             scroll_offset: 0,
             markdown_widget: None,
             markdown_state: MarkdownWidgetState::default(),
-            theme: DefaultTheme,
+            search_mode: false,
+            search_query: String::new(),
+            role_styles: RoleStyles::new(&DefaultTheme),
+            highlighter: Arc::new(CodeHighlighter::new(DEFAULT_CODE_THEME)),
+            file_modified: None,
+            base_dir: None,
+            images_enabled: true,
+            theme_name: DEFAULT_THEME_NAME.to_string(),
+            byte_budget: None,
         }
     }
 }
@@ -37,11 +71,95 @@ impl PreviewWidget {
         Self::default()
     }
 
+    /// The `syntect` theme name fenced code blocks are currently highlighted
+    /// with.
+    pub fn code_theme_name(&self) -> &str {
+        self.highlighter.theme_name()
+    }
+
     pub fn set_content(&mut self, content: Arc<String>) {
         self.content = Arc::clone(&content);
-        self.markdown_widget = Some(MarkdownWidget::new(Arc::clone(&content)));
+        let mut widget = MarkdownWidget::with_theme(
+            Arc::clone(&content),
+            Arc::clone(&self.highlighter),
+            self.base_dir.clone(),
+            self.images_enabled,
+            theme_for_name(&self.theme_name),
+        );
+        if self.byte_budget.is_some() {
+            widget.set_byte_budget(self.byte_budget);
+        }
+        self.markdown_widget = Some(widget);
         self.scroll_offset = 0;
         self.markdown_state.scroll_offset = 0;
+        self.file_modified = None;
+        self.cancel_search();
+    }
+
+    /// Caps rendering to roughly the first `budget` bytes of the document,
+    /// giving large files a fast first paint; `None` renders in full. Takes
+    /// effect on the next [`Self::set_content`] call, and re-applies to the
+    /// already-loaded document immediately if one is open.
+    pub fn set_byte_budget(&mut self, budget: Option<usize>) {
+        self.byte_budget = budget;
+        if let Some(widget) = &mut self.markdown_widget {
+            widget.set_byte_budget(budget);
+        }
+    }
+
+    /// Whether the currently loaded document was cut short by a byte budget.
+    pub fn is_truncated(&self) -> bool {
+        self.markdown_widget
+            .as_ref()
+            .is_some_and(|widget| widget.is_truncated())
+    }
+
+    /// How many of the document's bytes were actually rendered, and its
+    /// total size, for the ` TRUNCATED 42KB/180KB ` status bar indicator.
+    pub fn truncation_progress(&self) -> (usize, usize) {
+        let rendered = self
+            .markdown_widget
+            .as_ref()
+            .map(|widget| widget.rendered_byte_count())
+            .unwrap_or(0);
+        (rendered, self.content.len())
+    }
+
+    /// The name of the theme fenced headings and other markdown roles are
+    /// currently rendered with.
+    pub fn theme_name(&self) -> &str {
+        &self.theme_name
+    }
+
+    /// Switches the active rendering theme, re-coloring the already-parsed
+    /// document in place without resetting scroll position or search state.
+    pub fn set_theme(&mut self, name: &str) {
+        self.theme_name = name.to_string();
+        if let Some(widget) = &mut self.markdown_widget {
+            widget.set_theme(theme_for_name(name));
+        }
+    }
+
+    /// Sets the directory local image references in the next [`Self::set_content`]
+    /// call should be resolved against. Call this before `set_content` when
+    /// loading a file, since image links in markdown are relative to the
+    /// file they appear in, not the process's working directory.
+    pub fn set_base_dir(&mut self, base_dir: Option<PathBuf>) {
+        self.base_dir = base_dir;
+    }
+
+    /// Gates inline image rendering on or off; when disabled, image
+    /// references fall back to showing their alt text.
+    pub fn set_images_enabled(&mut self, enabled: bool) {
+        self.images_enabled = enabled;
+    }
+
+    /// Records the on-disk modification time for the file currently loaded,
+    /// so the status line can show it without `stat`-ing the file on every
+    /// frame. Call this after [`Self::set_content`] whenever the content
+    /// came from disk.
+    pub fn set_file_modified(&mut self, modified: Option<SystemTime>) {
+        self.file_modified = modified;
     }
 
     pub fn scroll_up(&mut self, lines: u16) {
@@ -77,15 +195,110 @@ impl PreviewWidget {
         self.scroll_down(10);
     }
 
+    /// The current document's headings in document order, for the TOC jump
+    /// mode's listing. Empty when no document is loaded.
+    pub fn headings(&self) -> &[HeadingEntry] {
+        self.markdown_widget
+            .as_ref()
+            .map(|widget| widget.headings())
+            .unwrap_or(&[])
+    }
+
+    /// The declared language of the fenced code block the viewport's top
+    /// line currently falls inside, if any, for the status bar to show.
+    pub fn code_block_language_at_cursor(&self) -> Option<&str> {
+        self.markdown_widget
+            .as_ref()
+            .and_then(|widget| widget.language_at_line(self.scroll_offset as usize))
+    }
+
+    /// Scrolls so the given rendered line is at the top of the viewport,
+    /// used by the TOC jump mode to jump to a chosen heading.
+    pub fn jump_to_line(&mut self, line: usize) {
+        self.scroll_offset = line as u16;
+        self.markdown_state.scroll_offset = self.scroll_offset;
+    }
+
+    pub fn start_search(&mut self) {
+        self.search_mode = true;
+        self.search_query.clear();
+        self.update_search_matches();
+    }
+
+    pub fn cancel_search(&mut self) {
+        self.search_mode = false;
+        self.search_query.clear();
+        self.markdown_state.search_matches.clear();
+        self.markdown_state.active_match = None;
+    }
+
+    pub fn add_search_char(&mut self, c: char) {
+        self.search_query.push(c);
+        self.update_search_matches();
+    }
+
+    pub fn remove_search_char(&mut self) {
+        self.search_query.pop();
+        self.update_search_matches();
+    }
+
+    /// Jumps to the next match, wrapping around to the first after the last.
+    pub fn next_match(&mut self) {
+        let len = self.markdown_state.search_matches.len();
+        if len == 0 {
+            return;
+        }
+        let next = match self.markdown_state.active_match {
+            Some(index) => (index + 1) % len,
+            None => 0,
+        };
+        self.markdown_state.active_match = Some(next);
+        self.scroll_to_active_match();
+    }
+
+    /// Jumps to the previous match, wrapping around to the last after the first.
+    pub fn prev_match(&mut self) {
+        let len = self.markdown_state.search_matches.len();
+        if len == 0 {
+            return;
+        }
+        let prev = match self.markdown_state.active_match {
+            Some(0) | None => len - 1,
+            Some(index) => index - 1,
+        };
+        self.markdown_state.active_match = Some(prev);
+        self.scroll_to_active_match();
+    }
+
+    /// Re-runs the content search against the current query, jumping to the
+    /// first match found.
+    fn update_search_matches(&mut self) {
+        let matches = self
+            .markdown_widget
+            .as_ref()
+            .map(|widget| widget.find_matches(&self.search_query))
+            .unwrap_or_default();
+
+        self.markdown_state.active_match = if matches.is_empty() { None } else { Some(0) };
+        self.markdown_state.search_matches = matches;
+        self.scroll_to_active_match();
+    }
+
+    /// Scrolls the viewport so the currently active match's line is visible.
+    fn scroll_to_active_match(&mut self) {
+        if let Some(index) = self.markdown_state.active_match
+            && let Some(matched) = self.markdown_state.search_matches.get(index)
+        {
+            self.scroll_offset = matched.line as u16;
+            self.markdown_state.scroll_offset = self.scroll_offset;
+        }
+    }
+
     pub fn render(&mut self, frame: &mut Frame, area: Rect, is_focused: bool) {
         let border_style = if is_focused {
-            let focus_color = self.theme.focus_border_style().color;
-            let adapter = RatatuiThemeAdapter;
-            Style::default().fg(adapter.to_color(&focus_color))
+            self.role_styles.focus_border
         } else {
-            let delimiter_color = self.theme.delimiter_style().color;
-            let adapter = RatatuiThemeAdapter;
-            Style::default().fg(adapter.to_color(&delimiter_color))
+            self.role_styles.delimiter
         };
 
         if let Some(widget) = &self.markdown_widget {
@@ -115,15 +328,74 @@ impl PreviewWidget {
 
     pub fn get_status_info(&self) -> String {
         if self.content.is_empty() {
-            "No content".to_string()
-        } else {
-            let lines = self.content.lines().count();
-            let chars = self.content.len();
-            format!(
-                "Lines: {} | Chars: {} | Scroll: {}",
-                lines, chars, self.scroll_offset
-            )
+            return "No content".to_string();
+        }
+
+        let lines = self.content.lines().count();
+        let size = format_byte_size(self.content.len() as u64);
+        let scroll_percent = self.scroll_percent();
+
+        let mut parts = vec![format!("Lines: {}", lines), format!("Size: {}", size)];
+        if let Some(modified) = self.file_modified {
+            parts.push(format!("Modified: {}", format_relative_time(modified)));
         }
+        parts.push(format!("Scroll: {}%", scroll_percent));
+
+        parts.join(" | ")
+    }
+
+    /// Current scroll position as a percentage of the document's line count.
+    fn scroll_percent(&self) -> u8 {
+        let line_count = self
+            .markdown_widget
+            .as_ref()
+            .map(|widget| widget.line_count())
+            .unwrap_or(0);
+
+        if line_count <= 1 {
+            return 100;
+        }
+
+        let percent = self.scroll_offset as f64 / (line_count - 1) as f64 * 100.0;
+        percent.clamp(0.0, 100.0).round() as u8
+    }
+}
+
+/// Formats a byte count in human-readable form (e.g. `12.4K`), matching the
+/// style of file-manager footers like joshuto's.
+fn format_byte_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "K", "M", "G", "T"];
+
+    let mut size = bytes as f64;
+    let mut unit_index = 0;
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+
+    if unit_index == 0 {
+        format!("{}{}", bytes, UNITS[unit_index])
+    } else {
+        format!("{:.1}{}", size, UNITS[unit_index])
+    }
+}
+
+/// Formats how long ago `modified` was, in the coarsest unit that keeps the
+/// number small (seconds, minutes, hours, then days).
+fn format_relative_time(modified: SystemTime) -> String {
+    let Ok(elapsed) = SystemTime::now().duration_since(modified) else {
+        return "just now".to_string();
+    };
+
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{}s ago", secs)
+    } else if secs < 3600 {
+        format!("{}m ago", secs / 60)
+    } else if secs < 86_400 {
+        format!("{}h ago", secs / 3600)
+    } else {
+        format!("{}d ago", secs / 86_400)
     }
 }
 
@@ -165,6 +437,43 @@ mod tests {
         widget.set_content(Arc::new("Line 1\nLine 2".to_string()));
         let status = widget.get_status_info();
         assert!(status.contains("Lines: 2"));
-        assert!(status.contains("Chars: 13"));
+        assert!(status.contains("Size: 13B"));
+        assert!(status.contains("Scroll: 0%"));
+        assert!(!status.contains("Modified:"));
+    }
+
+    #[test]
+    fn test_status_info_includes_modified_time_once_set() {
+        let mut widget = PreviewWidget::new();
+        widget.set_content(Arc::new("Line 1\nLine 2".to_string()));
+        widget.set_file_modified(Some(SystemTime::now()));
+
+        let status = widget.get_status_info();
+        assert!(status.contains("Modified: 0s ago"));
+    }
+
+    #[test]
+    fn test_status_info_scroll_percent_reflects_scroll_offset() {
+        let mut widget = PreviewWidget::new();
+        // A tight five-item list renders to exactly five lines, so offset 2
+        // is halfway to the last line.
+        widget.set_content(Arc::new("- L1\n- L2\n- L3\n- L4\n- L5".to_string()));
+
+        widget.scroll_offset = 2;
+        let status = widget.get_status_info();
+        assert!(status.contains("Scroll: 50%"));
+    }
+
+    #[test]
+    fn test_format_byte_size_uses_human_readable_units() {
+        assert_eq!(format_byte_size(13), "13B");
+        assert_eq!(format_byte_size(12_698), "12.4K");
+        assert_eq!(format_byte_size(1_500_000), "1.4M");
+    }
+
+    #[test]
+    fn test_code_theme_name_defaults_to_bundled_theme() {
+        let widget = PreviewWidget::new();
+        assert_eq!(widget.code_theme_name(), DEFAULT_CODE_THEME);
     }
 }
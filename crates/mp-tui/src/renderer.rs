@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::sync::{Arc, LazyLock};
 
 use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
@@ -10,43 +11,280 @@ use ratatui::{
 };
 use regex::Regex;
 
+use mp_core::HeadingIdGenerator;
 use mp_core::theme::{MarkdownTheme, SolarizedOsaka, ThemeAdapter};
 
+use crate::highlight::CodeHighlighter;
 use crate::theme_adapter::RatatuiThemeAdapter;
 use unicode_width::UnicodeWidthStr;
 
+/// Character-cell budget local images are rasterized into. Images are
+/// rendered once, at parse time, the same way tables and code blocks
+/// already are in this widget — before the actual viewport width is known —
+/// so this is a fixed size rather than one derived from the render area.
+const MAX_IMAGE_WIDTH: u16 = 60;
+const MAX_IMAGE_HEIGHT_CELLS: u16 = 20;
+
+/// Maximum on-screen width (including borders) `create_table_lines` lays a
+/// table out for, since tables, like images above, are rendered once at
+/// parse time, before the actual viewport width is known. Columns wider
+/// than this budget shrink one column at a time (always the currently widest), wrap cell
+/// text onto extra rows within their column, and fall back to an ellipsis
+/// when a single token still can't fit.
+const MAX_TABLE_WIDTH: usize = 100;
+
+/// Whether the `NO_COLOR` environment variable is set, checked once and
+/// cached for the process lifetime (the convention `xplr` and other
+/// terminal tools follow). Used only to pick [`MarkdownWidget`]'s initial
+/// [`MarkdownWidget::set_monochrome`] value; the setter lets callers
+/// override it programmatically regardless of the environment.
+static NO_COLOR: LazyLock<bool> = LazyLock::new(|| std::env::var_os("NO_COLOR").is_some());
+
 pub struct MarkdownWidget {
     content: Arc<String>,
     lines: Vec<Line<'static>>,
-    theme: SolarizedOsaka,
+    headings: Vec<HeadingEntry>,
+    code_blocks: Vec<CodeBlockEntry>,
+    theme: Box<dyn MarkdownTheme>,
+    highlighter: Arc<CodeHighlighter>,
+    base_dir: Option<PathBuf>,
+    images_enabled: bool,
+    byte_budget: Option<usize>,
+    truncated: bool,
+    rendered_bytes: usize,
+    monochrome: bool,
+}
+
+/// A heading discovered while parsing the document, carrying a stable
+/// mdBook-style anchor ID and the rendered line it starts at so the TOC jump
+/// mode can list headings and scroll straight to one.
+#[derive(Debug, Clone)]
+pub struct HeadingEntry {
+    pub id: String,
+    pub level: u8,
+    pub text: String,
+    pub line: usize,
+}
+
+/// A fenced code block discovered while parsing the document, carrying its
+/// declared language (if any) and the rendered line range it occupies, so
+/// the status bar can show what language the cursor is currently inside.
+#[derive(Debug, Clone)]
+pub struct CodeBlockEntry {
+    pub language: Option<String>,
+    pub start_line: usize,
+    pub end_line: usize,
 }
 
 #[derive(Default, Clone)]
 pub struct MarkdownWidgetState {
     pub scroll_offset: u16,
+    pub search_matches: Vec<SearchMatch>,
+    pub active_match: Option<usize>,
+    /// Reflow long lines across multiple visual rows at word boundaries
+    /// instead of hard-truncating them at the render area's width. Off by
+    /// default, matching today's truncating behavior. See
+    /// [`MarkdownWidget::visual_line_count`] for bounding scroll against the
+    /// row count this produces.
+    pub wrap: bool,
+    /// Display columns to skip from the start of every rendered line, so
+    /// wide code blocks and tables can be panned horizontally the same way
+    /// [`MarkdownWidgetState::scroll_offset`] pans vertically. Counted with
+    /// [`unicode_width::UnicodeWidthStr`] like every other column
+    /// measurement in this widget, so a partially-scrolled double-width
+    /// character is skipped in its entirety rather than left half-drawn.
+    pub horizontal_offset: u16,
+}
+
+/// A single occurrence of a content-search query, located by the rendered
+/// line it falls on and its character range within that line's plain text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchMatch {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
 }
 
 impl MarkdownWidget {
-    pub fn new(content: Arc<String>) -> Self {
+    pub fn new(content: Arc<String>, highlighter: Arc<CodeHighlighter>) -> Self {
+        Self::with_images(content, highlighter, None, true)
+    }
+
+    /// Like [`Self::new`], additionally configuring inline image rendering:
+    /// `base_dir` is the directory local image references are resolved
+    /// against (typically the open file's parent directory), and
+    /// `images_enabled` gates the feature off entirely, falling back to
+    /// showing each image's alt text instead.
+    pub fn with_images(
+        content: Arc<String>,
+        highlighter: Arc<CodeHighlighter>,
+        base_dir: Option<PathBuf>,
+        images_enabled: bool,
+    ) -> Self {
+        Self::with_theme(
+            content,
+            highlighter,
+            base_dir,
+            images_enabled,
+            Box::new(SolarizedOsaka),
+        )
+    }
+
+    /// Like [`Self::with_images`], additionally taking the theme to render
+    /// with, so the preview can be re-themed live by the theme picker mode.
+    pub fn with_theme(
+        content: Arc<String>,
+        highlighter: Arc<CodeHighlighter>,
+        base_dir: Option<PathBuf>,
+        images_enabled: bool,
+        theme: Box<dyn MarkdownTheme>,
+    ) -> Self {
         let mut widget = Self {
             content,
             lines: Vec::with_capacity(100),
-            theme: SolarizedOsaka,
+            headings: Vec::new(),
+            code_blocks: Vec::new(),
+            theme,
+            highlighter,
+            base_dir,
+            images_enabled,
+            byte_budget: None,
+            truncated: false,
+            rendered_bytes: 0,
+            monochrome: *NO_COLOR,
         };
         widget.parse_markdown();
         widget
     }
 
+    /// Switches the active theme and re-parses the document so every
+    /// rendered span picks up the new colors, preserving scroll position
+    /// (which lives on `MarkdownWidgetState`, not here).
+    pub fn set_theme(&mut self, theme: Box<dyn MarkdownTheme>) {
+        self.theme = theme;
+        self.parse_markdown();
+    }
+
+    /// Enables or disables monochrome rendering and re-parses the document,
+    /// stripping every emitted [`Span`]'s foreground/background color while
+    /// keeping bold/italic/underline [`Modifier`]s, so headings, emphasis,
+    /// links, and code stay visually distinguishable without color. Defaults
+    /// to the `NO_COLOR` environment variable's presence (see [`NO_COLOR`])
+    /// but can be toggled independently of it.
+    pub fn set_monochrome(&mut self, monochrome: bool) {
+        self.monochrome = monochrome;
+        self.parse_markdown();
+    }
+
+    pub fn is_monochrome(&self) -> bool {
+        self.monochrome
+    }
+
+    /// Limits rendering to roughly the first `budget` bytes of source
+    /// markdown, stopping at the next completed block (heading, paragraph,
+    /// list, table, or code block) rather than mid-inline, and re-parses to
+    /// apply it. Pass `None` to render the whole document again.
+    pub fn set_byte_budget(&mut self, budget: Option<usize>) {
+        self.byte_budget = budget;
+        self.parse_markdown();
+    }
+
+    /// Whether the last parse stopped early because of [`Self::set_byte_budget`].
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// How many bytes of the source document were actually rendered; equal
+    /// to the full document length unless [`Self::is_truncated`] is true.
+    pub fn rendered_byte_count(&self) -> usize {
+        self.rendered_bytes
+    }
+
     pub fn line_count(&self) -> usize {
         self.lines.len()
     }
 
+    /// The number of visual rows the document occupies once reflowed to
+    /// `width` columns per [`MarkdownWidgetState::wrap`], so scroll logic
+    /// that otherwise bounds itself against [`Self::line_count`] can clamp
+    /// correctly in wrap mode. `width == 0` (no usable area yet) falls back
+    /// to [`Self::line_count`].
+    pub fn visual_line_count(&self, width: u16) -> usize {
+        if width == 0 {
+            return self.line_count();
+        }
+        self.lines
+            .iter()
+            .map(|line| wrap_line(line, width as usize).len())
+            .sum()
+    }
+
     pub fn get_lines(&self) -> &Vec<Line<'static>> {
         &self.lines
     }
 
+    /// The document's headings in document order, each with a unique
+    /// mdBook-style anchor ID and the rendered line it starts at.
+    pub fn headings(&self) -> &[HeadingEntry] {
+        &self.headings
+    }
+
+    /// The declared language of the fenced code block containing `line`, if
+    /// any line of the document is currently inside one.
+    pub fn language_at_line(&self, line: usize) -> Option<&str> {
+        self.code_blocks
+            .iter()
+            .find(|block| line >= block.start_line && line < block.end_line)
+            .and_then(|block| block.language.as_deref())
+    }
+
+    /// Finds every case-insensitive occurrence of `query` in the rendered
+    /// document, in line then left-to-right order. Returns no matches for an
+    /// empty query.
+    pub fn find_matches(&self, query: &str) -> Vec<SearchMatch> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let needle: Vec<char> = query.to_lowercase().chars().collect();
+        let mut matches = Vec::new();
+
+        for (line_index, line) in self.lines.iter().enumerate() {
+            let haystack: Vec<char> = line
+                .spans
+                .iter()
+                .flat_map(|span| span.content.chars())
+                .collect::<String>()
+                .to_lowercase()
+                .chars()
+                .collect();
+
+            if needle.len() > haystack.len() {
+                continue;
+            }
+
+            for start in 0..=(haystack.len() - needle.len()) {
+                if haystack[start..start + needle.len()] == needle[..] {
+                    matches.push(SearchMatch {
+                        line: line_index,
+                        start,
+                        end: start + needle.len(),
+                    });
+                }
+            }
+        }
+
+        matches
+    }
+
     pub fn parse_markdown(&mut self) {
         self.lines.clear();
+        self.headings.clear();
+        self.code_blocks.clear();
+        let mut heading_ids = HeadingIdGenerator::new();
+        let mut current_heading_level: Option<u8> = None;
+        let mut heading_text = String::new();
+        let mut in_heading = false;
 
         let mut current_line = Vec::with_capacity(10);
         let mut current_style = Style::default();
@@ -57,6 +295,7 @@ impl MarkdownWidget {
         let mut first_table_row = true;
         let mut table_headers: Vec<String> = Vec::with_capacity(10);
         let mut table_rows: Vec<Vec<String>> = Vec::with_capacity(20);
+        let mut table_alignments: Vec<pulldown_cmark::Alignment> = Vec::with_capacity(10);
         let mut current_row: Vec<String> = Vec::with_capacity(10);
         let mut current_cell = String::new();
 
@@ -64,16 +303,35 @@ impl MarkdownWidget {
         let mut code_block_content = String::new();
         let mut code_block_language: Option<String> = None;
 
+        let mut in_image = false;
+        let mut image_dest = String::new();
+        let mut image_alt = String::new();
+
         let mut options = Options::empty();
         options.insert(Options::ENABLE_TABLES);
         options.insert(Options::ENABLE_STRIKETHROUGH);
 
         let parser = Parser::new_ext(&self.content, options);
 
-        for event in parser {
+        self.truncated = false;
+        self.rendered_bytes = self.content.len();
+
+        for (event, range) in parser.into_offset_iter() {
+            let is_block_boundary = matches!(
+                &event,
+                Event::End(
+                    TagEnd::Table
+                        | TagEnd::CodeBlock
+                        | TagEnd::Heading(_)
+                        | TagEnd::List(_)
+                        | TagEnd::Paragraph
+                        | TagEnd::FootnoteDefinition
+                )
+            );
+
             match event {
                 Event::Start(tag) => match tag {
-                    Tag::Table(_) => {
+                    Tag::Table(alignments) => {
                         if !current_line.is_empty() {
                             self.lines
                                 .push(Line::from(std::mem::take(&mut current_line)));
@@ -82,6 +340,7 @@ impl MarkdownWidget {
                         first_table_row = true;
                         table_headers.clear();
                         table_rows.clear();
+                        table_alignments = alignments;
                     }
                     Tag::TableHead => {}
                     Tag::TableRow => {
@@ -120,6 +379,9 @@ impl MarkdownWidget {
                         let heading_style = self.theme.heading_style(heading_level);
                         let adapter = RatatuiThemeAdapter;
                         current_style = adapter.to_style(&heading_style);
+                        current_heading_level = Some(heading_level);
+                        heading_text.clear();
+                        in_heading = true;
                     }
                     Tag::Emphasis => {
                         let emphasis_style = self.theme.emphasis_style();
@@ -170,6 +432,15 @@ impl MarkdownWidget {
                             .fg(color)
                             .add_modifier(Modifier::UNDERLINED);
                     }
+                    Tag::Image { dest_url, .. } => {
+                        if !current_line.is_empty() {
+                            self.lines
+                                .push(Line::from(std::mem::take(&mut current_line)));
+                        }
+                        in_image = true;
+                        image_dest = dest_url.to_string();
+                        image_alt.clear();
+                    }
                     Tag::FootnoteDefinition(label) => {
                         if !current_line.is_empty() {
                             self.lines
@@ -188,10 +459,15 @@ impl MarkdownWidget {
                 Event::End(tag) => match tag {
                     TagEnd::Table => {
                         in_table = false;
-                        let rendered_lines = Self::create_table_lines(&table_headers, &table_rows);
+                        let rendered_lines = Self::create_table_lines(
+                            &table_headers,
+                            &table_rows,
+                            &table_alignments,
+                        );
                         self.lines.extend(rendered_lines);
                         table_headers.clear();
                         table_rows.clear();
+                        table_alignments.clear();
                     }
                     TagEnd::TableHead => {}
                     TagEnd::TableRow => {
@@ -211,19 +487,38 @@ impl MarkdownWidget {
                     }
                     TagEnd::CodeBlock => {
                         in_code_block = false;
+                        let start_line = self.lines.len();
                         let rendered_lines =
                             self.create_code_block_lines(&code_block_language, &code_block_content);
+                        self.code_blocks.push(CodeBlockEntry {
+                            language: code_block_language.clone(),
+                            start_line,
+                            end_line: start_line + rendered_lines.len(),
+                        });
                         self.lines.extend(rendered_lines);
                         code_block_content.clear();
                         code_block_language = None;
                     }
                     TagEnd::Heading(_) => {
+                        let line_index = self.lines.len();
                         if !current_line.is_empty() {
                             self.lines
                                 .push(Line::from(std::mem::take(&mut current_line)));
                         }
                         self.lines.push(Line::from(""));
                         current_style = Style::default();
+
+                        if let Some(level) = current_heading_level.take() {
+                            let id = heading_ids.unique_id(&heading_text);
+                            self.headings.push(HeadingEntry {
+                                id,
+                                level,
+                                text: heading_text.clone(),
+                                line: line_index,
+                            });
+                        }
+                        heading_text.clear();
+                        in_heading = false;
                     }
                     TagEnd::Emphasis | TagEnd::Strong => {
                         current_style = Style::default();
@@ -245,6 +540,13 @@ impl MarkdownWidget {
                     TagEnd::Link => {
                         current_style = Style::default();
                     }
+                    TagEnd::Image => {
+                        in_image = false;
+                        self.lines
+                            .extend(self.render_image_block(&image_dest, &image_alt));
+                        image_dest.clear();
+                        image_alt.clear();
+                    }
                     TagEnd::Paragraph => {
                         if !current_line.is_empty() && !in_table {
                             self.lines
@@ -264,7 +566,12 @@ impl MarkdownWidget {
                     _ => {}
                 },
                 Event::Text(text) => {
-                    if in_table {
+                    if in_heading {
+                        heading_text.push_str(&text);
+                    }
+                    if in_image {
+                        image_alt.push_str(&text);
+                    } else if in_table {
                         current_cell.push_str(&text);
                     } else if in_code_block {
                         code_block_content.push_str(&text);
@@ -280,6 +587,9 @@ impl MarkdownWidget {
                     }
                 }
                 Event::Code(code) => {
+                    if in_heading {
+                        heading_text.push_str(&code);
+                    }
                     if in_table {
                         current_cell.push_str(&format!("`{}`", code));
                     } else {
@@ -315,25 +625,202 @@ impl MarkdownWidget {
                 }
                 _ => {}
             }
+
+            if let Some(budget) = self.byte_budget
+                && is_block_boundary
+                && range.end > budget
+            {
+                self.truncated = true;
+                self.rendered_bytes = range.end;
+                break;
+            }
         }
 
         if !current_line.is_empty() {
             self.lines.push(Line::from(current_line));
         }
+
+        if self.monochrome {
+            for line in &mut self.lines {
+                for span in &mut line.spans {
+                    span.style = strip_color(span.style);
+                }
+            }
+        }
+    }
+
+    /// Renders a local image reference as half-block character art, falling
+    /// back to its alt text when image rendering is disabled, the reference
+    /// isn't a local path, or decoding fails.
+    fn render_image_block(&self, dest: &str, alt: &str) -> Vec<Line<'static>> {
+        if self.images_enabled && !dest.contains("://") {
+            if let Some(base_dir) = &self.base_dir {
+                let path = base_dir.join(dest);
+                if let Ok(mut lines) = crate::image_render::render_image(
+                    &path,
+                    MAX_IMAGE_WIDTH,
+                    MAX_IMAGE_HEIGHT_CELLS,
+                ) {
+                    lines.push(Line::from(""));
+                    return lines;
+                }
+            }
+        }
+
+        image_fallback_lines(dest, alt)
     }
 
-    fn pad_unicode_str(s: &str, target_width: usize) -> String {
+    /// Pads `s` to `target_width` display columns per `alignment`:
+    /// `Alignment::Right` pads on the left, `Alignment::Center` splits the
+    /// padding across both sides (the extra column, if any, goes on the
+    /// right), and `Left`/`None` pad on the right as before. Width is
+    /// measured with [`UnicodeWidthStr`] throughout.
+    fn pad_unicode_str(s: &str, target_width: usize, alignment: pulldown_cmark::Alignment) -> String {
         let current_width = s.width();
         if current_width >= target_width {
-            s.to_string()
-        } else {
-            let padding = " ".repeat(target_width - current_width);
-            format!("{}{}", s, padding)
+            return s.to_string();
+        }
+        let total_padding = target_width - current_width;
+        match alignment {
+            pulldown_cmark::Alignment::Right => format!("{}{}", " ".repeat(total_padding), s),
+            pulldown_cmark::Alignment::Center => {
+                let left = total_padding / 2;
+                let right = total_padding - left;
+                format!("{}{}{}", " ".repeat(left), s, " ".repeat(right))
+            }
+            pulldown_cmark::Alignment::Left | pulldown_cmark::Alignment::None => {
+                format!("{}{}", s, " ".repeat(total_padding))
+            }
         }
     }
 
-    fn create_table_lines(headers: &[String], rows: &[Vec<String>]) -> Vec<Line<'static>> {
-        let mut lines = Vec::with_capacity(rows.len() + 3);
+    /// Renders a box-drawing border row (e.g. `┌─┬─┐`) for `column_widths`,
+    /// using `left`/`mid`/`right` as the corner/junction characters and `─`
+    /// to fill each column.
+    fn table_border_line(column_widths: &[usize], left: char, mid: char, right: char) -> String {
+        let segments: Vec<String> = column_widths
+            .iter()
+            .map(|&width| "─".repeat(width + 2))
+            .collect();
+        format!("{left}{}{right}", segments.join(&mid.to_string()))
+    }
+
+    /// Shrinks the widest column one column-width at a time (repeating over
+    /// whichever column is currently widest) until `column_widths` fits
+    /// within `max_total_width` including its `│`/padding overhead, or every
+    /// column has been shrunk down to the 3-column floor [`Self::create_table_lines`]
+    /// already enforces.
+    fn fit_column_widths(column_widths: &mut [usize], max_total_width: usize) {
+        let border_overhead = column_widths.len() + 1;
+        loop {
+            let total: usize =
+                column_widths.iter().map(|width| width + 2).sum::<usize>() + border_overhead;
+            if total <= max_total_width {
+                return;
+            }
+            let Some((widest_index, &widest_width)) =
+                column_widths.iter().enumerate().max_by_key(|&(_, &w)| w)
+            else {
+                return;
+            };
+            if widest_width <= 3 {
+                return;
+            }
+            column_widths[widest_index] -= 1;
+        }
+    }
+
+    /// Wraps `cell` onto as many lines as needed to fit `width` display
+    /// columns, breaking at word boundaries. A single word wider than
+    /// `width` on its own is truncated with a trailing `…` rather than left
+    /// to overflow its column.
+    fn wrap_cell_text(cell: &str, width: usize) -> Vec<String> {
+        if width == 0 || cell.width() <= width {
+            return vec![cell.to_string()];
+        }
+
+        let mut lines = Vec::new();
+        let mut current = String::new();
+        let mut current_width = 0usize;
+
+        for word in cell.split_whitespace() {
+            let word_width = word.width();
+            if word_width > width {
+                if !current.is_empty() {
+                    lines.push(std::mem::take(&mut current));
+                    current_width = 0;
+                }
+                let (truncated, _) = truncate_unicode_string(word, width.saturating_sub(1));
+                lines.push(format!("{truncated}…"));
+                continue;
+            }
+
+            let needs_space = !current.is_empty();
+            if current_width + usize::from(needs_space) + word_width > width {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+            if !current.is_empty() {
+                current.push(' ');
+                current_width += 1;
+            }
+            current.push_str(word);
+            current_width += word_width;
+        }
+
+        if !current.is_empty() || lines.is_empty() {
+            lines.push(current);
+        }
+        lines
+    }
+
+    /// Renders one logical table row as one or more [`Line`]s: a cell whose
+    /// text doesn't fit its column wraps onto additional rows via
+    /// [`Self::wrap_cell_text`], with every other column blank-padded on
+    /// those extra rows so the row stays rectangular.
+    fn render_table_row(
+        cells: &[String],
+        column_widths: &[usize],
+        alignment_for: &impl Fn(usize) -> pulldown_cmark::Alignment,
+        style: Style,
+    ) -> Vec<Line<'static>> {
+        let wrapped_cells: Vec<Vec<String>> = column_widths
+            .iter()
+            .enumerate()
+            .map(|(i, &width)| {
+                let cell = cells.get(i).map(String::as_str).unwrap_or("");
+                Self::wrap_cell_text(cell, width)
+            })
+            .collect();
+
+        let row_height = wrapped_cells.iter().map(Vec::len).max().unwrap_or(1);
+
+        (0..row_height)
+            .map(|sub_row| {
+                let rendered_cells: Vec<String> = wrapped_cells
+                    .iter()
+                    .enumerate()
+                    .map(|(i, sub_lines)| {
+                        let text = sub_lines.get(sub_row).map(String::as_str).unwrap_or("");
+                        let padded =
+                            Self::pad_unicode_str(text, column_widths[i], alignment_for(i));
+                        format!(" {padded} ")
+                    })
+                    .collect();
+                Line::from(vec![Span::styled(
+                    format!("│{}│", rendered_cells.join("│")),
+                    style,
+                )])
+            })
+            .collect()
+    }
+
+    fn create_table_lines(
+        headers: &[String],
+        rows: &[Vec<String>],
+        alignments: &[pulldown_cmark::Alignment],
+    ) -> Vec<Line<'static>> {
+        let mut lines = Vec::with_capacity(rows.len() + 4);
 
         let num_columns = if !headers.is_empty() {
             headers.len()
@@ -347,6 +834,13 @@ impl MarkdownWidget {
             return lines;
         }
 
+        let alignment_for = |i: usize| {
+            alignments
+                .get(i)
+                .copied()
+                .unwrap_or(pulldown_cmark::Alignment::None)
+        };
+
         let mut column_widths = vec![0; num_columns];
 
         for (i, header) in headers.iter().enumerate() {
@@ -367,42 +861,49 @@ impl MarkdownWidget {
             *width = (*width).max(3);
         }
 
-        if !headers.is_empty() {
-            let mut header_cells = Vec::new();
-            for (i, header) in headers.iter().enumerate() {
-                let width = column_widths.get(i).copied().unwrap_or(3);
-                let padded = Self::pad_unicode_str(header, width);
-                header_cells.push(padded);
-            }
-            let header_line = header_cells.join(" | ");
+        Self::fit_column_widths(&mut column_widths, MAX_TABLE_WIDTH);
 
-            lines.push(Line::from(vec![Span::styled(
-                header_line.clone(),
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            )]));
+        lines.push(Line::from(Self::table_border_line(
+            &column_widths,
+            '┌',
+            '┬',
+            '┐',
+        )));
 
-            let separator_cells: Vec<String> = column_widths
-                .iter()
-                .map(|&width| "-".repeat(width))
-                .collect();
-            let separator_line = separator_cells.join("-+-");
-            lines.push(Line::from(separator_line));
+        if !headers.is_empty() {
+            let header_style = Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD);
+            lines.extend(Self::render_table_row(
+                headers,
+                &column_widths,
+                &alignment_for,
+                header_style,
+            ));
+
+            lines.push(Line::from(Self::table_border_line(
+                &column_widths,
+                '├',
+                '┼',
+                '┤',
+            )));
         }
 
         for row in rows {
-            let mut row_cells = Vec::new();
-            for i in 0..num_columns {
-                let width = column_widths.get(i).copied().unwrap_or(3);
-                let cell = row.get(i).map(|s| s.as_str()).unwrap_or("");
-                let padded = Self::pad_unicode_str(cell, width);
-                row_cells.push(padded);
-            }
-            let row_line = row_cells.join(" | ");
-            lines.push(Line::from(row_line));
+            lines.extend(Self::render_table_row(
+                row,
+                &column_widths,
+                &alignment_for,
+                Style::default(),
+            ));
         }
 
+        lines.push(Line::from(Self::table_border_line(
+            &column_widths,
+            '└',
+            '┴',
+            '┘',
+        )));
         lines.push(Line::from(""));
         lines
     }
@@ -430,11 +931,12 @@ impl MarkdownWidget {
         let theme_code_style = self.theme.code_style();
         let adapter = RatatuiThemeAdapter;
         let code_color = adapter.to_color(&theme_code_style.color);
-        let code_style = Style::default().fg(code_color);
+        let fallback_style = Style::default().fg(code_color);
 
-        for line in content.lines() {
-            lines.push(Line::from(Span::styled(line.to_owned(), code_style)));
-        }
+        lines.extend(
+            self.highlighter
+                .highlight(language.as_deref(), content, fallback_style),
+        );
 
         lines.push(Line::from(Span::styled("```", fence_style)));
         lines.push(Line::from(""));
@@ -442,6 +944,15 @@ impl MarkdownWidget {
     }
 }
 
+fn image_fallback_lines(dest: &str, alt: &str) -> Vec<Line<'static>> {
+    let label = if alt.is_empty() {
+        dest.to_string()
+    } else {
+        format!("{} ({})", alt, dest)
+    };
+    vec![Line::from(format!("[image: {}]", label)), Line::from("")]
+}
+
 fn truncate_unicode_string(text: &str, max_width: usize) -> (String, usize) {
     let mut current_width = 0;
     let mut char_count = 0;
@@ -459,6 +970,43 @@ fn truncate_unicode_string(text: &str, max_width: usize) -> (String, usize) {
     (truncated, current_width)
 }
 
+/// Clears a [`Style`]'s foreground and background color for
+/// [`MarkdownWidget::set_monochrome`], keeping every other field (in
+/// particular its bold/italic/underline [`Modifier`]s) untouched so
+/// structural emphasis still reads without color.
+fn strip_color(style: Style) -> Style {
+    Style {
+        fg: None,
+        bg: None,
+        ..style
+    }
+}
+
+/// Skips `skip` display columns from the start of `text` for horizontal
+/// scrolling, returning the remaining slice and however much of `skip`
+/// wasn't consumed because `text` ran out first (so a caller can carry it
+/// over into the next span). A character whose width would straddle the
+/// skip boundary (a double-width glyph when only one column of skip
+/// remains) is skipped in its entirety rather than left half-drawn.
+fn skip_display_columns(text: &str, skip: usize) -> (&str, usize) {
+    if skip == 0 {
+        return (text, 0);
+    }
+
+    let mut consumed = 0;
+    let mut byte_index = 0;
+
+    for ch in text.chars() {
+        if consumed >= skip {
+            break;
+        }
+        consumed += ch.to_string().width();
+        byte_index += ch.len_utf8();
+    }
+
+    (&text[byte_index..], skip.saturating_sub(consumed))
+}
+
 impl StatefulWidget for &MarkdownWidget {
     type State = MarkdownWidgetState;
 
@@ -466,37 +1014,667 @@ impl StatefulWidget for &MarkdownWidget {
         let visible_lines = area.height as usize;
         let skip_lines = state.scroll_offset as usize;
 
-        for (i, line) in self
+        if !state.wrap {
+            for (i, line) in self
+                .lines
+                .iter()
+                .skip(skip_lines)
+                .take(visible_lines)
+                .enumerate()
+            {
+                let line_index = skip_lines + i;
+                let y = area.y + i as u16;
+
+                let line_matches: Vec<(usize, &SearchMatch)> = state
+                    .search_matches
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, m)| m.line == line_index)
+                    .collect();
+
+                if line_matches.is_empty() {
+                    render_plain_line(line, state.horizontal_offset as usize, area, y, buf);
+                } else {
+                    render_highlighted_line(
+                        line,
+                        &line_matches,
+                        state.active_match,
+                        0,
+                        state.horizontal_offset as usize,
+                        area,
+                        y,
+                        buf,
+                    );
+                }
+            }
+            return;
+        }
+
+        // Wrap mode: reflow every logical line to `area.width` columns and
+        // scroll/render over the resulting visual rows instead, carrying
+        // each row's originating logical line index (for search-match
+        // lookup) and its starting character offset into that logical
+        // line's plain text (so highlighted character ranges still line up
+        // after a line has been split across rows).
+        let visual_rows: Vec<(usize, usize, Line<'static>)> = self
             .lines
             .iter()
-            .skip(skip_lines)
-            .take(visible_lines)
             .enumerate()
+            .flat_map(|(logical_index, line)| {
+                wrap_line(line, area.width as usize)
+                    .into_iter()
+                    .map(move |(offset, row)| (logical_index, offset, row))
+            })
+            .collect();
+
+        for (i, (logical_index, offset, line)) in
+            visual_rows.iter().skip(skip_lines).take(visible_lines).enumerate()
         {
             let y = area.y + i as u16;
-            let mut x = area.x;
 
-            for span in &line.spans {
-                let content = span.content.as_ref();
-                let remaining_width = (area.width as usize).saturating_sub((x - area.x) as usize);
+            let line_matches: Vec<(usize, &SearchMatch)> = state
+                .search_matches
+                .iter()
+                .enumerate()
+                .filter(|(_, m)| m.line == *logical_index)
+                .collect();
 
-                let (truncated, actual_width) = truncate_unicode_string(content, remaining_width);
+            if line_matches.is_empty() {
+                render_plain_line(line, state.horizontal_offset as usize, area, y, buf);
+            } else {
+                render_highlighted_line(
+                    line,
+                    &line_matches,
+                    state.active_match,
+                    *offset,
+                    state.horizontal_offset as usize,
+                    area,
+                    y,
+                    buf,
+                );
+            }
+        }
+    }
+}
+
+/// Splits `line` into [`Line`]s that each fit within `width` display
+/// columns, greedily accumulating whitespace-delimited words (measured with
+/// [`UnicodeWidthStr`]) and breaking a single word wider than `width` at
+/// character boundaries as a fallback. Each output row keeps its spans'
+/// original [`Style`]s and is paired with the character offset, into the
+/// input line's flattened text, that it starts at — so callers can shift
+/// search-match character ranges (which index the unwrapped line) onto the
+/// row that now contains them. Returns the line unchanged (offset `0`) when
+/// `width` is `0`.
+fn wrap_line(line: &Line<'static>, width: usize) -> Vec<(usize, Line<'static>)> {
+    if width == 0 {
+        return vec![(0, line.clone())];
+    }
+
+    struct Token {
+        text: String,
+        style: Style,
+        is_space: bool,
+        char_offset: usize,
+    }
 
-                if !truncated.is_empty() {
-                    buf.set_stringn(x, y, &truncated, remaining_width, span.style);
-                    x += actual_width as u16;
+    let mut tokens = Vec::new();
+    let mut abs_char_index = 0usize;
+    for span in &line.spans {
+        let mut current = String::new();
+        let mut current_is_space: Option<bool> = None;
+        let mut token_start = abs_char_index;
+        for ch in span.content.chars() {
+            let is_space = ch.is_whitespace();
+            if let Some(was_space) = current_is_space
+                && was_space != is_space
+            {
+                tokens.push(Token {
+                    text: std::mem::take(&mut current),
+                    style: span.style,
+                    is_space: was_space,
+                    char_offset: token_start,
+                });
+                token_start = abs_char_index;
+            }
+            current.push(ch);
+            current_is_space = Some(is_space);
+            abs_char_index += 1;
+        }
+        if !current.is_empty() {
+            tokens.push(Token {
+                text: current,
+                style: span.style,
+                is_space: current_is_space.unwrap_or(false),
+                char_offset: token_start,
+            });
+        }
+    }
+
+    let mut rows: Vec<(usize, Vec<Span<'static>>)> = Vec::new();
+    let mut current_row: Vec<Span<'static>> = Vec::new();
+    let mut current_width = 0usize;
+    let mut row_start_char = 0usize;
+    let mut row_has_content = false;
+
+    for token in tokens {
+        let token_width = token.text.width();
+
+        if token_width > width {
+            let mut remaining = token.text.as_str();
+            let mut rem_char_offset = token.char_offset;
+            while !remaining.is_empty() {
+                if current_width >= width {
+                    rows.push((row_start_char, std::mem::take(&mut current_row)));
+                    current_width = 0;
+                    row_start_char = rem_char_offset;
+                    row_has_content = false;
                 }
 
-                if x >= area.x + area.width {
-                    break;
+                let space_left = width - current_width;
+                let (chunk, chunk_width) = truncate_unicode_string(remaining, space_left);
+                let chunk_char_len = chunk.chars().count();
+                if chunk_char_len == 0 {
+                    // A lone character wider than `width` itself; emit it
+                    // anyway so the loop always makes progress.
+                    let ch = remaining.chars().next().expect("remaining is non-empty");
+                    let ch_str = ch.to_string();
+                    current_row.push(Span::styled(ch_str.clone(), token.style));
+                    current_width += ch_str.width();
+                    remaining = &remaining[ch_str.len()..];
+                    rem_char_offset += 1;
+                } else {
+                    current_row.push(Span::styled(chunk.clone(), token.style));
+                    current_width += chunk_width;
+                    remaining = &remaining[chunk.len()..];
+                    rem_char_offset += chunk_char_len;
                 }
+                row_has_content = true;
+            }
+            continue;
+        }
+
+        if !token.is_space && row_has_content && current_width + token_width > width {
+            // Drop the whitespace that separated the row's last word from
+            // the word now moving to the next row.
+            while current_row
+                .last()
+                .is_some_and(|span| span.content.chars().all(char::is_whitespace))
+            {
+                current_row.pop();
+            }
+            rows.push((row_start_char, std::mem::take(&mut current_row)));
+            current_width = 0;
+            row_start_char = token.char_offset;
+            row_has_content = false;
+        }
+
+        if token.is_space && !row_has_content {
+            // Don't let a wrapped row start with the whitespace that used
+            // to separate it from the previous row's last word.
+            continue;
+        }
+
+        current_row.push(Span::styled(token.text, token.style));
+        current_width += token_width;
+        row_has_content = true;
+    }
+
+    if row_has_content || rows.is_empty() {
+        rows.push((row_start_char, current_row));
+    }
+
+    rows.into_iter()
+        .map(|(offset, spans)| (offset, Line::from(spans)))
+        .collect()
+}
+
+fn render_plain_line(line: &Line<'static>, horizontal_offset: usize, area: Rect, y: u16, buf: &mut Buffer) {
+    let mut x = area.x;
+    let mut skip_remaining = horizontal_offset;
+
+    for span in &line.spans {
+        let (content, leftover) = skip_display_columns(span.content.as_ref(), skip_remaining);
+        skip_remaining = leftover;
+
+        if content.is_empty() {
+            continue;
+        }
+
+        let remaining_width = (area.width as usize).saturating_sub((x - area.x) as usize);
+
+        let (truncated, actual_width) = truncate_unicode_string(content, remaining_width);
+
+        if !truncated.is_empty() {
+            buf.set_stringn(x, y, &truncated, remaining_width, span.style);
+            x += actual_width as u16;
+        }
+
+        if x >= area.x + area.width {
+            break;
+        }
+    }
+}
+
+/// Matched-substring style for a search result other than the active one.
+fn match_style() -> Style {
+    Style::default()
+        .fg(Color::Black)
+        .bg(Color::Rgb(147, 161, 161))
+}
+
+/// Matched-substring style for the currently active search result, rendered
+/// more prominently than the rest.
+fn active_match_style() -> Style {
+    Style::default()
+        .fg(Color::Black)
+        .bg(Color::Rgb(181, 137, 0))
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Renders a line character-by-character, overriding each matched
+/// character's style so search results stand out from the surrounding text.
+/// Only used for lines with at least one match; other lines take the
+/// cheaper whole-span path in [`render_plain_line`]. `char_offset` is the
+/// position, within the logical (unwrapped) line the matches were found
+/// against, that this row's first character starts at (`0` for an
+/// un-wrapped line). `horizontal_offset` is a display-column count of
+/// leading columns to skip for horizontal scrolling; skipped characters
+/// still advance `char_index` (since search-match ranges are absolute
+/// character positions, not display columns) but are never drawn, and one
+/// straddling the skip boundary is skipped whole rather than half-drawn.
+fn render_highlighted_line(
+    line: &Line<'static>,
+    line_matches: &[(usize, &SearchMatch)],
+    active_match: Option<usize>,
+    char_offset: usize,
+    horizontal_offset: usize,
+    area: Rect,
+    y: u16,
+    buf: &mut Buffer,
+) {
+    let mut x = area.x;
+    let mut char_index = char_offset;
+    let mut skip_remaining = horizontal_offset;
+
+    for span in &line.spans {
+        for ch in span.content.chars() {
+            let ch_width = ch.to_string().width();
+
+            if skip_remaining > 0 {
+                skip_remaining = skip_remaining.saturating_sub(ch_width);
+                char_index += 1;
+                continue;
             }
+
+            if x >= area.x + area.width {
+                return;
+            }
+
+            let highlight = line_matches
+                .iter()
+                .find(|(_, m)| char_index >= m.start && char_index < m.end);
+
+            let style = match highlight {
+                Some((index, _)) if Some(*index) == active_match => active_match_style(),
+                Some(_) => match_style(),
+                None => span.style,
+            };
+
+            let ch_str = ch.to_string();
+            if ch_width > 0 {
+                buf.set_stringn(x, y, &ch_str, ch_width, style);
+                x += ch_width as u16;
+            }
+            char_index += 1;
         }
     }
 }
 
+
 static ANSI_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\x1b\[[0-9;]*[mGKHF]").unwrap());
 
 fn strip_ansi_codes(s: &str) -> String {
     ANSI_REGEX.replace_all(s, "").into_owned()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::highlight::CodeHighlighter;
+
+    fn widget_with_images(content: &str, base_dir: Option<PathBuf>) -> MarkdownWidget {
+        MarkdownWidget::with_images(
+            Arc::new(content.to_string()),
+            Arc::new(CodeHighlighter::new("base16-ocean.dark")),
+            base_dir,
+            true,
+        )
+    }
+
+    fn all_text(widget: &MarkdownWidget) -> String {
+        widget
+            .get_lines()
+            .iter()
+            .flat_map(|line| line.spans.iter())
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_image_without_base_dir_falls_back_to_alt_text() {
+        let widget = widget_with_images("![a diagram](diagram.png)", None);
+        assert!(all_text(&widget).contains("[image: a diagram (diagram.png)]"));
+    }
+
+    #[test]
+    fn test_remote_image_falls_back_to_alt_text() {
+        let widget = widget_with_images(
+            "![logo](https://example.com/logo.png)",
+            Some(PathBuf::from(".")),
+        );
+        assert!(all_text(&widget).contains("[image: logo (https://example.com/logo.png)]"));
+    }
+
+    #[test]
+    fn test_images_disabled_falls_back_to_alt_text() {
+        let widget = MarkdownWidget::with_images(
+            Arc::new("![a diagram](diagram.png)".to_string()),
+            Arc::new(CodeHighlighter::new("base16-ocean.dark")),
+            Some(PathBuf::from(".")),
+            false,
+        );
+        assert!(all_text(&widget).contains("[image: a diagram (diagram.png)]"));
+    }
+
+    #[test]
+    fn test_missing_local_image_falls_back_to_alt_text() {
+        let widget = widget_with_images("![missing](does-not-exist.png)", Some(PathBuf::from(".")));
+        assert!(all_text(&widget).contains("[image: missing (does-not-exist.png)]"));
+    }
+
+    #[test]
+    fn test_language_at_line_reports_fenced_code_block_language() {
+        let widget = widget_with_images("# Title\n\n```rust\nfn main() {}\n```\n", None);
+
+        let code_line = widget
+            .get_lines()
+            .iter()
+            .position(|line| {
+                line.spans
+                    .iter()
+                    .any(|span| span.content.contains("fn main"))
+            })
+            .expect("code block line");
+
+        assert_eq!(widget.language_at_line(code_line), Some("rust"));
+        assert_eq!(widget.language_at_line(0), None);
+    }
+
+    #[test]
+    fn test_language_at_line_is_none_for_unlabeled_fence() {
+        let widget = widget_with_images("```\nplain text\n```\n", None);
+
+        let code_line = widget
+            .get_lines()
+            .iter()
+            .position(|line| {
+                line.spans
+                    .iter()
+                    .any(|span| span.content.contains("plain text"))
+            })
+            .expect("code block line");
+
+        assert_eq!(widget.language_at_line(code_line), None);
+    }
+
+    #[test]
+    fn test_pad_unicode_str_right_aligns_by_padding_on_the_left() {
+        let padded = MarkdownWidget::pad_unicode_str("ab", 5, pulldown_cmark::Alignment::Right);
+        assert_eq!(padded, "   ab");
+    }
+
+    #[test]
+    fn test_pad_unicode_str_centers_by_splitting_padding() {
+        let padded = MarkdownWidget::pad_unicode_str("ab", 5, pulldown_cmark::Alignment::Center);
+        assert_eq!(padded, " ab  ");
+    }
+
+    #[test]
+    fn test_pad_unicode_str_left_aligns_by_padding_on_the_right() {
+        let padded = MarkdownWidget::pad_unicode_str("ab", 5, pulldown_cmark::Alignment::Left);
+        assert_eq!(padded, "ab   ");
+    }
+
+    #[test]
+    fn test_create_table_lines_draws_a_box_drawing_border() {
+        let headers = vec!["Name".to_string(), "Age".to_string()];
+        let rows = vec![vec!["Alice".to_string(), "30".to_string()]];
+        let lines = MarkdownWidget::create_table_lines(&headers, &rows, &[]);
+
+        let rendered: Vec<String> = lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+
+        assert!(rendered[0].starts_with('┌') && rendered[0].ends_with('┐'));
+        assert!(rendered[1].contains("Name") && rendered[1].starts_with('│'));
+        assert!(rendered[2].starts_with('├') && rendered[2].ends_with('┤'));
+        assert!(rendered[3].contains("Alice"));
+        assert!(rendered[4].starts_with('└') && rendered[4].ends_with('┘'));
+    }
+
+    #[test]
+    fn test_create_table_lines_right_aligns_per_column() {
+        let headers = vec!["Name".to_string(), "Age".to_string()];
+        let rows = vec![vec!["Bob".to_string(), "7".to_string()]];
+        let alignments = vec![
+            pulldown_cmark::Alignment::Left,
+            pulldown_cmark::Alignment::Right,
+        ];
+        let lines = MarkdownWidget::create_table_lines(&headers, &rows, &alignments);
+
+        let body_line: String = lines[3]
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect();
+        // "Age" is 3 wide so the right-aligned "7" cell pads on the left.
+        assert!(body_line.contains("  7"));
+    }
+
+    fn line_text(line: &Line<'_>) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_wrap_line_splits_at_word_boundaries() {
+        let line = Line::from(Span::raw("alpha beta gamma"));
+        let rows = wrap_line(&line, 10);
+        let texts: Vec<String> = rows.iter().map(|(_, row)| line_text(row)).collect();
+        assert_eq!(texts, vec!["alpha beta".to_string(), "gamma".to_string()]);
+    }
+
+    #[test]
+    fn test_wrap_line_breaks_an_over_long_word_at_char_boundaries() {
+        let line = Line::from(Span::raw("supercalifragilistic"));
+        let rows = wrap_line(&line, 5);
+        assert!(rows.iter().all(|(_, row)| line_text(row).width() <= 5));
+        let rejoined: String = rows.iter().map(|(_, row)| line_text(row)).collect();
+        assert_eq!(rejoined, "supercalifragilistic");
+    }
+
+    #[test]
+    fn test_wrap_line_preserves_span_style() {
+        let style = Style::default().fg(Color::Red);
+        let line = Line::from(Span::styled("alpha beta", style));
+        let rows = wrap_line(&line, 5);
+        for (_, row) in &rows {
+            for span in &row.spans {
+                assert_eq!(span.style, style);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrap_line_reports_the_char_offset_each_row_starts_at() {
+        let line = Line::from(Span::raw("alpha beta gamma"));
+        let rows = wrap_line(&line, 10);
+        let offsets: Vec<usize> = rows.iter().map(|(offset, _)| *offset).collect();
+        // "alpha beta" occupies chars 0..10, "gamma" starts after the space at 11.
+        assert_eq!(offsets, vec![0, 11]);
+    }
+
+    #[test]
+    fn test_visual_line_count_matches_wrap_line_for_every_row() {
+        let widget = widget_with_images("alpha beta gamma delta\n", None);
+        let wrapped_total: usize = widget
+            .get_lines()
+            .iter()
+            .map(|line| wrap_line(line, 10).len())
+            .sum();
+        assert_eq!(widget.visual_line_count(10), wrapped_total);
+    }
+
+    #[test]
+    fn test_visual_line_count_falls_back_to_line_count_at_zero_width() {
+        let widget = widget_with_images("alpha beta gamma\n", None);
+        assert_eq!(widget.visual_line_count(0), widget.line_count());
+    }
+
+    #[test]
+    fn test_skip_display_columns_zero_skip_returns_text_unchanged() {
+        let (remaining, leftover) = skip_display_columns("alpha", 0);
+        assert_eq!(remaining, "alpha");
+        assert_eq!(leftover, 0);
+    }
+
+    #[test]
+    fn test_skip_display_columns_skips_exactly_to_a_boundary() {
+        let (remaining, leftover) = skip_display_columns("alpha beta", 6);
+        assert_eq!(remaining, "beta");
+        assert_eq!(leftover, 0);
+    }
+
+    #[test]
+    fn test_skip_display_columns_skips_a_straddling_double_width_char_whole() {
+        // "中文" is two double-width characters (4 columns total); skipping 1
+        // column must consume the whole first character rather than half of
+        // it, leaving the second character intact.
+        let (remaining, leftover) = skip_display_columns("中文", 1);
+        assert_eq!(remaining, "文");
+        assert_eq!(leftover, 0);
+    }
+
+    #[test]
+    fn test_skip_display_columns_past_end_of_string_reports_leftover() {
+        let (remaining, leftover) = skip_display_columns("hi", 5);
+        assert_eq!(remaining, "");
+        assert_eq!(leftover, 3);
+    }
+
+    #[test]
+    fn test_render_plain_line_honors_horizontal_offset() {
+        let line = Line::from(Span::raw("alpha beta"));
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        render_plain_line(&line, 6, area, 0, &mut buf);
+        assert_eq!(buf.cell((0, 0)).unwrap().symbol(), "b");
+    }
+
+    #[test]
+    fn test_render_plain_line_carries_leftover_skip_across_spans() {
+        let line = Line::from(vec![Span::raw("ab"), Span::raw("cd")]);
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        render_plain_line(&line, 3, area, 0, &mut buf);
+        assert_eq!(buf.cell((0, 0)).unwrap().symbol(), "d");
+    }
+
+    #[test]
+    fn test_strip_color_clears_fg_and_bg_but_keeps_modifiers() {
+        let style = Style::default()
+            .fg(Color::Red)
+            .bg(Color::Blue)
+            .add_modifier(Modifier::BOLD | Modifier::ITALIC);
+        let stripped = strip_color(style);
+        assert_eq!(stripped.fg, None);
+        assert_eq!(stripped.bg, None);
+        assert!(stripped.add_modifier.contains(Modifier::BOLD));
+        assert!(stripped.add_modifier.contains(Modifier::ITALIC));
+    }
+
+    #[test]
+    fn test_monochrome_widget_has_no_colored_spans() {
+        let mut widget = widget_with_images("# Heading\n\n**bold** and *italic*\n", None);
+        widget.set_monochrome(true);
+        assert!(widget.is_monochrome());
+        for line in widget.get_lines() {
+            for span in &line.spans {
+                assert_eq!(span.style.fg, None);
+                assert_eq!(span.style.bg, None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_monochrome_widget_still_has_colored_spans() {
+        let widget = widget_with_images("# Heading\n", None);
+        assert!(!widget.is_monochrome());
+        assert!(
+            widget
+                .get_lines()
+                .iter()
+                .flat_map(|line| line.spans.iter())
+                .any(|span| span.style.fg.is_some())
+        );
+    }
+
+    #[test]
+    fn test_wrap_cell_text_splits_at_word_boundaries() {
+        let wrapped = MarkdownWidget::wrap_cell_text("alpha beta gamma", 10);
+        assert_eq!(wrapped, vec!["alpha beta".to_string(), "gamma".to_string()]);
+    }
+
+    #[test]
+    fn test_wrap_cell_text_truncates_an_over_long_word_with_an_ellipsis() {
+        let wrapped = MarkdownWidget::wrap_cell_text("supercalifragilistic", 5);
+        assert_eq!(wrapped, vec!["supe…".to_string()]);
+    }
+
+    #[test]
+    fn test_wrap_cell_text_is_a_no_op_when_the_cell_already_fits() {
+        let wrapped = MarkdownWidget::wrap_cell_text("short", 10);
+        assert_eq!(wrapped, vec!["short".to_string()]);
+    }
+
+    #[test]
+    fn test_fit_column_widths_shrinks_the_widest_column_first() {
+        let mut widths = vec![3, 50, 3];
+        MarkdownWidget::fit_column_widths(&mut widths, 20);
+        assert!(widths[1] < 50);
+        assert_eq!(widths[0], 3);
+        assert_eq!(widths[2], 3);
+    }
+
+    #[test]
+    fn test_fit_column_widths_stops_at_the_three_column_floor() {
+        let mut widths = vec![3, 3, 3, 3];
+        MarkdownWidget::fit_column_widths(&mut widths, 5);
+        assert_eq!(widths, vec![3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_create_table_lines_wraps_a_cell_wider_than_max_table_width() {
+        let headers = vec!["Notes".to_string()];
+        let long_cell = "word ".repeat(60);
+        let rows = vec![vec![long_cell]];
+        let lines = MarkdownWidget::create_table_lines(&headers, &rows, &[]);
+
+        // Top border, header, mid border, trailing border, and blank line
+        // account for 5 of the lines; a single-column body cell this long
+        // must wrap across several more once its column is shrunk to
+        // MAX_TABLE_WIDTH.
+        assert!(lines.len() > 6);
+    }
+}
@@ -9,15 +9,29 @@ use ratatui::{
 };
 
 use crate::theme_adapter::RatatuiThemeAdapter;
-use mp_core::theme::{MarkdownTheme, SolarizedOsaka, ThemeAdapter};
+use mp_core::theme::{MarkdownTheme, SolarizedOsaka, Theme, ThemeAdapter};
 
-pub struct StatusBar<T: MarkdownTheme> {
+/// Name of the preset `StatusBar::new` falls back to when none has been
+/// persisted yet.
+const DEFAULT_THEME_NAME: &str = "solarized-dark";
+
+/// The status bar's theme is boxed behind [`MarkdownTheme`] rather than a
+/// generic parameter so it can be swapped at runtime by the theme picker
+/// mode, re-theming the whole bar without rebuilding `App`.
+pub struct StatusBar {
     pub file_path: Option<String>,
     pub message: Option<String>,
     pub error: Option<String>,
     pub mode: StatusMode,
     pub search_query: Option<String>,
-    theme: T,
+    pub match_index: Option<usize>,
+    pub match_total: Option<usize>,
+    pub zoomed: bool,
+    pub toc_heading: Option<String>,
+    pub code_language: Option<String>,
+    pub truncation: Option<(usize, usize)>,
+    theme: Box<dyn MarkdownTheme>,
+    theme_name: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -25,17 +39,53 @@ pub enum StatusMode {
     Normal,
     Search,
     Help,
+    Toc,
+    Theme,
 }
 
-impl<T: MarkdownTheme> StatusBar<T> {
-    pub fn new(theme: T) -> Self {
+impl StatusBar {
+    pub fn new() -> Self {
+        Self::with_theme(DEFAULT_THEME_NAME)
+    }
+
+    /// Constructs a `StatusBar` with a named bundled preset, falling back to
+    /// [`DEFAULT_THEME_NAME`] if `name` isn't recognized.
+    pub fn with_theme(name: &str) -> Self {
+        let (theme, name) = match Theme::preset(name) {
+            Some(theme) => (theme, name),
+            None => (
+                Box::new(SolarizedOsaka) as Box<dyn MarkdownTheme>,
+                DEFAULT_THEME_NAME,
+            ),
+        };
         Self {
             file_path: None,
             message: None,
             error: None,
             mode: StatusMode::Normal,
             search_query: None,
+            match_index: None,
+            match_total: None,
+            zoomed: false,
+            toc_heading: None,
+            code_language: None,
+            truncation: None,
             theme,
+            theme_name: name.to_string(),
+        }
+    }
+
+    /// The name of the currently active theme preset.
+    pub fn theme_name(&self) -> &str {
+        &self.theme_name
+    }
+
+    /// Switches the active theme, re-coloring the bar on its next render.
+    /// A name that isn't a recognized preset leaves the current theme alone.
+    pub fn set_theme(&mut self, name: &str) {
+        if let Some(theme) = Theme::preset(name) {
+            self.theme = theme;
+            self.theme_name = name.to_string();
         }
     }
 
@@ -63,12 +113,49 @@ impl<T: MarkdownTheme> StatusBar<T> {
         self.mode = mode;
     }
 
+    pub fn set_zoomed(&mut self, zoomed: bool) {
+        self.zoomed = zoomed;
+    }
+
     pub fn set_search_query(&mut self, query: &str) {
         self.search_query = Some(query.to_string());
     }
 
     pub fn clear_search_query(&mut self) {
         self.search_query = None;
+        self.clear_match_counts();
+    }
+
+    /// Records how many matches the current search query found and which one
+    /// is active, for the ` [3/15] `-style counter shown next to the query.
+    pub fn set_match_counts(&mut self, index: Option<usize>, total: usize) {
+        self.match_index = index;
+        self.match_total = Some(total);
+    }
+
+    pub fn clear_match_counts(&mut self) {
+        self.match_index = None;
+        self.match_total = None;
+    }
+
+    pub fn set_toc_heading(&mut self, heading: impl Into<String>) {
+        self.toc_heading = Some(heading.into());
+    }
+
+    pub fn clear_toc_heading(&mut self) {
+        self.toc_heading = None;
+    }
+
+    /// Sets the language badge shown when the viewport is scrolled into a
+    /// fenced code block, or clears it when `language` is `None`.
+    pub fn set_code_language(&mut self, language: Option<String>) {
+        self.code_language = language;
+    }
+
+    /// Sets the `(rendered_bytes, total_bytes)` pair shown by the
+    /// ` TRUNCATED 42KB/180KB ` badge, or clears it when `progress` is `None`.
+    pub fn set_truncation(&mut self, progress: Option<(usize, usize)>) {
+        self.truncation = progress;
     }
 
     pub fn render(&self, frame: &mut Frame, area: Rect) {
@@ -105,10 +192,67 @@ impl<T: MarkdownTheme> StatusBar<T> {
                     })
                     .add_modifier(Modifier::BOLD),
             ),
+            StatusMode::Toc => Span::styled(
+                " TOC ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg({
+                        let adapter = RatatuiThemeAdapter;
+                        adapter.to_color(&self.theme.status_toc_color())
+                    })
+                    .add_modifier(Modifier::BOLD),
+            ),
+            StatusMode::Theme => Span::styled(
+                " THEME ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg({
+                        let adapter = RatatuiThemeAdapter;
+                        adapter.to_color(&self.theme.status_theme_color())
+                    })
+                    .add_modifier(Modifier::BOLD),
+            ),
         };
         spans.push(mode_span);
         spans.push(Span::raw(" "));
 
+        if self.zoomed {
+            spans.push(Span::styled(
+                "[ZOOM] ",
+                Style::default()
+                    .fg({
+                        let adapter = RatatuiThemeAdapter;
+                        adapter.to_color(&self.theme.status_message_color())
+                    })
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        if let Some(language) = &self.code_language {
+            spans.push(Span::styled(
+                format!("[{}] ", language),
+                Style::default()
+                    .fg({
+                        let adapter = RatatuiThemeAdapter;
+                        adapter.to_color(&self.theme.status_message_color())
+                    })
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        if let Some((rendered, total)) = self.truncation {
+            spans.push(Span::styled(
+                format!(" TRUNCATED {}/{} ", format_kb(rendered), format_kb(total)),
+                Style::default()
+                    .fg(Color::Black)
+                    .bg({
+                        let adapter = RatatuiThemeAdapter;
+                        adapter.to_color(&self.theme.status_error_color())
+                    })
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+
         if let Some(error) = &self.error {
             spans.push(Span::styled(
                 error,
@@ -129,6 +273,28 @@ impl<T: MarkdownTheme> StatusBar<T> {
                     })
                     .add_modifier(Modifier::ITALIC),
             ));
+        } else if self.mode == StatusMode::Toc {
+            if let Some(heading) = &self.toc_heading {
+                spans.push(Span::styled(
+                    heading,
+                    Style::default()
+                        .fg({
+                            let adapter = RatatuiThemeAdapter;
+                            adapter.to_color(&self.theme.status_toc_color())
+                        })
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+        } else if self.mode == StatusMode::Theme {
+            spans.push(Span::styled(
+                self.theme_name.clone(),
+                Style::default()
+                    .fg({
+                        let adapter = RatatuiThemeAdapter;
+                        adapter.to_color(&self.theme.status_theme_color())
+                    })
+                    .add_modifier(Modifier::BOLD),
+            ));
         } else if self.mode == StatusMode::Search {
             if let Some(query) = &self.search_query {
                 spans.push(Span::styled(
@@ -140,6 +306,30 @@ impl<T: MarkdownTheme> StatusBar<T> {
                         })
                         .add_modifier(Modifier::BOLD),
                 ));
+                if !query.is_empty() {
+                    match self.match_total {
+                        Some(0) => spans.push(Span::styled(
+                            " (no matches)",
+                            Style::default()
+                                .fg({
+                                    let adapter = RatatuiThemeAdapter;
+                                    adapter.to_color(&self.theme.status_error_color())
+                                })
+                                .add_modifier(Modifier::BOLD),
+                        )),
+                        Some(total) => {
+                            let position = self.match_index.map(|i| i + 1).unwrap_or(0);
+                            spans.push(Span::styled(
+                                format!(" [{}/{}] ", position, total),
+                                Style::default().fg({
+                                    let adapter = RatatuiThemeAdapter;
+                                    adapter.to_color(&self.theme.status_message_color())
+                                }),
+                            ));
+                        }
+                        None => {}
+                    }
+                }
             } else {
                 spans.push(Span::styled(
                     "",
@@ -174,12 +364,18 @@ impl<T: MarkdownTheme> StatusBar<T> {
     }
 }
 
-impl Default for StatusBar<SolarizedOsaka> {
+impl Default for StatusBar {
     fn default() -> Self {
-        Self::new(SolarizedOsaka)
+        Self::new()
     }
 }
 
+/// Formats a byte count in whole kilobytes, rounding up so a non-empty
+/// remainder never reads as `0KB`.
+fn format_kb(bytes: usize) -> String {
+    format!("{}KB", bytes.div_ceil(1024))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -187,7 +383,7 @@ mod tests {
 
     #[test]
     fn test_status_bar_creation() {
-        let status = StatusBar::new(SolarizedOsaka);
+        let status = StatusBar::new();
         assert_eq!(status.mode, StatusMode::Normal);
         assert!(status.file_path.is_none());
         assert!(status.message.is_none());
@@ -196,7 +392,7 @@ mod tests {
 
     #[test]
     fn test_status_bar_file_path() {
-        let mut status = StatusBar::new(SolarizedOsaka);
+        let mut status = StatusBar::new();
         let path = PathBuf::from("/test/file.md");
 
         status.set_file(&path);
@@ -207,7 +403,7 @@ mod tests {
 
     #[test]
     fn test_status_bar_messages() {
-        let mut status = StatusBar::new(SolarizedOsaka);
+        let mut status = StatusBar::new();
 
         status.set_message("Test message");
         assert_eq!(status.message, Some("Test message".to_string()));
@@ -224,7 +420,7 @@ mod tests {
 
     #[test]
     fn test_status_bar_mode() {
-        let mut status = StatusBar::new(SolarizedOsaka);
+        let mut status = StatusBar::new();
 
         assert_eq!(status.mode, StatusMode::Normal);
 
@@ -234,4 +430,90 @@ mod tests {
         status.set_mode(StatusMode::Help);
         assert_eq!(status.mode, StatusMode::Help);
     }
+
+    #[test]
+    fn test_status_bar_zoomed() {
+        let mut status = StatusBar::new();
+        assert!(!status.zoomed);
+
+        status.set_zoomed(true);
+        assert!(status.zoomed);
+
+        status.set_zoomed(false);
+        assert!(!status.zoomed);
+    }
+
+    #[test]
+    fn test_status_bar_code_language() {
+        let mut status = StatusBar::new();
+        assert!(status.code_language.is_none());
+
+        status.set_code_language(Some("rust".to_string()));
+        assert_eq!(status.code_language.as_deref(), Some("rust"));
+
+        status.set_code_language(None);
+        assert!(status.code_language.is_none());
+    }
+
+    #[test]
+    fn test_status_bar_truncation() {
+        let mut status = StatusBar::new();
+        assert!(status.truncation.is_none());
+
+        status.set_truncation(Some((43_008, 184_320)));
+        assert_eq!(status.truncation, Some((43_008, 184_320)));
+
+        status.set_truncation(None);
+        assert!(status.truncation.is_none());
+    }
+
+    #[test]
+    fn test_format_kb_rounds_up_to_whole_kilobytes() {
+        assert_eq!(format_kb(43_008), "42KB");
+        assert_eq!(format_kb(1), "1KB");
+        assert_eq!(format_kb(0), "0KB");
+    }
+
+    #[test]
+    fn test_status_bar_match_counts() {
+        let mut status = StatusBar::new();
+        assert!(status.match_index.is_none());
+        assert!(status.match_total.is_none());
+
+        status.set_match_counts(Some(2), 15);
+        assert_eq!(status.match_index, Some(2));
+        assert_eq!(status.match_total, Some(15));
+
+        status.clear_match_counts();
+        assert!(status.match_index.is_none());
+        assert!(status.match_total.is_none());
+    }
+
+    #[test]
+    fn test_status_bar_clear_search_query_also_clears_match_counts() {
+        let mut status = StatusBar::new();
+        status.set_search_query("needle");
+        status.set_match_counts(Some(0), 3);
+
+        status.clear_search_query();
+        assert!(status.search_query.is_none());
+        assert!(status.match_total.is_none());
+    }
+
+    #[test]
+    fn test_status_bar_theme_defaults_to_solarized_dark() {
+        let status = StatusBar::new();
+        assert_eq!(status.theme_name(), "solarized-dark");
+    }
+
+    #[test]
+    fn test_status_bar_set_theme_switches_active_preset() {
+        let mut status = StatusBar::new();
+
+        status.set_theme("dracula");
+        assert_eq!(status.theme_name(), "dracula");
+
+        status.set_theme("nonexistent");
+        assert_eq!(status.theme_name(), "dracula");
+    }
 }
@@ -1,9 +1,38 @@
 use ratatui::style::{Color, Modifier, Style};
+use syntect::highlighting::{Color as SyntectColor, FontStyle as SyntectFontStyle};
 
 use mp_core::theme::{ThemeAdapter, ThemeColor, ThemeStyle};
 
 pub struct RatatuiThemeAdapter;
 
+impl RatatuiThemeAdapter {
+    /// Converts a `syntect` highlighting color, as produced by
+    /// [`crate::highlight::CodeHighlighter`], into the same [`Color::Rgb`]
+    /// representation [`RatatuiThemeAdapter::to_color`] uses for theme
+    /// colors, so highlighted code spans and theme-driven spans share one
+    /// conversion path.
+    pub fn to_color_from_syntect(&self, color: SyntectColor) -> Color {
+        Color::Rgb(color.r, color.g, color.b)
+    }
+
+    /// Maps `syntect`'s bold/italic/underline font-style bits onto the
+    /// matching [`Modifier`]s, mirroring [`RatatuiThemeAdapter::to_style`]'s
+    /// handling of [`ThemeStyle`]'s bold/italic/underline fields.
+    pub fn to_modifier_from_syntect(&self, style: SyntectFontStyle) -> Modifier {
+        let mut modifiers = Modifier::empty();
+        if style.contains(SyntectFontStyle::BOLD) {
+            modifiers |= Modifier::BOLD;
+        }
+        if style.contains(SyntectFontStyle::ITALIC) {
+            modifiers |= Modifier::ITALIC;
+        }
+        if style.contains(SyntectFontStyle::UNDERLINE) {
+            modifiers |= Modifier::UNDERLINED;
+        }
+        modifiers
+    }
+}
+
 impl ThemeAdapter for RatatuiThemeAdapter {
     type Color = Color;
     type Style = Style;
@@ -14,6 +43,10 @@ impl ThemeAdapter for RatatuiThemeAdapter {
 
     fn to_style(&self, style: &ThemeStyle) -> Self::Style {
         let mut ratatui_style = Style::default().fg(self.to_color(&style.color));
+        if let Some(bg) = style.bg {
+            ratatui_style = ratatui_style.bg(self.to_color(&bg));
+        }
+
         let mut modifiers = Modifier::empty();
 
         if style.bold {
@@ -25,6 +58,15 @@ impl ThemeAdapter for RatatuiThemeAdapter {
         if style.underline {
             modifiers |= Modifier::UNDERLINED;
         }
+        if style.reverse {
+            modifiers |= Modifier::REVERSED;
+        }
+        if style.strikethrough {
+            modifiers |= Modifier::CROSSED_OUT;
+        }
+        if style.dim {
+            modifiers |= Modifier::DIM;
+        }
 
         if !modifiers.is_empty() {
             ratatui_style = ratatui_style.add_modifier(modifiers);
@@ -34,6 +76,37 @@ impl ThemeAdapter for RatatuiThemeAdapter {
     }
 }
 
+/// Precomputes the ratatui [`Style`] for every theme-independent role once,
+/// so widgets that style many cells per frame (file tree rows, status line
+/// segments) look the style up instead of re-running [`RatatuiThemeAdapter::to_style`]
+/// on every redraw.
+pub struct RoleStyles {
+    pub strong: Style,
+    pub emphasis: Style,
+    pub link: Style,
+    pub code: Style,
+    pub list_marker: Style,
+    pub delimiter: Style,
+    pub text: Style,
+    pub focus_border: Style,
+}
+
+impl RoleStyles {
+    pub fn new(theme: &dyn mp_core::theme::MarkdownTheme) -> Self {
+        let adapter = RatatuiThemeAdapter;
+        Self {
+            strong: adapter.to_style(&theme.strong_style()),
+            emphasis: adapter.to_style(&theme.emphasis_style()),
+            link: adapter.to_style(&theme.link_style()),
+            code: adapter.to_style(&theme.code_style()),
+            list_marker: adapter.to_style(&theme.list_marker_style()),
+            delimiter: adapter.to_style(&theme.delimiter_style()),
+            text: adapter.to_style(&theme.text_style()),
+            focus_border: adapter.to_style(&theme.focus_border_style()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,6 +137,10 @@ mod tests {
             bold: true,
             italic: false,
             underline: true,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
         let adapter = RatatuiThemeAdapter;
         let ratatui_style = adapter.to_style(&theme_style);
@@ -89,6 +166,10 @@ mod tests {
             bold: true,
             italic: true,
             underline: true,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
         let adapter = RatatuiThemeAdapter;
         let ratatui_style = adapter.to_style(&theme_style);
@@ -98,6 +179,79 @@ mod tests {
         assert!(ratatui_style.add_modifier.contains(Modifier::UNDERLINED));
     }
 
+    #[test]
+    fn test_style_with_background_and_new_modifiers() {
+        let theme_style = ThemeStyle {
+            color: ThemeColor { r: 0, g: 0, b: 0 },
+            bold: false,
+            italic: false,
+            underline: false,
+            bg: Some(ThemeColor {
+                r: 255,
+                g: 255,
+                b: 255,
+            }),
+            reverse: true,
+            strikethrough: true,
+            dim: true,
+        };
+        let adapter = RatatuiThemeAdapter;
+        let ratatui_style = adapter.to_style(&theme_style);
+
+        match ratatui_style.bg {
+            Some(Color::Rgb(r, g, b)) => {
+                assert_eq!(r, 255);
+                assert_eq!(g, 255);
+                assert_eq!(b, 255);
+            }
+            _ => panic!("Expected RGB background color"),
+        }
+        assert!(ratatui_style.add_modifier.contains(Modifier::REVERSED));
+        assert!(ratatui_style.add_modifier.contains(Modifier::CROSSED_OUT));
+        assert!(ratatui_style.add_modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn test_role_styles_precomputes_every_role() {
+        use mp_core::theme::SolarizedOsaka;
+
+        let roles = RoleStyles::new(&SolarizedOsaka);
+        assert!(roles.strong.fg.is_some());
+        assert!(roles.strong.add_modifier.contains(Modifier::BOLD));
+        assert!(roles.emphasis.add_modifier.contains(Modifier::ITALIC));
+        assert!(roles.link.add_modifier.contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn test_to_color_from_syntect_converts_rgb_channels() {
+        let adapter = RatatuiThemeAdapter;
+        let color = adapter.to_color_from_syntect(SyntectColor {
+            r: 10,
+            g: 20,
+            b: 30,
+            a: 255,
+        });
+        assert_eq!(color, Color::Rgb(10, 20, 30));
+    }
+
+    #[test]
+    fn test_to_modifier_from_syntect_maps_each_font_style_bit() {
+        let adapter = RatatuiThemeAdapter;
+
+        let modifiers = adapter.to_modifier_from_syntect(SyntectFontStyle::BOLD);
+        assert!(modifiers.contains(Modifier::BOLD));
+        assert!(!modifiers.contains(Modifier::ITALIC));
+
+        let modifiers = adapter.to_modifier_from_syntect(
+            SyntectFontStyle::ITALIC | SyntectFontStyle::UNDERLINE,
+        );
+        assert!(modifiers.contains(Modifier::ITALIC));
+        assert!(modifiers.contains(Modifier::UNDERLINED));
+        assert!(!modifiers.contains(Modifier::BOLD));
+
+        assert!(adapter.to_modifier_from_syntect(SyntectFontStyle::empty()).is_empty());
+    }
+
     #[test]
     fn test_style_with_no_modifiers() {
         let theme_style = ThemeStyle {
@@ -109,6 +263,10 @@ mod tests {
             bold: false,
             italic: false,
             underline: false,
+            bg: None,
+            reverse: false,
+            strikethrough: false,
+            dim: false,
         };
         let adapter = RatatuiThemeAdapter;
         let ratatui_style = adapter.to_style(&theme_style);
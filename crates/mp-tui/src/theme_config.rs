@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+
+/// Persists the user's last-chosen UI theme across sessions, to a TOML file
+/// under the user's config directory, mirroring how [`crate::bookmarks::Bookmarks`]
+/// persists its own store.
+#[derive(Default, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    theme: Option<String>,
+}
+
+impl ThemeConfig {
+    /// Loads the persisted theme config, falling back to an empty one if it
+    /// doesn't exist yet or can't be parsed.
+    pub fn load() -> Self {
+        match theme_config_file() {
+            Some(path) => std::fs::read_to_string(path)
+                .ok()
+                .and_then(|contents| toml::from_str(&contents).ok())
+                .unwrap_or_default(),
+            None => Self::default(),
+        }
+    }
+
+    /// The last-persisted theme name, if any.
+    pub fn theme_name(&self) -> Option<&str> {
+        self.theme.as_deref()
+    }
+
+    /// Writes a single theme name to disk, creating the config directory if
+    /// it doesn't exist yet.
+    pub fn save(name: &str) -> Result<()> {
+        let path = theme_config_file().context("could not determine config directory")?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let config = ThemeConfig {
+            theme: Some(name.to_string()),
+        };
+        let contents =
+            toml::to_string_pretty(&config).context("failed to serialize theme config")?;
+        std::fs::write(&path, contents)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+fn theme_config_file() -> Option<PathBuf> {
+    ProjectDirs::from("", "", "markdown-preview").map(|dirs| dirs.config_dir().join("theme.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_name_is_none_by_default() {
+        let config = ThemeConfig::default();
+        assert_eq!(config.theme_name(), None);
+    }
+
+    #[test]
+    fn test_serializes_round_trip_through_toml() {
+        let config = ThemeConfig {
+            theme: Some("dracula".to_string()),
+        };
+
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        let deserialized: ThemeConfig = toml::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.theme_name(), Some("dracula"));
+    }
+}
@@ -0,0 +1,109 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, channel};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use notify::{Event as NotifyEvent, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+/// Debounce window applied before a filesystem change is surfaced to the
+/// caller, so editors that write a file in several quick syscalls (truncate
+/// then write, or a temp-file-then-rename save) only trigger one reload.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// What kind of debounced change [`FileWatcher::poll_reload`] is reporting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchSignal {
+    /// Nothing has settled past the debounce window yet.
+    None,
+    /// The actively-open file was modified on disk; reload its content.
+    FileChanged,
+    /// A file was created or removed under the watched root; rebuild the tree.
+    TreeChanged,
+}
+
+/// Watches the actively-open markdown file and the tree's root directory for
+/// changes, reporting debounced reload signals to [`crate::app::App`]'s
+/// event loop.
+pub struct FileWatcher {
+    watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<NotifyEvent>>,
+    watched_file: Option<PathBuf>,
+    watched_root: Option<PathBuf>,
+    file_pending_since: Option<Instant>,
+    tree_pending_since: Option<Instant>,
+}
+
+impl FileWatcher {
+    pub fn new() -> Result<Self> {
+        let (tx, rx) = channel();
+        let watcher = notify::recommended_watcher(move |event| {
+            let _ = tx.send(event);
+        })
+        .context("failed to create file watcher")?;
+
+        Ok(Self {
+            watcher,
+            events: rx,
+            watched_file: None,
+            watched_root: None,
+            file_pending_since: None,
+            tree_pending_since: None,
+        })
+    }
+
+    /// Starts watching `path` as the actively-open file, replacing whatever
+    /// file was previously watched.
+    pub fn watch_file(&mut self, path: &Path) -> Result<()> {
+        if let Some(previous) = self.watched_file.take() {
+            let _ = self.watcher.unwatch(&previous);
+        }
+        self.watcher
+            .watch(path, RecursiveMode::NonRecursive)
+            .with_context(|| format!("failed to watch {}", path.display()))?;
+        self.watched_file = Some(path.to_path_buf());
+        self.file_pending_since = None;
+        Ok(())
+    }
+
+    /// Starts recursively watching `path` as the file tree's root, so
+    /// creating or deleting markdown files anywhere underneath it is noticed.
+    pub fn watch_root(&mut self, path: &Path) -> Result<()> {
+        if let Some(previous) = self.watched_root.take() {
+            let _ = self.watcher.unwatch(&previous);
+        }
+        self.watcher
+            .watch(path, RecursiveMode::Recursive)
+            .with_context(|| format!("failed to watch {}", path.display()))?;
+        self.watched_root = Some(path.to_path_buf());
+        self.tree_pending_since = None;
+        Ok(())
+    }
+
+    /// Drains any filesystem events received since the last call and reports
+    /// the first debounced signal that has settled for at least
+    /// [`DEBOUNCE`], coalescing a burst of events into a single reload.
+    pub fn poll_reload(&mut self) -> WatchSignal {
+        while let Ok(Ok(event)) = self.events.try_recv() {
+            if matches!(event.kind, EventKind::Create(_) | EventKind::Remove(_)) {
+                self.tree_pending_since.get_or_insert_with(Instant::now);
+            }
+            if let Some(watched) = &self.watched_file
+                && event.paths.iter().any(|p| p == watched)
+            {
+                self.file_pending_since.get_or_insert_with(Instant::now);
+            }
+        }
+
+        if matches!(self.file_pending_since, Some(since) if since.elapsed() >= DEBOUNCE) {
+            self.file_pending_since = None;
+            return WatchSignal::FileChanged;
+        }
+
+        if matches!(self.tree_pending_since, Some(since) if since.elapsed() >= DEBOUNCE) {
+            self.tree_pending_since = None;
+            return WatchSignal::TreeChanged;
+        }
+
+        WatchSignal::None
+    }
+}
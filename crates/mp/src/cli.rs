@@ -27,11 +27,19 @@ pub struct Args {
     /// Do not respect the global gitignore file
     #[arg(long = "no-global-ignore-file")]
     pub no_global_ignore_file: bool,
+
+    /// Validate the active theme's contrast ratios and report unreadable colors
+    #[arg(long = "check-theme")]
+    pub check_theme: bool,
 }
 
 pub fn run() -> Result<()> {
     let args = Args::parse();
 
+    if args.check_theme {
+        return run_check_theme();
+    }
+
     match args.file {
         Some(path) => {
             if !path.exists() {
@@ -53,6 +61,7 @@ pub fn run() -> Result<()> {
                 no_ignore: args.no_ignore,
                 no_ignore_parent: args.no_ignore_parent,
                 no_global_ignore_file: args.no_global_ignore_file,
+                ..Default::default()
             };
 
             mp_tui::run_tui(finder_config)
@@ -63,6 +72,31 @@ pub fn run() -> Result<()> {
     Ok(())
 }
 
+/// Audit `SolarizedOsaka`, the active built-in theme, and print every role
+/// whose contrast ratio fails WCAG AA, or confirm the theme is clean.
+fn run_check_theme() -> Result<()> {
+    let theme = mp_core::theme::themes::SolarizedOsaka;
+    let findings = mp_core::theme::audit_theme(&theme);
+
+    if findings.is_empty() {
+        println!("Theme OK: all color pairs meet WCAG AA");
+        return Ok(());
+    }
+
+    println!("Theme has {} contrast issue(s):", findings.len());
+    for finding in &findings {
+        println!(
+            "  {}: ratio {:.2} < required {:.2} (suggested fix: {})",
+            finding.role,
+            finding.measured_ratio,
+            finding.required_ratio,
+            finding.suggested_fix.to_hex()
+        );
+    }
+
+    anyhow::bail!("theme failed accessibility audit");
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +119,13 @@ mod tests {
         assert!(!args.no_ignore);
     }
 
+    #[test]
+    fn test_args_parsing_with_check_theme() {
+        let result = Args::try_parse_from(vec!["mp", "--check-theme"]);
+        assert!(result.is_ok());
+        assert!(result.unwrap().check_theme);
+    }
+
     #[test]
     fn test_args_parsing_with_flags() {
         let result = Args::try_parse_from(vec!["mp", "--hidden", "--no-ignore"]);
@@ -17,6 +17,7 @@ fn test_workspace_integration() {
         no_ignore: false,
         no_ignore_parent: false,
         no_global_ignore_file: false,
+        ..Default::default()
     };
 
     let files = find_markdown_files_in_dir(temp_dir.path().to_str().unwrap(), config).unwrap();
@@ -47,6 +48,7 @@ fn test_cross_crate_functionality() {
         no_ignore: false,
         no_ignore_parent: false,
         no_global_ignore_file: false,
+        ..Default::default()
     };
 
     let files = find_markdown_files_in_dir(temp_dir.path().to_str().unwrap(), config).unwrap();
@@ -1,15 +1,96 @@
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 
-use markdown_preview::MarkdownRenderer;
+use markdown_preview::{MarkdownRenderer, diff, html_export};
+
+/// Export target for `--export`, in place of the default terminal preview.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Html,
+    Pdf,
+}
 
 #[derive(Debug, Parser)]
 #[command(name = "mp", version, about = "Markdown previewer in terminal")]
 pub struct Args {
     #[arg(name = "FILE", required = true, help = "Markdown file to preview")]
     pub file: PathBuf,
+
+    #[arg(
+        long,
+        conflicts_with = "no_hyperlinks",
+        help = "Force-enable OSC 8 terminal hyperlinks for links and images, overriding auto-detection"
+    )]
+    pub hyperlinks: bool,
+
+    #[arg(
+        long,
+        help = "Disable OSC 8 terminal hyperlinks for links and images, overriding auto-detection"
+    )]
+    pub no_hyperlinks: bool,
+
+    #[arg(
+        long,
+        help = "Print a table of contents built from the document's headings before the rendered body"
+    )]
+    pub toc: bool,
+
+    #[arg(
+        long,
+        help = "Produce deterministic, script-friendly output: no ANSI color or OSC 8 hyperlinks, a fixed render width, and [CWD]/[HOME] in place of the current/home directory. Also enabled by setting MP_PLAIN to any non-empty value; useful for snapshot-testing mp itself"
+    )]
+    pub plain: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Export the document instead of previewing it in the terminal: html or pdf"
+    )]
+    pub export: Option<ExportFormat>,
+
+    #[arg(long, requires = "export", help = "Output file path for --export")]
+    pub output: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Wrap the horizontal rule and paragraph text to this many columns instead of the detected terminal width"
+    )]
+    pub width: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Color theme to render with instead of auto-detecting one: a built-in name (solarized-dark, solarized-light, ayu, ansi16, monochrome) or a path to a theme file"
+    )]
+    pub theme: Option<String>,
+
+    #[arg(
+        long,
+        help = "Syntect theme name to syntax-highlight fenced code blocks with (e.g. base16-ocean.dark), independent of --theme's prose palette"
+    )]
+    pub code_theme: Option<String>,
+
+    #[arg(
+        long,
+        help = "Text encoding to assume for FILE when it has no byte-order mark (e.g. \"utf-8\", \"utf-16le\", \"windows-1252\"); a BOM always takes precedence"
+    )]
+    pub encoding: Option<String>,
+
+    #[arg(
+        long,
+        requires = "export",
+        help = "Stylesheet to inline into exported HTML instead of the built-in default"
+    )]
+    pub css: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "NEW_FILE",
+        conflicts_with = "export",
+        help = "Render a line-level diff of FILE (old) against NEW_FILE (new) instead of previewing a single document"
+    )]
+    pub diff: Option<PathBuf>,
 }
 
 pub fn run() -> Result<()> {
@@ -23,7 +104,47 @@ pub fn run() -> Result<()> {
         anyhow::bail!("Path is not a file: '{}'", args.file.display());
     }
 
+    if let Some(format) = args.export {
+        return export_document(&args, format);
+    }
+
+    if let Some(new_file) = &args.diff {
+        return diff_documents(&args, new_file);
+    }
+
     let mut renderer = MarkdownRenderer::new();
+    if args.hyperlinks {
+        renderer.config.hyperlinks = true;
+    } else if args.no_hyperlinks {
+        renderer.config.hyperlinks = false;
+    }
+    if args.toc {
+        renderer.config.toc = true;
+    }
+    if args.plain || std::env::var_os("MP_PLAIN").is_some_and(|value| !value.is_empty()) {
+        renderer.set_normalize(true);
+    }
+    if let Some(width) = args.width {
+        renderer.config.width_override = Some(width);
+    }
+    if let Some(name) = &args.theme {
+        renderer.set_theme(markdown_preview::theme::resolve_theme(name)?);
+    } else if std::env::var_os("MP_THEME").is_none()
+        && let Some(path) = markdown_preview::theme::standard_config_path()
+        && path.is_file()
+    {
+        let theme = markdown_preview::theme::Theme::from_path(&path)
+            .with_context(|| format!("Failed to load theme file '{}'", path.display()))?;
+        renderer.set_theme(Box::new(theme));
+    }
+    if let Some(name) = &args.code_theme {
+        renderer.set_syntax_theme(name.clone());
+    }
+    if let Some(label) = &args.encoding {
+        let encoding = encoding_rs::Encoding::for_label(label.as_bytes())
+            .ok_or_else(|| anyhow::anyhow!("Unknown --encoding '{label}'"))?;
+        renderer.config.encoding_override = Some(encoding);
+    }
     renderer
         .render_file(&args.file)
         .with_context(|| format!("Failed to render markdown file: {}", args.file.display()))?;
@@ -31,6 +152,63 @@ pub fn run() -> Result<()> {
     Ok(())
 }
 
+/// Renders `args.file` to HTML (reusing the same [`ParsedDocument`](markdown_preview::renderer::ParsedDocument)
+/// structure the terminal renderer walks) and, for [`ExportFormat::Pdf`],
+/// drives a headless-Chromium step over the generated HTML.
+fn export_document(args: &Args, format: ExportFormat) -> Result<()> {
+    let output = args
+        .output
+        .as_ref()
+        .context("--export requires --output <path>")?;
+
+    let content = std::fs::read_to_string(&args.file)
+        .with_context(|| format!("Failed to read markdown file: {}", args.file.display()))?;
+    let document = MarkdownRenderer::new().parse(&content);
+
+    let css = args
+        .css
+        .as_ref()
+        .map(std::fs::read_to_string)
+        .transpose()
+        .context("Failed to read --css file")?;
+    let html = html_export::render_html(&document, css.as_deref());
+
+    match format {
+        ExportFormat::Html => std::fs::write(output, html)
+            .with_context(|| format!("Failed to write HTML to {}", output.display()))?,
+        ExportFormat::Pdf => {
+            let html_path = output.with_extension("html");
+            std::fs::write(&html_path, html).with_context(|| {
+                format!(
+                    "Failed to write intermediate HTML to {}",
+                    html_path.display()
+                )
+            })?;
+            html_export::export_pdf(&html_path, output)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders a line-level diff of `args.file` (old) against `new_file` (new)
+/// to the terminal, honoring `--theme` the same way the single-document
+/// preview path does so diff colors follow the same theme as the body text.
+fn diff_documents(args: &Args, new_file: &PathBuf) -> Result<()> {
+    let old_content = std::fs::read_to_string(&args.file)
+        .with_context(|| format!("Failed to read markdown file: {}", args.file.display()))?;
+    let new_content = std::fs::read_to_string(new_file)
+        .with_context(|| format!("Failed to read markdown file: {}", new_file.display()))?;
+
+    let mut renderer = MarkdownRenderer::new();
+    if let Some(name) = &args.theme {
+        renderer.set_theme(markdown_preview::theme::resolve_theme(name)?);
+    }
+
+    let palette = diff::DiffPalette::from_theme(renderer.theme.as_ref());
+    diff::print_diff(&mut renderer, &old_content, &new_content, &palette)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +218,114 @@ mod tests {
         let result = Args::try_parse_from(vec!["mp", "test.md"]);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_hyperlinks_and_no_hyperlinks_are_mutually_exclusive() {
+        let args = Args::try_parse_from(vec!["mp", "test.md", "--hyperlinks"]).unwrap();
+        assert!(args.hyperlinks);
+        assert!(!args.no_hyperlinks);
+
+        let args = Args::try_parse_from(vec!["mp", "test.md", "--no-hyperlinks"]).unwrap();
+        assert!(!args.hyperlinks);
+        assert!(args.no_hyperlinks);
+
+        let result = Args::try_parse_from(vec!["mp", "test.md", "--hyperlinks", "--no-hyperlinks"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_toc_flag_parses() {
+        let args = Args::try_parse_from(vec!["mp", "test.md"]).unwrap();
+        assert!(!args.toc);
+
+        let args = Args::try_parse_from(vec!["mp", "test.md", "--toc"]).unwrap();
+        assert!(args.toc);
+    }
+
+    #[test]
+    fn test_width_flag_parses() {
+        let args = Args::try_parse_from(vec!["mp", "test.md"]).unwrap();
+        assert_eq!(args.width, None);
+
+        let args = Args::try_parse_from(vec!["mp", "test.md", "--width", "60"]).unwrap();
+        assert_eq!(args.width, Some(60));
+    }
+
+    #[test]
+    fn test_theme_flag_parses() {
+        let args = Args::try_parse_from(vec!["mp", "test.md"]).unwrap();
+        assert_eq!(args.theme, None);
+
+        let args = Args::try_parse_from(vec!["mp", "test.md", "--theme", "ayu"]).unwrap();
+        assert_eq!(args.theme, Some("ayu".to_string()));
+    }
+
+    #[test]
+    fn test_code_theme_flag_parses() {
+        let args = Args::try_parse_from(vec!["mp", "test.md"]).unwrap();
+        assert_eq!(args.code_theme, None);
+
+        let args =
+            Args::try_parse_from(vec!["mp", "test.md", "--code-theme", "base16-ocean.dark"])
+                .unwrap();
+        assert_eq!(args.code_theme, Some("base16-ocean.dark".to_string()));
+    }
+
+    #[test]
+    fn test_encoding_flag_parses() {
+        let args = Args::try_parse_from(vec!["mp", "test.md"]).unwrap();
+        assert_eq!(args.encoding, None);
+
+        let args =
+            Args::try_parse_from(vec!["mp", "test.md", "--encoding", "windows-1252"]).unwrap();
+        assert_eq!(args.encoding, Some("windows-1252".to_string()));
+    }
+
+    #[test]
+    fn test_export_requires_output() {
+        let result = Args::try_parse_from(vec!["mp", "test.md", "--export", "html"]);
+        assert!(result.is_err());
+
+        let args = Args::try_parse_from(vec![
+            "mp", "test.md", "--export", "html", "--output", "out.html",
+        ])
+        .unwrap();
+        assert_eq!(args.export, Some(ExportFormat::Html));
+        assert_eq!(args.output, Some(PathBuf::from("out.html")));
+    }
+
+    #[test]
+    fn test_output_and_css_without_export_are_rejected() {
+        let result = Args::try_parse_from(vec!["mp", "test.md", "--output", "out.html"]);
+        assert!(result.is_err());
+
+        let result = Args::try_parse_from(vec!["mp", "test.md", "--css", "style.css"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_plain_flag_parses() {
+        let args = Args::try_parse_from(vec!["mp", "test.md"]).unwrap();
+        assert!(!args.plain);
+
+        let args = Args::try_parse_from(vec!["mp", "test.md", "--plain"]).unwrap();
+        assert!(args.plain);
+    }
+
+    #[test]
+    fn test_diff_flag_parses() {
+        let args = Args::try_parse_from(vec!["mp", "test.md"]).unwrap();
+        assert_eq!(args.diff, None);
+
+        let args = Args::try_parse_from(vec!["mp", "old.md", "--diff", "new.md"]).unwrap();
+        assert_eq!(args.diff, Some(PathBuf::from("new.md")));
+    }
+
+    #[test]
+    fn test_diff_and_export_are_mutually_exclusive() {
+        let result = Args::try_parse_from(vec![
+            "mp", "old.md", "--diff", "new.md", "--export", "html", "--output", "out.html",
+        ]);
+        assert!(result.is_err());
+    }
 }
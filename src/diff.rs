@@ -0,0 +1,224 @@
+//! Line-level diff between two Markdown documents, rendered through
+//! [`MarkdownRenderer`] so headings/emphasis/etc. stay styled in the diff
+//! output instead of comparing raw text.
+
+use anyhow::Result;
+
+use crate::renderer::MarkdownRenderer;
+use crate::theme::{MarkdownTheme, ThemeStyle, dim_color, styled_text};
+use crate::utils::normalize_line_endings;
+
+/// One line of a two-document diff, tagged with how it differs between the
+/// old and new document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineOp {
+    /// Present, unchanged, in both documents.
+    Equal(String),
+    /// Present only in the old document.
+    Delete(String),
+    /// Present only in the new document.
+    Insert(String),
+}
+
+/// Computes a line-level diff between `old` and `new` with the standard
+/// LCS backtrack. `lcs[i][j]` holds the length of the longest common
+/// subsequence of `old_lines[i..]` and `new_lines[j..]` — a suffix table,
+/// built from the bottom-right corner up, so reconstructing the edit
+/// script is a single forward walk from `(0, 0)` with no final reversal.
+/// Lines are compared after [`normalize_line_endings`], so CRLF/CR inputs
+/// diff the same as LF; an empty `old` or `new` degenerates to an
+/// all-`Insert`/all-`Delete` table (the `i == m`/`j == n` border rows,
+/// which stay `0`), so neither file being empty panics.
+pub fn diff_lines(old: &str, new: &str) -> Vec<LineOp> {
+    let old = normalize_line_endings(old);
+    let new = normalize_line_endings(new);
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let m = old_lines.len();
+    let n = new_lines.len();
+
+    let mut lcs = vec![vec![0usize; n + 1]; m + 1];
+    for i in (0..m).rev() {
+        for j in (0..n).rev() {
+            lcs[i][j] = if old_lines[i] == new_lines[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(m + n);
+    let (mut i, mut j) = (0, 0);
+    while i < m && j < n {
+        if old_lines[i] == new_lines[j] {
+            ops.push(LineOp::Equal(old_lines[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push(LineOp::Delete(old_lines[i].to_string()));
+            i += 1;
+        } else {
+            ops.push(LineOp::Insert(new_lines[j].to_string()));
+            j += 1;
+        }
+    }
+    ops.extend(old_lines[i..].iter().map(|line| LineOp::Delete((*line).to_string())));
+    ops.extend(new_lines[j..].iter().map(|line| LineOp::Insert((*line).to_string())));
+    ops
+}
+
+/// The style applied to each [`LineOp`] variant's gutter marker, so diff
+/// colors follow a theme instead of being fixed literals. The line text
+/// itself is not colored by the palette; it keeps the renderer's own styles.
+#[derive(Debug, Clone)]
+pub struct DiffPalette {
+    pub delete: ThemeStyle,
+    pub insert: ThemeStyle,
+    pub context: ThemeStyle,
+}
+
+impl DiffPalette {
+    /// Builds a palette from `theme`: red/green for delete/insert (no
+    /// [`MarkdownTheme`] role maps cleanly to either), and `theme`'s own
+    /// text color dimmed one step (see [`dim_color`]) for the gutter of
+    /// unchanged context lines. That gutter is a single space, so context
+    /// lines are not visibly dimmed.
+    pub fn from_theme(theme: &dyn MarkdownTheme) -> Self {
+        const DELETE_RED: (u8, u8, u8) = (220, 50, 47);
+        const INSERT_GREEN: (u8, u8, u8) = (0, 200, 83);
+        Self {
+            delete: ThemeStyle {
+                color: DELETE_RED,
+                bold: false,
+                italic: false,
+                underline: false,
+            },
+            insert: ThemeStyle {
+                color: INSERT_GREEN,
+                bold: false,
+                italic: false,
+                underline: false,
+            },
+            context: ThemeStyle {
+                color: dim_color(theme.text_color(), 1),
+                bold: false,
+                italic: false,
+                underline: false,
+            },
+        }
+    }
+}
+
+/// Renders a line-level diff of `old` and `new` to stdout through
+/// `renderer`: each line is gutter-prefixed (`-`/`+`/` `), colored per
+/// `palette`, and still run through [`MarkdownRenderer::render_content`] so
+/// headings/emphasis within the line stay styled rather than printed as
+/// raw Markdown source.
+pub fn print_diff(renderer: &mut MarkdownRenderer, old: &str, new: &str, palette: &DiffPalette) -> Result<()> {
+    for op in diff_lines(old, new) {
+        let (gutter, style, line) = match &op {
+            LineOp::Delete(line) => ("-", &palette.delete, line),
+            LineOp::Insert(line) => ("+", &palette.insert, line),
+            LineOp::Equal(line) => (" ", &palette.context, line),
+        };
+        print!("{} ", styled_text(gutter, style.color, style.bold, style.italic, style.underline));
+        renderer.render_content(line)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines_all_equal() {
+        let ops = diff_lines("a\nb\nc\n", "a\nb\nc\n");
+        assert_eq!(
+            ops,
+            vec![
+                LineOp::Equal("a".to_string()),
+                LineOp::Equal("b".to_string()),
+                LineOp::Equal("c".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_detects_a_single_line_replacement() {
+        let ops = diff_lines("a\nb\nc\n", "a\nx\nc\n");
+        assert_eq!(
+            ops,
+            vec![
+                LineOp::Equal("a".to_string()),
+                LineOp::Delete("b".to_string()),
+                LineOp::Insert("x".to_string()),
+                LineOp::Equal("c".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_detects_pure_insertion() {
+        let ops = diff_lines("a\nc\n", "a\nb\nc\n");
+        assert_eq!(
+            ops,
+            vec![
+                LineOp::Equal("a".to_string()),
+                LineOp::Insert("b".to_string()),
+                LineOp::Equal("c".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_empty_old_is_all_insertions() {
+        let ops = diff_lines("", "a\nb\n");
+        assert_eq!(
+            ops,
+            vec![
+                LineOp::Insert("a".to_string()),
+                LineOp::Insert("b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_empty_new_is_all_deletions() {
+        let ops = diff_lines("a\nb\n", "");
+        assert_eq!(
+            ops,
+            vec![
+                LineOp::Delete("a".to_string()),
+                LineOp::Delete("b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_both_empty_is_empty() {
+        assert_eq!(diff_lines("", ""), Vec::new());
+    }
+
+    #[test]
+    fn test_diff_lines_normalizes_crlf_before_comparing() {
+        let ops = diff_lines("a\r\nb\r\n", "a\nb\n");
+        assert_eq!(
+            ops,
+            vec![
+                LineOp::Equal("a".to_string()),
+                LineOp::Equal("b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_palette_derives_context_from_the_theme_text_color() {
+        let palette = DiffPalette::from_theme(&crate::theme::SolarizedOsaka);
+        assert_eq!(
+            palette.context.color,
+            dim_color(crate::theme::SolarizedOsaka.text_color(), 1)
+        );
+    }
+}
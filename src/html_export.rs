@@ -0,0 +1,189 @@
+//! Renders a [`ParsedDocument`] to a self-contained HTML document instead of
+//! `MarkdownRenderer`'s ANSI terminal output. Both renderers walk the same
+//! parsed tree (see `chunk5-4`'s [`crate::renderer::document`]), so the
+//! document structure itself has a single source of truth and the terminal
+//! and HTML renderers can't drift apart on what the source Markdown means.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::renderer::{InlineSpan, ParsedDocument, ParsedElement};
+
+/// Inlined when `--css` isn't given, so an exported file still looks
+/// reasonable with no other files alongside it.
+const DEFAULT_STYLESHEET: &str = r#"body { font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", sans-serif; max-width: 52rem; margin: 2rem auto; padding: 0 1rem; line-height: 1.6; color: #1a1a1a; }
+pre { background: #272822; color: #f8f8f2; padding: 1rem; overflow-x: auto; border-radius: 4px; }
+code { background: #f0f0f0; padding: 0.1rem 0.3rem; border-radius: 3px; }
+pre code { background: none; padding: 0; }
+blockquote { border-left: 4px solid #ccc; margin: 0; padding-left: 1rem; color: #555; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #ccc; padding: 0.4rem 0.8rem; text-align: left; }
+th { background: #f5f5f5; }
+hr { border: none; border-top: 1px solid #ccc; }
+"#;
+
+/// Renders `document` into a complete, self-contained HTML page: `css`
+/// (the contents of a user-supplied `--css` file) replaces
+/// [`DEFAULT_STYLESHEET`] when given, inlined directly into a `<style>`
+/// block so the result has no external dependencies.
+pub fn render_html(document: &ParsedDocument, css: Option<&str>) -> String {
+    let stylesheet = css.unwrap_or(DEFAULT_STYLESHEET);
+    let mut body = String::new();
+    for element in &document.elements {
+        render_element(element, &mut body);
+    }
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<style>\n{stylesheet}</style>\n</head>\n<body>\n{body}</body>\n</html>\n"
+    )
+}
+
+fn render_element(element: &ParsedElement, out: &mut String) {
+    match element {
+        ParsedElement::Heading { level, text } => {
+            out.push_str(&format!("<h{level}>{}</h{level}>\n", escape_html(text)));
+        }
+        ParsedElement::Paragraph(spans) => {
+            out.push_str("<p>");
+            for span in spans {
+                render_span(span, out);
+            }
+            out.push_str("</p>\n");
+        }
+        ParsedElement::List { ordered, items } => {
+            let tag = if *ordered { "ol" } else { "ul" };
+            out.push_str(&format!("<{tag}>\n"));
+            for item in items {
+                out.push_str("<li>");
+                for child in item {
+                    render_element(child, out);
+                }
+                out.push_str("</li>\n");
+            }
+            out.push_str(&format!("</{tag}>\n"));
+        }
+        ParsedElement::Table { header, rows, .. } => {
+            out.push_str("<table>\n");
+            if let Some(header) = header {
+                out.push_str("<thead><tr>");
+                for cell in header {
+                    out.push_str(&format!("<th>{}</th>", escape_html(cell)));
+                }
+                out.push_str("</tr></thead>\n");
+            }
+            out.push_str("<tbody>\n");
+            for row in rows {
+                out.push_str("<tr>");
+                for cell in row {
+                    out.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                }
+                out.push_str("</tr>\n");
+            }
+            out.push_str("</tbody>\n</table>\n");
+        }
+        ParsedElement::BlockQuote(children) => {
+            out.push_str("<blockquote>\n");
+            for child in children {
+                render_element(child, out);
+            }
+            out.push_str("</blockquote>\n");
+        }
+        ParsedElement::CodeBlock { language, content } => {
+            let class = language
+                .as_deref()
+                .map(|lang| format!(" class=\"language-{}\"", escape_html(lang)))
+                .unwrap_or_default();
+            out.push_str(&format!(
+                "<pre><code{class}>{}</code></pre>\n",
+                escape_html(content)
+            ));
+        }
+        ParsedElement::HorizontalRule => out.push_str("<hr>\n"),
+    }
+}
+
+fn render_span(span: &InlineSpan, out: &mut String) {
+    match span {
+        InlineSpan::Text(text) => out.push_str(&escape_html(text)),
+        InlineSpan::Code(code) => out.push_str(&format!("<code>{}</code>", escape_html(code))),
+        InlineSpan::Strong(text) => {
+            out.push_str(&format!("<strong>{}</strong>", escape_html(text)))
+        }
+        InlineSpan::Emphasis(text) => out.push_str(&format!("<em>{}</em>", escape_html(text))),
+        InlineSpan::Link { text, url } => out.push_str(&format!(
+            "<a href=\"{}\">{}</a>",
+            escape_html(url),
+            escape_html(text)
+        )),
+        InlineSpan::Image { alt, url } => out.push_str(&format!(
+            "<img src=\"{}\" alt=\"{}\">",
+            escape_html(url),
+            escape_html(alt)
+        )),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Drives a headless-Chromium-family browser over `html_path`, rendering it
+/// to a PDF at `pdf_path`. Tries each of a handful of binary names in turn,
+/// since distros package the same `--headless --print-to-pdf` support under
+/// different executables.
+pub fn export_pdf(html_path: &Path, pdf_path: &Path) -> Result<()> {
+    const CANDIDATES: &[&str] = &["chromium", "chromium-browser", "google-chrome"];
+
+    let mut last_error = None;
+    for binary in CANDIDATES {
+        let result = std::process::Command::new(binary)
+            .arg("--headless")
+            .arg("--disable-gpu")
+            .arg(format!("--print-to-pdf={}", pdf_path.display()))
+            .arg(html_path)
+            .status();
+
+        match result {
+            Ok(status) if status.success() => return Ok(()),
+            Ok(status) => last_error = Some(format!("{binary} exited with {status}")),
+            Err(err) => last_error = Some(format!("{binary}: {err}")),
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| format!("none of {CANDIDATES:?} are available on PATH")))
+        .context("failed to render PDF via headless Chromium")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulldown_cmark::Options;
+
+    #[test]
+    fn test_render_html_escapes_text_and_preserves_structure() {
+        let document = ParsedDocument::parse("# Title\n\n<b>&\n", Options::empty());
+        let html = render_html(&document, None);
+        assert!(html.contains("<h1>Title</h1>"));
+        assert!(html.contains("&lt;b&gt;&amp;"));
+    }
+
+    #[test]
+    fn test_render_html_uses_custom_css_when_given() {
+        let document = ParsedDocument::parse("text\n", Options::empty());
+        let html = render_html(&document, Some("body { color: red; }"));
+        assert!(html.contains("body { color: red; }"));
+        assert!(!html.contains("max-width: 52rem"));
+    }
+
+    #[test]
+    fn test_render_html_renders_nested_blockquotes_and_code_blocks() {
+        let document =
+            ParsedDocument::parse("> quoted\n\n```rust\nlet x = 1;\n```\n", Options::empty());
+        let html = render_html(&document, None);
+        assert!(html.contains("<blockquote>"));
+        assert!(html.contains("class=\"language-rust\""));
+    }
+}
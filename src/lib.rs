@@ -1,6 +1,9 @@
+pub mod diff;
 pub mod html_entity;
+pub mod html_export;
 pub mod output;
 pub mod parser;
+pub mod redact;
 pub mod renderer;
 pub mod theme;
 pub mod utils;
@@ -0,0 +1,100 @@
+//! Stabilizes volatile substrings — the current working directory, the
+//! user's home directory, and any extra substitutions a caller registers —
+//! in rendered output, so two runs of the same document produce
+//! byte-identical stdout regardless of machine or invocation directory.
+//! Used by [`crate::renderer::RenderConfig::normalize`] to turn the
+//! existing `Command`-based integration tests into reliable golden-file
+//! comparisons.
+
+use std::path::{Path, PathBuf};
+
+/// A table of literal substring → stable placeholder substitutions,
+/// applied line-by-line to text about to be printed.
+#[derive(Debug, Clone, Default)]
+pub struct Redactor {
+    substitutions: Vec<(String, String)>,
+}
+
+impl Redactor {
+    /// A redactor with no substitutions registered yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The default redactor for `--plain`/`MP_PLAIN` output: the current
+    /// working directory maps to `[CWD]` and the user's home directory to
+    /// `[HOME]`, when either can be resolved. Longer paths are registered
+    /// first, so a home directory nested under the working directory (or
+    /// vice versa) is replaced by its more specific match rather than
+    /// being partially consumed by the shorter one.
+    pub fn for_normalize() -> Self {
+        let mut candidates: Vec<(String, String)> = Vec::new();
+        if let Ok(cwd) = std::env::current_dir() {
+            candidates.push((path_string(&cwd), "[CWD]".to_string()));
+        }
+        if let Some(home) = std::env::var_os("HOME") {
+            candidates.push((path_string(&PathBuf::from(home)), "[HOME]".to_string()));
+        }
+        candidates.sort_by_key(|(literal, _)| std::cmp::Reverse(literal.len()));
+
+        let mut redactor = Self::new();
+        for (literal, token) in candidates {
+            redactor.register(literal, token);
+        }
+        redactor
+    }
+
+    /// Registers an extra literal → placeholder substitution, applied
+    /// after any already registered. Lets callers stabilize substrings
+    /// specific to their own fixtures (a temp-directory prefix, a
+    /// generated id, ...) on top of the CWD/HOME defaults.
+    pub fn register(&mut self, literal: impl Into<String>, token: impl Into<String>) {
+        self.substitutions.push((literal.into(), token.into()));
+    }
+
+    /// Applies every registered substitution to `line`, in registration
+    /// order.
+    pub fn redact_line(&self, line: &str) -> String {
+        let mut redacted = line.to_string();
+        for (literal, token) in &self.substitutions {
+            if !literal.is_empty() {
+                redacted = redacted.replace(literal.as_str(), token.as_str());
+            }
+        }
+        redacted
+    }
+}
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_line_replaces_every_registered_literal() {
+        let mut redactor = Redactor::new();
+        redactor.register("/home/alice", "[HOME]");
+        redactor.register("/tmp/build", "[CWD]");
+        assert_eq!(
+            redactor.redact_line("see /home/alice/notes.md from /tmp/build"),
+            "see [HOME]/notes.md from [CWD]"
+        );
+    }
+
+    #[test]
+    fn test_redact_line_is_a_no_op_with_no_substitutions() {
+        let redactor = Redactor::new();
+        assert_eq!(redactor.redact_line("unchanged"), "unchanged");
+    }
+
+    #[test]
+    fn test_for_normalize_redacts_the_current_working_directory() {
+        let redactor = Redactor::for_normalize();
+        let cwd = std::env::current_dir().unwrap().to_string_lossy().into_owned();
+        let line = format!("path: {cwd}/file.md");
+        assert_eq!(redactor.redact_line(&line), "path: [CWD]/file.md");
+    }
+}
@@ -6,28 +6,48 @@
 use std::path::Path;
 
 use anyhow::Result;
-use pulldown_cmark::{Options, Parser};
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, LinkType, Options, Tag, TagEnd};
 
-use crate::theme::SolarizedOsaka;
+use crate::redact::Redactor;
+use crate::theme::MarkdownTheme;
 
 // Internal modules for separating rendering concerns
+mod cleaner;
 mod config;
+mod document;
 mod element_accessor;
-mod formatting;
+mod footnotes;
+mod handler;
 mod handlers;
+mod highlight;
 mod io;
+mod lang_string;
+mod length_limit;
+mod links;
+mod preprocess;
 pub mod state;
 mod styling;
 mod table_builder;
+mod table_layout;
+mod toc;
 
 // Public API exports for external module usage
-pub use config::RenderConfig;
+pub use cleaner::{Cleaner, EnglishCleaner, FrenchCleaner};
+pub use config::{RenderConfig, TableOverflow};
+pub use document::{InlineSpan, ParsedDocument, ParsedElement};
 pub use element_accessor::{
-    CodeBlockAccessor, ElementData, ImageAccessor, LinkAccessor, TableAccessor,
+    CodeBlockAccessor, ElementData, FootnoteAccessor, ImageAccessor, LinkAccessor, TableAccessor,
 };
+pub use footnotes::FootnoteRegistry;
+pub use handler::{ElementKind, Handler, HandlerChain, HandlerResult, RenderCtx};
+pub use highlight::{BuiltinHighlighter, Highlighter, Style as HighlightStyle, SyntectHighlighter};
+pub use lang_string::LangString;
+pub use links::{LinkValidity, ResolvedLink};
+pub use preprocess::{AdmonitionPreprocessor, AutoLinkPreprocessor, HeadingNumberer, Preprocessor};
 pub use state::{ActiveElement, RenderState};
 pub use styling::TextStyle;
-pub use table_builder::{Table, TableBuilder};
+pub use table_builder::{Cell, CellStyle, Table, TableBuilder, TableStyle};
+pub use toc::{Toc, TocBuilder, TocEntry};
 
 // Re-export core rendering functionality
 use self::io::read_file;
@@ -38,12 +58,40 @@ use self::io::read_file;
 /// - Loading and parsing Markdown files
 /// - Markdown parsing using pulldown_cmark
 /// - Converting events to terminal-displayable format
-#[derive(Debug)]
 pub struct MarkdownRenderer {
-    pub theme: SolarizedOsaka,
+    pub theme: Box<dyn MarkdownTheme>,
     pub state: RenderState,
     pub options: Options,
     pub config: RenderConfig,
+    highlighter: Box<dyn Highlighter>,
+    handlers: HandlerChain,
+    preprocessors: Vec<Box<dyn Preprocessor>>,
+    cleaner: Box<dyn Cleaner>,
+    /// The file most recently passed to [`Self::render_file`], used to
+    /// resolve relative link targets in [`Self::links`]. `None` when
+    /// rendering came from [`Self::render_content`] directly.
+    base_path: Option<std::path::PathBuf>,
+    /// Substitutions applied to printed text when [`RenderConfig::normalize`]
+    /// is set, e.g. the current working directory/home directory to
+    /// `[CWD]`/`[HOME]`. Populated by [`Self::set_normalize`]; extend with
+    /// [`Self::register_redaction`].
+    redactor: Redactor,
+    /// Set once an explicit syntax theme has been requested — via
+    /// [`RenderConfig::code_theme`] at construction or [`Self::set_syntax_theme`]
+    /// afterward — so [`Self::set_theme`] knows not to clobber it with the
+    /// new color theme's own [`MarkdownTheme::syntax_theme_name`].
+    syntax_theme_explicit: bool,
+}
+
+impl std::fmt::Debug for MarkdownRenderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownRenderer")
+            .field("theme", &self.theme)
+            .field("state", &self.state)
+            .field("options", &self.options)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for MarkdownRenderer {
@@ -61,12 +109,111 @@ impl MarkdownRenderer {
         options.insert(Options::ENABLE_FOOTNOTES);
         options.insert(Options::ENABLE_TASKLISTS);
 
+        let config = RenderConfig::default();
+        let syntax_theme_explicit = config.code_theme.is_some();
+        let highlighter: Box<dyn Highlighter> = match &config.code_theme {
+            Some(theme_name) => Box::new(SyntectHighlighter::with_theme(theme_name.clone())),
+            None => Box::new(BuiltinHighlighter::new()),
+        };
         Self {
-            theme: SolarizedOsaka,
+            theme: config.theme.build(),
             state: RenderState::default(),
             options,
-            config: RenderConfig::default(),
+            config,
+            highlighter,
+            handlers: HandlerChain::new(),
+            preprocessors: Vec::new(),
+            cleaner: Box::new(EnglishCleaner),
+            base_path: None,
+            redactor: Redactor::new(),
+            syntax_theme_explicit,
+        }
+    }
+
+    /// Replace the syntax highlighter used for fenced code blocks, e.g. to
+    /// plug in a grammar-backed implementation in place of the built-in
+    /// lexer.
+    pub fn set_highlighter(&mut self, highlighter: Box<dyn Highlighter>) {
+        self.highlighter = highlighter;
+    }
+
+    /// Convenience for [`Self::set_highlighter`] with a [`SyntectHighlighter`]
+    /// loaded from one of syntect's bundled theme names (e.g.
+    /// `"base16-ocean.dark"`), so fenced code blocks are colorized
+    /// independently of [`Self::set_theme`]'s prose/heading colors.
+    pub fn set_syntax_theme(&mut self, theme_name: impl Into<String>) {
+        self.highlighter = Box::new(SyntectHighlighter::with_theme(theme_name));
+        self.syntax_theme_explicit = true;
+    }
+
+    /// Replaces the active color theme, e.g. to load a user-customized one
+    /// via [`crate::theme::Theme::from_path`] in place of a built-in. Also
+    /// switches the fenced-code-block highlighter to the new theme's
+    /// [`MarkdownTheme::syntax_theme_name`], unless [`Self::set_syntax_theme`]
+    /// (or [`RenderConfig::code_theme`] at construction) already requested a
+    /// specific one — that explicit choice takes precedence.
+    pub fn set_theme(&mut self, theme: Box<dyn MarkdownTheme>) {
+        if !self.syntax_theme_explicit {
+            self.highlighter = Box::new(SyntectHighlighter::with_theme(theme.syntax_theme_name()));
         }
+        self.theme = theme;
+    }
+
+    /// Builder-style variant of [`Self::set_theme`] for chaining off
+    /// [`Self::new`].
+    pub fn with_theme(mut self, theme: Box<dyn MarkdownTheme>) -> Self {
+        self.set_theme(theme);
+        self
+    }
+
+    /// Replaces the active typographic cleaner, e.g. to opt into
+    /// [`FrenchCleaner`] in place of the default [`EnglishCleaner`]. Has no
+    /// effect unless [`RenderConfig::clean_typography`] is enabled.
+    pub fn set_cleaner(&mut self, cleaner: Box<dyn Cleaner>) {
+        self.cleaner = cleaner;
+    }
+
+    /// Builder-style variant of [`Self::set_cleaner`] for chaining off
+    /// [`Self::new`].
+    pub fn with_cleaner(mut self, cleaner: Box<dyn Cleaner>) -> Self {
+        self.cleaner = cleaner;
+        self
+    }
+
+    /// Turns deterministic "plain" output on or off (see
+    /// [`RenderConfig::normalize`]): enabling it seeds [`Self::redactor`]
+    /// with the current working directory/home directory so they print as
+    /// `[CWD]`/`[HOME]`. Mirrors `mp --plain`/`MP_PLAIN`.
+    pub fn set_normalize(&mut self, normalize: bool) {
+        self.config.normalize = normalize;
+        if normalize {
+            self.redactor = Redactor::for_normalize();
+        }
+    }
+
+    /// Registers an extra literal → placeholder substitution on top of
+    /// [`Self::set_normalize`]'s defaults, for callers whose fixtures
+    /// contain other volatile substrings (a temp-directory prefix, a
+    /// generated id, ...). Has no effect unless [`RenderConfig::normalize`]
+    /// is also on.
+    pub fn register_redaction(&mut self, literal: impl Into<String>, token: impl Into<String>) {
+        self.redactor.register(literal, token);
+    }
+
+    /// Registers a [`Preprocessor`] to run over the parsed document tree,
+    /// after [`Self::parse`] and before rendering. Preprocessors run in
+    /// registration order, each seeing the previous one's output.
+    pub fn add_preprocessor(&mut self, preprocessor: Box<dyn Preprocessor>) {
+        self.preprocessors.push(preprocessor);
+    }
+
+    /// Runs every registered preprocessor over `document` in order.
+    fn apply_preprocessors(&self, document: ParsedDocument) -> Result<ParsedDocument> {
+        self.preprocessors
+            .iter()
+            .try_fold(document, |document, preprocessor| {
+                preprocessor.run(document)
+            })
     }
 
     // Generic accessor methods using ElementData trait.
@@ -106,10 +253,15 @@ impl MarkdownRenderer {
         self.state.emphasis.italic = value;
     }
 
-    pub fn set_link(&mut self, url: String) {
+    pub fn set_strikethrough(&mut self, value: bool) {
+        self.state.emphasis.strikethrough = value;
+    }
+
+    pub fn set_link(&mut self, url: String, title: String) {
         self.state.active_element = Some(ActiveElement::Link(state::LinkState {
             text: String::new(),
             url,
+            title,
         }));
     }
 
@@ -124,10 +276,11 @@ impl MarkdownRenderer {
         )
     }
 
-    pub fn set_image(&mut self, url: String) {
+    pub fn set_image(&mut self, url: String, title: String) {
         self.state.active_element = Some(ActiveElement::Image(state::ImageState {
             alt_text: String::new(),
             url,
+            title,
         }));
     }
 
@@ -175,13 +328,7 @@ impl MarkdownRenderer {
     pub fn set_code_block(&mut self, kind: pulldown_cmark::CodeBlockKind<'static>) {
         let language = match kind {
             pulldown_cmark::CodeBlockKind::Indented => None,
-            pulldown_cmark::CodeBlockKind::Fenced(lang) => {
-                if lang.is_empty() {
-                    None
-                } else {
-                    Some(lang.to_string())
-                }
-            }
+            pulldown_cmark::CodeBlockKind::Fenced(info) => LangString::parse(&info).lang,
         };
         self.state.active_element = Some(ActiveElement::CodeBlock(state::CodeBlockState {
             language,
@@ -193,11 +340,23 @@ impl MarkdownRenderer {
         self.clear_active_element();
     }
 
+    /// Lower-level variant of [`Self::set_code_block`] for callers (namely
+    /// the handler chain) that have already resolved the fence language to
+    /// an owned string rather than a borrowed [`pulldown_cmark::CodeBlockKind`].
+    pub(crate) fn set_code_block_language(&mut self, language: Option<String>) {
+        self.state.active_element = Some(ActiveElement::CodeBlock(state::CodeBlockState {
+            language,
+            content: String::new(),
+        }));
+    }
+
     pub fn set_table(&mut self, alignments: Vec<pulldown_cmark::Alignment>) {
         self.state.active_element = Some(ActiveElement::Table(state::TableState {
             alignments,
             current_row: Vec::new(),
             is_header: true,
+            header: None,
+            rows: Vec::new(),
         }));
     }
 
@@ -205,6 +364,34 @@ impl MarkdownRenderer {
         self.clear_active_element();
     }
 
+    pub fn get_footnote(&self) -> Option<state::FootnoteState> {
+        self.get_cloned::<FootnoteAccessor>()
+    }
+
+    pub fn get_footnote_mut(&mut self) -> Option<&mut state::FootnoteState> {
+        self.get_mut::<FootnoteAccessor>()
+    }
+
+    /// Begins buffering a footnote definition's body, assigning `label` a
+    /// display number if it hasn't been seen yet (as a reference or a prior
+    /// definition).
+    pub fn set_footnote(&mut self, label: String) {
+        self.state.footnotes.number_for(&label);
+        self.state.active_element = Some(ActiveElement::Footnote(state::FootnoteState {
+            label,
+            content: String::new(),
+        }));
+    }
+
+    /// Stores the buffered body against its label and clears the active
+    /// element.
+    pub fn clear_footnote(&mut self) {
+        if let Some(footnote) = self.get_footnote() {
+            self.state.footnotes.define(&footnote.label, footnote.content);
+        }
+        self.clear_active_element();
+    }
+
     /// Build a table using the TableBuilder API
     ///
     /// # Example
@@ -227,6 +414,7 @@ impl MarkdownRenderer {
                 right: self.config.table_alignment.right.clone(),
                 none: self.config.table_alignment.none.clone(),
             })
+            .ascii_only(self.config.ascii_only)
     }
 
     pub fn push_list(&mut self, start: Option<u64>) {
@@ -253,24 +441,210 @@ impl MarkdownRenderer {
     /// # Error Handling
     /// - Returns detailed error message if file doesn't exist
     pub fn render_file(&mut self, path: &Path) -> Result<()> {
-        let content = read_file(path)?;
+        let content = read_file(path, self.config.encoding_override)?;
+        self.base_path = Some(path.to_path_buf());
         self.render_content(&content)
     }
 
     /// Render Markdown content directly
     ///
     /// # Processing Flow
-    /// 1. Parse Markdown with pulldown_cmark
-    /// 2. Process each event
-    /// 3. Convert to terminal format
+    /// 1. Parse Markdown into a [`ParsedDocument`] via [`Self::parse`]
+    /// 2. Render that document via [`Self::render_document`]
     pub fn render_content(&mut self, content: &str) -> Result<()> {
-        let parser = Parser::new_ext(content, self.options);
-
-        for event in parser {
-            self.process_event(event)?;
+        let toc = self.build_toc(content);
+        self.state.heading_anchors = toc.anchor_ids();
+        if self.config.toc {
+            self.print_toc(&toc)?;
+        }
+        self.state.toc = Some(toc);
+
+        let document = self.parse(content);
+        let mut document = self.apply_preprocessors(document)?;
+        self.state.links = links::collect_links(
+            &document,
+            self.base_path.as_deref(),
+            &self.state.heading_anchors,
+        );
+        if self.config.clean_typography {
+            cleaner::clean_document(&mut document, self.cleaner.as_ref());
         }
+        self.render_document(&document)?;
 
         self.flush()?;
+        self.render_footnotes_section()?;
+        Ok(())
+    }
+
+    /// Scans `content` for headings and returns the resulting outline,
+    /// without rendering anything. Intended to run before `render_content`
+    /// when a table of contents is wanted ahead of the body.
+    pub fn build_toc(&self, content: &str) -> Toc {
+        toc::collect_toc(content, self.options)
+    }
+
+    /// Alias for [`Self::build_toc`] for callers that just want to skim a
+    /// large file's structure without rendering its body at all.
+    pub fn render_toc(&self, content: &str) -> Toc {
+        self.build_toc(content)
+    }
+
+    /// The heading outline built while rendering the document most recently
+    /// passed to [`Self::render_content`], for callers that want to surface
+    /// navigation (e.g. a sidebar or jump list) after the fact instead of
+    /// re-scanning the source with [`Self::build_toc`]. Empty until a
+    /// document has been rendered.
+    pub fn table_of_contents(&self) -> Toc {
+        self.state.toc.clone().unwrap_or_default()
+    }
+
+    /// Every link collected while rendering the document most recently
+    /// passed to [`Self::render_content`]/[`Self::render_file`], resolved
+    /// against [`Self::render_file`]'s path (if that's how rendering
+    /// happened) and checked against the document's own headings. Empty
+    /// until a document has been rendered.
+    pub fn links(&self) -> Vec<ResolvedLink> {
+        self.state.links.clone()
+    }
+
+    /// Parses `content` into a [`ParsedDocument`] without rendering
+    /// anything. Unlike driving `process_event` straight off a live
+    /// `pulldown_cmark` stream, the resulting tree can be walked more than
+    /// once — re-rendered with a different theme or config, inspected for
+    /// structure, or asserted on directly in a test — without re-parsing
+    /// the source text.
+    pub fn parse(&self, content: &str) -> ParsedDocument {
+        ParsedDocument::parse(content, self.options)
+    }
+
+    /// Renders a [`ParsedDocument`] previously produced by [`Self::parse`].
+    /// Replays the tree through the same `process_event` pipeline that
+    /// driving a live `pulldown_cmark::Parser` does, so a document parsed
+    /// once renders identically to parsing and rendering it fresh.
+    pub fn render_document(&mut self, document: &ParsedDocument) -> Result<()> {
+        for element in &document.elements {
+            self.render_parsed_element(element)?;
+        }
+        Ok(())
+    }
+
+    fn render_parsed_element(&mut self, element: &ParsedElement) -> Result<()> {
+        match element {
+            ParsedElement::Heading { level, text } => {
+                let level = heading_level(*level);
+                self.process_event(Event::Start(Tag::Heading {
+                    level,
+                    id: None,
+                    classes: vec![],
+                    attrs: vec![],
+                }))?;
+                self.process_event(Event::Text(text.clone().into()))?;
+                self.process_event(Event::End(TagEnd::Heading(level)))?;
+            }
+            ParsedElement::Paragraph(spans) => {
+                self.process_event(Event::Start(Tag::Paragraph))?;
+                for span in spans {
+                    self.render_inline_span(span)?;
+                }
+                self.process_event(Event::End(TagEnd::Paragraph))?;
+            }
+            ParsedElement::List { ordered, items } => {
+                let start = ordered.then_some(1);
+                self.process_event(Event::Start(Tag::List(start)))?;
+                for item in items {
+                    self.process_event(Event::Start(Tag::Item))?;
+                    for child in item {
+                        self.render_parsed_element(child)?;
+                    }
+                    self.process_event(Event::End(TagEnd::Item))?;
+                }
+                self.process_event(Event::End(TagEnd::List(*ordered)))?;
+            }
+            ParsedElement::BlockQuote(children) => {
+                self.process_event(Event::Start(Tag::BlockQuote(None)))?;
+                for child in children {
+                    self.render_parsed_element(child)?;
+                }
+                self.process_event(Event::End(TagEnd::BlockQuote(None)))?;
+            }
+            ParsedElement::CodeBlock { language, content } => {
+                let kind = match language {
+                    Some(lang) => CodeBlockKind::Fenced(lang.clone().into()),
+                    None => CodeBlockKind::Indented,
+                };
+                self.process_event(Event::Start(Tag::CodeBlock(kind)))?;
+                self.process_event(Event::Text(content.clone().into()))?;
+                self.process_event(Event::End(TagEnd::CodeBlock))?;
+            }
+            ParsedElement::Table {
+                alignments,
+                header,
+                rows,
+            } => {
+                self.process_event(Event::Start(Tag::Table(alignments.clone())))?;
+                if let Some(header) = header {
+                    self.process_event(Event::Start(Tag::TableHead))?;
+                    self.render_table_cells(header)?;
+                    self.process_event(Event::End(TagEnd::TableHead))?;
+                }
+                for row in rows {
+                    self.process_event(Event::Start(Tag::TableRow))?;
+                    self.render_table_cells(row)?;
+                    self.process_event(Event::End(TagEnd::TableRow))?;
+                }
+                self.process_event(Event::End(TagEnd::Table))?;
+            }
+            ParsedElement::HorizontalRule => {
+                self.process_event(Event::Rule)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn render_table_cells(&mut self, cells: &[String]) -> Result<()> {
+        for cell in cells {
+            self.process_event(Event::Start(Tag::TableCell))?;
+            self.process_event(Event::Text(cell.clone().into()))?;
+            self.process_event(Event::End(TagEnd::TableCell))?;
+        }
+        Ok(())
+    }
+
+    fn render_inline_span(&mut self, span: &InlineSpan) -> Result<()> {
+        match span {
+            InlineSpan::Text(text) => self.process_event(Event::Text(text.clone().into()))?,
+            InlineSpan::Code(code) => self.process_event(Event::Code(code.clone().into()))?,
+            InlineSpan::Strong(text) => {
+                self.process_event(Event::Start(Tag::Strong))?;
+                self.process_event(Event::Text(text.clone().into()))?;
+                self.process_event(Event::End(TagEnd::Strong))?;
+            }
+            InlineSpan::Emphasis(text) => {
+                self.process_event(Event::Start(Tag::Emphasis))?;
+                self.process_event(Event::Text(text.clone().into()))?;
+                self.process_event(Event::End(TagEnd::Emphasis))?;
+            }
+            InlineSpan::Link { text, url } => {
+                self.process_event(Event::Start(Tag::Link {
+                    link_type: LinkType::Inline,
+                    dest_url: url.clone().into(),
+                    title: "".into(),
+                    id: "".into(),
+                }))?;
+                self.process_event(Event::Text(text.clone().into()))?;
+                self.process_event(Event::End(TagEnd::Link))?;
+            }
+            InlineSpan::Image { alt, url } => {
+                self.process_event(Event::Start(Tag::Image {
+                    link_type: LinkType::Inline,
+                    dest_url: url.clone().into(),
+                    title: "".into(),
+                    id: "".into(),
+                }))?;
+                self.process_event(Event::Text(alt.clone().into()))?;
+                self.process_event(Event::End(TagEnd::Image))?;
+            }
+        }
         Ok(())
     }
 
@@ -284,6 +658,17 @@ impl MarkdownRenderer {
     }
 }
 
+fn heading_level(level: u8) -> HeadingLevel {
+    match level {
+        1 => HeadingLevel::H1,
+        2 => HeadingLevel::H2,
+        3 => HeadingLevel::H3,
+        4 => HeadingLevel::H4,
+        5 => HeadingLevel::H5,
+        _ => HeadingLevel::H6,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -414,7 +799,7 @@ fn main() {
         set_emphasis_state(&mut renderer, strong, italic);
 
         if has_link {
-            renderer.set_link("test".to_string());
+            renderer.set_link("test".to_string(), String::new());
         }
 
         assert_eq!(renderer.state.emphasis.strong, strong);
@@ -426,12 +811,12 @@ fn main() {
     fn test_add_text_to_state() {
         let mut renderer = create_renderer();
 
-        renderer.set_link("".to_string());
+        renderer.set_link("".to_string(), String::new());
         assert!(renderer.add_text_to_state("link text"));
         assert_eq!(renderer.get_link().unwrap().text, "link text");
 
         renderer.clear_link();
-        renderer.set_image("".to_string());
+        renderer.set_image("".to_string(), String::new());
         assert!(renderer.add_text_to_state("alt text"));
         assert_eq!(renderer.get_image().unwrap().alt_text, "alt text");
 
@@ -552,4 +937,176 @@ fn main() {
     fn test_complex_markdown() {
         assert_render_success(test_data::COMPLEX_MARKDOWN);
     }
+
+    #[test]
+    fn test_render_toc_skims_headings_without_rendering_body() {
+        let renderer = create_renderer();
+        let toc = renderer.render_toc("# Title\n\nBody text\n\n## Sub\n");
+        assert_eq!(toc.entries.len(), 1);
+        assert_eq!(toc.entries[0].title, "Title");
+        assert_eq!(toc.entries[0].children[0].title, "Sub");
+    }
+
+    #[test]
+    fn test_render_content_populates_heading_anchors_for_link_resolution() {
+        let mut renderer = create_renderer();
+        renderer
+            .render_content("# My Section\n\n[see](#my-section)\n\n[broken](#nowhere)\n")
+            .unwrap();
+        assert!(renderer.state.heading_anchors.contains("my-section"));
+        assert!(!renderer.state.heading_anchors.contains("nowhere"));
+    }
+
+    #[test]
+    fn test_render_content_collects_a_footnote_defined_after_its_reference() {
+        let mut renderer = create_renderer();
+        renderer
+            .render_content("See this[^note].\n\n[^note]: An explanation.\n")
+            .unwrap();
+        assert_eq!(
+            renderer.state.footnotes.entries(),
+            vec![(1, "note", Some("An explanation."))]
+        );
+    }
+
+    #[test]
+    fn test_table_of_contents_is_empty_before_any_render() {
+        let renderer = create_renderer();
+        assert!(renderer.table_of_contents().entries.is_empty());
+    }
+
+    #[test]
+    fn test_table_of_contents_reflects_the_last_rendered_document() {
+        let mut renderer = create_renderer();
+        renderer
+            .render_content("# Title\n\nBody text\n\n## Sub\n")
+            .unwrap();
+        let toc = renderer.table_of_contents();
+        assert_eq!(toc.entries.len(), 1);
+        assert_eq!(toc.entries[0].title, "Title");
+        assert_eq!(toc.entries[0].children[0].title, "Sub");
+    }
+
+    #[test]
+    fn test_links_reflects_the_last_rendered_document() {
+        let mut renderer = create_renderer();
+        renderer
+            .render_content("# Section\n\n[jump](#section) and [missing](#nowhere)\n")
+            .unwrap();
+        let links = renderer.links();
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].validity, LinkValidity::Valid);
+        assert_eq!(links[1].validity, LinkValidity::Broken);
+    }
+
+    #[test]
+    fn test_parse_then_render_document_succeeds_like_render_content() {
+        let mut via_content = create_renderer();
+        via_content
+            .render_content(test_data::COMPLEX_MARKDOWN)
+            .unwrap();
+
+        let mut via_document = create_renderer();
+        let document = via_document.parse(test_data::COMPLEX_MARKDOWN);
+        assert!(via_document.render_document(&document).is_ok());
+    }
+
+    #[test]
+    fn test_parse_returns_a_reusable_document() {
+        let renderer = create_renderer();
+        let document = renderer.parse("# Title\n\nSome text.\n");
+        assert_eq!(document.heading_count(), 1);
+        assert_eq!(document.elements.len(), 2);
+    }
+
+    #[test]
+    fn test_registered_preprocessors_run_in_order_before_rendering() {
+        let mut renderer = create_renderer();
+        renderer.add_preprocessor(Box::new(HeadingNumberer));
+        renderer.add_preprocessor(Box::new(AdmonitionPreprocessor));
+
+        let document = renderer.parse("# Intro\n\n## Setup\n\n> [!NOTE]\n> Read this first.\n");
+        let document = renderer.apply_preprocessors(document).unwrap();
+
+        let ParsedElement::Heading { text, .. } = &document.elements[0] else {
+            panic!("expected a heading");
+        };
+        assert_eq!(text, "1 Intro");
+
+        let ParsedElement::BlockQuote(children) = &document.elements[2] else {
+            panic!("expected a blockquote");
+        };
+        let ParsedElement::Paragraph(spans) = &children[0] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(spans[0], InlineSpan::Strong("Note".to_string()));
+    }
+
+    #[test]
+    fn test_new_builds_theme_from_render_config_default() {
+        let renderer = create_renderer();
+        assert_eq!(
+            renderer.theme.heading_color(1),
+            RenderConfig::default().theme.build().heading_color(1)
+        );
+    }
+
+    #[test]
+    fn test_with_theme_replaces_the_default_theme() {
+        let renderer = create_renderer().with_theme(Box::new(crate::theme::Monochrome));
+        assert_eq!(
+            renderer.theme.text_color(),
+            crate::theme::Monochrome.text_color()
+        );
+    }
+
+    #[test]
+    fn test_set_theme_replaces_the_active_theme_in_place() {
+        let mut renderer = create_renderer();
+        renderer.set_theme(Box::new(crate::theme::Ansi16));
+        assert_eq!(
+            renderer.theme.strong_color(),
+            crate::theme::Ansi16.strong_color()
+        );
+    }
+
+    #[test]
+    fn test_new_uses_builtin_highlighter_when_code_theme_is_unset() {
+        let renderer = create_renderer();
+        // "go" has no entry in `BuiltinHighlighter::keywords_for`, so the
+        // default config's highlighter must be the built-in one.
+        assert!(!renderer.highlighter.supports("go"));
+    }
+
+    #[test]
+    fn test_set_syntax_theme_switches_to_a_syntect_highlighter() {
+        let mut renderer = create_renderer();
+        assert!(!renderer.highlighter.supports("go"));
+        renderer.set_syntax_theme("base16-ocean.dark");
+        assert!(renderer.highlighter.supports("go"));
+    }
+
+    #[test]
+    fn test_set_theme_also_switches_the_fenced_code_highlighter() {
+        let mut renderer = create_renderer();
+        renderer.set_theme(Box::new(crate::theme::Monochrome));
+        assert_eq!(
+            renderer.highlighter.theme_name(),
+            Some(crate::theme::Monochrome.syntax_theme_name())
+        );
+
+        renderer.set_theme(Box::new(crate::theme::Ansi16));
+        assert_eq!(
+            renderer.highlighter.theme_name(),
+            Some(crate::theme::Ansi16.syntax_theme_name())
+        );
+    }
+
+    #[test]
+    fn test_set_theme_does_not_override_an_explicit_syntax_theme() {
+        let mut renderer = create_renderer();
+        renderer.set_syntax_theme("base16-mocha.dark");
+        renderer.set_theme(Box::new(crate::theme::Monochrome));
+        assert_eq!(renderer.highlighter.theme_name(), Some("base16-mocha.dark"));
+    }
 }
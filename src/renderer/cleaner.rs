@@ -0,0 +1,207 @@
+//! An optional typographic-normalization pass applied to rendered text runs
+//! (headings, paragraph text, table cells), in the spirit of crowbook's
+//! pluggable `Cleaner`. Code spans and code blocks are left untouched, since
+//! source text must round-trip exactly. Disabled by default; enable via
+//! [`super::RenderConfig::clean_typography`] and pick an implementation via
+//! [`super::MarkdownRenderer::set_cleaner`]/[`super::MarkdownRenderer::with_cleaner`].
+
+use super::document::{InlineSpan, ParsedDocument, ParsedElement};
+
+/// Normalizes a single run of plain text. Implementations never need to
+/// worry about code spans or code blocks — [`clean_document`] skips those
+/// before a [`Cleaner`] ever sees them.
+pub trait Cleaner {
+    fn clean(&self, text: &str) -> String;
+}
+
+/// Straight quotes to curly (`'` becomes opening `'`/closing `'`, `"`
+/// becomes opening `"`/closing `"`, chosen by whether the preceding
+/// character is whitespace/an opening bracket or start-of-text), `--` to an
+/// en dash, `---` to an em dash, and `...` to an ellipsis character.
+#[derive(Debug, Default)]
+pub struct EnglishCleaner;
+
+impl Cleaner for EnglishCleaner {
+    fn clean(&self, text: &str) -> String {
+        let text = normalize_dashes_and_ellipsis(text);
+        curl_quotes(&text, '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}')
+    }
+}
+
+/// Everything [`EnglishCleaner`] does, plus French typographic spacing: a
+/// narrow no-break space (U+202F) before `;`, `:`, `!`, `?`, after an
+/// opening guillemet, and before a closing one. Uses `«`/`»` as the quote
+/// pair for both single and double straight quotes, in place of curly
+/// quotes.
+#[derive(Debug, Default)]
+pub struct FrenchCleaner;
+
+impl Cleaner for FrenchCleaner {
+    fn clean(&self, text: &str) -> String {
+        let text = normalize_dashes_and_ellipsis(text);
+        let text = curl_quotes(&text, '\u{ab}', '\u{bb}', '\u{ab}', '\u{bb}');
+        apply_french_spacing(&text)
+    }
+}
+
+fn normalize_dashes_and_ellipsis(text: &str) -> String {
+    text.replace("...", "\u{2026}")
+        .replace("---", "\u{2014}")
+        .replace("--", "\u{2013}")
+}
+
+/// Replaces straight `'`/`"` with the given opening/closing pair, opening
+/// when the preceding character is absent, whitespace, or an opening
+/// bracket, closing otherwise.
+fn curl_quotes(
+    text: &str,
+    single_open: char,
+    single_close: char,
+    double_open: char,
+    double_close: char,
+) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut prev: Option<char> = None;
+    for ch in text.chars() {
+        let opening = prev.map_or(true, |p| p.is_whitespace() || matches!(p, '(' | '[' | '{'));
+        match ch {
+            '\'' => out.push(if opening { single_open } else { single_close }),
+            '"' => out.push(if opening { double_open } else { double_close }),
+            _ => out.push(ch),
+        }
+        prev = Some(ch);
+    }
+    out
+}
+
+/// Inserts a narrow no-break space before `;`, `:`, `!`, `?`, and `»`,
+/// and after `«`, matching French typographic convention.
+fn apply_french_spacing(text: &str) -> String {
+    const NARROW_NBSP: char = '\u{202f}';
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    for (i, &ch) in chars.iter().enumerate() {
+        if matches!(ch, ';' | ':' | '!' | '?' | '\u{bb}') {
+            if out.ends_with(' ') {
+                out.pop();
+                out.push(NARROW_NBSP);
+            } else if !out.ends_with(NARROW_NBSP) {
+                out.push(NARROW_NBSP);
+            }
+        }
+        out.push(ch);
+        if ch == '\u{ab}' && chars.get(i + 1).is_some_and(|next| *next != NARROW_NBSP && !next.is_whitespace()) {
+            out.push(NARROW_NBSP);
+        }
+    }
+    out
+}
+
+/// Runs `cleaner` over every text run in `doc` — heading text, paragraph
+/// spans, and table cells — leaving [`InlineSpan::Code`] and
+/// [`ParsedElement::CodeBlock`] content untouched.
+pub fn clean_document(doc: &mut ParsedDocument, cleaner: &dyn Cleaner) {
+    clean_elements(&mut doc.elements, cleaner);
+}
+
+fn clean_elements(elements: &mut [ParsedElement], cleaner: &dyn Cleaner) {
+    for element in elements.iter_mut() {
+        match element {
+            ParsedElement::Heading { text, .. } => *text = cleaner.clean(text),
+            ParsedElement::Paragraph(spans) => clean_spans(spans, cleaner),
+            ParsedElement::List { items, .. } => {
+                for item in items.iter_mut() {
+                    clean_elements(item, cleaner);
+                }
+            }
+            ParsedElement::BlockQuote(children) => clean_elements(children, cleaner),
+            ParsedElement::Table { header, rows, .. } => {
+                if let Some(header) = header {
+                    for cell in header.iter_mut() {
+                        *cell = cleaner.clean(cell);
+                    }
+                }
+                for row in rows.iter_mut() {
+                    for cell in row.iter_mut() {
+                        *cell = cleaner.clean(cell);
+                    }
+                }
+            }
+            ParsedElement::CodeBlock { .. } | ParsedElement::HorizontalRule => {}
+        }
+    }
+}
+
+fn clean_spans(spans: &mut [InlineSpan], cleaner: &dyn Cleaner) {
+    for span in spans.iter_mut() {
+        match span {
+            InlineSpan::Text(text) | InlineSpan::Strong(text) | InlineSpan::Emphasis(text) => {
+                *text = cleaner.clean(text);
+            }
+            InlineSpan::Link { text, .. } | InlineSpan::Image { alt: text, .. } => {
+                *text = cleaner.clean(text);
+            }
+            InlineSpan::Code(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulldown_cmark::Options;
+
+    #[test]
+    fn test_english_cleaner_curls_quotes_by_surrounding_context() {
+        let cleaner = EnglishCleaner;
+        assert_eq!(
+            cleaner.clean("she said \"hello\" to 'them'"),
+            "she said \u{201c}hello\u{201d} to \u{2018}them\u{2019}"
+        );
+    }
+
+    #[test]
+    fn test_english_cleaner_converts_dashes_and_ellipsis() {
+        let cleaner = EnglishCleaner;
+        assert_eq!(cleaner.clean("wait--really"), "wait\u{2013}really");
+        assert_eq!(cleaner.clean("wait---really"), "wait\u{2014}really");
+        assert_eq!(cleaner.clean("wait..."), "wait\u{2026}");
+    }
+
+    #[test]
+    fn test_french_cleaner_uses_guillemets_and_narrow_spaces() {
+        let cleaner = FrenchCleaner;
+        assert_eq!(
+            cleaner.clean("il a dit \"bonjour\""),
+            "il a dit \u{ab}\u{202f}bonjour\u{202f}\u{bb}"
+        );
+        assert_eq!(cleaner.clean("vraiment ?"), "vraiment\u{202f}?");
+    }
+
+    #[test]
+    fn test_clean_document_skips_code_spans_and_code_blocks() {
+        let mut doc = ParsedDocument::parse(
+            "# It's \"great\"\n\n`it's` still \"code\"\n\n```\nit's \"code\"\n```\n",
+            Options::empty(),
+        );
+        clean_document(&mut doc, &EnglishCleaner);
+
+        let ParsedElement::Heading { text, .. } = &doc.elements[0] else {
+            panic!("expected a heading");
+        };
+        assert_eq!(text, "It\u{2019}s \u{201c}great\u{201d}");
+
+        let ParsedElement::Paragraph(spans) = &doc.elements[1] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(spans[0], InlineSpan::Code("it's".to_string()));
+        assert!(
+            matches!(&spans[1], InlineSpan::Text(text) if text == " still \u{201c}code\u{201d}")
+        );
+
+        let ParsedElement::CodeBlock { content, .. } = &doc.elements[2] else {
+            panic!("expected a code block");
+        };
+        assert_eq!(content, "it's \"code\"\n");
+    }
+}
@@ -1,6 +1,14 @@
+use crate::theme::{ColorDepth, ThemeKind, detect_color_depth, detect_theme_kind};
+
 /// Configuration for markdown rendering behavior and visual settings.
 #[derive(Debug, Clone)]
 pub struct RenderConfig {
+    /// The built-in color theme to construct [`super::MarkdownRenderer::theme`]
+    /// from at startup. Use [`super::MarkdownRenderer::set_theme`] or
+    /// [`super::MarkdownRenderer::with_theme`] afterward to load a
+    /// customized theme instead.
+    pub theme: ThemeKind,
+
     pub indent_width: usize,
 
     pub table_separator: String,
@@ -9,11 +17,122 @@ pub struct RenderConfig {
 
     pub enable_colors: bool,
 
+    /// How many colors the output terminal supports, detected from
+    /// `$COLORTERM`/`$TERM`/`$FORCE_COLOR` (see [`crate::theme::detect_color_depth`])
+    /// at startup. Every color this renderer emits is downsampled to this
+    /// depth; override it directly to force a narrower palette than what
+    /// was detected (e.g. for a CI log that strips unsupported escapes
+    /// rather than rendering them).
+    pub color_depth: ColorDepth,
+
     pub enable_bold: bool,
 
     pub enable_italic: bool,
 
     pub enable_underline: bool,
+
+    /// Render GFM strikethrough (`~~text~~`) with a struck-through style;
+    /// disable to render the text plain, as if the `~~` markers weren't
+    /// recognized at all.
+    pub enable_strikethrough: bool,
+
+    /// Print a table of contents built from the document's headings before
+    /// the rendered body.
+    pub toc: bool,
+
+    /// Colorize fenced code blocks using the configured [`Highlighter`](super::highlight::Highlighter).
+    /// Disable to fall back to the flat, single-color code rendering.
+    pub syntax_highlight: bool,
+
+    /// Prefix each fenced code block line with a right-aligned,
+    /// `Delimiter`-styled line number, sized to the block's total line
+    /// count. Off by default, since it adds width to every code block.
+    pub code_line_numbers: bool,
+
+    /// Replace Unicode box-drawing glyphs (the horizontal rule, and
+    /// non-`Markdown` [`super::table_builder::TableStyle`] borders) with
+    /// their ASCII twins, for legacy/limited terminals and tools that choke
+    /// on multi-byte box-drawing characters. Auto-detected from the `LANG`/
+    /// `LC_ALL`/`LC_CTYPE` locale not naming a UTF-8 charset; there's no
+    /// dedicated CLI flag yet, so set the field directly to override.
+    pub ascii_only: bool,
+
+    /// A `syntect` bundled theme name (e.g. `"base16-ocean.dark"`) to
+    /// highlight fenced code blocks with, independently of
+    /// [`RenderConfig::theme`]'s prose/heading palette. `None` uses the
+    /// built-in keyword-based [`super::highlight::BuiltinHighlighter`]
+    /// instead. Only consulted by [`super::MarkdownRenderer::new`]; switch
+    /// themes afterward with [`super::MarkdownRenderer::set_syntax_theme`].
+    pub code_theme: Option<String>,
+
+    /// Maximum on-screen width a rendered table may occupy, including its
+    /// borders. Cells wider than their column wrap onto multiple lines
+    /// instead of overflowing the terminal. Defaults to the detected
+    /// terminal width (see [`RenderConfig::get_terminal_width`]); `None`
+    /// disables wrapping entirely.
+    pub max_table_width: Option<usize>,
+
+    /// How a table cell wider than its column (per [`RenderConfig::max_table_width`])
+    /// is brought back into budget: wrap it onto additional lines, or
+    /// truncate it to one line with a trailing `…`. Mirrors the choice
+    /// [`super::table_builder::Table`]'s own `max_width` already offers.
+    pub table_overflow: TableOverflow,
+
+    /// Maximum number of display characters of body content to render
+    /// before truncating, so previewing very large documents stays
+    /// bounded. `None` disables the limit.
+    pub max_output_length: Option<usize>,
+
+    /// Wrap link and image text in OSC 8 terminal hyperlink escapes so the
+    /// text itself is clickable in supporting terminals. Defaults to
+    /// [`detect_hyperlink_support`]; override via `--hyperlinks`/
+    /// `--no-hyperlinks` in [`crate::cli::Args`].
+    pub hyperlinks: bool,
+
+    /// Append a link/image's `title` attribute (when present) after its URL
+    /// as a dimmed `— "title"` annotation. Disable for terminals that want
+    /// compact output with just the link text and URL.
+    pub link_titles: bool,
+
+    /// Overrides [`RenderConfig::get_terminal_width`]'s auto-detection for
+    /// the horizontal rule and paragraph word-wrapping. `None` detects the
+    /// width from the tty, falling back to 80 columns; override via
+    /// `--width` in [`crate::cli::Args`].
+    pub width_override: Option<usize>,
+
+    /// Text encoding to assume for input files that have no byte-order
+    /// mark, overriding the default of UTF-8. A BOM always wins regardless
+    /// of this setting, per [`crate::renderer::io::read_file`]. `None`
+    /// means plain UTF-8; override via `--encoding` in [`crate::cli::Args`].
+    pub encoding_override: Option<&'static encoding_rs::Encoding>,
+
+    /// Run the configured [`Cleaner`](super::Cleaner) over rendered text
+    /// (smart quotes, dashes, ellipsis), skipping code spans and code
+    /// blocks. Off by default so plain output remains available; pick an
+    /// implementation with [`super::MarkdownRenderer::set_cleaner`].
+    pub clean_typography: bool,
+
+    /// Produce deterministic, script-friendly output: suppresses ANSI
+    /// color/OSC 8 hyperlink escapes, pins [`RenderConfig::effective_width`]
+    /// to [`RenderConfig::PLAIN_WIDTH`] instead of the detected terminal
+    /// width, and redacts the current working directory/home directory
+    /// (via [`super::MarkdownRenderer::redactor`]) to `[CWD]`/`[HOME]`
+    /// placeholders. Off by default; set via `--plain`/`MP_PLAIN` in
+    /// [`crate::cli::Args`] or [`super::MarkdownRenderer::set_normalize`].
+    /// Turns the `Command`-based integration tests into reliable
+    /// golden-file comparisons instead of brittle `stdout.contains(...)`
+    /// checks.
+    pub normalize: bool,
+}
+
+/// How [`super::table_layout`] handles a cell wider than its column budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableOverflow {
+    /// Word-wrap the cell onto additional lines within the same row.
+    #[default]
+    Wrap,
+    /// Keep the cell to one line, truncating with a trailing `…`.
+    Truncate,
 }
 
 #[derive(Debug, Clone)]
@@ -27,15 +146,96 @@ pub struct TableAlignmentConfig {
 impl Default for RenderConfig {
     fn default() -> Self {
         Self {
+            theme: detect_theme_kind(),
             indent_width: 2,
             table_separator: "|".to_string(),
             table_alignment: TableAlignmentConfig::default(),
             enable_colors: true,
+            color_depth: detect_color_depth(),
             enable_bold: true,
             enable_italic: true,
             enable_underline: true,
+            enable_strikethrough: true,
+            toc: false,
+            syntax_highlight: true,
+            code_line_numbers: false,
+            ascii_only: detect_ascii_only(),
+            code_theme: None,
+            max_table_width: Some(Self::get_terminal_width()),
+            table_overflow: TableOverflow::default(),
+            max_output_length: None,
+            hyperlinks: detect_hyperlink_support(),
+            link_titles: true,
+            width_override: None,
+            encoding_override: None,
+            clean_typography: false,
+            normalize: false,
+        }
+    }
+}
+
+/// Guesses whether the terminal attached to stdout understands OSC 8
+/// hyperlinks: stdout must be an actual TTY (not redirected to a file or
+/// pipe) and `$TERM`/`$TERM_PROGRAM` must name a terminal known to render
+/// the escape rather than printing it literally, mirroring
+/// [`crate::theme::detect_theme`]'s `NO_COLOR`/TTY check for color support.
+pub fn detect_hyperlink_support() -> bool {
+    use std::io::IsTerminal;
+
+    if !std::io::stdout().is_terminal() {
+        return false;
+    }
+    if std::env::var_os("KITTY_WINDOW_ID").is_some() {
+        return true;
+    }
+    if matches!(
+        std::env::var("TERM_PROGRAM").as_deref(),
+        Ok("iTerm.app" | "WezTerm" | "vscode" | "Hyper")
+    ) {
+        return true;
+    }
+
+    let term = std::env::var("TERM").unwrap_or_default();
+    term.contains("kitty") || term.contains("xterm") || term.contains("vte")
+}
+
+/// Computes the clicolors-convention default for [`RenderConfig::enable_colors`]:
+/// `NO_COLOR` set or `CLICOLOR=0` forces colors off; `CLICOLOR_FORCE` set to
+/// anything but `"0"` forces them on regardless of TTY; otherwise colors are
+/// on only when stdout is a TTY and `CLICOLOR` isn't `"0"`.
+fn detect_color_support() -> bool {
+    use std::io::IsTerminal;
+
+    if std::env::var_os("NO_COLOR").is_some() {
+        return false;
+    }
+    if std::env::var("CLICOLOR").is_ok_and(|value| value == "0") {
+        return false;
+    }
+    if std::env::var("CLICOLOR_FORCE").is_ok_and(|value| value != "0") {
+        return true;
+    }
+    std::io::stdout().is_terminal()
+}
+
+/// Guesses whether the terminal's locale can render Unicode box-drawing
+/// glyphs: checks `LC_ALL`, then `LC_CTYPE`, then `LANG` (the standard
+/// locale-category precedence) for the first one that's set and non-empty,
+/// falling back to ASCII only if that locale doesn't name a UTF-8 charset
+/// (e.g. `LANG=C`). None of the three being set (common for stripped-down
+/// containers and CI runners whose terminal is UTF-8 capable regardless)
+/// assumes Unicode rather than ASCII.
+fn detect_ascii_only() -> bool {
+    for var in ["LC_ALL", "LC_CTYPE", "LANG"] {
+        if let Ok(value) = std::env::var(var) {
+            if value.is_empty() {
+                continue;
+            }
+            let lower = value.to_ascii_lowercase();
+            return !(lower.contains("utf-8") || lower.contains("utf8"));
         }
     }
+    false
 }
 
 impl Default for TableAlignmentConfig {
@@ -50,24 +250,62 @@ impl Default for TableAlignmentConfig {
 }
 
 impl RenderConfig {
+    /// The fixed column width [`RenderConfig::effective_width`] uses under
+    /// [`RenderConfig::normalize`], so the horizontal rule and paragraph
+    /// wrapping don't vary with the terminal the tests happen to run in.
+    pub const PLAIN_WIDTH: usize = 80;
+
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Like [`RenderConfig::default`], but computes `enable_colors` from the
+    /// [clicolors](https://bixense.com/clicolors/) convention instead of
+    /// hard-coding it on: colors are enabled when stdout is a TTY and
+    /// `CLICOLOR` isn't `"0"`, forced on when `CLICOLOR_FORCE` isn't `"0"`
+    /// regardless of TTY, and forced off when `NO_COLOR` is set or
+    /// `CLICOLOR` is `"0"`. Mirrors [`crate::theme::detect_theme_kind`]'s
+    /// `NO_COLOR` handling so piped output and CI logs don't get ANSI noise.
+    pub fn with_auto_colors() -> Self {
+        Self {
+            enable_colors: detect_color_support(),
+            ..Self::default()
+        }
+    }
+
     pub fn get_terminal_width() -> usize {
         terminal_size::terminal_size()
             .map(|(width, _)| width.0 as usize)
             .unwrap_or(80)
     }
 
+    /// The width to lay out against: [`RenderConfig::width_override`] if
+    /// set, [`RenderConfig::PLAIN_WIDTH`] under [`RenderConfig::normalize`],
+    /// otherwise the auto-detected terminal width.
+    pub fn effective_width(&self) -> usize {
+        if let Some(width) = self.width_override {
+            return width;
+        }
+        if self.normalize {
+            return Self::PLAIN_WIDTH;
+        }
+        Self::get_terminal_width()
+    }
+
     pub fn create_indent(&self, depth: usize) -> String {
         " ".repeat(self.indent_width * depth)
     }
 
     pub fn create_horizontal_rule(&self) -> String {
-        let width = Self::get_terminal_width();
+        let width = self.effective_width();
         let rule_length = ((width as f32 * 0.8) as usize).min(100);
-        "â”€".repeat(rule_length)
+        self.horizontal_rule_glyph().to_string().repeat(rule_length)
+    }
+
+    /// The character [`RenderConfig::create_horizontal_rule`] repeats:
+    /// `-` under [`RenderConfig::ascii_only`], `─` otherwise.
+    fn horizontal_rule_glyph(&self) -> char {
+        if self.ascii_only { '-' } else { '─' }
     }
 }
 
@@ -78,10 +316,13 @@ mod tests {
     #[test]
     fn test_default_config() {
         let config = RenderConfig::default();
+        // `theme` is auto-detected (see `detect_theme_kind`) rather than a
+        // fixed default, so it isn't asserted here.
         assert_eq!(config.indent_width, 2);
         assert_eq!(config.table_separator, "|");
         assert!(config.enable_colors);
         assert!(config.enable_bold);
+        assert!(config.enable_strikethrough);
     }
 
     #[test]
@@ -98,7 +339,7 @@ mod tests {
         let rule = config.create_horizontal_rule();
         assert!(rule.chars().count() > 0);
         assert!(rule.chars().count() <= 100);
-        assert!(rule.chars().all(|c| c == 'â”€'));
+        assert!(rule.chars().all(|c| c == '─'));
     }
 
     #[test]
@@ -106,4 +347,142 @@ mod tests {
         let width = RenderConfig::get_terminal_width();
         assert!(width >= 80);
     }
+
+    #[test]
+    fn test_effective_width_prefers_the_override() {
+        let mut config = RenderConfig::default();
+        assert_eq!(config.effective_width(), RenderConfig::get_terminal_width());
+
+        config.width_override = Some(40);
+        assert_eq!(config.effective_width(), 40);
+    }
+
+    #[test]
+    fn test_encoding_override_defaults_to_none() {
+        let config = RenderConfig::default();
+        assert!(config.encoding_override.is_none());
+    }
+
+    #[test]
+    fn test_clean_typography_defaults_to_off() {
+        let config = RenderConfig::default();
+        assert!(!config.clean_typography);
+    }
+
+    #[test]
+    fn test_max_table_width_defaults_to_the_terminal_width() {
+        let config = RenderConfig::default();
+        assert_eq!(config.max_table_width, Some(RenderConfig::get_terminal_width()));
+    }
+
+    #[test]
+    fn test_table_overflow_defaults_to_wrap() {
+        let config = RenderConfig::default();
+        assert_eq!(config.table_overflow, TableOverflow::Wrap);
+    }
+
+    #[test]
+    fn test_code_line_numbers_defaults_to_off() {
+        let config = RenderConfig::default();
+        assert!(!config.code_line_numbers);
+    }
+
+    #[test]
+    fn test_color_depth_defaults_to_the_detected_depth() {
+        let config = RenderConfig::default();
+        assert_eq!(config.color_depth, detect_color_depth());
+    }
+
+    #[test]
+    fn test_normalize_defaults_to_off() {
+        let config = RenderConfig::default();
+        assert!(!config.normalize);
+    }
+
+    #[test]
+    fn test_code_theme_defaults_to_none() {
+        let config = RenderConfig::default();
+        assert_eq!(config.code_theme, None);
+    }
+
+    #[test]
+    fn test_with_auto_colors_forces_off_under_no_color() {
+        // SAFETY: test runs single-threaded within this process' env access.
+        unsafe {
+            std::env::set_var("NO_COLOR", "1");
+        }
+        assert!(!RenderConfig::with_auto_colors().enable_colors);
+        unsafe {
+            std::env::remove_var("NO_COLOR");
+        }
+    }
+
+    #[test]
+    fn test_with_auto_colors_forces_on_under_clicolor_force() {
+        // SAFETY: test runs single-threaded within this process' env access.
+        unsafe {
+            std::env::set_var("CLICOLOR_FORCE", "1");
+        }
+        assert!(RenderConfig::with_auto_colors().enable_colors);
+        unsafe {
+            std::env::remove_var("CLICOLOR_FORCE");
+        }
+    }
+
+    #[test]
+    fn test_with_auto_colors_honors_clicolor_zero() {
+        // SAFETY: test runs single-threaded within this process' env access.
+        unsafe {
+            std::env::set_var("CLICOLOR", "0");
+        }
+        assert!(!RenderConfig::with_auto_colors().enable_colors);
+        unsafe {
+            std::env::remove_var("CLICOLOR");
+        }
+    }
+
+    #[test]
+    fn test_ascii_only_defaults_to_false_without_a_locale() {
+        // SAFETY: test runs single-threaded within this process' env access.
+        unsafe {
+            std::env::remove_var("LC_ALL");
+            std::env::remove_var("LC_CTYPE");
+            std::env::remove_var("LANG");
+        }
+        assert!(!RenderConfig::default().ascii_only);
+    }
+
+    #[test]
+    fn test_ascii_only_is_set_for_a_non_utf8_locale() {
+        // SAFETY: test runs single-threaded within this process' env access.
+        unsafe {
+            std::env::set_var("LANG", "C");
+        }
+        assert!(RenderConfig::default().ascii_only);
+        unsafe {
+            std::env::remove_var("LANG");
+        }
+    }
+
+    #[test]
+    fn test_create_horizontal_rule_uses_ascii_dashes_when_ascii_only() {
+        let config = RenderConfig {
+            ascii_only: true,
+            ..RenderConfig::default()
+        };
+        let rule = config.create_horizontal_rule();
+        assert!(rule.chars().all(|c| c == '-'));
+    }
+
+    #[test]
+    fn test_effective_width_prefers_override_over_normalize() {
+        let mut config = RenderConfig {
+            normalize: true,
+            ..RenderConfig::default()
+        };
+        assert_eq!(config.effective_width(), RenderConfig::PLAIN_WIDTH);
+
+        config.width_override = Some(40);
+        assert_eq!(config.effective_width(), 40);
+    }
 }
@@ -0,0 +1,430 @@
+//! A parsed, typed intermediate representation of a Markdown document,
+//! decoupled from rendering. [`ParsedDocument::parse`] consumes a
+//! `pulldown_cmark` event stream once into an owned [`ParsedElement`] tree,
+//! so the result can be walked more than once, inspected (count headings,
+//! pull out code blocks), or rendered with a different theme/config without
+//! re-parsing the source text.
+
+use std::iter::Peekable;
+
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+
+/// An inline run of text within a paragraph or heading, stripped of markup.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InlineSpan {
+    Text(String),
+    Code(String),
+    Strong(String),
+    Emphasis(String),
+    Link { text: String, url: String },
+    Image { alt: String, url: String },
+}
+
+/// A single block-level element of the document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsedElement {
+    Heading {
+        level: u8,
+        text: String,
+    },
+    Paragraph(Vec<InlineSpan>),
+    List {
+        ordered: bool,
+        items: Vec<Vec<ParsedElement>>,
+    },
+    Table {
+        alignments: Vec<Alignment>,
+        header: Option<Vec<String>>,
+        rows: Vec<Vec<String>>,
+    },
+    BlockQuote(Vec<ParsedElement>),
+    CodeBlock {
+        language: Option<String>,
+        content: String,
+    },
+    HorizontalRule,
+}
+
+/// The full document as a tree of [`ParsedElement`]s, with no rendering
+/// concerns attached.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedDocument {
+    pub elements: Vec<ParsedElement>,
+}
+
+impl ParsedDocument {
+    /// Parses `content` into a [`ParsedDocument`], consuming the
+    /// `pulldown_cmark` event stream exactly once.
+    pub fn parse(content: &str, options: Options) -> Self {
+        let mut events = Parser::new_ext(content, options).peekable();
+        Self {
+            elements: parse_blocks(&mut events),
+        }
+    }
+
+    /// Total number of headings in the document, at any nesting depth.
+    pub fn heading_count(&self) -> usize {
+        count_elements(&self.elements, &|element| {
+            matches!(element, ParsedElement::Heading { .. })
+        })
+    }
+
+    /// Every fenced/indented code block's content, in document order.
+    pub fn code_blocks(&self) -> Vec<&str> {
+        let mut blocks = Vec::new();
+        collect_code_blocks(&self.elements, &mut blocks);
+        blocks
+    }
+}
+
+fn count_elements(
+    elements: &[ParsedElement],
+    predicate: &impl Fn(&ParsedElement) -> bool,
+) -> usize {
+    elements
+        .iter()
+        .map(|element| {
+            let here = usize::from(predicate(element));
+            let nested = match element {
+                ParsedElement::List { items, .. } => items
+                    .iter()
+                    .map(|item| count_elements(item, predicate))
+                    .sum(),
+                ParsedElement::BlockQuote(children) => count_elements(children, predicate),
+                _ => 0,
+            };
+            here + nested
+        })
+        .sum()
+}
+
+fn collect_code_blocks<'a>(elements: &'a [ParsedElement], out: &mut Vec<&'a str>) {
+    for element in elements {
+        match element {
+            ParsedElement::CodeBlock { content, .. } => out.push(content),
+            ParsedElement::List { items, .. } => {
+                for item in items {
+                    collect_code_blocks(item, out);
+                }
+            }
+            ParsedElement::BlockQuote(children) => collect_code_blocks(children, out),
+            _ => {}
+        }
+    }
+}
+
+/// Consumes block-level events until the iterator runs dry or a `TagEnd`
+/// closing an ancestor container is reached (which is left unconsumed for
+/// the caller that opened it to observe).
+fn parse_blocks<'a>(events: &mut Peekable<Parser<'a>>) -> Vec<ParsedElement> {
+    let mut elements = Vec::new();
+
+    while let Some(event) = events.peek() {
+        match event {
+            Event::End(TagEnd::Item)
+            | Event::End(TagEnd::List(_))
+            | Event::End(TagEnd::BlockQuote(_)) => break,
+            _ => {}
+        }
+
+        let event = events.next().expect("just peeked");
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                let text = consume_inline_text(events, TagEnd::Heading(level));
+                elements.push(ParsedElement::Heading {
+                    level: level as u8,
+                    text,
+                });
+            }
+            Event::Start(Tag::Paragraph) => {
+                let spans = parse_inline_spans(events);
+                elements.push(ParsedElement::Paragraph(spans));
+            }
+            Event::Start(Tag::List(start)) => {
+                elements.push(parse_list(events, start.is_none()));
+            }
+            Event::Start(Tag::BlockQuote(_)) => {
+                let children = parse_blocks(events);
+                consume_end(events, |tag| matches!(tag, TagEnd::BlockQuote(_)));
+                elements.push(ParsedElement::BlockQuote(children));
+            }
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let language = match kind {
+                    CodeBlockKind::Indented => None,
+                    CodeBlockKind::Fenced(info) => super::lang_string::LangString::parse(&info).lang,
+                };
+                let mut content = String::new();
+                while let Some(event) =
+                    events.next_if(|event| !matches!(event, Event::End(TagEnd::CodeBlock)))
+                {
+                    if let Event::Text(text) = event {
+                        content.push_str(&text);
+                    }
+                }
+                consume_end(events, |tag| matches!(tag, TagEnd::CodeBlock));
+                elements.push(ParsedElement::CodeBlock { language, content });
+            }
+            Event::Start(Tag::Table(alignments)) => {
+                elements.push(parse_table(events, alignments));
+            }
+            Event::Rule => elements.push(ParsedElement::HorizontalRule),
+            _ => {}
+        }
+    }
+
+    elements
+}
+
+fn parse_list<'a>(events: &mut Peekable<Parser<'a>>, ordered: bool) -> ParsedElement {
+    let mut items = Vec::new();
+    loop {
+        match events.peek() {
+            Some(Event::Start(Tag::Item)) => {
+                events.next();
+                items.push(parse_blocks(events));
+                consume_end(events, |tag| matches!(tag, TagEnd::Item));
+            }
+            Some(Event::End(TagEnd::List(_))) => {
+                events.next();
+                break;
+            }
+            Some(_) => {
+                events.next();
+            }
+            None => break,
+        }
+    }
+    ParsedElement::List { ordered, items }
+}
+
+fn parse_table<'a>(events: &mut Peekable<Parser<'a>>, alignments: Vec<Alignment>) -> ParsedElement {
+    let mut header = None;
+    let mut rows = Vec::new();
+
+    loop {
+        match events.peek() {
+            Some(Event::Start(Tag::TableHead)) => {
+                events.next();
+                header = Some(parse_table_row(events, TagEnd::TableHead));
+            }
+            Some(Event::Start(Tag::TableRow)) => {
+                events.next();
+                rows.push(parse_table_row(events, TagEnd::TableRow));
+            }
+            Some(Event::End(TagEnd::Table)) => {
+                events.next();
+                break;
+            }
+            Some(_) => {
+                events.next();
+            }
+            None => break,
+        }
+    }
+
+    ParsedElement::Table {
+        alignments,
+        header,
+        rows,
+    }
+}
+
+fn parse_table_row<'a>(events: &mut Peekable<Parser<'a>>, end: TagEnd) -> Vec<String> {
+    let mut cells = Vec::new();
+    let mut current = String::new();
+    loop {
+        match events.peek() {
+            Some(Event::Start(Tag::TableCell)) => {
+                events.next();
+            }
+            Some(Event::End(TagEnd::TableCell)) => {
+                events.next();
+                cells.push(std::mem::take(&mut current));
+            }
+            Some(event) if *event == Event::End(end.clone()) => {
+                events.next();
+                break;
+            }
+            Some(_) => {
+                if let Some(Event::Text(text) | Event::Code(text)) = events.next() {
+                    current.push_str(&text);
+                }
+            }
+            None => break,
+        }
+    }
+    cells
+}
+
+/// Collects the plain-text content of an inline run (heading text, link
+/// text) until `end` closes it, flattening any nested emphasis/strong
+/// markup into plain text.
+fn consume_inline_text<'a>(events: &mut Peekable<Parser<'a>>, end: TagEnd) -> String {
+    let mut text = String::new();
+    while let Some(event) = events.next() {
+        match event {
+            Event::Text(t) | Event::Code(t) => text.push_str(&t),
+            Event::End(tag) if tag == end => break,
+            _ => {}
+        }
+    }
+    text
+}
+
+fn parse_inline_spans<'a>(events: &mut Peekable<Parser<'a>>) -> Vec<InlineSpan> {
+    let mut spans = Vec::new();
+    while let Some(event) = events.next() {
+        match event {
+            Event::End(TagEnd::Paragraph) => break,
+            Event::Text(text) => spans.push(InlineSpan::Text(text.to_string())),
+            Event::Code(code) => spans.push(InlineSpan::Code(code.to_string())),
+            Event::Start(Tag::Strong) => {
+                let text = consume_inline_text(events, TagEnd::Strong);
+                spans.push(InlineSpan::Strong(text));
+            }
+            Event::Start(Tag::Emphasis) => {
+                let text = consume_inline_text(events, TagEnd::Emphasis);
+                spans.push(InlineSpan::Emphasis(text));
+            }
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                let text = consume_inline_text(events, TagEnd::Link);
+                spans.push(InlineSpan::Link {
+                    text,
+                    url: dest_url.to_string(),
+                });
+            }
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                let alt = consume_inline_text(events, TagEnd::Image);
+                spans.push(InlineSpan::Image {
+                    alt,
+                    url: dest_url.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+    spans
+}
+
+fn consume_end<'a>(events: &mut Peekable<Parser<'a>>, matches_end: impl Fn(&TagEnd) -> bool) {
+    for event in events.by_ref() {
+        if let Event::End(tag) = &event {
+            if matches_end(tag) {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_collects_headings_and_paragraphs() {
+        let doc = ParsedDocument::parse("# Title\n\nSome **bold** text.\n", Options::empty());
+        assert_eq!(doc.elements.len(), 2);
+        assert_eq!(
+            doc.elements[0],
+            ParsedElement::Heading {
+                level: 1,
+                text: "Title".to_string()
+            }
+        );
+        assert!(matches!(doc.elements[1], ParsedElement::Paragraph(_)));
+    }
+
+    #[test]
+    fn test_parse_nests_list_items_and_blockquotes() {
+        let doc = ParsedDocument::parse("- one\n- two\n\n> quoted\n", Options::empty());
+        assert!(matches!(
+            doc.elements[0],
+            ParsedElement::List { ordered: false, .. }
+        ));
+        if let ParsedElement::List { items, .. } = &doc.elements[0] {
+            assert_eq!(items.len(), 2);
+        }
+        assert!(matches!(doc.elements[1], ParsedElement::BlockQuote(_)));
+    }
+
+    #[test]
+    fn test_parse_captures_fenced_code_block_language_and_content() {
+        let doc = ParsedDocument::parse("```rust\nfn main() {}\n```\n", Options::empty());
+        assert_eq!(
+            doc.elements[0],
+            ParsedElement::CodeBlock {
+                language: Some("rust".to_string()),
+                content: "fn main() {}\n".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_captures_table_header_and_rows() {
+        let mut options = Options::empty();
+        options.insert(Options::ENABLE_TABLES);
+        let doc = ParsedDocument::parse("| A | B |\n|---|---|\n| 1 | 2 |\n", options);
+        let ParsedElement::Table { header, rows, .. } = &doc.elements[0] else {
+            panic!("expected a table");
+        };
+        assert_eq!(
+            header.as_deref(),
+            Some(&["A".to_string(), "B".to_string()][..])
+        );
+        assert_eq!(rows, &vec![vec!["1".to_string(), "2".to_string()]]);
+    }
+
+    #[test]
+    fn test_heading_count_counts_nested_headings() {
+        let doc = ParsedDocument::parse("# One\n\n## Two\n\n> ### Three\n", Options::empty());
+        assert_eq!(doc.heading_count(), 3);
+    }
+
+    #[test]
+    fn test_code_blocks_extracts_content_in_document_order() {
+        let doc = ParsedDocument::parse("```\nfirst\n```\n\n```\nsecond\n```\n", Options::empty());
+        assert_eq!(doc.code_blocks(), vec!["first\n", "second\n"]);
+    }
+
+    #[test]
+    fn test_parse_nests_a_code_block_inside_a_blockquote() {
+        let doc = ParsedDocument::parse("> ```rust\n> fn main() {}\n> ```\n", Options::empty());
+        let ParsedElement::BlockQuote(inner) = &doc.elements[0] else {
+            panic!("expected a blockquote");
+        };
+        assert_eq!(
+            inner[0],
+            ParsedElement::CodeBlock {
+                language: Some("rust".to_string()),
+                content: "fn main() {}\n".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_nests_a_blockquote_inside_a_list_item() {
+        let doc = ParsedDocument::parse("- one\n\n  > quoted\n", Options::empty());
+        let ParsedElement::List { items, .. } = &doc.elements[0] else {
+            panic!("expected a list");
+        };
+        assert!(
+            items[0]
+                .iter()
+                .any(|element| matches!(element, ParsedElement::BlockQuote(_)))
+        );
+    }
+
+    #[test]
+    fn test_parse_nests_multi_level_lists() {
+        let doc = ParsedDocument::parse("- one\n  - nested\n- two\n", Options::empty());
+        let ParsedElement::List { items, .. } = &doc.elements[0] else {
+            panic!("expected a list");
+        };
+        assert_eq!(items.len(), 2);
+        let nested_list = items[0].iter().find_map(|element| match element {
+            ParsedElement::List { items, .. } => Some(items),
+            _ => None,
+        });
+        assert_eq!(nested_list.expect("expected a nested list").len(), 1);
+    }
+}
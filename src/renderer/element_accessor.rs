@@ -3,7 +3,7 @@
 //! This module provides a trait-based approach to accessing different element types
 //! within ActiveElement, eliminating repetitive getter/setter methods.
 
-use super::state::{ActiveElement, CodeBlockState, ImageState, LinkState, TableState};
+use super::state::{ActiveElement, CodeBlockState, FootnoteState, ImageState, LinkState, TableState};
 
 /// Trait for extracting specific element data from ActiveElement.
 /// Each element type implements this trait to provide type-safe access.
@@ -120,3 +120,28 @@ impl ElementData for TableAccessor {
         ActiveElement::Table(data)
     }
 }
+
+/// Marker type for FootnoteState element access
+pub struct FootnoteAccessor;
+
+impl ElementData for FootnoteAccessor {
+    type Output = FootnoteState;
+
+    fn extract(element: &ActiveElement) -> Option<&FootnoteState> {
+        match element {
+            ActiveElement::Footnote(footnote) => Some(footnote),
+            _ => None,
+        }
+    }
+
+    fn extract_mut(element: &mut ActiveElement) -> Option<&mut FootnoteState> {
+        match element {
+            ActiveElement::Footnote(footnote) => Some(footnote),
+            _ => None,
+        }
+    }
+
+    fn create(data: FootnoteState) -> ActiveElement {
+        ActiveElement::Footnote(data)
+    }
+}
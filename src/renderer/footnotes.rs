@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+/// Tracks footnote references and definitions seen while rendering a
+/// document, assigning each label a stable display number the first time it
+/// is seen (as either a reference or a definition) so a numbered "Notes"
+/// block can be printed once rendering finishes.
+#[derive(Debug, Clone, Default)]
+pub struct FootnoteRegistry {
+    order: Vec<String>,
+    numbers: HashMap<String, usize>,
+    definitions: HashMap<String, String>,
+}
+
+impl FootnoteRegistry {
+    /// Registers `label`, assigning it the next display number the first
+    /// time it's seen, and returns that number either way.
+    pub fn number_for(&mut self, label: &str) -> usize {
+        if let Some(&number) = self.numbers.get(label) {
+            return number;
+        }
+        let number = self.order.len() + 1;
+        self.order.push(label.to_string());
+        self.numbers.insert(label.to_string(), number);
+        number
+    }
+
+    /// Records `body` as the rendered content of `label`'s definition.
+    pub fn define(&mut self, label: &str, body: String) {
+        self.number_for(label);
+        self.definitions.insert(label.to_string(), body);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Every footnote in assigned-number order, with its body if a matching
+    /// `[^label]: ...` definition was ever seen.
+    pub fn entries(&self) -> Vec<(usize, &str, Option<&str>)> {
+        self.order
+            .iter()
+            .enumerate()
+            .map(|(i, label)| (i + 1, label.as_str(), self.definitions.get(label).map(String::as_str)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_for_assigns_numbers_in_first_seen_order() {
+        let mut registry = FootnoteRegistry::default();
+        assert_eq!(registry.number_for("b"), 1);
+        assert_eq!(registry.number_for("a"), 2);
+        assert_eq!(registry.number_for("b"), 1);
+    }
+
+    #[test]
+    fn test_define_before_reference_keeps_the_same_number() {
+        let mut registry = FootnoteRegistry::default();
+        registry.define("a", "body".to_string());
+        assert_eq!(registry.number_for("a"), 1);
+        assert_eq!(registry.entries(), vec![(1, "a", Some("body"))]);
+    }
+
+    #[test]
+    fn test_reference_with_no_definition_has_no_body() {
+        let mut registry = FootnoteRegistry::default();
+        registry.number_for("missing");
+        assert_eq!(registry.entries(), vec![(1, "missing", None)]);
+    }
+}
@@ -0,0 +1,166 @@
+//! Pluggable element dispatch, modeled on the handler-chain architecture
+//! used by editors like Zed for their own Markdown writers: an ordered list
+//! of handlers is each given a chance to handle an element before the
+//! built-in terminal rendering gets the final say. This lets callers inject
+//! custom rendering for specific element kinds (e.g. callout admonitions or
+//! domain-specific fenced blocks) without forking the renderer.
+
+use anyhow::Result;
+use pulldown_cmark::Alignment;
+
+use super::MarkdownRenderer;
+
+/// An element dispatched through the [`HandlerChain`]. Tag-delimited
+/// elements are dispatched twice, once via `handle_start` and once via
+/// `handle_end`; one-shot content events (text, breaks, rules, ...) are
+/// dispatched via `handle_start` only, since they have no closing
+/// counterpart.
+#[derive(Debug, Clone)]
+pub enum ElementKind {
+    Heading(u8),
+    Paragraph,
+    Strong,
+    Emphasis,
+    Strikethrough,
+    /// `(dest_url, title)`
+    Link(String, String),
+    List(Option<u64>),
+    ListItem,
+    CodeBlock(Option<String>),
+    Table(Vec<Alignment>),
+    TableHead,
+    TableRow,
+    BlockQuote,
+    /// `(dest_url, title)`
+    Image(String, String),
+    FootnoteDefinition(String),
+    FootnoteReference(String),
+    Text(String),
+    Code(String),
+    Html(String),
+    SoftBreak,
+    HardBreak,
+    Rule,
+    TaskMarker(bool),
+}
+
+/// The outcome of offering an element to a single handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandlerResult {
+    /// The handler rendered (or deliberately ignored) the element; no
+    /// further handler in the chain should see it.
+    Handled,
+    /// The handler has nothing to say about this element; defer to the
+    /// next one in the chain.
+    Pass,
+}
+
+/// Mutable access to the renderer passed to each handler in the chain.
+pub struct RenderCtx<'a> {
+    pub renderer: &'a mut MarkdownRenderer,
+}
+
+impl std::ops::Deref for RenderCtx<'_> {
+    type Target = MarkdownRenderer;
+
+    fn deref(&self) -> &Self::Target {
+        self.renderer
+    }
+}
+
+impl std::ops::DerefMut for RenderCtx<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.renderer
+    }
+}
+
+/// A stage in the element-rendering pipeline. Implementors get first look
+/// at an element before it falls through to the rest of the chain; the
+/// default implementations `Pass` on everything, so a handler only needs to
+/// override the methods for the element kinds it actually customizes.
+pub trait Handler {
+    fn handle_start(&mut self, el: &ElementKind, ctx: &mut RenderCtx) -> Result<HandlerResult> {
+        let _ = (el, ctx);
+        Ok(HandlerResult::Pass)
+    }
+
+    fn handle_end(&mut self, el: &ElementKind, ctx: &mut RenderCtx) -> Result<HandlerResult> {
+        let _ = (el, ctx);
+        Ok(HandlerResult::Pass)
+    }
+}
+
+/// An ordered list of [`Handler`]s. Custom handlers added via
+/// [`MarkdownRenderer::add_handler`] are tried in registration order; the
+/// built-in terminal handler is always last, so it can serve as the
+/// default when nothing upstream claims the element.
+#[derive(Default)]
+pub struct HandlerChain {
+    handlers: Vec<Box<dyn Handler>>,
+}
+
+impl HandlerChain {
+    /// A chain with only the built-in handlers installed: the length-limit
+    /// handler first, so it can gate every other handler once its budget is
+    /// exhausted, and the terminal handler last, as the default renderer.
+    pub(super) fn new() -> Self {
+        Self {
+            handlers: vec![
+                Box::new(super::length_limit::LengthLimitHandler::default()),
+                Box::new(super::handlers::TerminalHandler),
+            ],
+        }
+    }
+
+    /// Registers `handler` to run before the built-in terminal handler,
+    /// which always remains last in the chain.
+    pub(super) fn add_handler(&mut self, handler: Box<dyn Handler>) {
+        let default_index = self.handlers.len().saturating_sub(1);
+        self.handlers.insert(default_index, handler);
+    }
+
+    fn run_start(&mut self, el: &ElementKind, renderer: &mut MarkdownRenderer) -> Result<()> {
+        let mut ctx = RenderCtx { renderer };
+        for handler in &mut self.handlers {
+            if handler.handle_start(el, &mut ctx)? == HandlerResult::Handled {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn run_end(&mut self, el: &ElementKind, renderer: &mut MarkdownRenderer) -> Result<()> {
+        let mut ctx = RenderCtx { renderer };
+        for handler in &mut self.handlers {
+            if handler.handle_end(el, &mut ctx)? == HandlerResult::Handled {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MarkdownRenderer {
+    /// Registers a custom handler to run before the built-in terminal
+    /// handler, which always remains last in the chain so it can serve as
+    /// the default rendering behavior.
+    pub fn add_handler(&mut self, handler: Box<dyn Handler>) {
+        self.handlers.add_handler(handler);
+    }
+
+    /// Offers `el` to the handler chain's `handle_start` stage.
+    pub(super) fn dispatch_start(&mut self, el: &ElementKind) -> Result<()> {
+        let mut chain = std::mem::take(&mut self.handlers);
+        let result = chain.run_start(el, self);
+        self.handlers = chain;
+        result
+    }
+
+    /// Offers `el` to the handler chain's `handle_end` stage.
+    pub(super) fn dispatch_end(&mut self, el: &ElementKind) -> Result<()> {
+        let mut chain = std::mem::take(&mut self.handlers);
+        let result = chain.run_end(el, self);
+        self.handlers = chain;
+        result
+    }
+}
@@ -1,13 +1,356 @@
 use anyhow::Result;
-use pulldown_cmark::{Event, Tag, TagEnd};
+use pulldown_cmark::{CodeBlockKind, Event, Tag, TagEnd};
 
-use super::{MarkdownRenderer, state::ContentType};
-use crate::{
-    html_entity::decode_html_entities,
-    output::{OutputType, TableVariant},
+use super::{
+    MarkdownRenderer,
+    handler::{ElementKind, Handler, HandlerResult, RenderCtx},
+    state::{CodeBlockState, ContentType, ListType},
+    styling::TextStyle,
 };
+use crate::{html_entity::decode_html_entities, output::OutputType, theme::Decorations};
+
+use super::table_layout::display_width;
 
 impl MarkdownRenderer {
+    /// Emits the final rendered text for the element kinds whose output
+    /// can't be written as soon as their tag arrives — either because it's
+    /// only known at the closing tag (headings, block quotes) or because it
+    /// needs state buffered since the opening tag (link/image destination
+    /// URLs, code block content). `handle_*_start`/`handle_*_end` below
+    /// convert each tag into the matching variant and call through here so
+    /// the styling/emission logic for a given kind lives in one place.
+    pub fn print_output(&mut self, output_type: OutputType) -> Result<()> {
+        match output_type {
+            OutputType::Heading { level, is_end } => {
+                if is_end {
+                    println!();
+                    println!();
+                    self.state.current_column = 0;
+                } else {
+                    let marker = format!("{} ", "#".repeat(level as usize));
+                    let styled =
+                        self.create_styled_marker(&marker, self.theme.heading_color(level), true);
+                    print!("{}{}", self.quote_prefix(), styled);
+                    self.state.current_column = self.quote_prefix_width() + display_width(&marker);
+                }
+            }
+            OutputType::Paragraph { is_end } => {
+                if is_end {
+                    println!();
+                    self.state.current_column = 0;
+                }
+            }
+            OutputType::ListItem { is_end } => {
+                if is_end {
+                    println!();
+                    self.state.current_column = 0;
+                } else {
+                    let depth = self.state.list_stack.len();
+                    let indent = self.config.create_indent(depth.saturating_sub(1));
+                    print!("{}{}", self.quote_prefix(), indent);
+                    self.state.current_column = self.quote_prefix_width() + display_width(&indent);
+
+                    if let Some(list_type) = self.state.list_stack.last_mut() {
+                        let marker = match list_type {
+                            ListType::Unordered => "\u{2022} ".to_string(),
+                            ListType::Ordered { current } => {
+                                let marker = format!("{}.  ", current);
+                                *current += 1;
+                                marker
+                            }
+                        };
+                        let styled = self.create_styled_marker(
+                            &marker,
+                            self.theme.list_marker_color(),
+                            false,
+                        );
+                        print!("{}", styled);
+                        self.state.current_column += display_width(&marker);
+                    }
+                }
+            }
+            OutputType::BlockQuote { is_end } => {
+                if is_end {
+                    self.state.blockquote_depth = self.state.blockquote_depth.saturating_sub(1);
+                    println!();
+                    self.state.current_column = 0;
+                } else {
+                    self.state.blockquote_depth += 1;
+                    print!("{}", self.quote_prefix());
+                    self.state.current_column = self.quote_prefix_width();
+                }
+            }
+            OutputType::Link => {
+                if let Some(link) = self.get_link() {
+                    self.clear_link();
+                    let link_style = match link.url.strip_prefix('#') {
+                        Some(anchor) if !self.state.heading_anchors.contains(anchor) => {
+                            TextStyle::Delimiter
+                        }
+                        _ => TextStyle::Link,
+                    };
+                    let styled_text = self.apply_text_style(&link.text, link_style).to_string();
+                    let url_text = self.create_styled_url(&link.url);
+                    print!("{}", self.hyperlink(&link.url, &styled_text));
+                    print!("{}", url_text);
+                    self.state.current_column +=
+                        display_width(&link.text) + display_width(&link.url) + 3;
+
+                    if self.config.link_titles && !link.title.is_empty() {
+                        print!("{}", self.create_styled_title(&link.title));
+                        self.state.current_column += display_width(&link.title) + 5;
+                    }
+                }
+            }
+            OutputType::Image => {
+                if let Some(image) = self.get_image() {
+                    self.clear_image();
+                    let display_text = if image.alt_text.is_empty() {
+                        "[Image]"
+                    } else {
+                        &image.alt_text
+                    };
+                    let styled_text = self
+                        .apply_text_style(display_text, TextStyle::Emphasis)
+                        .to_string();
+                    let url_text = self.create_styled_url(&image.url);
+                    print!("{}", self.hyperlink(&image.url, &styled_text));
+                    print!("{}", url_text);
+
+                    if self.config.link_titles && !image.title.is_empty() {
+                        print!("{}", self.create_styled_title(&image.title));
+                        self.state.current_column += display_width(&image.title) + 5;
+                    }
+
+                    self.state.current_column +=
+                        display_width(display_text) + display_width(&image.url) + 3;
+                }
+            }
+            OutputType::InlineCode { ref code } => {
+                let styled = self.apply_text_style(code, TextStyle::CodeBlock);
+                print!("{}", styled);
+                self.state.current_column += display_width(code);
+            }
+            OutputType::TaskMarker { checked } => {
+                let marker = if checked { "[x] " } else { "[ ] " };
+                let styled =
+                    self.create_styled_marker(marker, self.theme.list_marker_color(), false);
+                print!("{}", styled);
+                self.state.current_column += display_width(marker);
+            }
+            OutputType::CodeBlock => {
+                if let Some(code_block) = self.get_code_block() {
+                    self.clear_code_block();
+                    self.render_code_block(&code_block)?;
+                }
+            }
+            // Tables are buffered and flushed by `handle_table_end` rather
+            // than dispatched through `print_output`; nothing constructs
+            // this variant in the real rendering pipeline.
+            OutputType::Table { .. } => {}
+        }
+        Ok(())
+    }
+
+    /// The `"│ "` gutter marker repeated once per open
+    /// [`RenderState::blockquote_depth`] and styled with the quote delimiter
+    /// color (itself dimmed one step further per depth by
+    /// [`Self::apply_text_style`]), or the empty string outside a quote.
+    /// Printed before any physical line of output — a heading, list marker,
+    /// or code block line — so nested block content still reads as quoted,
+    /// with deeper quotes reading as progressively dimmer rather than
+    /// collapsing into plain text.
+    fn quote_prefix(&self) -> String {
+        if self.state.blockquote_depth == 0 {
+            return String::new();
+        }
+        self.create_styled_marker(
+            &"│ ".repeat(self.state.blockquote_depth),
+            self.theme.delimiter_color(),
+            false,
+        )
+    }
+
+    /// Display width of [`Self::quote_prefix`], without needing to strip the
+    /// ANSI escapes it wraps the marker in.
+    fn quote_prefix_width(&self) -> usize {
+        2 * self.state.blockquote_depth
+    }
+
+    /// The prefix to start each wrapped continuation line of paragraph text
+    /// with: [`Self::quote_prefix`] plus one indent level per open list
+    /// item, so wrapped text lines up under (rather than past) the bullet
+    /// or number that started the line. Used by
+    /// [`super::styling::render_styled_text`](super::MarkdownRenderer::render_styled_text).
+    pub(super) fn continuation_prefix(&self) -> String {
+        let depth = self.state.list_stack.len();
+        format!("{}{}", self.quote_prefix(), self.config.create_indent(depth))
+    }
+
+    /// Display width of [`Self::continuation_prefix`], computed without
+    /// stripping ANSI escapes from the (already styled) prefix string.
+    pub(super) fn continuation_width(&self) -> usize {
+        let depth = self.state.list_stack.len();
+        self.quote_prefix_width() + display_width(&self.config.create_indent(depth))
+    }
+
+    /// Writes a fenced code block's opening fence, its (optionally
+    /// syntax-highlighted) content, and its closing fence.
+    fn render_code_block(&mut self, code_block: &CodeBlockState) -> Result<()> {
+        self.render_code_fence(code_block.language.as_deref());
+        self.render_code_content(&code_block.content, code_block.language.as_deref());
+        self.render_code_fence(None);
+        Ok(())
+    }
+
+    fn render_code_fence(&self, language: Option<&str>) {
+        let fence = self.create_styled_marker("```", self.theme.delimiter_color(), false);
+        match language {
+            Some(lang) => {
+                let lang_text = self.create_styled_marker(lang, self.theme.code_color(), false);
+                println!("{}{}{}", self.quote_prefix(), fence, lang_text);
+            }
+            None => println!("{}{}", self.quote_prefix(), fence),
+        }
+    }
+
+    /// Prints `content` line by line, syntax-highlighting it when
+    /// `language` is known to the configured
+    /// [`super::Highlighter`](super::highlight::Highlighter), and falling
+    /// back to plain dimmed text otherwise. A `diff`-tagged fence instead
+    /// colors each line by its leading `+`/`-` marker, bypassing syntax
+    /// highlighting entirely. Either way, lines are prefixed with a
+    /// [`super::config::RenderConfig::code_line_numbers`] gutter when enabled.
+    fn render_code_content(&self, content: &str, language: Option<&str>) {
+        let lines: Vec<String> = if language == Some("diff") {
+            content.lines().map(|line| self.style_diff_line(line)).collect()
+        } else {
+            let highlighted = language
+                .filter(|_| self.config.syntax_highlight)
+                .filter(|lang| self.highlighter.supports(lang))
+                .map(|lang| self.highlighter.highlight(lang, content));
+
+            match highlighted {
+                Some(spans) => self.highlighted_lines(&spans),
+                None => content
+                    .lines()
+                    .map(|line| self.apply_text_style(line, TextStyle::CodeBlock))
+                    .collect(),
+            }
+        };
+
+        let gutter_width = self
+            .config
+            .code_line_numbers
+            .then(|| lines.len().to_string().len());
+
+        for (index, line) in lines.iter().enumerate() {
+            match gutter_width {
+                Some(width) => println!(
+                    "{}{}{}",
+                    self.quote_prefix(),
+                    self.code_gutter(index + 1, width),
+                    line
+                ),
+                None => println!("{}{}", self.quote_prefix(), line),
+            }
+        }
+    }
+
+    /// Right-aligned, [`TextStyle::Delimiter`]-styled line number for a
+    /// [`super::config::RenderConfig::code_line_numbers`] gutter, `width`
+    /// digits wide, followed by a separating space.
+    fn code_gutter(&self, line_number: usize, width: usize) -> String {
+        let number = format!("{line_number:>width$}");
+        format!("{} ", self.apply_text_style(&number, TextStyle::Delimiter))
+    }
+
+    /// Styles one line of a ` ```diff ` fence by its leading marker: `+`
+    /// gets the theme's success color, `-` gets its error color, and
+    /// anything else (context lines, hunk headers) renders as plain code
+    /// text.
+    fn style_diff_line(&self, line: &str) -> String {
+        let style = match line.as_bytes().first() {
+            Some(b'+') => TextStyle::Custom {
+                color: self.theme.success_color(),
+                bold: false,
+                decorations: Decorations::default(),
+            },
+            Some(b'-') => TextStyle::Custom {
+                color: self.theme.error_color(),
+                bold: false,
+                decorations: Decorations::default(),
+            },
+            _ => TextStyle::CodeBlock,
+        };
+        self.apply_text_style(line, style)
+    }
+
+    /// Regroups flat `(Style, text)` spans into per-line styled strings,
+    /// splitting a span on embedded newlines so each rendered line gets its
+    /// own closed set of ANSI escapes rather than one spanning multiple
+    /// lines.
+    fn highlighted_lines(&self, spans: &[(super::highlight::Style, String)]) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut current = String::new();
+
+        for (style, text) in spans {
+            let mut parts = text.split('\n');
+            if let Some(first) = parts.next()
+                && !first.is_empty()
+            {
+                current.push_str(&self.style_highlight_span(*style, first));
+            }
+            for part in parts {
+                lines.push(std::mem::take(&mut current));
+                if !part.is_empty() {
+                    current.push_str(&self.style_highlight_span(*style, part));
+                }
+            }
+        }
+        if !current.is_empty() || lines.is_empty() {
+            lines.push(current);
+        }
+        lines
+    }
+
+    fn style_highlight_span(&self, style: super::highlight::Style, text: &str) -> String {
+        use super::highlight::Style as HighlightStyle;
+        let text_style = match style {
+            HighlightStyle::Keyword => TextStyle::Custom {
+                color: self.theme.strong_color(),
+                bold: true,
+                decorations: Decorations::default(),
+            },
+            HighlightStyle::Literal => TextStyle::Custom {
+                color: self.theme.emphasis_color(),
+                bold: false,
+                decorations: Decorations::default(),
+            },
+            HighlightStyle::String => TextStyle::Custom {
+                color: self.theme.link_color(),
+                bold: false,
+                decorations: Decorations::default(),
+            },
+            HighlightStyle::Comment => TextStyle::Custom {
+                color: self.theme.delimiter_color(),
+                bold: false,
+                decorations: Decorations::default(),
+            },
+            HighlightStyle::Type => TextStyle::Custom {
+                color: self.theme.heading_color(3),
+                bold: false,
+                decorations: Decorations::default(),
+            },
+            HighlightStyle::Function => TextStyle::Custom {
+                color: self.theme.heading_color(2),
+                bold: false,
+                decorations: Decorations::default(),
+            },
+            HighlightStyle::Normal => TextStyle::CodeBlock,
+        };
+        self.apply_text_style(text, text_style).to_string()
+    }
     /// Process pulldown_cmark events and route them to appropriate handlers.
     /// Converts Tag events to handle_tag and content events to handle_content.
     pub fn process_event(&mut self, event: Event) -> Result<()> {
@@ -26,6 +369,7 @@ impl MarkdownRenderer {
                 TagEnd::Paragraph => self.handle_tag(Tag::Paragraph, false),
                 TagEnd::Strong => self.handle_tag(Tag::Strong, false),
                 TagEnd::Emphasis => self.handle_tag(Tag::Emphasis, false),
+                TagEnd::Strikethrough => self.handle_tag(Tag::Strikethrough, false),
                 TagEnd::Link => self.handle_tag(
                     Tag::Link {
                         link_type: pulldown_cmark::LinkType::Inline,
@@ -37,10 +381,9 @@ impl MarkdownRenderer {
                 ),
                 TagEnd::List(_) => self.handle_tag(Tag::List(None), false),
                 TagEnd::Item => self.handle_tag(Tag::Item, false),
-                TagEnd::CodeBlock => self.handle_tag(
-                    Tag::CodeBlock(pulldown_cmark::CodeBlockKind::Indented),
-                    false,
-                ),
+                TagEnd::CodeBlock => {
+                    self.handle_tag(Tag::CodeBlock(CodeBlockKind::Indented), false)
+                }
                 TagEnd::Table => self.handle_tag(Tag::Table(vec![]), false),
                 TagEnd::TableHead => self.handle_tag(Tag::TableHead, false),
                 TagEnd::TableRow => self.handle_tag(Tag::TableRow, false),
@@ -54,6 +397,9 @@ impl MarkdownRenderer {
                     },
                     false,
                 ),
+                TagEnd::FootnoteDefinition => {
+                    self.handle_tag(Tag::FootnoteDefinition("".into()), false)
+                }
                 _ => Ok(()),
             },
             Event::Text(text) => self.handle_content(ContentType::Text(&text)),
@@ -63,62 +409,43 @@ impl MarkdownRenderer {
             Event::HardBreak => self.handle_content(ContentType::HardBreak),
             Event::Rule => self.handle_content(ContentType::Rule),
             Event::TaskListMarker(checked) => self.handle_content(ContentType::TaskMarker(checked)),
+            Event::FootnoteReference(label) => {
+                self.dispatch_start(&ElementKind::FootnoteReference(label.to_string()))
+            }
             _ => Ok(()),
         }
     }
 
-    /// Process opening and closing tags to manage state transitions and output.
-    /// Opening tags set up state, closing tags trigger rendering and cleanup.
+    /// Process opening and closing tags by converting them to an
+    /// [`ElementKind`] and offering it to the handler chain.
     pub(super) fn handle_tag(&mut self, tag: Tag, is_start: bool) -> Result<()> {
+        let kind = element_kind_for_tag(&tag);
         if is_start {
-            self.handle_tag_start(tag)
+            self.dispatch_start(&kind)
         } else {
-            self.handle_tag_end(tag)
-        }
-    }
-
-    /// Handle opening tags to set up state for element processing
-    fn handle_tag_start(&mut self, tag: Tag) -> Result<()> {
-        match tag {
-            Tag::Heading { level, .. } => self.handle_heading_start(level as u8)?,
-            Tag::Paragraph => self.handle_paragraph_start()?,
-            Tag::Strong => self.set_strong_emphasis(true),
-            Tag::Emphasis => self.set_italic_emphasis(true),
-            Tag::Link { dest_url, .. } => self.set_link(dest_url.to_string()),
-            Tag::List(start) => self.handle_list_start(start),
-            Tag::Item => self.handle_list_item_start()?,
-            Tag::CodeBlock(kind) => self.handle_code_block_start(kind),
-            Tag::Table(alignments) => self.set_table(alignments),
-            Tag::TableHead => self.handle_table_head_start()?,
-            Tag::BlockQuote(_) => self.handle_block_quote_start()?,
-            Tag::Image { dest_url, .. } => self.set_image(dest_url.to_string()),
-            _ => {}
+            self.dispatch_end(&kind)
         }
-        Ok(())
     }
 
-    /// Handle closing tags to trigger rendering and cleanup
-    fn handle_tag_end(&mut self, tag: Tag) -> Result<()> {
-        match tag {
-            Tag::Heading { .. } => self.handle_heading_end()?,
-            Tag::Paragraph => self.handle_paragraph_end()?,
-            Tag::Strong => self.set_strong_emphasis(false),
-            Tag::Emphasis => self.set_italic_emphasis(false),
-            Tag::Link { .. } => self.handle_link_end()?,
-            Tag::List(_) => self.handle_list_end(),
-            Tag::Item => self.handle_list_item_end()?,
-            Tag::CodeBlock(_) => self.print_output(OutputType::CodeBlock)?,
-            Tag::Table(_) => self.handle_table_end(),
-            Tag::TableHead => self.handle_table_head_end()?,
-            Tag::TableRow => self.handle_table_row_end()?,
-            Tag::BlockQuote(_) => self.handle_block_quote_end()?,
-            Tag::Image { .. } => self.handle_image_end()?,
-            _ => {}
-        }
-        Ok(())
+    /// Process content events by converting them to an [`ElementKind`] and
+    /// offering it to the handler chain.
+    pub(super) fn handle_content(&mut self, content: ContentType) -> Result<()> {
+        let kind = match content {
+            ContentType::Text(text) => ElementKind::Text(text.to_string()),
+            ContentType::Code(code) => ElementKind::Code(code.to_string()),
+            ContentType::Html(html) => ElementKind::Html(html.to_string()),
+            ContentType::SoftBreak => ElementKind::SoftBreak,
+            ContentType::HardBreak => ElementKind::HardBreak,
+            ContentType::Rule => ElementKind::Rule,
+            ContentType::TaskMarker(checked) => ElementKind::TaskMarker(checked),
+        };
+        self.dispatch_start(&kind)
     }
 
-    // Helper methods for handling specific tag types
+    // Helper methods for handling specific tag types. These remain inherent
+    // methods on MarkdownRenderer so both the handler chain (via
+    // TerminalHandler, below) and any code calling them directly share one
+    // implementation.
     fn handle_heading_start(&mut self, level: u8) -> Result<()> {
         self.print_output(OutputType::Heading {
             level,
@@ -165,39 +492,40 @@ impl MarkdownRenderer {
         self.print_output(OutputType::ListItem { is_end: true })
     }
 
-    fn handle_code_block_start(&mut self, kind: pulldown_cmark::CodeBlockKind) {
-        // Convert borrowed language string to owned for state storage.
-        // Required because state outlives the parsing event lifetime
-        let static_kind = match kind {
-            pulldown_cmark::CodeBlockKind::Indented => pulldown_cmark::CodeBlockKind::Indented,
-            pulldown_cmark::CodeBlockKind::Fenced(lang) => {
-                pulldown_cmark::CodeBlockKind::Fenced(lang.to_string().into())
-            }
-        };
-        self.set_code_block(static_kind);
-    }
-
     fn handle_table_head_start(&mut self) -> Result<()> {
-        self.print_output(OutputType::Table {
-            variant: TableVariant::HeadStart,
-        })
+        if let Some(table) = self.get_table_mut() {
+            table.is_header = true;
+        }
+        Ok(())
     }
 
+    /// Buffers the collected header row rather than printing it, since the
+    /// table as a whole isn't laid out until every row is known.
     fn handle_table_head_end(&mut self) -> Result<()> {
-        self.print_output(OutputType::Table {
-            variant: TableVariant::HeadEnd,
-        })
+        if let Some(table) = self.get_table_mut() {
+            table.header = Some(std::mem::take(&mut table.current_row));
+            table.is_header = false;
+        }
+        Ok(())
     }
 
+    /// Buffers the collected body row rather than printing it; see
+    /// `handle_table_head_end`.
     fn handle_table_row_end(&mut self) -> Result<()> {
-        self.print_output(OutputType::Table {
-            variant: TableVariant::RowEnd,
-        })
+        if let Some(table) = self.get_table_mut() {
+            let row = std::mem::take(&mut table.current_row);
+            table.rows.push(row);
+        }
+        Ok(())
     }
 
-    fn handle_table_end(&mut self) {
+    fn handle_table_end(&mut self) -> Result<()> {
+        if let Some(table) = self.get_table() {
+            self.render_table(&table)?;
+        }
         self.clear_table();
         println!();
+        Ok(())
     }
 
     fn handle_block_quote_start(&mut self) -> Result<()> {
@@ -216,47 +544,430 @@ impl MarkdownRenderer {
         self.print_output(OutputType::Image)
     }
 
-    /// Process content events including text, code, HTML, and breaks.
-    /// Routes content to active elements or renders directly based on state.
-    pub(super) fn handle_content(&mut self, content: ContentType) -> Result<()> {
-        match content {
-            ContentType::Text(text) => {
+    /// Prints an inline `[N]` marker for a footnote reference, assigning the
+    /// label a display number on first sight if this is the first time it's
+    /// been referenced or defined.
+    fn handle_footnote_reference(&mut self, label: &str) -> Result<()> {
+        let number = self.state.footnotes.number_for(label);
+        let marker = self.create_styled_marker(
+            &format!("[{}]", number),
+            self.theme.delimiter_color(),
+            false,
+        );
+        print!("{}", marker);
+        Ok(())
+    }
+
+    /// Prints a delimiter rule followed by a numbered "Notes" section
+    /// listing every footnote seen during rendering, in first-seen order.
+    /// Called once after the document body has finished rendering. A no-op
+    /// when no footnotes were encountered.
+    pub(super) fn render_footnotes_section(&mut self) -> Result<()> {
+        if self.state.footnotes.is_empty() {
+            return Ok(());
+        }
+
+        let rule = self.config.create_horizontal_rule();
+        let styled_rule = self.apply_text_style(&rule, super::styling::TextStyle::Delimiter);
+        println!("\n{}", styled_rule);
+        let heading = self.create_styled_marker("Notes", self.theme.heading_color(2), true);
+        println!("{}", heading);
+        println!();
+
+        for (number, label, body) in self.state.footnotes.entries() {
+            let marker = self.create_styled_marker(
+                &format!("[{}] ", number),
+                self.theme.delimiter_color(),
+                false,
+            );
+            match body {
+                Some(body) => println!("{}{}", marker, self.styled_footnote_text(body)),
+                None => println!(
+                    "{}({} undefined)",
+                    marker,
+                    self.styled_footnote_text(label)
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    /// Styles a footnote's body or (for an undefined reference) its label,
+    /// same as any other inline document text. Split out from
+    /// [`Self::render_footnotes_section`] so it's testable without capturing
+    /// stdout, and so `--plain`/`MP_PLAIN` redaction reaches footnote text
+    /// the same way it reaches everything else.
+    fn styled_footnote_text(&self, text: &str) -> String {
+        self.apply_text_style(text, TextStyle::Normal)
+    }
+
+    /// Prints `toc` as an indented, numbered table of contents, following
+    /// heading hierarchy. Each entry's own numbering restarts among its
+    /// siblings, as in a typical nested document outline.
+    pub(super) fn print_toc(&mut self, toc: &super::toc::Toc) -> Result<()> {
+        if toc.entries.is_empty() {
+            return Ok(());
+        }
+        for (index, entry) in toc.entries.iter().enumerate() {
+            self.print_toc_entry(entry, 0, &(index + 1).to_string())?;
+        }
+        println!();
+        Ok(())
+    }
+
+    fn print_toc_entry(
+        &mut self,
+        entry: &super::toc::TocEntry,
+        depth: usize,
+        number: &str,
+    ) -> Result<()> {
+        let indent = self.config.create_indent(depth);
+        let styled_number = self.create_styled_marker(
+            &format!("{}. ", number),
+            self.theme.list_marker_color(),
+            false,
+        );
+        let styled_title = self.apply_text_style(&entry.title, TextStyle::Normal);
+        println!("{}{}{}", indent, styled_number, styled_title);
+
+        for (index, child) in entry.children.iter().enumerate() {
+            let child_number = format!("{}.{}", number, index + 1);
+            self.print_toc_entry(child, depth + 1, &child_number)?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a parsed tag into the [`ElementKind`] dispatched through the
+/// handler chain. Used for both start and end tags: `process_event`
+/// reconstructs a placeholder `Tag` for end events (mirroring the fields
+/// `TagEnd` actually carries), so the conversion logic only needs to live
+/// in one place.
+fn element_kind_for_tag(tag: &Tag) -> ElementKind {
+    match tag {
+        Tag::Heading { level, .. } => ElementKind::Heading(*level as u8),
+        Tag::Paragraph => ElementKind::Paragraph,
+        Tag::Strong => ElementKind::Strong,
+        Tag::Emphasis => ElementKind::Emphasis,
+        Tag::Strikethrough => ElementKind::Strikethrough,
+        Tag::Link { dest_url, title, .. } => {
+            ElementKind::Link(dest_url.to_string(), title.to_string())
+        }
+        Tag::List(start) => ElementKind::List(*start),
+        Tag::Item => ElementKind::ListItem,
+        Tag::CodeBlock(kind) => ElementKind::CodeBlock(match kind {
+            CodeBlockKind::Indented => None,
+            CodeBlockKind::Fenced(info) => super::lang_string::LangString::parse(info).lang,
+        }),
+        Tag::Table(alignments) => ElementKind::Table(alignments.clone()),
+        Tag::TableHead => ElementKind::TableHead,
+        Tag::TableRow => ElementKind::TableRow,
+        Tag::BlockQuote(_) => ElementKind::BlockQuote,
+        Tag::Image { dest_url, title, .. } => {
+            ElementKind::Image(dest_url.to_string(), title.to_string())
+        }
+        Tag::FootnoteDefinition(label) => ElementKind::FootnoteDefinition(label.to_string()),
+        // Every tag `handle_tag` is ever called with is listed above.
+        _ => ElementKind::Paragraph,
+    }
+}
+
+/// The built-in terminal rendering behavior, packaged as the default
+/// handler that always sits at the end of the chain. A custom [`Handler`]
+/// that returns [`HandlerResult::Handled`] for a given element prevents
+/// this from running for it.
+pub(super) struct TerminalHandler;
+
+impl Handler for TerminalHandler {
+    fn handle_start(&mut self, el: &ElementKind, ctx: &mut RenderCtx) -> Result<HandlerResult> {
+        match el {
+            ElementKind::Heading(level) => ctx.handle_heading_start(*level)?,
+            ElementKind::Paragraph => ctx.handle_paragraph_start()?,
+            ElementKind::Strong => ctx.set_strong_emphasis(true),
+            ElementKind::Emphasis => ctx.set_italic_emphasis(true),
+            ElementKind::Strikethrough => ctx.set_strikethrough(true),
+            ElementKind::Link(dest_url, title) => ctx.set_link(dest_url.clone(), title.clone()),
+            ElementKind::List(start) => ctx.handle_list_start(*start),
+            ElementKind::ListItem => ctx.handle_list_item_start()?,
+            ElementKind::CodeBlock(language) => ctx.set_code_block_language(language.clone()),
+            ElementKind::Table(alignments) => ctx.set_table(alignments.clone()),
+            ElementKind::TableHead => ctx.handle_table_head_start()?,
+            ElementKind::TableRow => {}
+            ElementKind::BlockQuote => ctx.handle_block_quote_start()?,
+            ElementKind::Image(dest_url, title) => ctx.set_image(dest_url.clone(), title.clone()),
+            ElementKind::FootnoteDefinition(label) => ctx.set_footnote(label.clone()),
+            ElementKind::FootnoteReference(label) => ctx.handle_footnote_reference(label)?,
+            ElementKind::Text(text) => {
                 let decoded_text = decode_html_entities(text);
-                if !self.add_text_to_state(&decoded_text) {
-                    self.render_styled_text(&decoded_text);
+                if !ctx.add_text_to_state(&decoded_text) {
+                    ctx.render_styled_text(&decoded_text);
                 }
             }
-            ContentType::Code(code) => {
-                if let Some(ref mut cb) = self.get_code_block_mut() {
+            ElementKind::Code(code) => {
+                if let Some(ref mut cb) = ctx.get_code_block_mut() {
                     cb.content.push_str(code);
                 } else {
-                    self.print_output(OutputType::InlineCode {
-                        code: code.to_string(),
-                    })?;
+                    ctx.print_output(OutputType::InlineCode { code: code.clone() })?;
                 }
             }
-            ContentType::Html(html) => {
+            ElementKind::Html(html) => {
                 let decoded = decode_html_entities(html);
-                if !self.add_text_to_state(&decoded) {
-                    self.render_styled_text(&decoded);
+                if !ctx.add_text_to_state(&decoded) {
+                    ctx.render_styled_text(&decoded);
                 }
             }
-            ContentType::SoftBreak => {
+            ElementKind::SoftBreak => {
                 print!(" ");
+                ctx.state.current_column += 1;
             }
-            ContentType::HardBreak => {
+            ElementKind::HardBreak => {
                 println!();
+                print!("{}", ctx.continuation_prefix());
+                ctx.state.current_column = ctx.continuation_width();
             }
-            ContentType::Rule => {
-                let line = self.config.create_horizontal_rule();
-                let styled_line =
-                    self.apply_text_style(&line, super::styling::TextStyle::Delimiter);
+            ElementKind::Rule => {
+                let line = ctx.config.create_horizontal_rule();
+                let styled_line = ctx.apply_text_style(&line, super::styling::TextStyle::Delimiter);
                 println!("\n{}\n", styled_line);
+                ctx.state.current_column = 0;
             }
-            ContentType::TaskMarker(checked) => {
-                self.print_output(OutputType::TaskMarker { checked })?;
+            ElementKind::TaskMarker(checked) => {
+                ctx.print_output(OutputType::TaskMarker { checked: *checked })?;
             }
         }
-        Ok(())
+        Ok(HandlerResult::Handled)
+    }
+
+    fn handle_end(&mut self, el: &ElementKind, ctx: &mut RenderCtx) -> Result<HandlerResult> {
+        match el {
+            ElementKind::Heading(_) => ctx.handle_heading_end()?,
+            ElementKind::Paragraph => ctx.handle_paragraph_end()?,
+            ElementKind::Strong => ctx.set_strong_emphasis(false),
+            ElementKind::Emphasis => ctx.set_italic_emphasis(false),
+            ElementKind::Strikethrough => ctx.set_strikethrough(false),
+            ElementKind::Link(_, _) => ctx.handle_link_end()?,
+            ElementKind::List(_) => ctx.handle_list_end(),
+            ElementKind::ListItem => ctx.handle_list_item_end()?,
+            ElementKind::CodeBlock(_) => ctx.print_output(OutputType::CodeBlock)?,
+            ElementKind::Table(_) => ctx.handle_table_end()?,
+            ElementKind::TableHead => ctx.handle_table_head_end()?,
+            ElementKind::TableRow => ctx.handle_table_row_end()?,
+            ElementKind::BlockQuote => ctx.handle_block_quote_end()?,
+            ElementKind::Image(_, _) => ctx.handle_image_end()?,
+            ElementKind::FootnoteDefinition(_) => ctx.clear_footnote(),
+            _ => {}
+        }
+        Ok(HandlerResult::Handled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::MarkdownRenderer;
+
+    #[test]
+    fn test_print_output_link_clears_the_active_link() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.set_link("https://example.com".to_string(), String::new());
+        if let Some(link) = renderer.get_link_mut() {
+            link.text = "Example".to_string();
+        }
+        assert!(renderer.print_output(OutputType::Link).is_ok());
+        assert!(!renderer.has_link());
+    }
+
+    #[test]
+    fn test_print_output_image_clears_the_active_image() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.set_image("https://example.com/x.png".to_string(), String::new());
+        assert!(renderer.print_output(OutputType::Image).is_ok());
+        assert!(renderer.get_image().is_none());
+    }
+
+    #[test]
+    fn test_print_output_link_advances_column_for_a_non_empty_title() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.link_titles = true;
+        renderer.set_link("https://example.com".to_string(), "Example Site".to_string());
+        if let Some(link) = renderer.get_link_mut() {
+            link.text = "Example".to_string();
+        }
+        assert!(renderer.print_output(OutputType::Link).is_ok());
+        // "Example" (7) + url (19) + 3 decoration + title (12) + 5 decoration
+        assert_eq!(renderer.state.current_column, 7 + 19 + 3 + 12 + 5);
+    }
+
+    #[test]
+    fn test_print_output_link_suppresses_title_when_disabled() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.link_titles = false;
+        renderer.set_link("https://example.com".to_string(), "Example Site".to_string());
+        if let Some(link) = renderer.get_link_mut() {
+            link.text = "Example".to_string();
+        }
+        assert!(renderer.print_output(OutputType::Link).is_ok());
+        assert_eq!(renderer.state.current_column, 7 + 19 + 3);
+    }
+
+    #[test]
+    fn test_element_kind_for_tag_resolves_reference_style_link_titles() {
+        use pulldown_cmark::{Options, Parser};
+
+        let markdown = "[text][ref]\n\n[ref]: https://example.com \"a title\"\n";
+        let mut title = None;
+        for event in Parser::new_ext(markdown, Options::empty()) {
+            if let Event::Start(tag @ Tag::Link { .. }) = event {
+                if let ElementKind::Link(url, found_title) = element_kind_for_tag(&tag) {
+                    assert_eq!(url, "https://example.com");
+                    title = Some(found_title);
+                }
+            }
+        }
+        assert_eq!(title.as_deref(), Some("a title"));
+    }
+
+    #[test]
+    fn test_print_output_code_block_highlights_known_languages() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.set_code_block(CodeBlockKind::Fenced("rust".into()));
+        if let Some(code) = renderer.get_code_block_mut() {
+            code.content = "let x = 1;".to_string();
+        }
+        assert!(renderer.print_output(OutputType::CodeBlock).is_ok());
+        assert!(renderer.get_code_block().is_none());
+    }
+
+    #[test]
+    fn test_print_output_code_block_with_line_numbers_does_not_error() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.code_line_numbers = true;
+        renderer.set_code_block(CodeBlockKind::Fenced("rust".into()));
+        if let Some(code) = renderer.get_code_block_mut() {
+            code.content = "let x = 1;\nlet y = 2;".to_string();
+        }
+        assert!(renderer.print_output(OutputType::CodeBlock).is_ok());
+    }
+
+    #[test]
+    fn test_print_output_diff_code_block_does_not_error() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.set_code_block(CodeBlockKind::Fenced("diff".into()));
+        if let Some(code) = renderer.get_code_block_mut() {
+            code.content = "+added\n-removed\n context".to_string();
+        }
+        assert!(renderer.print_output(OutputType::CodeBlock).is_ok());
+    }
+
+    #[test]
+    fn test_code_gutter_right_aligns_within_width() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.normalize = true;
+        assert_eq!(renderer.code_gutter(3, 3), "  3 ");
+    }
+
+    #[test]
+    fn test_style_diff_line_is_unchanged_text_under_normalize() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.normalize = true;
+        assert_eq!(renderer.style_diff_line("+added"), "+added");
+        assert_eq!(renderer.style_diff_line("-removed"), "-removed");
+        assert_eq!(renderer.style_diff_line(" context"), " context");
+    }
+
+    #[test]
+    fn test_block_quote_start_and_end_track_nesting_depth() {
+        let mut renderer = MarkdownRenderer::new();
+        assert_eq!(renderer.state.blockquote_depth, 0);
+
+        assert!(
+            renderer
+                .print_output(OutputType::BlockQuote { is_end: false })
+                .is_ok()
+        );
+        assert_eq!(renderer.state.blockquote_depth, 1);
+
+        assert!(
+            renderer
+                .print_output(OutputType::BlockQuote { is_end: false })
+                .is_ok()
+        );
+        assert_eq!(renderer.state.blockquote_depth, 2);
+        assert_eq!(
+            renderer
+                .quote_prefix()
+                .chars()
+                .filter(|c| *c == '│')
+                .count(),
+            2
+        );
+
+        assert!(
+            renderer
+                .print_output(OutputType::BlockQuote { is_end: true })
+                .is_ok()
+        );
+        assert_eq!(renderer.state.blockquote_depth, 1);
+
+        assert!(
+            renderer
+                .print_output(OutputType::BlockQuote { is_end: true })
+                .is_ok()
+        );
+        assert_eq!(renderer.state.blockquote_depth, 0);
+        assert!(renderer.quote_prefix().is_empty());
+    }
+
+    #[test]
+    fn test_list_item_marker_advances_current_column() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.push_list(None);
+        assert!(
+            renderer
+                .print_output(OutputType::ListItem { is_end: false })
+                .is_ok()
+        );
+        // "• " at depth 0 (no extra indent, since the marker replaces it).
+        assert_eq!(renderer.state.current_column, display_width("\u{2022} "));
+    }
+
+    #[test]
+    fn test_continuation_prefix_indents_one_level_per_open_list() {
+        let mut renderer = MarkdownRenderer::new();
+        assert_eq!(renderer.continuation_width(), 0);
+
+        renderer.push_list(None);
+        assert_eq!(renderer.continuation_width(), renderer.config.indent_width);
+    }
+
+    #[test]
+    fn test_styled_footnote_text_redacts_under_normalize() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.set_normalize(true);
+        renderer.register_redaction("/secret/path", "[CWD]");
+        assert_eq!(
+            renderer.styled_footnote_text("see /secret/path/notes.md"),
+            "see [CWD]/notes.md"
+        );
+    }
+
+    #[test]
+    fn test_print_toc_entry_title_is_redacted_under_normalize() {
+        use super::super::toc::TocEntry;
+
+        let mut renderer = MarkdownRenderer::new();
+        renderer.set_normalize(true);
+        renderer.register_redaction("/secret/path", "[CWD]");
+        let entry = TocEntry {
+            level: 1,
+            title: "/secret/path/readme".to_string(),
+            id: "readme".to_string(),
+            children: Vec::new(),
+        };
+        assert_eq!(
+            renderer.apply_text_style(&entry.title, TextStyle::Normal),
+            "[CWD]/readme"
+        );
+        assert!(renderer.print_toc_entry(&entry, 0, "1").is_ok());
     }
 }
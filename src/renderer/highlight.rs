@@ -0,0 +1,416 @@
+//! Syntax highlighting for fenced code blocks.
+//!
+//! Mirrors rustdoc's `html/highlight.rs` in spirit: source text is tokenized
+//! into spans tagged with a coarse semantic [`Style`], and the caller is
+//! responsible for turning those spans into themed output (ANSI colors here,
+//! HTML classes there).
+
+use std::collections::HashSet;
+
+/// Coarse token classification a [`Highlighter`] assigns to a span of source
+/// text. Kept small so any implementation can map onto it without depending
+/// on a specific language grammar's scope names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Keyword,
+    Literal,
+    /// A quoted string literal, kept distinct from [`Style::Literal`]'s
+    /// numeric/boolean constants so a theme can color them differently.
+    String,
+    Comment,
+    Type,
+    /// An identifier immediately followed by `(`, i.e. a function or macro
+    /// call.
+    Function,
+    Normal,
+}
+
+/// Tokenizes source text for a given language into `(Style, text)` spans.
+pub trait Highlighter {
+    /// Highlights `src` as `lang`. Implementations that don't recognize
+    /// `lang` should return the whole source as a single `Style::Normal`
+    /// span so callers can fall back to plain dimmed text.
+    fn highlight(&self, lang: &str, src: &str) -> Vec<(Style, String)>;
+
+    /// Whether this highlighter has any special handling for `lang`, as
+    /// opposed to the `Style::Normal` fallback.
+    fn supports(&self, lang: &str) -> bool;
+
+    /// The name of the color theme backing this highlighter, if it has one
+    /// (only [`SyntectHighlighter`] does). Lets callers confirm which theme
+    /// a fenced code block is actually being highlighted with, e.g. after
+    /// [`super::MarkdownRenderer::set_theme`].
+    fn theme_name(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Small built-in lexer covering keywords, string/numeric literals, and
+/// line comments for a handful of common languages. Good enough to make
+/// fenced code blocks legible without pulling in a full grammar engine.
+pub struct BuiltinHighlighter;
+
+impl BuiltinHighlighter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn keywords_for(lang: &str) -> Option<HashSet<&'static str>> {
+        let words: &[&str] = match lang {
+            "rust" | "rs" => &[
+                "fn", "let", "mut", "pub", "struct", "enum", "impl", "trait", "use", "mod",
+                "match", "if", "else", "for", "while", "loop", "return", "self", "Self", "const",
+                "static", "async", "await", "move", "ref", "where", "as", "in", "dyn",
+            ],
+            "toml" => &["true", "false"],
+            "json" => &["true", "false", "null"],
+            "python" | "py" => &[
+                "def", "class", "import", "from", "return", "if", "elif", "else", "for", "while",
+                "with", "as", "try", "except", "finally", "lambda", "None", "True", "False",
+                "self",
+            ],
+            "javascript" | "js" | "typescript" | "ts" => &[
+                "function", "const", "let", "var", "return", "if", "else", "for", "while", "class",
+                "import", "export", "from", "async", "await", "new", "this", "true", "false",
+                "null", "undefined",
+            ],
+            _ => return None,
+        };
+        Some(words.iter().copied().collect())
+    }
+
+    fn line_comment_prefix(lang: &str) -> Option<&'static str> {
+        match lang {
+            "rust" | "rs" | "javascript" | "js" | "typescript" | "ts" => Some("//"),
+            "toml" | "python" | "py" => Some("#"),
+            _ => None,
+        }
+    }
+}
+
+impl Default for BuiltinHighlighter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Highlighter for BuiltinHighlighter {
+    fn highlight(&self, lang: &str, src: &str) -> Vec<(Style, String)> {
+        let Some(keywords) = Self::keywords_for(lang) else {
+            return vec![(Style::Normal, src.to_string())];
+        };
+        let comment_prefix = Self::line_comment_prefix(lang);
+
+        let mut spans = Vec::new();
+        for (i, line) in src.split_inclusive('\n').enumerate() {
+            if i > 0 {
+                // split_inclusive already keeps the newline on the prior
+                // span, nothing to push here.
+            }
+            tokenize_line(line, &keywords, comment_prefix, &mut spans);
+        }
+        spans
+    }
+
+    fn supports(&self, lang: &str) -> bool {
+        Self::keywords_for(lang).is_some()
+    }
+}
+
+fn tokenize_line(
+    line: &str,
+    keywords: &HashSet<&'static str>,
+    comment_prefix: Option<&str>,
+    spans: &mut Vec<(Style, String)>,
+) {
+    if let Some(prefix) = comment_prefix
+        && let Some(pos) = line.find(prefix)
+    {
+        tokenize_line_no_comments(&line[..pos], keywords, spans);
+        spans.push((Style::Comment, line[pos..].to_string()));
+        return;
+    }
+    tokenize_line_no_comments(line, keywords, spans);
+}
+
+fn tokenize_line_no_comments(
+    line: &str,
+    keywords: &HashSet<&'static str>,
+    spans: &mut Vec<(Style, String)>,
+) {
+    let mut chars = line.char_indices().peekable();
+    let mut word_start = None;
+
+    let flush_word = |start: usize,
+                      end: usize,
+                      next_char: Option<char>,
+                      line: &str,
+                      spans: &mut Vec<(Style, String)>| {
+        if start == end {
+            return;
+        }
+        let word = &line[start..end];
+        let style = if keywords.contains(word) {
+            Style::Keyword
+        } else if word.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+            Style::Literal
+        } else if next_char == Some('(') {
+            Style::Function
+        } else {
+            Style::Normal
+        };
+        spans.push((style, word.to_string()));
+    };
+
+    while let Some(&(idx, ch)) = chars.peek() {
+        if ch == '"' || ch == '\'' {
+            if let Some(start) = word_start.take() {
+                flush_word(start, idx, Some(ch), line, spans);
+            }
+            let quote = ch;
+            let str_start = idx;
+            chars.next();
+            let mut end = line.len();
+            while let Some(&(i, c)) = chars.peek() {
+                chars.next();
+                if c == quote {
+                    end = i + c.len_utf8();
+                    break;
+                }
+            }
+            spans.push((Style::String, line[str_start..end].to_string()));
+            continue;
+        }
+
+        if ch.is_alphanumeric() || ch == '_' {
+            if word_start.is_none() {
+                word_start = Some(idx);
+            }
+            chars.next();
+        } else {
+            if let Some(start) = word_start.take() {
+                flush_word(start, idx, Some(ch), line, spans);
+            }
+            chars.next();
+            spans.push((Style::Normal, ch.to_string()));
+        }
+    }
+    if let Some(start) = word_start.take() {
+        flush_word(start, line.len(), None, line, spans);
+    }
+}
+
+/// Token-accurate highlighting backed by `syntect`'s bundled TextMate
+/// grammars and themes, for richer results than [`BuiltinHighlighter`]'s
+/// keyword list wherever syntect ships a grammar for the fence's language.
+///
+/// `syntect::easy::HighlightLines` resolves each token straight to a theme
+/// color rather than exposing the underlying scope name, so — in the same
+/// spirit as [`Style`] staying grammar-agnostic — tokens are bucketed by
+/// font style (bold/italic/underline, which themes apply fairly
+/// consistently to keywords/comments/types) and by whether a token's color
+/// differs from the theme's plain text color, rather than by scope name.
+pub struct SyntectHighlighter {
+    syntax_set: syntect::parsing::SyntaxSet,
+    theme_set: syntect::highlighting::ThemeSet,
+    theme_name: String,
+}
+
+impl SyntectHighlighter {
+    /// Loads syntect's bundled grammars and themes, highlighting with the
+    /// `base16-ocean.dark` theme.
+    pub fn new() -> Self {
+        Self::with_theme("base16-ocean.dark")
+    }
+
+    /// Like [`Self::new`], but highlighting with the named theme instead
+    /// (one of the names syntect's `ThemeSet::load_defaults` bundles).
+    pub fn with_theme(theme_name: impl Into<String>) -> Self {
+        Self {
+            syntax_set: syntect::parsing::SyntaxSet::load_defaults_newlines(),
+            theme_set: syntect::highlighting::ThemeSet::load_defaults(),
+            theme_name: theme_name.into(),
+        }
+    }
+}
+
+impl Default for SyntectHighlighter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Highlighter for SyntectHighlighter {
+    fn highlight(&self, lang: &str, src: &str) -> Vec<(Style, String)> {
+        let (Some(syntax), Some(theme)) = (
+            self.resolve_syntax(lang, src),
+            self.theme_set.themes.get(&self.theme_name),
+        ) else {
+            return vec![(Style::Normal, src.to_string())];
+        };
+
+        let default_foreground = theme
+            .settings
+            .foreground
+            .unwrap_or(syntect::highlighting::Color::WHITE);
+        let mut highlighter = syntect::easy::HighlightLines::new(syntax, theme);
+
+        let mut spans = Vec::new();
+        for line in syntect::util::LinesWithEndings::from(src) {
+            match highlighter.highlight_line(line, &self.syntax_set) {
+                Ok(ranges) => {
+                    for (style, text) in ranges {
+                        spans.push((classify_span(style, default_foreground), text.to_string()));
+                    }
+                }
+                Err(_) => spans.push((Style::Normal, line.to_string())),
+            }
+        }
+        spans
+    }
+
+    fn supports(&self, lang: &str) -> bool {
+        self.syntax_set.find_syntax_by_token(lang).is_some()
+    }
+
+    fn theme_name(&self) -> Option<&str> {
+        Some(&self.theme_name)
+    }
+}
+
+impl SyntectHighlighter {
+    /// Resolves a [`syntect::parsing::SyntaxReference`] for `lang`, falling
+    /// back to sniffing `src`'s first line (e.g. a `#!/usr/bin/env python3`
+    /// shebang) when `lang` doesn't name a grammar syntect ships directly —
+    /// covers fences tagged with a generic or unrecognized info string like
+    /// ` ```shell ` over a script that's actually bash.
+    fn resolve_syntax(&self, lang: &str, src: &str) -> Option<&syntect::parsing::SyntaxReference> {
+        self.syntax_set
+            .find_syntax_by_token(lang)
+            .or_else(|| self.syntax_set.find_syntax_by_first_line(src))
+    }
+}
+
+fn classify_span(
+    style: syntect::highlighting::Style,
+    default_foreground: syntect::highlighting::Color,
+) -> Style {
+    use syntect::highlighting::FontStyle;
+
+    if style.font_style.contains(FontStyle::ITALIC) {
+        Style::Comment
+    } else if style.font_style.contains(FontStyle::BOLD) {
+        Style::Keyword
+    } else if style.font_style.contains(FontStyle::UNDERLINE) {
+        Style::Type
+    } else if style.foreground != default_foreground {
+        Style::Literal
+    } else {
+        Style::Normal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_language_falls_back_to_a_single_normal_span() {
+        let highlighter = BuiltinHighlighter::new();
+        let spans = highlighter.highlight("not-a-real-language", "whatever");
+        assert_eq!(spans, vec![(Style::Normal, "whatever".to_string())]);
+    }
+
+    #[test]
+    fn test_supports_reports_known_languages_only() {
+        let highlighter = BuiltinHighlighter::new();
+        assert!(highlighter.supports("rust"));
+        assert!(!highlighter.supports("not-a-real-language"));
+    }
+
+    #[test]
+    fn test_rust_keyword_is_tagged() {
+        let highlighter = BuiltinHighlighter::new();
+        let spans = highlighter.highlight("rust", "let x");
+        assert!(
+            spans
+                .iter()
+                .any(|(style, text)| *style == Style::Keyword && text == "let")
+        );
+    }
+
+    #[test]
+    fn test_string_literal_is_tagged() {
+        let highlighter = BuiltinHighlighter::new();
+        let spans = highlighter.highlight("rust", "let s = \"hi\";");
+        assert!(
+            spans
+                .iter()
+                .any(|(style, text)| *style == Style::String && text == "\"hi\"")
+        );
+    }
+
+    #[test]
+    fn test_function_call_is_tagged() {
+        let highlighter = BuiltinHighlighter::new();
+        let spans = highlighter.highlight("rust", "print(x)");
+        assert!(
+            spans
+                .iter()
+                .any(|(style, text)| *style == Style::Function && text == "print")
+        );
+    }
+
+    #[test]
+    fn test_line_comment_is_tagged() {
+        let highlighter = BuiltinHighlighter::new();
+        let spans = highlighter.highlight("rust", "let x = 1; // note");
+        assert!(
+            spans
+                .iter()
+                .any(|(style, text)| *style == Style::Comment && text == "// note")
+        );
+    }
+
+    #[test]
+    fn test_syntect_highlighter_supports_a_grammar_it_ships() {
+        let highlighter = SyntectHighlighter::new();
+        assert!(highlighter.supports("rust"));
+        assert!(!highlighter.supports("not-a-real-language"));
+    }
+
+    #[test]
+    fn test_builtin_highlighter_reports_no_theme() {
+        assert_eq!(BuiltinHighlighter::new().theme_name(), None);
+    }
+
+    #[test]
+    fn test_syntect_highlighter_reports_its_theme_name() {
+        let highlighter = SyntectHighlighter::with_theme("base16-mocha.dark");
+        assert_eq!(highlighter.theme_name(), Some("base16-mocha.dark"));
+    }
+
+    #[test]
+    fn test_syntect_highlighter_falls_back_to_a_single_span_for_unknown_languages() {
+        let highlighter = SyntectHighlighter::new();
+        let spans = highlighter.highlight("not-a-real-language", "whatever");
+        assert_eq!(spans, vec![(Style::Normal, "whatever".to_string())]);
+    }
+
+    #[test]
+    fn test_syntect_highlighter_falls_back_to_first_line_heuristics_for_an_unknown_token() {
+        let highlighter = SyntectHighlighter::new();
+        let src = "#!/usr/bin/env python3\nprint(\"hi\")\n";
+        let spans = highlighter.highlight("not-a-real-language", src);
+        assert!(spans.iter().any(|(style, _)| *style != Style::Normal));
+    }
+
+    #[test]
+    fn test_syntect_highlighter_reassembles_the_source_verbatim() {
+        let highlighter = SyntectHighlighter::new();
+        let src = "fn main() {\n    let x = 1; // note\n}\n";
+        let spans = highlighter.highlight("rust", src);
+        let reassembled: String = spans.iter().map(|(_, text)| text.as_str()).collect();
+        assert_eq!(reassembled, src);
+    }
+}
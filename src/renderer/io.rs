@@ -1,11 +1,18 @@
 use anyhow::{Context, Result};
+use encoding_rs::Encoding;
 use std::fs::{self, File};
 use std::io::Read;
 use std::path::Path;
 
 use crate::utils::normalize_line_endings;
 
-pub fn read_file(path: &Path) -> Result<String> {
+/// Reads `path` and decodes it to UTF-8, detecting its encoding the way a
+/// browser would: a leading byte-order mark (`EF BB BF`, `FF FE`, or
+/// `FE FF`) always wins and is stripped; otherwise `encoding` is assumed
+/// (defaulting to UTF-8 when `None`, see [`crate::renderer::config::RenderConfig::encoding_override`]).
+/// Malformed sequences are replaced with U+FFFD rather than failing the
+/// read, so files saved in an unexpected encoding still preview.
+pub fn read_file(path: &Path, encoding: Option<&'static Encoding>) -> Result<String> {
     if !path.exists() {
         return Err(anyhow::anyhow!("File not found: {}", path.display()));
     }
@@ -17,9 +24,68 @@ pub fn read_file(path: &Path) -> Result<String> {
     let mut file =
         File::open(path).with_context(|| format!("Failed to open file: {}", path.display()))?;
 
-    let mut content = String::with_capacity(file_size as usize);
-    file.read_to_string(&mut content)
+    let mut bytes = Vec::with_capacity(file_size as usize);
+    file.read_to_end(&mut bytes)
         .with_context(|| format!("Failed to read file: {}", path.display()))?;
 
+    let (content, _used_encoding, _had_malformed_sequences) =
+        encoding.unwrap_or(encoding_rs::UTF_8).decode(&bytes);
+
     Ok(normalize_line_endings(&content).into_owned())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_temp_file(bytes: &[u8]) -> (TempDir, std::path::PathBuf) {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("test.md");
+        fs::write(&path, bytes).unwrap();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_read_file_decodes_plain_utf8_with_no_override() {
+        let (_dir, path) = write_temp_file("# café".as_bytes());
+        assert_eq!(read_file(&path, None).unwrap(), "# café");
+    }
+
+    #[test]
+    fn test_read_file_strips_utf8_bom() {
+        let mut bytes = vec![0xEF, 0xBB, 0xBF];
+        bytes.extend_from_slice("# heading".as_bytes());
+        let (_dir, path) = write_temp_file(&bytes);
+        assert_eq!(read_file(&path, None).unwrap(), "# heading");
+    }
+
+    #[test]
+    fn test_read_file_decodes_utf16le_bom() {
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in "hi".encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        let (_dir, path) = write_temp_file(&bytes);
+        assert_eq!(read_file(&path, None).unwrap(), "hi");
+    }
+
+    #[test]
+    fn test_read_file_bom_overrides_encoding_argument() {
+        let mut bytes = vec![0xEF, 0xBB, 0xBF];
+        bytes.extend_from_slice("bom wins".as_bytes());
+        let (_dir, path) = write_temp_file(&bytes);
+        assert_eq!(
+            read_file(&path, Some(encoding_rs::WINDOWS_1252)).unwrap(),
+            "bom wins"
+        );
+    }
+
+    #[test]
+    fn test_read_file_falls_back_to_malformed_replacement_instead_of_erroring() {
+        // Lone 0xFF is invalid UTF-8; should become U+FFFD rather than failing.
+        let (_dir, path) = write_temp_file(&[b'a', 0xFF, b'b']);
+        assert_eq!(read_file(&path, None).unwrap(), "a\u{FFFD}b");
+    }
+}
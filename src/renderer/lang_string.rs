@@ -0,0 +1,110 @@
+//! Parses a fenced code block's info string — the text after the opening
+//! fence, e.g. `rust,ignore` — modeled on rustdoc's `LangString`
+//! (`html/markdown.rs`). The info string carries more than a syntax-highlight
+//! hint: reserved tokens flag doctest behavior, which a future runnable mode
+//! can use to pick candidate doctests; today only [`LangString::lang`] feeds
+//! the renderer's [`super::Highlighter`].
+
+/// The parsed form of a fenced code block's info string. The first token is
+/// taken as the highlight language unless it's one of the reserved flags
+/// below, in which case `lang` is `None`. Every other token is checked
+/// against the same flag set; anything left over is preserved in
+/// [`LangString::unknown`] rather than discarded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LangString {
+    pub lang: Option<String>,
+    pub ignore: bool,
+    pub no_run: bool,
+    pub should_panic: bool,
+    pub compile_fail: bool,
+    pub edition: Option<u16>,
+    pub unknown: Vec<String>,
+}
+
+impl LangString {
+    /// Parses `info`, e.g. `"rust,ignore"` or `"{.rust}"`.
+    pub fn parse(info: &str) -> Self {
+        let mut result = Self::default();
+        for (i, token) in tokenize(info).enumerate() {
+            match token {
+                "ignore" => result.ignore = true,
+                "no_run" => result.no_run = true,
+                "should_panic" => result.should_panic = true,
+                "compile_fail" => result.compile_fail = true,
+                token if token.starts_with("edition") => {
+                    result.edition = token["edition".len()..].parse().ok();
+                }
+                token if i == 0 => result.lang = Some(token.to_string()),
+                token => result.unknown.push(token.to_string()),
+            }
+        }
+        result
+    }
+}
+
+/// Splits an info string on commas/whitespace, tolerating the `{.rust}`
+/// curly-brace form some Markdown flavors use, and strips each token's
+/// leading `.` (the class-selector dot in that same form).
+fn tokenize(info: &str) -> impl Iterator<Item = &str> {
+    info.trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .map(|token| token.trim_start_matches('.'))
+        .filter(|token| !token.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_language() {
+        let lang = LangString::parse("sh");
+        assert_eq!(lang.lang.as_deref(), Some("sh"));
+        assert!(!lang.ignore);
+    }
+
+    #[test]
+    fn test_parse_language_with_ignore_flag() {
+        let lang = LangString::parse("rust,ignore");
+        assert_eq!(lang.lang.as_deref(), Some("rust"));
+        assert!(lang.ignore);
+    }
+
+    #[test]
+    fn test_parse_curly_brace_class_form() {
+        let lang = LangString::parse("{.rust}");
+        assert_eq!(lang.lang.as_deref(), Some("rust"));
+    }
+
+    #[test]
+    fn test_parse_unrecognized_leading_token_becomes_the_language() {
+        let lang = LangString::parse("ignore-foo");
+        assert_eq!(lang.lang.as_deref(), Some("ignore-foo"));
+        assert!(!lang.ignore);
+    }
+
+    #[test]
+    fn test_parse_recognizes_every_reserved_flag_and_the_edition() {
+        let lang = LangString::parse("rust,no_run,should_panic,compile_fail,edition2021");
+        assert_eq!(lang.lang.as_deref(), Some("rust"));
+        assert!(lang.no_run);
+        assert!(lang.should_panic);
+        assert!(lang.compile_fail);
+        assert_eq!(lang.edition, Some(2021));
+    }
+
+    #[test]
+    fn test_parse_preserves_unknown_trailing_tokens() {
+        let lang = LangString::parse("rust,editable");
+        assert_eq!(lang.lang.as_deref(), Some("rust"));
+        assert_eq!(lang.unknown, vec!["editable".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_empty_info_string_has_no_language() {
+        let lang = LangString::parse("");
+        assert_eq!(lang, LangString::default());
+    }
+}
@@ -0,0 +1,146 @@
+//! Bounds total rendered output size, modeled on rustdoc's
+//! `html/length_limit.rs`: a running budget of display characters is spent
+//! as body content is emitted, and once an element would overrun it,
+//! rendering stops rather than cutting the element in half. The limit is
+//! enforced in the handler chain (see `handler.rs`), so it always runs
+//! before the built-in terminal handler and sees every element first,
+//! including ones a custom handler would otherwise render.
+
+use anyhow::Result;
+
+use super::handler::{ElementKind, Handler, HandlerResult, RenderCtx};
+
+/// Caps total rendered body size at `RenderConfig::max_output_length`
+/// display characters. Once the budget set there is exhausted, any
+/// still-open list levels, an in-progress table, and any active emphasis
+/// or link/image are cleanly closed before a truncation marker is printed,
+/// and every subsequent element is silently dropped for the rest of the
+/// render.
+#[derive(Default)]
+pub(super) struct LengthLimitHandler {
+    /// Characters still available before truncation. `None` until the
+    /// configured limit has been read for the first time, after which it
+    /// either holds the remaining budget or stays `None` because no limit
+    /// was configured.
+    remaining: Option<usize>,
+    limit_read: bool,
+    truncated: bool,
+}
+
+impl Handler for LengthLimitHandler {
+    fn handle_start(&mut self, el: &ElementKind, ctx: &mut RenderCtx) -> Result<HandlerResult> {
+        if self.truncated {
+            return Ok(HandlerResult::Handled);
+        }
+
+        if !self.limit_read {
+            self.limit_read = true;
+            self.remaining = ctx.config.max_output_length;
+        }
+
+        let Some(budget) = self.remaining else {
+            return Ok(HandlerResult::Pass);
+        };
+
+        let content_len = element_display_len(el);
+        if content_len <= budget {
+            self.remaining = Some(budget - content_len);
+            return Ok(HandlerResult::Pass);
+        }
+
+        self.truncated = true;
+        close_open_elements(ctx);
+        print_truncation_marker(ctx);
+        Ok(HandlerResult::Handled)
+    }
+
+    fn handle_end(&mut self, _el: &ElementKind, _ctx: &mut RenderCtx) -> Result<HandlerResult> {
+        if self.truncated {
+            return Ok(HandlerResult::Handled);
+        }
+        Ok(HandlerResult::Pass)
+    }
+}
+
+/// The number of display characters `el` would add to the output. Only the
+/// content-bearing element kinds count against the budget; tags themselves
+/// contribute no visible text of their own.
+fn element_display_len(el: &ElementKind) -> usize {
+    match el {
+        ElementKind::Text(text) | ElementKind::Code(text) | ElementKind::Html(text) => {
+            text.chars().count()
+        }
+        _ => 0,
+    }
+}
+
+/// Leaves the terminal in a sane styling state before truncation: closes
+/// every open list level, renders (and clears) an in-progress table so its
+/// closing border still gets printed, and drops any active emphasis or
+/// link/image so nothing is left dangling mid-element.
+fn close_open_elements(ctx: &mut RenderCtx) {
+    while !ctx.state.list_stack.is_empty() {
+        ctx.pop_list();
+    }
+    if let Some(table) = ctx.get_table() {
+        let _ = ctx.render_table(&table);
+    }
+    ctx.clear_active_element();
+    ctx.set_strong_emphasis(false);
+    ctx.set_italic_emphasis(false);
+}
+
+fn print_truncation_marker(ctx: &RenderCtx) {
+    println!();
+    let marker = ctx.create_styled_marker("[output truncated]", ctx.theme.delimiter_color(), true);
+    println!("{}", marker);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::MarkdownRenderer;
+
+    #[test]
+    fn test_handle_start_passes_through_when_no_limit_configured() {
+        let mut renderer = MarkdownRenderer::new();
+        let mut ctx = RenderCtx {
+            renderer: &mut renderer,
+        };
+        let mut handler = LengthLimitHandler::default();
+        let result = handler.handle_start(&ElementKind::Text("hello".to_string()), &mut ctx);
+        assert_eq!(result.unwrap(), HandlerResult::Pass);
+    }
+
+    #[test]
+    fn test_handle_start_truncates_once_the_budget_is_exceeded() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.max_output_length = Some(3);
+        let mut ctx = RenderCtx {
+            renderer: &mut renderer,
+        };
+        let mut handler = LengthLimitHandler::default();
+
+        let first = handler.handle_start(&ElementKind::Text("ab".to_string()), &mut ctx);
+        assert_eq!(first.unwrap(), HandlerResult::Pass);
+
+        let second = handler.handle_start(&ElementKind::Text("cdef".to_string()), &mut ctx);
+        assert_eq!(second.unwrap(), HandlerResult::Handled);
+
+        // Every element after truncation is dropped, including `handle_end`.
+        let third = handler.handle_start(&ElementKind::Text("g".to_string()), &mut ctx);
+        assert_eq!(third.unwrap(), HandlerResult::Handled);
+        let end = handler.handle_end(&ElementKind::Paragraph, &mut ctx);
+        assert_eq!(end.unwrap(), HandlerResult::Handled);
+    }
+
+    #[test]
+    fn test_element_display_len_counts_multibyte_text_as_whole_characters() {
+        // Budgeting by `chars().count()` rather than byte length means a
+        // multibyte character is never split mid-codepoint when the limit
+        // is hit, unlike a byte-count budget would risk.
+        let len = element_display_len(&ElementKind::Text("héllo".to_string()));
+        assert_eq!(len, 5);
+        assert_ne!(len, "héllo".len());
+    }
+}
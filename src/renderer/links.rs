@@ -0,0 +1,181 @@
+//! Collected, resolved link index for a rendered document, in the spirit of
+//! the Zed preview's internal-link resolution: every link discovered while
+//! walking the [`ParsedDocument`] tree is classified as external, an
+//! intra-document anchor, or a path relative to the file being rendered, so
+//! a terminal front-end can make links clickable and warn on broken ones
+//! without re-parsing the content itself.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use super::document::{InlineSpan, ParsedDocument, ParsedElement};
+
+/// Whether a [`ResolvedLink`]'s target could be confirmed to point
+/// somewhere real.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkValidity {
+    /// An absolute URL (`https://...`, `mailto:...`) — left for the
+    /// terminal/browser to resolve, not checked here.
+    External,
+    /// An intra-document `#anchor` link whose fragment matches a heading
+    /// id in the document's table of contents.
+    Valid,
+    /// An intra-document `#anchor` link with no matching heading, or a
+    /// relative path that doesn't exist on disk.
+    Broken,
+    /// A relative path link with no base path to resolve it against, so
+    /// its validity couldn't be determined.
+    Unknown,
+}
+
+/// A single link discovered in the document, with its raw Markdown target
+/// resolved against the file's location (if known) and classified by
+/// [`LinkValidity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedLink {
+    /// The link's visible text.
+    pub text: String,
+    /// The target exactly as written in the Markdown source.
+    pub target: String,
+    /// `target` rewritten to an absolute path for relative links, or
+    /// `target` unchanged for anchors and external URLs.
+    pub resolved: String,
+    pub validity: LinkValidity,
+}
+
+/// Walks `doc` collecting every link, resolving relative targets against
+/// `base_path` (the file `doc` was parsed from, if any) and checking
+/// `#anchor` targets against `anchors` (the document's heading ids, see
+/// [`super::toc::Toc::anchor_ids`]).
+pub fn collect_links(
+    doc: &ParsedDocument,
+    base_path: Option<&Path>,
+    anchors: &HashSet<String>,
+) -> Vec<ResolvedLink> {
+    let mut links = Vec::new();
+    collect_from_elements(&doc.elements, base_path, anchors, &mut links);
+    links
+}
+
+fn collect_from_elements(
+    elements: &[ParsedElement],
+    base_path: Option<&Path>,
+    anchors: &HashSet<String>,
+    links: &mut Vec<ResolvedLink>,
+) {
+    for element in elements {
+        match element {
+            ParsedElement::Paragraph(spans) => {
+                for span in spans {
+                    if let Some(link) = resolve_span(span, base_path, anchors) {
+                        links.push(link);
+                    }
+                }
+            }
+            ParsedElement::List { items, .. } => {
+                for item in items {
+                    collect_from_elements(item, base_path, anchors, links);
+                }
+            }
+            ParsedElement::BlockQuote(children) => {
+                collect_from_elements(children, base_path, anchors, links);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn resolve_span(
+    span: &InlineSpan,
+    base_path: Option<&Path>,
+    anchors: &HashSet<String>,
+) -> Option<ResolvedLink> {
+    let (text, target) = match span {
+        InlineSpan::Link { text, url } => (text.clone(), url.clone()),
+        InlineSpan::Image { alt, url } => (alt.clone(), url.clone()),
+        _ => return None,
+    };
+
+    let (resolved, validity) = if let Some(anchor) = target.strip_prefix('#') {
+        let validity = if anchors.contains(anchor) {
+            LinkValidity::Valid
+        } else {
+            LinkValidity::Broken
+        };
+        (target.clone(), validity)
+    } else if is_external(&target) {
+        (target.clone(), LinkValidity::External)
+    } else {
+        resolve_relative(&target, base_path)
+    };
+
+    Some(ResolvedLink {
+        text,
+        target,
+        resolved,
+        validity,
+    })
+}
+
+fn is_external(target: &str) -> bool {
+    target.contains("://") || target.starts_with("mailto:")
+}
+
+fn resolve_relative(target: &str, base_path: Option<&Path>) -> (String, LinkValidity) {
+    let Some(base_path) = base_path else {
+        return (target.to_string(), LinkValidity::Unknown);
+    };
+    let base_dir = base_path.parent().unwrap_or_else(|| Path::new(""));
+    let joined: PathBuf = base_dir.join(target);
+    let validity = if joined.exists() {
+        LinkValidity::Valid
+    } else {
+        LinkValidity::Broken
+    };
+    (joined.to_string_lossy().into_owned(), validity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulldown_cmark::Options;
+
+    #[test]
+    fn test_external_links_are_left_unresolved() {
+        let doc = ParsedDocument::parse("[docs](https://example.com/docs)\n", Options::empty());
+        let links = collect_links(&doc, None, &HashSet::new());
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].resolved, "https://example.com/docs");
+        assert_eq!(links[0].validity, LinkValidity::External);
+    }
+
+    #[test]
+    fn test_anchor_links_are_checked_against_the_heading_table() {
+        let doc = ParsedDocument::parse(
+            "[see](#section) and [missing](#nowhere)\n",
+            Options::empty(),
+        );
+        let mut anchors = HashSet::new();
+        anchors.insert("section".to_string());
+        let links = collect_links(&doc, None, &anchors);
+
+        assert_eq!(links[0].validity, LinkValidity::Valid);
+        assert_eq!(links[1].validity, LinkValidity::Broken);
+    }
+
+    #[test]
+    fn test_relative_links_resolve_against_the_base_path() {
+        let doc = ParsedDocument::parse("[see](./docs.md)\n", Options::empty());
+        let base_path = Path::new("/project/README.md");
+        let links = collect_links(&doc, Some(base_path), &HashSet::new());
+
+        assert_eq!(links[0].resolved, "/project/./docs.md");
+    }
+
+    #[test]
+    fn test_relative_links_without_a_base_path_are_unknown() {
+        let doc = ParsedDocument::parse("[see](./docs.md)\n", Options::empty());
+        let links = collect_links(&doc, None, &HashSet::new());
+        assert_eq!(links[0].validity, LinkValidity::Unknown);
+    }
+}
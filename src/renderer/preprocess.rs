@@ -0,0 +1,275 @@
+//! A pluggable transform stage that runs over the parsed document tree
+//! before it's rendered, in the spirit of mdbook's preprocessors. Each
+//! [`Preprocessor`] sees the output of the one before it, so effects like
+//! heading numbering and admonition rewriting compose in whatever order
+//! they're registered via [`super::MarkdownRenderer::add_preprocessor`].
+
+use anyhow::Result;
+
+use super::document::{InlineSpan, ParsedDocument, ParsedElement};
+
+pub trait Preprocessor {
+    fn run(&self, doc: ParsedDocument) -> Result<ParsedDocument>;
+}
+
+/// Prefixes every heading with a `1.2.3`-style section counter, numbering
+/// in document order and nesting under the counter of the nearest
+/// shallower heading — the same depth-based nesting `TocBuilder` uses.
+#[derive(Debug, Default)]
+pub struct HeadingNumberer;
+
+impl Preprocessor for HeadingNumberer {
+    fn run(&self, mut doc: ParsedDocument) -> Result<ParsedDocument> {
+        let mut counters = Vec::new();
+        number_headings(&mut doc.elements, &mut counters);
+        Ok(doc)
+    }
+}
+
+fn number_headings(elements: &mut [ParsedElement], counters: &mut Vec<usize>) {
+    for element in elements.iter_mut() {
+        match element {
+            ParsedElement::Heading { level, text } => {
+                let depth = *level as usize;
+                counters.truncate(depth);
+                while counters.len() < depth {
+                    counters.push(0);
+                }
+                counters[depth - 1] += 1;
+                let number = counters
+                    .iter()
+                    .map(usize::to_string)
+                    .collect::<Vec<_>>()
+                    .join(".");
+                *text = format!("{} {}", number, text);
+            }
+            ParsedElement::List { items, .. } => {
+                for item in items.iter_mut() {
+                    number_headings(item, counters);
+                }
+            }
+            ParsedElement::BlockQuote(children) => number_headings(children, counters),
+            _ => {}
+        }
+    }
+}
+
+/// Rewrites GitHub-style alert blockquotes (`> [!NOTE]`, `> [!WARNING]`)
+/// into callouts: the marker is dropped and the alert kind becomes a bold
+/// label at the head of the blockquote's first paragraph, so it picks up
+/// the renderer's normal strong-emphasis styling rather than hardcoding a
+/// color here.
+#[derive(Debug, Default)]
+pub struct AdmonitionPreprocessor;
+
+impl Preprocessor for AdmonitionPreprocessor {
+    fn run(&self, mut doc: ParsedDocument) -> Result<ParsedDocument> {
+        rewrite_admonitions(&mut doc.elements);
+        Ok(doc)
+    }
+}
+
+fn rewrite_admonitions(elements: &mut [ParsedElement]) {
+    for element in elements.iter_mut() {
+        match element {
+            ParsedElement::BlockQuote(children) => {
+                rewrite_admonition_blockquote(children);
+                rewrite_admonitions(children);
+            }
+            ParsedElement::List { items, .. } => {
+                for item in items.iter_mut() {
+                    rewrite_admonitions(item);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn rewrite_admonition_blockquote(children: &mut [ParsedElement]) {
+    let Some(ParsedElement::Paragraph(spans)) = children.first_mut() else {
+        return;
+    };
+    let Some(InlineSpan::Text(first)) = spans.first() else {
+        return;
+    };
+
+    let (label, marker_len) = if first.starts_with("[!NOTE]") {
+        ("Note", "[!NOTE]".len())
+    } else if first.starts_with("[!WARNING]") {
+        ("Warning", "[!WARNING]".len())
+    } else {
+        return;
+    };
+
+    let Some(InlineSpan::Text(first)) = spans.first_mut() else {
+        unreachable!("matched a Text span above")
+    };
+    let rest = first[marker_len..].trim_start().to_string();
+    if rest.is_empty() {
+        spans.remove(0);
+    } else {
+        *first = rest;
+    }
+    spans.insert(0, InlineSpan::Text(" ".to_string()));
+    spans.insert(0, InlineSpan::Strong(label.to_string()));
+}
+
+/// Turns bare `http://`/`https://` URLs appearing in plain text runs into
+/// proper [`InlineSpan::Link`]s, so pasting a raw link into a paragraph
+/// still renders (and hyperlinks) like a Markdown-authored one.
+#[derive(Debug, Default)]
+pub struct AutoLinkPreprocessor;
+
+impl Preprocessor for AutoLinkPreprocessor {
+    fn run(&self, mut doc: ParsedDocument) -> Result<ParsedDocument> {
+        autolink_elements(&mut doc.elements);
+        Ok(doc)
+    }
+}
+
+fn autolink_elements(elements: &mut [ParsedElement]) {
+    for element in elements.iter_mut() {
+        match element {
+            ParsedElement::Paragraph(spans) => autolink_spans(spans),
+            ParsedElement::List { items, .. } => {
+                for item in items.iter_mut() {
+                    autolink_elements(item);
+                }
+            }
+            ParsedElement::BlockQuote(children) => autolink_elements(children),
+            _ => {}
+        }
+    }
+}
+
+fn autolink_spans(spans: &mut Vec<InlineSpan>) {
+    let mut rewritten = Vec::with_capacity(spans.len());
+    for span in spans.drain(..) {
+        match span {
+            InlineSpan::Text(text) => rewritten.extend(autolink_text(&text)),
+            other => rewritten.push(other),
+        }
+    }
+    *spans = rewritten;
+}
+
+fn autolink_text(text: &str) -> Vec<InlineSpan> {
+    let mut spans = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest
+        .find("https://")
+        .into_iter()
+        .chain(rest.find("http://"))
+        .min()
+    {
+        if start > 0 {
+            spans.push(InlineSpan::Text(rest[..start].to_string()));
+        }
+        let url_len = rest[start..]
+            .find(char::is_whitespace)
+            .unwrap_or(rest.len() - start);
+        let url = &rest[start..start + url_len];
+        spans.push(InlineSpan::Link {
+            text: url.to_string(),
+            url: url.to_string(),
+        });
+        rest = &rest[start + url_len..];
+    }
+    if !rest.is_empty() {
+        spans.push(InlineSpan::Text(rest.to_string()));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulldown_cmark::Options;
+
+    #[test]
+    fn test_heading_numberer_prefixes_nested_headings() {
+        let doc = ParsedDocument::parse(
+            "# Intro\n\n## Setup\n\n## Usage\n\n# Appendix\n",
+            Options::empty(),
+        );
+        let doc = HeadingNumberer.run(doc).unwrap();
+
+        let titles: Vec<&str> = doc
+            .elements
+            .iter()
+            .filter_map(|element| match element {
+                ParsedElement::Heading { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            titles,
+            vec!["1 Intro", "1.1 Setup", "1.2 Usage", "2 Appendix"]
+        );
+    }
+
+    #[test]
+    fn test_admonition_preprocessor_labels_note_and_leaves_warning_blockquote_alone() {
+        let doc = ParsedDocument::parse(
+            "> [!NOTE]\n> Keep this in mind.\n\n> Just a quote.\n",
+            Options::empty(),
+        );
+        let doc = AdmonitionPreprocessor.run(doc).unwrap();
+
+        let ParsedElement::BlockQuote(children) = &doc.elements[0] else {
+            panic!("expected a blockquote");
+        };
+        let ParsedElement::Paragraph(spans) = &children[0] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(spans[0], InlineSpan::Strong("Note".to_string()));
+        assert!(
+            matches!(&spans[2], InlineSpan::Text(text) if text.starts_with("Keep this in mind"))
+        );
+
+        let ParsedElement::BlockQuote(plain_children) = &doc.elements[1] else {
+            panic!("expected a blockquote");
+        };
+        let ParsedElement::Paragraph(plain_spans) = &plain_children[0] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(
+            plain_spans[0],
+            InlineSpan::Text("Just a quote.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_autolink_preprocessor_turns_a_bare_url_into_a_link_span() {
+        let doc = ParsedDocument::parse("See https://example.com for details.\n", Options::empty());
+        let doc = AutoLinkPreprocessor.run(doc).unwrap();
+
+        let ParsedElement::Paragraph(spans) = &doc.elements[0] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(spans[0], InlineSpan::Text("See ".to_string()));
+        assert_eq!(
+            spans[1],
+            InlineSpan::Link {
+                text: "https://example.com".to_string(),
+                url: "https://example.com".to_string(),
+            }
+        );
+        assert_eq!(spans[2], InlineSpan::Text(" for details.".to_string()));
+    }
+
+    #[test]
+    fn test_autolink_preprocessor_leaves_plain_text_untouched() {
+        let doc = ParsedDocument::parse("Nothing to link here.\n", Options::empty());
+        let doc = AutoLinkPreprocessor.run(doc).unwrap();
+
+        let ParsedElement::Paragraph(spans) = &doc.elements[0] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(
+            spans,
+            &vec![InlineSpan::Text("Nothing to link here.".to_string())]
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use pulldown_cmark::{Alignment, CodeBlockKind};
 use std::collections::HashMap;
 
+use crate::theme::MarkdownTheme;
+
 /// Text content types
 pub enum ContentType<'a> {
     Text(&'a str),
@@ -19,6 +21,7 @@ pub enum ElementType {
     Image,
     StrongEmphasis,
     ItalicEmphasis,
+    Strikethrough,
     CodeBlock,
     Table,
     List,
@@ -55,6 +58,32 @@ pub struct RenderState {
     pub stack: Vec<StateFrame>,
     pub list_stack: Vec<ListType>,
     pub current_line: String,
+    pub emphasis: EmphasisState,
+    pub active_element: Option<ActiveElement>,
+    pub footnotes: crate::renderer::footnotes::FootnoteRegistry,
+    /// Anchor ids assigned to the document's own headings (see
+    /// `TocBuilder::derive_id`), used to tell apart intra-document links
+    /// that resolve from ones that dangle.
+    pub heading_anchors: std::collections::HashSet<String>,
+    /// How many `BlockQuote`s are currently open, incremented on `Start` and
+    /// decremented on `End`. Drives the `"> "`-per-depth prefix that
+    /// `MarkdownRenderer::quote_prefix` prepends to each physical line
+    /// printed while nested inside one or more quotes.
+    pub blockquote_depth: usize,
+    /// Display width of the current physical output line printed so far,
+    /// reset to 0 on every newline and to the relevant prefix width after a
+    /// heading marker, list marker, or block quote marker. Drives the word
+    /// wrapping in `MarkdownRenderer::render_styled_text`.
+    pub current_column: usize,
+    /// The heading outline built for the document most recently rendered
+    /// via `MarkdownRenderer::render_content`, exposed by
+    /// `MarkdownRenderer::table_of_contents`. `None` until a document has
+    /// been rendered.
+    pub toc: Option<crate::renderer::toc::Toc>,
+    /// Every link collected from the document most recently rendered via
+    /// `MarkdownRenderer::render_content`, exposed by
+    /// `MarkdownRenderer::links`.
+    pub links: Vec<crate::renderer::links::ResolvedLink>,
 }
 
 impl RenderState {
@@ -64,6 +93,14 @@ impl RenderState {
             stack: Vec::new(),
             list_stack: Vec::new(),
             current_line: String::new(),
+            emphasis: EmphasisState::default(),
+            active_element: None,
+            footnotes: crate::renderer::footnotes::FootnoteRegistry::default(),
+            heading_anchors: std::collections::HashSet::new(),
+            blockquote_depth: 0,
+            current_column: 0,
+            toc: None,
+            links: Vec::new(),
         }
     }
 
@@ -124,19 +161,21 @@ impl RenderState {
         self.has_element(&ElementType::Link)
     }
 
-    /// Get text color based on current emphasis state
-    pub fn get_text_color(&self) -> (u8, u8, u8) {
+    /// Get text color based on current emphasis state, resolved against
+    /// `theme` instead of a hardcoded palette so callers can swap themes at
+    /// runtime.
+    pub fn get_text_color(&self, theme: &dyn MarkdownTheme) -> (u8, u8, u8) {
         let has_strong = self.has_element(&ElementType::StrongEmphasis);
         let has_italic = self.has_element(&ElementType::ItalicEmphasis);
         let has_link = self.has_link();
 
         match (has_strong, has_italic, has_link) {
-            (true, true, _) => (181, 137, 0),       // Yellow for bold italic
-            (true, false, _) => (203, 75, 22),      // Orange for bold
-            (false, true, false) => (133, 153, 0),  // Bright green for italic
-            (false, true, true) => (38, 139, 210),  // Blue for italic links
-            (false, false, true) => (38, 139, 210), // Blue for links
-            _ => (147, 161, 161),                   // Default base1
+            (true, true, _) => theme.heading_color(4), // Bold italic
+            (true, false, _) => theme.strong_color(),
+            (false, true, false) => theme.emphasis_color(),
+            (false, true, true) => theme.link_color(), // Italic links
+            (false, false, true) => theme.link_color(),
+            _ => theme.text_color(),
         }
     }
 
@@ -162,6 +201,7 @@ impl Default for RenderState {
 pub enum StateChange {
     SetStrongEmphasis(bool),
     SetItalicEmphasis(bool),
+    SetStrikethrough(bool),
     SetLink(String),
     SetImage(String),
     SetCodeBlock(CodeBlockKind<'static>),
@@ -169,6 +209,7 @@ pub enum StateChange {
     PushList(Option<u64>),
     PopList,
     ClearTable,
+    SetFootnoteDefinition(String),
 }
 
 impl StateChange {
@@ -177,16 +218,19 @@ impl StateChange {
         match self {
             StateChange::SetStrongEmphasis(value) => context.emphasis.strong = value,
             StateChange::SetItalicEmphasis(value) => context.emphasis.italic = value,
+            StateChange::SetStrikethrough(value) => context.emphasis.strikethrough = value,
             StateChange::SetLink(url) => {
                 context.link = Some(LinkState {
                     text: String::new(),
                     url,
+                    title: String::new(),
                 });
             }
             StateChange::SetImage(url) => {
                 context.image = Some(ImageState {
                     alt_text: String::new(),
                     url,
+                    title: String::new(),
                 });
             }
             StateChange::SetCodeBlock(kind) => {
@@ -213,6 +257,8 @@ impl StateChange {
                     alignments,
                     current_row,
                     is_header: true,
+                    header: None,
+                    rows: Vec::new(),
                 });
             }
             StateChange::PushList(start) => {
@@ -229,79 +275,15 @@ impl StateChange {
                 context.list_stack.pop();
             }
             StateChange::ClearTable => context.table = None,
-        }
-    }
-
-    /// Apply this state change to the new RenderState
-    pub fn apply_to_state(self, state: &mut RenderState) {
-        match self {
-            StateChange::SetStrongEmphasis(value) => {
-                if value {
-                    state.push(StateFrame::new(ElementType::StrongEmphasis));
-                } else {
-                    state
-                        .stack
-                        .retain(|f| f.element_type != ElementType::StrongEmphasis);
-                }
-            }
-            StateChange::SetItalicEmphasis(value) => {
-                if value {
-                    state.push(StateFrame::new(ElementType::ItalicEmphasis));
-                } else {
-                    state
-                        .stack
-                        .retain(|f| f.element_type != ElementType::ItalicEmphasis);
-                }
-            }
-            StateChange::SetLink(url) => {
-                let mut attrs = HashMap::new();
-                attrs.insert("url".to_string(), url);
-                attrs.insert("text".to_string(), String::new());
-                state.push(StateFrame::with_attributes(ElementType::Link, attrs));
-            }
-            StateChange::SetImage(url) => {
-                let mut attrs = HashMap::new();
-                attrs.insert("url".to_string(), url);
-                attrs.insert("alt_text".to_string(), String::new());
-                state.push(StateFrame::with_attributes(ElementType::Image, attrs));
-            }
-            StateChange::SetCodeBlock(kind) => {
-                let mut attrs = HashMap::new();
-                match kind {
-                    CodeBlockKind::Indented => {}
-                    CodeBlockKind::Fenced(lang) => {
-                        if !lang.is_empty() {
-                            attrs.insert("language".to_string(), lang.to_string());
-                        }
-                    }
-                }
-                attrs.insert("content".to_string(), String::new());
-                state.push(StateFrame::with_attributes(ElementType::CodeBlock, attrs));
-            }
-            StateChange::SetTable(alignments) => {
-                let mut attrs = HashMap::new();
-                attrs.insert("alignments".to_string(), format!("{:?}", alignments));
-                attrs.insert("is_header".to_string(), "true".to_string());
-                state.push(StateFrame::with_attributes(ElementType::Table, attrs));
-            }
-            StateChange::PushList(start) => {
-                let list_type = if let Some(n) = start {
-                    ListType::Ordered {
-                        current: n as usize,
-                    }
-                } else {
-                    ListType::Unordered
-                };
-                state.list_stack.push(list_type);
-            }
-            StateChange::PopList => {
-                state.list_stack.pop();
-            }
-            StateChange::ClearTable => {
-                state.stack.retain(|f| f.element_type != ElementType::Table);
+            StateChange::SetFootnoteDefinition(label) => {
+                context.footnote_definition = Some(FootnoteDefinitionState {
+                    label,
+                    content: String::new(),
+                });
             }
         }
     }
+
 }
 
 /// Represents the context for rendering Markdown content
@@ -314,24 +296,28 @@ pub struct RenderContext {
     pub code_block: Option<CodeBlockState>,
     pub table: Option<TableState>,
     pub current_line: String,
+    pub footnote_definition: Option<FootnoteDefinitionState>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct LinkState {
     pub text: String,
     pub url: String,
+    pub title: String,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct ImageState {
     pub alt_text: String,
     pub url: String,
+    pub title: String,
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct EmphasisState {
     pub strong: bool,
     pub italic: bool,
+    pub strikethrough: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -351,6 +337,39 @@ pub struct TableState {
     pub alignments: Vec<Alignment>,
     pub current_row: Vec<String>,
     pub is_header: bool,
+    /// The header row, buffered once its closing tag is seen.
+    pub header: Option<Vec<String>>,
+    /// Completed body rows, buffered as each one closes. The table is only
+    /// rendered once all of these are known, so columns can be sized from
+    /// every cell rather than guessed row by row.
+    pub rows: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FootnoteState {
+    pub label: String,
+    pub content: String,
+}
+
+/// A footnote definition's body, buffered in [`RenderContext`] while its
+/// inline events are parsed so it can be printed after the main document
+/// body rather than inline at its definition site.
+#[derive(Debug, Clone, Default)]
+pub struct FootnoteDefinitionState {
+    pub label: String,
+    pub content: String,
+}
+
+/// The single element currently accumulating text content, if any. Only one
+/// can be active at a time since Markdown doesn't nest these constructs
+/// (e.g. a link's text can't itself contain a table).
+#[derive(Debug, Clone)]
+pub enum ActiveElement {
+    Link(LinkState),
+    Image(ImageState),
+    CodeBlock(CodeBlockState),
+    Table(TableState),
+    Footnote(FootnoteState),
 }
 
 impl RenderContext {
@@ -372,142 +391,18 @@ impl RenderContext {
         self.link.is_some()
     }
 
-    /// Get text color based on current emphasis state
-    pub fn get_text_color(&self) -> (u8, u8, u8) {
+    /// Get text color based on current emphasis state, resolved against
+    /// `theme` instead of a hardcoded palette so callers can swap themes at
+    /// runtime.
+    pub fn get_text_color(&self, theme: &dyn MarkdownTheme) -> (u8, u8, u8) {
         match (self.emphasis.strong, self.emphasis.italic, self.has_link()) {
-            (true, true, _) => (181, 137, 0),       // Yellow for bold italic
-            (true, false, _) => (203, 75, 22),      // Orange for bold
-            (false, true, false) => (133, 153, 0),  // Bright green for italic
-            (false, true, true) => (38, 139, 210),  // Blue for italic links
-            (false, false, true) => (38, 139, 210), // Blue for links
-            _ => (147, 161, 161),                   // Default base1
+            (true, true, _) => theme.heading_color(4), // Bold italic
+            (true, false, _) => theme.strong_color(),
+            (false, true, false) => theme.emphasis_color(),
+            (false, true, true) => theme.link_color(), // Italic links
+            (false, false, true) => theme.link_color(),
+            _ => theme.text_color(),
         }
     }
 
-    /// Convert from RenderState for backward compatibility
-    pub fn from_state(state: &RenderState) -> Self {
-        let mut context = RenderContext::default();
-
-        // Restore emphasis states
-        if state.has_element(&ElementType::StrongEmphasis) {
-            context.emphasis.strong = true;
-        }
-        if state.has_element(&ElementType::ItalicEmphasis) {
-            context.emphasis.italic = true;
-        }
-
-        // Restore link state
-        if let Some(link_frame) = state.get_frame(&ElementType::Link).and_then(|frame| {
-            match (frame.attributes.get("url"), frame.attributes.get("text")) {
-                (Some(url), Some(text)) => Some(LinkState {
-                    url: url.clone(),
-                    text: text.clone(),
-                }),
-                _ => None,
-            }
-        }) {
-            context.link = Some(link_frame);
-        }
-
-        // Restore image state
-        if let Some(image_frame) = state.get_frame(&ElementType::Image).and_then(|frame| {
-            match (
-                frame.attributes.get("url"),
-                frame.attributes.get("alt_text"),
-            ) {
-                (Some(url), Some(alt_text)) => Some(ImageState {
-                    url: url.clone(),
-                    alt_text: alt_text.clone(),
-                }),
-                _ => None,
-            }
-        }) {
-            context.image = Some(image_frame);
-        }
-
-        // Restore code block state
-        if let Some(code_frame) = state.get_frame(&ElementType::CodeBlock) {
-            let language = code_frame.attributes.get("language").cloned();
-            let content = code_frame
-                .attributes
-                .get("content")
-                .cloned()
-                .unwrap_or_default();
-            context.code_block = Some(CodeBlockState { language, content });
-        }
-
-        // Restore table state
-        if let Some(table_frame) = state.get_frame(&ElementType::Table) {
-            // For now, create a simple default table state
-            // In production, you'd parse the alignments from the stored string
-            context.table = Some(TableState {
-                alignments: Vec::new(),
-                current_row: Vec::new(),
-                is_header: table_frame
-                    .attributes
-                    .get("is_header")
-                    .map(|s| s == "true")
-                    .unwrap_or(false),
-            });
-        }
-
-        // Copy list stack and current line
-        context.list_stack = state.list_stack.clone();
-        context.current_line = state.current_line.clone();
-
-        context
-    }
-
-    /// Convert to RenderState for new implementation
-    pub fn to_state(&self) -> RenderState {
-        let mut state = RenderState::new();
-
-        // Convert emphasis states
-        if self.emphasis.strong {
-            state.push(StateFrame::new(ElementType::StrongEmphasis));
-        }
-        if self.emphasis.italic {
-            state.push(StateFrame::new(ElementType::ItalicEmphasis));
-        }
-
-        // Convert link state
-        if let Some(ref link) = self.link {
-            let mut attrs = HashMap::new();
-            attrs.insert("url".to_string(), link.url.clone());
-            attrs.insert("text".to_string(), link.text.clone());
-            state.push(StateFrame::with_attributes(ElementType::Link, attrs));
-        }
-
-        // Convert image state
-        if let Some(ref image) = self.image {
-            let mut attrs = HashMap::new();
-            attrs.insert("url".to_string(), image.url.clone());
-            attrs.insert("alt_text".to_string(), image.alt_text.clone());
-            state.push(StateFrame::with_attributes(ElementType::Image, attrs));
-        }
-
-        // Convert code block state
-        if let Some(ref code_block) = self.code_block {
-            let mut attrs = HashMap::new();
-            if let Some(ref lang) = code_block.language {
-                attrs.insert("language".to_string(), lang.clone());
-            }
-            attrs.insert("content".to_string(), code_block.content.clone());
-            state.push(StateFrame::with_attributes(ElementType::CodeBlock, attrs));
-        }
-
-        // Convert table state
-        if let Some(ref table) = self.table {
-            let mut attrs = HashMap::new();
-            attrs.insert("alignments".to_string(), format!("{:?}", table.alignments));
-            attrs.insert("is_header".to_string(), table.is_header.to_string());
-            state.push(StateFrame::with_attributes(ElementType::Table, attrs));
-        }
-
-        // Copy list stack and current line
-        state.list_stack = self.list_stack.clone();
-        state.current_line = self.current_line.clone();
-
-        state
-    }
 }
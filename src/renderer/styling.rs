@@ -1,6 +1,5 @@
 use super::MarkdownRenderer;
-use crate::theme::MarkdownTheme;
-use colored::ColoredString;
+use crate::theme::{Decorations, MarkdownTheme};
 
 /// Comprehensive text styling system for all Markdown element types.
 /// Encapsulates color, weight, and decoration for consistent terminal output.
@@ -15,54 +14,196 @@ pub enum TextStyle {
     ListMarker,
     Delimiter,
     CodeBlock,
-    /// Allows arbitrary color and bold combination for special cases
+    /// GFM strikethrough (`~~text~~`).
+    Strikethrough,
+    /// Allows an arbitrary color, bold flag, and [`Decorations`] combination
+    /// for special cases (currently syntax-highlighted code spans).
     Custom {
         color: (u8, u8, u8),
         bold: bool,
+        decorations: Decorations,
     },
 }
 
 impl MarkdownRenderer {
     /// Converts TextStyle enum to colored terminal output.
-    /// Centralizes all styling logic for consistency across the renderer.
-    pub fn apply_text_style(&self, text: &str, style: TextStyle) -> ColoredString {
-        use crate::theme::{styled_text, styled_text_with_bg};
+    /// Centralizes all styling logic for consistency across the renderer,
+    /// which also makes it the one place [`super::config::RenderConfig::normalize`]
+    /// needs to touch: redact volatile substrings and skip ANSI entirely
+    /// instead of picking a color.
+    pub fn apply_text_style(&self, text: &str, style: TextStyle) -> String {
+        use crate::theme::{
+            dim_color, styled_text_at_depth, styled_text_with_bg_at_depth,
+            styled_text_with_decorations_at_depth,
+        };
+
+        if self.config.normalize {
+            return self.redactor.redact_line(text);
+        }
+
+        if !self.config.enable_colors {
+            return text.to_string();
+        }
 
+        let depth = self.state.blockquote_depth;
+        let color_depth = self.config.color_depth;
         match style {
-            TextStyle::Normal => styled_text(text, self.theme.text_color(), false, false, false),
-            TextStyle::Strong => styled_text(text, self.theme.strong_color(), true, false, false),
-            TextStyle::Emphasis => {
-                styled_text(text, self.theme.emphasis_color(), false, true, false)
-            }
-            TextStyle::Code => styled_text(text, self.theme.code_color(), false, false, false),
-            TextStyle::Link => styled_text(text, self.theme.link_color(), false, false, true),
-            TextStyle::Heading(level) => {
-                styled_text(text, self.theme.heading_color(level), true, false, false)
-            }
-            TextStyle::ListMarker => {
-                styled_text(text, self.theme.list_marker_color(), false, false, false)
-            }
-            TextStyle::Delimiter => {
-                styled_text(text, self.theme.delimiter_color(), false, false, false)
-            }
-            TextStyle::CodeBlock => {
-                styled_text_with_bg(text, self.theme.code_color(), self.theme.code_background())
-            }
-            TextStyle::Custom { color, bold } => styled_text(text, color, bold, false, false),
+            TextStyle::Normal => styled_text_at_depth(
+                text,
+                dim_color(self.theme.text_color(), depth),
+                false,
+                false,
+                false,
+                color_depth,
+            ),
+            TextStyle::Strong => styled_text_at_depth(
+                text,
+                dim_color(self.theme.strong_color(), depth),
+                true,
+                false,
+                false,
+                color_depth,
+            ),
+            TextStyle::Emphasis => styled_text_at_depth(
+                text,
+                dim_color(self.theme.emphasis_color(), depth),
+                false,
+                true,
+                false,
+                color_depth,
+            ),
+            TextStyle::Code => styled_text_at_depth(
+                text,
+                dim_color(self.theme.code_color(), depth),
+                false,
+                false,
+                false,
+                color_depth,
+            ),
+            TextStyle::Link => styled_text_at_depth(
+                text,
+                dim_color(self.theme.link_color(), depth),
+                false,
+                false,
+                true,
+                color_depth,
+            ),
+            TextStyle::Heading(level) => styled_text_at_depth(
+                text,
+                dim_color(self.theme.heading_color(level), depth),
+                true,
+                false,
+                false,
+                color_depth,
+            ),
+            TextStyle::ListMarker => styled_text_at_depth(
+                text,
+                dim_color(self.theme.list_marker_color(), depth),
+                false,
+                false,
+                false,
+                color_depth,
+            ),
+            TextStyle::Delimiter => styled_text_at_depth(
+                text,
+                dim_color(self.theme.delimiter_color(), depth),
+                false,
+                false,
+                false,
+                color_depth,
+            ),
+            TextStyle::CodeBlock => styled_text_with_bg_at_depth(
+                text,
+                dim_color(self.theme.code_color(), depth),
+                self.theme.code_background(),
+                color_depth,
+            ),
+            TextStyle::Strikethrough => styled_text_with_decorations_at_depth(
+                text,
+                dim_color(self.theme.text_color(), depth),
+                false,
+                Decorations {
+                    strikethrough: true,
+                    ..Decorations::default()
+                },
+                color_depth,
+            ),
+            TextStyle::Custom {
+                color,
+                bold,
+                decorations,
+            } => styled_text_with_decorations_at_depth(
+                text,
+                dim_color(color, depth),
+                bold,
+                decorations,
+                color_depth,
+            ),
         }
     }
 
-    pub fn render_styled_text(&self, text: &str) {
-        print!("{}", self.create_styled_text(text));
+    /// Prints `text` styled per [`RenderState::emphasis`](super::state::RenderState),
+    /// soft-wrapping at word boundaries against
+    /// [`super::config::RenderConfig::effective_width`] and continuing
+    /// wrapped lines with [`MarkdownRenderer::continuation_prefix`] so text
+    /// inside list items and block quotes stays indented under their
+    /// marker. Tracks [`RenderState::current_column`] as it prints, since
+    /// that's also updated by the other markers/inline elements
+    /// `print_output` writes directly.
+    pub fn render_styled_text(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        let words: Vec<&str> = text.split_whitespace().collect();
+        if words.is_empty() {
+            // Pure whitespace (e.g. a lone space between inline elements).
+            print!("{}", text);
+            self.state.current_column += super::table_layout::display_width(text);
+            return;
+        }
+
+        let width = self.config.effective_width();
+        let continuation = self.continuation_prefix();
+        let continuation_width = self.continuation_width();
+        let leading_space = text.starts_with(char::is_whitespace);
+        let trailing_space = text.ends_with(char::is_whitespace);
+
+        for (index, word) in words.iter().enumerate() {
+            let word_width = super::table_layout::display_width(word);
+            let wants_space_before = index > 0 || leading_space;
+
+            if self.state.current_column > continuation_width
+                && self.state.current_column + usize::from(wants_space_before) + word_width > width
+            {
+                println!();
+                print!("{}", continuation);
+                self.state.current_column = continuation_width;
+            } else if wants_space_before && self.state.current_column > 0 {
+                print!(" ");
+                self.state.current_column += 1;
+            }
+
+            print!("{}", self.create_styled_text(word));
+            self.state.current_column += word_width;
+        }
+
+        if trailing_space {
+            print!(" ");
+            self.state.current_column += 1;
+        }
     }
 
     /// Determines appropriate style based on current emphasis state.
-    /// Priority: strong > italic > link > normal for style selection.
+    /// Priority: strong > italic > strikethrough > link > normal for style
+    /// selection.
     pub fn create_styled_text(&self, text: &str) -> String {
         let style = if self.state.emphasis.strong {
             TextStyle::Strong
         } else if self.state.emphasis.italic {
             TextStyle::Emphasis
+        } else if self.config.enable_strikethrough && self.state.emphasis.strikethrough {
+            TextStyle::Strikethrough
         } else if self.has_link() {
             TextStyle::Link
         } else {
@@ -81,7 +222,11 @@ impl MarkdownRenderer {
         } else if color == self.theme.delimiter_color() {
             TextStyle::Delimiter
         } else {
-            TextStyle::Custom { color, bold }
+            TextStyle::Custom {
+                color,
+                bold,
+                decorations: Decorations::default(),
+            }
         };
 
         self.apply_text_style(marker, style).to_string()
@@ -93,6 +238,37 @@ impl MarkdownRenderer {
             .to_string()
     }
 
+    /// Formats a link/image `title` attribute as a dimmed `— "title"`
+    /// annotation, appended after the URL by the `Link`/`Image` arms of
+    /// [`super::MarkdownRenderer::print_output`] when
+    /// [`super::config::RenderConfig::link_titles`] is enabled. Redacted
+    /// and printed without the dimming escape under
+    /// [`super::config::RenderConfig::normalize`].
+    pub fn create_styled_title(&self, title: &str) -> String {
+        if self.config.normalize {
+            return format!(" \u{2014} \"{}\"", self.redactor.redact_line(title));
+        }
+        format!("\x1b[2m \u{2014} \"{title}\"\x1b[0m")
+    }
+
+    /// Wraps already-styled `text` in an OSC 8 terminal hyperlink escape
+    /// pointing at `url`, so terminals that support it (iTerm2, kitty,
+    /// WezTerm, modern VTE) make the text itself clickable. Returns `text`
+    /// unchanged when [`super::config::RenderConfig::hyperlinks`] is off, so
+    /// links and images still render their visible text normally when
+    /// output is redirected or the terminal doesn't support the escape —
+    /// also unchanged under [`super::config::RenderConfig::normalize`],
+    /// since the escape sequence itself (and the raw `url` it carries)
+    /// would otherwise make "plain" output neither plain nor stable.
+    /// Shared by the `Link` and `Image` arms of
+    /// [`super::MarkdownRenderer::print_output`].
+    pub fn hyperlink(&self, url: &str, text: &str) -> String {
+        if !self.config.hyperlinks || self.config.normalize {
+            return text.to_string();
+        }
+        format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+    }
+
     /// Routes text to the appropriate active element buffer.
     /// Returns true if text was consumed, false if no active element exists.
     /// Handles link text, image alt text, code content, and table cells.
@@ -121,6 +297,163 @@ impl MarkdownRenderer {
             return true;
         }
 
+        if let Some(ref mut footnote) = self.get_footnote_mut() {
+            footnote.content.push_str(text);
+            return true;
+        }
+
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::MarkdownRenderer;
+
+    #[test]
+    fn test_hyperlink_wraps_text_in_osc8_escape_when_enabled() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.hyperlinks = true;
+        let wrapped = renderer.hyperlink("https://example.com", "Example");
+        assert_eq!(
+            wrapped,
+            "\x1b]8;;https://example.com\x1b\\Example\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_hyperlink_returns_text_unchanged_when_disabled() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.hyperlinks = false;
+        assert_eq!(
+            renderer.hyperlink("https://example.com", "Example"),
+            "Example"
+        );
+    }
+
+    #[test]
+    fn test_hyperlink_returns_text_unchanged_under_normalize() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.hyperlinks = true;
+        renderer.config.normalize = true;
+        assert_eq!(
+            renderer.hyperlink("https://example.com", "Example"),
+            "Example"
+        );
+    }
+
+    #[test]
+    fn test_apply_text_style_emits_no_ansi_under_normalize() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.normalize = true;
+        assert_eq!(
+            renderer.apply_text_style("text", TextStyle::Strong),
+            "text"
+        );
+    }
+
+    #[test]
+    fn test_apply_text_style_emits_no_ansi_when_colors_disabled() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.enable_colors = false;
+        assert_eq!(
+            renderer.apply_text_style("text", TextStyle::Strong),
+            "text"
+        );
+    }
+
+    #[test]
+    fn test_apply_text_style_downsamples_to_the_configured_color_depth() {
+        use crate::theme::ColorDepth;
+
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.color_depth = ColorDepth::TrueColor;
+        let true_color = renderer.apply_text_style("text", TextStyle::Strong);
+        assert!(true_color.contains(";2;"));
+
+        renderer.config.color_depth = ColorDepth::Ansi16;
+        let ansi16 = renderer.apply_text_style("text", TextStyle::Strong);
+        assert!(!ansi16.contains(";2;"));
+    }
+
+    #[test]
+    fn test_apply_text_style_strikethrough_emits_sgr_9() {
+        let renderer = MarkdownRenderer::new();
+        let styled = renderer.apply_text_style("text", TextStyle::Strikethrough);
+        assert!(styled.contains(";9;") || styled.contains(";9m"));
+    }
+
+    #[test]
+    fn test_create_styled_text_uses_strikethrough_when_emphasis_state_is_set() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.set_strikethrough(true);
+        let styled = renderer.create_styled_text("text");
+        let expected = renderer.apply_text_style("text", TextStyle::Strikethrough);
+        assert_eq!(styled, expected);
+    }
+
+    #[test]
+    fn test_create_styled_text_ignores_strikethrough_when_disabled_in_config() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.enable_strikethrough = false;
+        renderer.set_strikethrough(true);
+        let styled = renderer.create_styled_text("text");
+        let expected = renderer.apply_text_style("text", TextStyle::Normal);
+        assert_eq!(styled, expected);
+    }
+
+    #[test]
+    fn test_apply_text_style_redacts_under_normalize() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.set_normalize(true);
+        renderer.register_redaction("/secret/path", "[CWD]");
+        assert_eq!(
+            renderer.apply_text_style("/secret/path/file.md", TextStyle::Normal),
+            "[CWD]/file.md"
+        );
+    }
+
+    #[test]
+    fn test_create_styled_title_is_plain_and_redacted_under_normalize() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.set_normalize(true);
+        renderer.register_redaction("/secret/path", "[HOME]");
+        assert_eq!(
+            renderer.create_styled_title("/secret/path/readme"),
+            " \u{2014} \"[HOME]/readme\""
+        );
+    }
+
+    #[test]
+    fn test_apply_text_style_dims_further_with_each_blockquote_depth() {
+        let mut renderer = MarkdownRenderer::new();
+        let flat = renderer.apply_text_style("text", TextStyle::Normal);
+
+        renderer.state.blockquote_depth = 1;
+        let nested_once = renderer.apply_text_style("text", TextStyle::Normal);
+        renderer.state.blockquote_depth = 2;
+        let nested_twice = renderer.apply_text_style("text", TextStyle::Normal);
+
+        assert_ne!(flat, nested_once);
+        assert_ne!(nested_once, nested_twice);
+    }
+
+    #[test]
+    fn test_render_styled_text_wraps_at_the_configured_width() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.width_override = Some(10);
+        renderer.render_styled_text("alpha beta gamma");
+        // "alpha beta" (10 cols) fills the first line; "gamma" (5 cols)
+        // wraps onto its own, so the column tracker ends at 5, not 16.
+        assert_eq!(renderer.state.current_column, 5);
+    }
+
+    #[test]
+    fn test_render_styled_text_does_not_wrap_within_the_width() {
+        let mut renderer = MarkdownRenderer::new();
+        renderer.config.width_override = Some(80);
+        renderer.render_styled_text("a short line");
+        assert_eq!(renderer.state.current_column, "a short line".len());
+    }
+}
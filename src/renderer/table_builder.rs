@@ -1,12 +1,24 @@
 //! Table builder module for constructing tables with fluent API
 //!
 //! Provides a builder pattern implementation for creating and rendering tables
-//! with support for headers, alignments, and customizable formatting.
+//! with support for headers, alignments, and customizable formatting. Beyond
+//! the default GFM pipe syntax, [`TableBuilder::style`] also supports
+//! rendering as box-drawing art (see [`TableStyle`]) for standalone CLI
+//! output, optionally colored per-header/column/cell (see [`CellStyle`]) or,
+//! via [`Table::render_styled`], colored wholesale from a [`MarkdownTheme`].
 
 use anyhow::Result;
 use pulldown_cmark::Alignment;
+use std::collections::HashMap;
 use std::fmt;
 
+use crate::theme::{MarkdownTheme, dim_color, styled_text};
+
+/// The narrowest a column is shrunk to by [`Table::shrink_widths_to_fit`]
+/// when fitting a [`TableBuilder::max_width`] budget: 3 columns of content
+/// plus 1 for the `…` that [`truncate_to_width`] appends.
+const MIN_COLUMN_WIDTH: usize = 4;
+
 /// A builder for constructing tables with a fluent API
 ///
 /// # Example
@@ -25,10 +37,210 @@ use std::fmt;
 #[derive(Debug, Clone)]
 pub struct TableBuilder {
     headers: Option<Vec<String>>,
-    rows: Vec<Vec<String>>,
+    rows: Vec<Vec<Cell>>,
     alignments: Vec<Alignment>,
     separator: &'static str,
     alignment_config: TableAlignmentConfig,
+    style: TableStyle,
+    float_precision: usize,
+    max_col_widths: Option<Vec<Option<usize>>>,
+    max_width: Option<usize>,
+    header_style: Option<CellStyle>,
+    column_styles: HashMap<usize, CellStyle>,
+    cell_styles: HashMap<(usize, usize), CellStyle>,
+    ascii_only: bool,
+}
+
+/// Selects how [`Table::render`] draws a table's borders.
+///
+/// `Markdown` is the default and the only style a Markdown document can
+/// actually embed; the rest render Unicode (or plain ASCII) box-drawing
+/// borders for display as standalone CLI output instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableStyle {
+    /// GitHub-flavored Markdown pipe table, e.g. `| a | b |`.
+    Markdown,
+    /// ASCII box-drawing using `+`/`-`/`|`, with a `=` header divider and a
+    /// divider between every data row, in the style of reStructuredText's
+    /// grid tables.
+    Grid,
+    /// Unicode box-drawing with double-line (`═`/`╤`) borders and header
+    /// divider, no dividers between data rows.
+    Fancy,
+    /// Unicode box-drawing with single-line (`─`/`┬`) borders and header
+    /// divider, no dividers between data rows.
+    Simple,
+    /// Like `Simple`, but with rounded (`╭╮╰╯`) corners instead of square
+    /// ones, in the style of tabled's `Style::rounded`.
+    Rounded,
+    /// No borders at all; cells are separated by two spaces.
+    Plain,
+}
+
+impl Default for TableStyle {
+    fn default() -> Self {
+        TableStyle::Markdown
+    }
+}
+
+/// The left/mid/right junction characters and horizontal line character for
+/// one border line (top, header divider, row divider, or bottom) of a
+/// non-`Markdown` [`TableStyle`].
+#[derive(Debug, Clone, Copy)]
+struct BorderLine {
+    left: char,
+    mid: char,
+    right: char,
+    horizontal: char,
+}
+
+/// The full set of border lines for a non-`Markdown`, non-`Plain`
+/// [`TableStyle`]. `row_divider` is `None` for styles (`Fancy`, `Simple`)
+/// that only separate the header from the body, not every row from the next.
+#[derive(Debug, Clone, Copy)]
+struct BorderGlyphs {
+    top: BorderLine,
+    header_divider: BorderLine,
+    row_divider: Option<BorderLine>,
+    bottom: BorderLine,
+    vertical: char,
+}
+
+impl TableStyle {
+    /// The border glyphs to draw this style with. `ascii_only` replaces
+    /// every style's Unicode box-drawing characters with `Grid`'s ASCII
+    /// twins (`+`/`-`/`=`/`|`), preserving each style's own structure —
+    /// `Fancy`/`Simple`/`Rounded` still draw no divider between data rows,
+    /// only `Grid` does — rather than literally collapsing every style to
+    /// `Grid`'s.
+    fn glyphs(self, ascii_only: bool) -> Option<BorderGlyphs> {
+        match self {
+            TableStyle::Markdown | TableStyle::Plain => None,
+            TableStyle::Grid => Some(BorderGlyphs {
+                top: BorderLine { left: '+', mid: '+', right: '+', horizontal: '-' },
+                header_divider: BorderLine { left: '+', mid: '+', right: '+', horizontal: '=' },
+                row_divider: Some(BorderLine { left: '+', mid: '+', right: '+', horizontal: '-' }),
+                bottom: BorderLine { left: '+', mid: '+', right: '+', horizontal: '-' },
+                vertical: '|',
+            }),
+            TableStyle::Fancy if ascii_only => Some(BorderGlyphs {
+                top: BorderLine { left: '+', mid: '+', right: '+', horizontal: '=' },
+                header_divider: BorderLine { left: '+', mid: '+', right: '+', horizontal: '=' },
+                row_divider: None,
+                bottom: BorderLine { left: '+', mid: '+', right: '+', horizontal: '=' },
+                vertical: '|',
+            }),
+            TableStyle::Fancy => Some(BorderGlyphs {
+                top: BorderLine { left: '╒', mid: '╤', right: '╕', horizontal: '═' },
+                header_divider: BorderLine { left: '╞', mid: '╪', right: '╡', horizontal: '═' },
+                row_divider: None,
+                bottom: BorderLine { left: '╘', mid: '╧', right: '╛', horizontal: '═' },
+                vertical: '│',
+            }),
+            TableStyle::Simple | TableStyle::Rounded if ascii_only => Some(BorderGlyphs {
+                top: BorderLine { left: '+', mid: '+', right: '+', horizontal: '-' },
+                header_divider: BorderLine { left: '+', mid: '+', right: '+', horizontal: '-' },
+                row_divider: None,
+                bottom: BorderLine { left: '+', mid: '+', right: '+', horizontal: '-' },
+                vertical: '|',
+            }),
+            TableStyle::Simple => Some(BorderGlyphs {
+                top: BorderLine { left: '┌', mid: '┬', right: '┐', horizontal: '─' },
+                header_divider: BorderLine { left: '├', mid: '┼', right: '┤', horizontal: '─' },
+                row_divider: None,
+                bottom: BorderLine { left: '└', mid: '┴', right: '┘', horizontal: '─' },
+                vertical: '│',
+            }),
+            TableStyle::Rounded => Some(BorderGlyphs {
+                top: BorderLine { left: '╭', mid: '┬', right: '╮', horizontal: '─' },
+                header_divider: BorderLine { left: '├', mid: '┼', right: '┤', horizontal: '─' },
+                row_divider: None,
+                bottom: BorderLine { left: '╰', mid: '┴', right: '╯', horizontal: '─' },
+                vertical: '│',
+            }),
+        }
+    }
+}
+
+/// A single table cell's value. Distinguishing numeric content from text
+/// lets [`Table::render`] auto-right-align a column whose cells are all
+/// `Int`/`Float` and format floats to a consistent precision (see
+/// [`TableBuilder::float_precision`]) instead of requiring callers to
+/// pre-format numbers into strings themselves.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Text(String),
+    Int(i64),
+    Float(f64),
+    /// An empty cell — ignored (rather than treated as text) when deciding
+    /// whether a column is numeric, so a blank entry in an otherwise
+    /// numeric column doesn't defeat auto-right-alignment.
+    Empty,
+}
+
+impl Cell {
+    fn is_numeric(&self) -> bool {
+        matches!(self, Cell::Int(_) | Cell::Float(_))
+    }
+
+    fn display(&self, float_precision: usize) -> String {
+        match self {
+            Cell::Text(text) => text.clone(),
+            Cell::Int(value) => value.to_string(),
+            Cell::Float(value) => format!("{value:.float_precision$}"),
+            Cell::Empty => String::new(),
+        }
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(value: &str) -> Self {
+        Cell::Text(value.to_string())
+    }
+}
+
+impl From<String> for Cell {
+    fn from(value: String) -> Self {
+        Cell::Text(value)
+    }
+}
+
+impl From<i64> for Cell {
+    fn from(value: i64) -> Self {
+        Cell::Int(value)
+    }
+}
+
+impl From<f64> for Cell {
+    fn from(value: f64) -> Self {
+        Cell::Float(value)
+    }
+}
+
+/// A foreground color and emphasis to paint a cell's *already-padded* text
+/// with, as an ANSI escape sequence, when rendering a non-`Markdown`
+/// [`TableStyle`] (see [`TableBuilder::header_style`],
+/// [`TableBuilder::column_style`], [`TableBuilder::cell_style`]). Applied at
+/// emit time only — width and padding are always computed from the
+/// unstyled cell text, so colored tables line up exactly like uncolored
+/// ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CellStyle {
+    pub color: Option<(u8, u8, u8)>,
+    pub bold: bool,
+}
+
+impl CellStyle {
+    /// A style with just a foreground color, not bold.
+    pub fn new(color: (u8, u8, u8)) -> Self {
+        Self { color: Some(color), bold: false }
+    }
+
+    /// Sets the bold flag.
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
 }
 
 /// Configuration for table alignment indicators
@@ -51,14 +263,39 @@ impl Default for TableAlignmentConfig {
     }
 }
 
+/// Overrides [`Table::style_for`] falls back to when [`Table::render_styled`]
+/// is asked to colorize a table from a [`MarkdownTheme`] and no explicit
+/// [`TableBuilder::header_style`]/[`TableBuilder::column_style`]/
+/// [`TableBuilder::cell_style`] already covers a cell: the header takes
+/// `theme.heading_color(1)`, border/separator glyphs take
+/// `theme.delimiter_color()`, and odd data rows are tinted with a dimmed
+/// `theme.text_color()` for zebra striping. Resolved fresh from whichever
+/// `theme` is passed to `render_styled`, so it lives on [`Table`] rather than
+/// being baked in at [`TableBuilder::build`] time.
+#[derive(Debug, Clone, Copy, Default)]
+struct ThemeOverrides {
+    header: Option<CellStyle>,
+    delimiter: Option<(u8, u8, u8)>,
+    zebra: Option<(u8, u8, u8)>,
+}
+
 /// Represents a built table ready for rendering
 #[derive(Debug, Clone)]
 pub struct Table {
     headers: Option<Vec<String>>,
-    rows: Vec<Vec<String>>,
+    rows: Vec<Vec<Cell>>,
     alignments: Vec<Alignment>,
     separator: &'static str,
     alignment_config: TableAlignmentConfig,
+    style: TableStyle,
+    float_precision: usize,
+    max_col_widths: Option<Vec<Option<usize>>>,
+    max_width: Option<usize>,
+    header_style: Option<CellStyle>,
+    column_styles: HashMap<usize, CellStyle>,
+    cell_styles: HashMap<(usize, usize), CellStyle>,
+    ascii_only: bool,
+    theme_overrides: ThemeOverrides,
 }
 
 impl TableBuilder {
@@ -70,6 +307,14 @@ impl TableBuilder {
             alignments: Vec::new(),
             separator: "|",
             alignment_config: TableAlignmentConfig::default(),
+            style: TableStyle::default(),
+            float_precision: 2,
+            max_col_widths: None,
+            max_width: None,
+            header_style: None,
+            column_styles: HashMap::new(),
+            cell_styles: HashMap::new(),
+            ascii_only: false,
         }
     }
 
@@ -97,11 +342,14 @@ impl TableBuilder {
         self
     }
 
-    /// Adds a data row
+    /// Adds a data row. Each item converts to a [`Cell`]: `&str`/`String`
+    /// become `Cell::Text`, `i64`/`f64` become `Cell::Int`/`Cell::Float`, so
+    /// a column of numbers can be auto-right-aligned and formatted
+    /// consistently — see [`TableBuilder::float_precision`].
     pub fn row<I, S>(mut self, row: I) -> Self
     where
         I: IntoIterator<Item = S>,
-        S: Into<String>,
+        S: Into<Cell>,
     {
         self.rows.push(row.into_iter().map(|s| s.into()).collect());
         self
@@ -112,7 +360,7 @@ impl TableBuilder {
     where
         I: IntoIterator<Item = R>,
         R: IntoIterator<Item = S>,
-        S: Into<String>,
+        S: Into<Cell>,
     {
         for row in rows {
             self.rows.push(row.into_iter().map(|s| s.into()).collect());
@@ -132,6 +380,82 @@ impl TableBuilder {
         self
     }
 
+    /// Sets the border style `render` draws the table with. Ignored by
+    /// [`Table::render_row`]/[`Table::render_separator`], which always emit
+    /// Markdown pipe syntax regardless of `style`.
+    pub fn style(mut self, style: TableStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Replaces `style`'s Unicode box-drawing borders with their ASCII
+    /// twins (see [`TableStyle::glyphs`]), for legacy/limited terminals.
+    /// Ignored by [`TableStyle::Markdown`]/[`TableStyle::Plain`], which
+    /// draw no Unicode glyphs to begin with.
+    pub fn ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+
+    /// Sets how many digits after the decimal point `Cell::Float` values are
+    /// formatted with (default: 2), e.g. `Cell::Float(3.14159)` renders as
+    /// `"3.14"` at the default precision.
+    pub fn float_precision(mut self, precision: usize) -> Self {
+        self.float_precision = precision;
+        self
+    }
+
+    /// Caps each column's display width to `widths[index]` (`None` leaves a
+    /// column uncapped). A cell wider than its column's cap is word-wrapped
+    /// at [`Table::render`] time, growing its logical row into several
+    /// physical lines with the other columns blank-padded on the
+    /// continuation lines, instead of overflowing the column.
+    pub fn max_col_width(mut self, widths: Vec<Option<usize>>) -> Self {
+        self.max_col_widths = Some(widths);
+        self
+    }
+
+    /// Caps the total display width of every rendered line (borders,
+    /// separators and padding included) to `width`, e.g. the TUI's
+    /// `draw_preview` can pass the preview pane's `Rect::width` so tables
+    /// never overflow it. Unlike [`Self::max_col_width`], an over-wide cell
+    /// is truncated with a trailing `…` rather than wrapped onto additional
+    /// physical lines — see [`Table::render`].
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Styles the header row, for non-`Markdown` [`TableStyle`]s (see
+    /// [`CellStyle`]).
+    pub fn header_style(mut self, style: CellStyle) -> Self {
+        self.header_style = Some(style);
+        self
+    }
+
+    /// Styles every data-row cell in column `col`, for non-`Markdown`
+    /// [`TableStyle`]s. Overridden per-cell by [`Self::cell_style`].
+    pub fn column_style(mut self, col: usize, style: CellStyle) -> Self {
+        self.column_styles.insert(col, style);
+        self
+    }
+
+    /// Styles a single data-row cell at `(row, col)`, for non-`Markdown`
+    /// [`TableStyle`]s. Takes priority over [`Self::column_style`].
+    pub fn cell_style(mut self, row: usize, col: usize, style: CellStyle) -> Self {
+        self.cell_styles.insert((row, col), style);
+        self
+    }
+
+    /// Styles the header row using `theme`'s level-1 heading color and
+    /// weight, the same treatment [`super::table_layout`] gives Markdown
+    /// table headers rendered inline in a document — so a standalone
+    /// `Table` rendered for CLI output (via [`Self::style`]) matches.
+    pub fn theme(mut self, theme: &dyn MarkdownTheme) -> Self {
+        self.header_style = Some(CellStyle::new(theme.heading_color(1)).bold());
+        self
+    }
+
     /// Validates the table structure
     fn validate(&self) -> Result<()> {
         let column_count = if let Some(ref headers) = self.headers {
@@ -174,6 +498,15 @@ impl TableBuilder {
             alignments: self.alignments,
             separator: self.separator,
             alignment_config: self.alignment_config,
+            style: self.style,
+            float_precision: self.float_precision,
+            max_col_widths: self.max_col_widths,
+            max_width: self.max_width,
+            header_style: self.header_style,
+            column_styles: self.column_styles,
+            cell_styles: self.cell_styles,
+            ascii_only: self.ascii_only,
+            theme_overrides: ThemeOverrides::default(),
         })
     }
 }
@@ -191,15 +524,91 @@ impl Table {
     }
 
     /// Gets the data rows
-    pub fn rows(&self) -> &Vec<Vec<String>> {
+    pub fn rows(&self) -> &Vec<Vec<Cell>> {
         &self.rows
     }
 
-    /// Gets the column alignments
+    /// Gets the column alignments, as explicitly set via
+    /// [`TableBuilder::alignments`] (or auto-generated as all `None` by
+    /// [`TableBuilder::header`]). This is what the caller asked for, not
+    /// necessarily what's used to render column `index` — see
+    /// [`Self::effective_alignment`].
     pub fn alignments(&self) -> &Vec<Alignment> {
         &self.alignments
     }
 
+    /// The alignment column `index` actually renders with: the explicit
+    /// [`Alignment`] from [`Self::alignments`] if it's anything but `None`,
+    /// otherwise `Right` if every non-empty [`Cell`] in that column (across
+    /// all rows) is `Int`/`Float`, and `None` otherwise.
+    fn effective_alignment(&self, index: usize) -> Alignment {
+        match self.alignments.get(index).copied().unwrap_or(Alignment::None) {
+            Alignment::None if self.column_is_numeric(index) => Alignment::Right,
+            explicit => explicit,
+        }
+    }
+
+    /// Whether column `index` is made up entirely of numeric (`Int`/`Float`)
+    /// cells, ignoring `Empty` ones, with at least one numeric cell present.
+    /// An all-empty or mixed text/numeric column is not numeric.
+    fn column_is_numeric(&self, index: usize) -> bool {
+        let mut saw_numeric = false;
+        for row in &self.rows {
+            match row.get(index) {
+                Some(Cell::Empty) | None => {}
+                Some(cell) if cell.is_numeric() => saw_numeric = true,
+                Some(_) => return false,
+            }
+        }
+        saw_numeric
+    }
+
+    /// Formats every cell in `row` per [`Self::float_precision`].
+    fn formatted_row(&self, row: &[Cell]) -> Vec<String> {
+        row.iter().map(|cell| cell.display(self.float_precision)).collect()
+    }
+
+    /// The [`CellStyle`] to paint the cell at column `col` with. For the
+    /// header (`row` is `None`): [`TableBuilder::header_style`], falling
+    /// back to [`TableBuilder::column_style`] for `col`, then to the themed
+    /// header color set by [`Self::render_styled`], if any. For data row
+    /// `index` (`row` is `Some(index)`): a [`TableBuilder::cell_style`]
+    /// override for `(index, col)`, falling back to
+    /// [`TableBuilder::column_style`], then to [`Self::render_styled`]'s
+    /// zebra-stripe color for odd rows.
+    fn style_for(&self, row: Option<usize>, col: usize) -> Option<CellStyle> {
+        match row {
+            None => self
+                .header_style
+                .or_else(|| self.column_styles.get(&col).copied())
+                .or(self.theme_overrides.header),
+            Some(index) => self
+                .cell_styles
+                .get(&(index, col))
+                .copied()
+                .or_else(|| self.column_styles.get(&col).copied())
+                .or_else(|| {
+                    (index % 2 == 1)
+                        .then_some(self.theme_overrides.zebra)
+                        .flatten()
+                        .map(CellStyle::new)
+                }),
+        }
+    }
+
+    /// Wraps already-padded `text` in an ANSI escape per `style`, if any.
+    /// Applied at emit time, after padding, so the escape codes never throw
+    /// off width calculations (see [`CellStyle`]).
+    fn apply_cell_style(text: &str, style: Option<CellStyle>) -> String {
+        match style {
+            Some(CellStyle { color: Some(color), bold }) => {
+                styled_text(text, color, bold, false, false)
+            }
+            Some(CellStyle { color: None, bold: true }) => format!("\x1b[1m{text}\x1b[0m"),
+            Some(CellStyle { color: None, bold: false }) | None => text.to_string(),
+        }
+    }
+
     /// Helper function to format table row with common logic
     fn format_table_row<I, F>(&self, items: I, formatter: F, estimated_cell_size: usize) -> String
     where
@@ -224,25 +633,174 @@ impl Table {
         output
     }
 
-    /// Renders a single row as a string
-    pub fn render_row(&self, row: &[String]) -> String {
-        let avg_cell_size = if row.is_empty() {
-            4
+    /// Display width of each column, measured across the header and every
+    /// row (not just the row being rendered), so [`Self::render_row`] pads
+    /// every row to the same width regardless of which one is widest.
+    /// Shares [`super::table_layout::display_width`] rather than counting
+    /// bytes or `chars`, so CJK and emoji cells still line up. Clamped to
+    /// [`Self::max_col_widths`] afterward, if set; cells that overflow their
+    /// clamped width are wrapped across multiple physical lines by
+    /// [`Self::wrap_row`] rather than left overflowing the column. If
+    /// [`Self::max_width`] is set, columns are then shrunk (see
+    /// [`Self::shrink_widths_to_fit`]) so the widest rendered line fits the
+    /// budget; cells that still overflow their column are truncated with an
+    /// ellipsis at render time instead of wrapped.
+    fn column_widths(&self) -> Vec<usize> {
+        let mut widths = vec![0usize; self.column_count()];
+        if let Some(headers) = &self.headers {
+            for (width, cell) in widths.iter_mut().zip(headers) {
+                *width = (*width).max(super::table_layout::display_width(cell));
+            }
+        }
+        for row in &self.rows {
+            for (index, cell) in row.iter().enumerate() {
+                if let Some(width) = widths.get_mut(index) {
+                    let rendered = cell.display(self.float_precision);
+                    *width = (*width).max(super::table_layout::display_width(&rendered));
+                }
+            }
+        }
+        if let Some(caps) = &self.max_col_widths {
+            for (index, width) in widths.iter_mut().enumerate() {
+                if let Some(Some(cap)) = caps.get(index) {
+                    *width = (*width).min(*cap).max(1);
+                }
+            }
+        }
+        if let Some(max_width) = self.max_width {
+            let overhead = self.line_overhead(widths.len());
+            widths = Self::shrink_widths_to_fit(widths, max_width, overhead);
+        }
+        widths
+    }
+
+    /// Total non-content width one rendered line adds on top of its columns'
+    /// widths: borders/separators between and around `column_count` cells,
+    /// plus one space of padding on each side of every cell. Used by
+    /// [`Self::shrink_widths_to_fit`] to know how much budget is actually
+    /// left for content.
+    fn line_overhead(&self, column_count: usize) -> usize {
+        if column_count == 0 {
+            return 0;
+        }
+        match self.style.glyphs(self.ascii_only) {
+            Some(_) => column_count + 1 + column_count * 2,
+            None if self.style == TableStyle::Plain => 2 * column_count.saturating_sub(1),
+            None => {
+                super::table_layout::display_width(self.separator) * (column_count + 1)
+                    + column_count * 2
+            }
+        }
+    }
+
+    /// Repeatedly shrinks the currently-widest column by 1 until `widths`
+    /// plus `overhead` fits `max_width`, never shrinking a column below
+    /// [`MIN_COLUMN_WIDTH`] (3 columns of content plus an ellipsis). Stops
+    /// early, possibly still over budget, once every column has hit that
+    /// floor — a budget narrower than `column_count * MIN_COLUMN_WIDTH` plus
+    /// overhead simply can't be met by shrinking alone.
+    fn shrink_widths_to_fit(mut widths: Vec<usize>, max_width: usize, overhead: usize) -> Vec<usize> {
+        while overhead + widths.iter().sum::<usize>() > max_width {
+            let widest = widths
+                .iter()
+                .enumerate()
+                .filter(|&(_, &w)| w > MIN_COLUMN_WIDTH)
+                .max_by_key(|&(_, &w)| w);
+            match widest {
+                Some((index, _)) => widths[index] -= 1,
+                None => break,
+            }
+        }
+        widths
+    }
+
+    /// Splits `row` into one or more physical rows of the same column count:
+    /// a cell wider than its column's `widths` entry is word-wrapped via
+    /// [`super::table_layout::wrap_cell`], and the row grows to as many
+    /// physical lines as its widest-wrapping cell needs, with shorter cells
+    /// blank-padded on the continuation lines.
+    fn wrap_row(&self, row: &[String], widths: &[usize]) -> Vec<Vec<String>> {
+        let wrapped: Vec<Vec<String>> = row
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                let width = widths
+                    .get(index)
+                    .copied()
+                    .unwrap_or_else(|| super::table_layout::display_width(cell));
+                if super::table_layout::display_width(cell) > width {
+                    super::table_layout::wrap_cell(cell, width)
+                } else {
+                    vec![cell.clone()]
+                }
+            })
+            .collect();
+        let line_count = wrapped.iter().map(Vec::len).max().unwrap_or(1);
+        (0..line_count)
+            .map(|line_index| {
+                wrapped
+                    .iter()
+                    .map(|lines| lines.get(line_index).cloned().unwrap_or_default())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Splits `row` into physical rows for rendering: word-wrapped via
+    /// [`Self::wrap_row`] normally, or, when [`Self::max_width`] is set,
+    /// truncated to exactly one physical line per logical row via
+    /// [`truncate_to_width`] instead — growing the row would defeat the
+    /// whole-line width budget the truncation path exists to guarantee.
+    fn rows_for_rendering(&self, row: &[String], widths: &[usize]) -> Vec<Vec<String>> {
+        if self.max_width.is_some() {
+            vec![
+                row.iter()
+                    .enumerate()
+                    .map(|(index, cell)| {
+                        let width = widths
+                            .get(index)
+                            .copied()
+                            .unwrap_or_else(|| super::table_layout::display_width(cell));
+                        truncate_to_width(cell, width)
+                    })
+                    .collect(),
+            ]
         } else {
-            row.iter().map(|s| s.len()).sum::<usize>() / row.len() + 4
-        };
+            self.wrap_row(row, widths)
+        }
+    }
+
+    /// Renders a single row as a string, with each cell padded to its
+    /// column's display width (see [`Self::column_widths`]) and aligned per
+    /// [`Self::alignments`], so columns line up across rows rather than
+    /// being separated by a single space regardless of neighboring cells.
+    pub fn render_row(&self, row: &[String]) -> String {
+        let widths = self.column_widths();
 
         self.format_table_row(
-            row.iter(),
-            |output, cell| output.push_str(cell),
-            avg_cell_size,
+            row.iter().enumerate(),
+            |output, (index, cell)| {
+                let width = widths
+                    .get(index)
+                    .copied()
+                    .unwrap_or_else(|| super::table_layout::display_width(cell));
+                let alignment = self.effective_alignment(index);
+                output.push_str(&super::table_layout::pad_cell(cell, width, alignment));
+            },
+            widths.iter().copied().max().unwrap_or(4) + 4,
         )
     }
 
-    /// Renders the alignment separator row
+    /// Renders the alignment separator row, reflecting
+    /// [`Self::effective_alignment`] rather than the raw
+    /// [`Self::alignments`] so a numeric column that was auto-right-aligned
+    /// shows a `---:` marker matching how its cells actually render.
     pub fn render_separator(&self) -> String {
+        let effective: Vec<Alignment> = (0..self.column_count())
+            .map(|index| self.effective_alignment(index))
+            .collect();
         self.format_table_row(
-            &self.alignments,
+            effective.iter(),
             |output, alignment| {
                 let sep = match alignment {
                     Alignment::Left => &self.alignment_config.left,
@@ -256,26 +814,175 @@ impl Table {
         )
     }
 
-    /// Renders the entire table
+    /// Renders the entire table per [`Self::style`]: `Markdown` emits GFM
+    /// pipe syntax via [`Self::render_row`]/[`Self::render_separator`];
+    /// every other style draws box-drawing borders via [`Self::glyphs`]
+    /// instead, for display as standalone CLI output.
     pub fn render(&self) -> Vec<String> {
+        match self.style.glyphs(self.ascii_only) {
+            Some(glyphs) => self.render_bordered(&glyphs),
+            None if self.style == TableStyle::Plain => self.render_plain(),
+            None => self.render_markdown(),
+        }
+    }
+
+    /// Renders like [`Self::render`], but colorizes the table from `theme`:
+    /// the header takes `theme.heading_color(1)`, border/separator glyphs
+    /// take `theme.delimiter_color()`, and — for non-`Markdown` styles —
+    /// odd data rows are zebra-striped with a dimmed `theme.text_color()`.
+    /// Any [`TableBuilder::header_style`]/[`TableBuilder::column_style`]/
+    /// [`TableBuilder::cell_style`] set at build time takes priority over
+    /// these theme colors, same as [`Self::style_for`] already prioritizes
+    /// them over each other. Since width and alignment are computed from the
+    /// unstyled cell text exactly as in [`Self::render`] (styling wraps
+    /// already-padded text — see [`Self::apply_cell_style`]), stripping the
+    /// ANSI escapes back out of `render_styled`'s output reproduces
+    /// `render()`'s output exactly, so callers like the TUI preview and the
+    /// colored terminal output can share one aligned representation.
+    ///
+    /// `Markdown` style is never colorized (a GFM document can't embed ANSI
+    /// escapes), so `render_styled` on a `Markdown`-style table is identical
+    /// to [`Self::render`].
+    pub fn render_styled(&self, theme: &dyn MarkdownTheme) -> Vec<String> {
+        let mut styled = self.clone();
+        styled.theme_overrides = ThemeOverrides {
+            header: Some(CellStyle::new(theme.heading_color(1)).bold()),
+            delimiter: Some(theme.delimiter_color()),
+            zebra: Some(dim_color(theme.text_color(), 1)),
+        };
+        styled.render()
+    }
+
+    fn render_markdown(&self) -> Vec<String> {
         // Pre-allocate capacity based on expected table size
         let estimated_lines = if self.headers.is_some() { 2 } else { 0 } + self.rows.len();
         let mut lines = Vec::with_capacity(estimated_lines);
+        let widths = self.column_widths();
 
         // Render header if present
         if let Some(ref headers) = self.headers {
-            lines.push(self.render_row(headers));
+            for physical in self.rows_for_rendering(headers, &widths) {
+                lines.push(self.render_row(&physical));
+            }
             lines.push(self.render_separator());
         }
 
         // Render data rows
         for row in &self.rows {
-            lines.push(self.render_row(row));
+            let formatted = self.formatted_row(row);
+            for physical in self.rows_for_rendering(&formatted, &widths) {
+                lines.push(self.render_row(&physical));
+            }
         }
 
         lines
     }
 
+    /// Renders with no borders at all: cells padded to their column's
+    /// display width and separated by two spaces, header included as a
+    /// plain row like any other (there's no divider line to draw it with).
+    fn render_plain(&self) -> Vec<String> {
+        let widths = self.column_widths();
+        let mut lines = Vec::new();
+        if let Some(ref headers) = self.headers {
+            for physical in self.rows_for_rendering(headers, &widths) {
+                lines.push(self.plain_row(&physical, &widths, None));
+            }
+        }
+        for (row_index, row) in self.rows.iter().enumerate() {
+            let formatted = self.formatted_row(row);
+            for physical in self.rows_for_rendering(&formatted, &widths) {
+                lines.push(self.plain_row(&physical, &widths, Some(row_index)));
+            }
+        }
+        lines
+    }
+
+    /// `row_index` is `None` for the header, `Some(index)` for data row
+    /// `index` — used to look up each cell's [`Self::style_for`].
+    fn plain_row(&self, row: &[String], widths: &[usize], row_index: Option<usize>) -> String {
+        row.iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                let width = widths
+                    .get(index)
+                    .copied()
+                    .unwrap_or_else(|| super::table_layout::display_width(cell));
+                let alignment = self.effective_alignment(index);
+                let padded = super::table_layout::pad_cell(cell, width, alignment);
+                Self::apply_cell_style(&padded, self.style_for(row_index, index))
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
+
+    /// Renders with Unicode/ASCII box-drawing borders per `glyphs`: a top
+    /// line, the header row and its divider (if there's a header), each data
+    /// row (with `glyphs.row_divider` between them, if the style has one),
+    /// and a bottom line.
+    fn render_bordered(&self, glyphs: &BorderGlyphs) -> Vec<String> {
+        let widths = self.column_widths();
+        let mut lines = Vec::new();
+        let border = |line: &BorderLine| {
+            Self::apply_cell_style(
+                &render_border_line(line, &widths),
+                self.theme_overrides.delimiter.map(CellStyle::new),
+            )
+        };
+
+        lines.push(border(&glyphs.top));
+        if let Some(ref headers) = self.headers {
+            for physical in self.rows_for_rendering(headers, &widths) {
+                lines.push(self.bordered_row(&physical, &widths, glyphs.vertical, None));
+            }
+            lines.push(border(&glyphs.header_divider));
+        }
+        for (row_index, row) in self.rows.iter().enumerate() {
+            if row_index > 0 {
+                if let Some(ref divider) = glyphs.row_divider {
+                    lines.push(border(divider));
+                }
+            }
+            let formatted = self.formatted_row(row);
+            for physical in self.rows_for_rendering(&formatted, &widths) {
+                lines.push(self.bordered_row(&physical, &widths, glyphs.vertical, Some(row_index)));
+            }
+        }
+        lines.push(border(&glyphs.bottom));
+
+        lines
+    }
+
+    /// `row_index` is `None` for the header, `Some(index)` for data row
+    /// `index` — used to look up each cell's [`Self::style_for`].
+    fn bordered_row(
+        &self,
+        row: &[String],
+        widths: &[usize],
+        vertical: char,
+        row_index: Option<usize>,
+    ) -> String {
+        let vertical = Self::apply_cell_style(
+            &vertical.to_string(),
+            self.theme_overrides.delimiter.map(CellStyle::new),
+        );
+        let mut output = String::new();
+        output.push_str(&vertical);
+        for (index, cell) in row.iter().enumerate() {
+            let width = widths
+                .get(index)
+                .copied()
+                .unwrap_or_else(|| super::table_layout::display_width(cell));
+            let alignment = self.effective_alignment(index);
+            let padded = super::table_layout::pad_cell(cell, width, alignment);
+            output.push(' ');
+            output.push_str(&Self::apply_cell_style(&padded, self.style_for(row_index, index)));
+            output.push(' ');
+            output.push_str(&vertical);
+        }
+        output
+    }
+
     /// Gets the column count
     pub fn column_count(&self) -> usize {
         if let Some(ref headers) = self.headers {
@@ -293,6 +1000,51 @@ impl Table {
     }
 }
 
+/// Truncates `text` to `width` display columns, on a display-width boundary
+/// rather than a byte boundary so a multibyte char is never split, appending
+/// `…` (counted as width 1) when truncation actually happens. Returns `text`
+/// unchanged if it already fits.
+fn truncate_to_width(text: &str, width: usize) -> String {
+    if super::table_layout::display_width(text) <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let budget = width - 1;
+    let mut truncated = String::new();
+    let mut used = 0;
+    for ch in text.chars() {
+        let char_width = super::table_layout::char_width(ch);
+        if used + char_width > budget {
+            break;
+        }
+        truncated.push(ch);
+        used += char_width;
+    }
+    truncated.push('…');
+    truncated
+}
+
+/// Draws one border line (top, header divider, row divider, or bottom) for
+/// [`Table::render_bordered`]: `line.left`, then `line.horizontal` repeated
+/// across each column's width plus its 2 cells of padding, joined by
+/// `line.mid` between columns, ending in `line.right`.
+fn render_border_line(line: &BorderLine, widths: &[usize]) -> String {
+    let mut output = String::new();
+    output.push(line.left);
+    for (index, width) in widths.iter().enumerate() {
+        if index > 0 {
+            output.push(line.mid);
+        }
+        for _ in 0..(width + 2) {
+            output.push(line.horizontal);
+        }
+    }
+    output.push(line.right);
+    output
+}
+
 impl fmt::Display for Table {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for line in self.render() {
@@ -390,6 +1142,142 @@ mod tests {
         assert_eq!(table.row_count(), 0);
     }
 
+    #[test]
+    fn test_render_pads_every_row_to_the_widest_cell_in_its_column() {
+        let table = TableBuilder::new()
+            .header(vec!["Name", "Age"])
+            .row(vec!["Alice", "30"])
+            .row(vec!["Bob", "25"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        let widths: Vec<usize> = lines
+            .iter()
+            .map(|line| line.split('|').nth(1).unwrap().len())
+            .collect();
+        assert!(widths.windows(2).all(|pair| pair[0] == pair[1]));
+    }
+
+    #[test]
+    fn test_grid_style_draws_plus_and_equals_divider() {
+        let table = TableBuilder::new()
+            .style(TableStyle::Grid)
+            .header(vec!["A", "B"])
+            .row(vec!["1", "2"])
+            .row(vec!["3", "4"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert_eq!(lines[0], "+---+---+");
+        assert_eq!(lines[2], "+===+===+");
+        // A divider separates every data row, not just the header.
+        assert_eq!(lines[4], "+---+---+");
+        assert_eq!(lines.last().unwrap(), "+---+---+");
+    }
+
+    #[test]
+    fn test_fancy_style_draws_double_line_borders_with_no_row_dividers() {
+        let table = TableBuilder::new()
+            .style(TableStyle::Fancy)
+            .header(vec!["A", "B"])
+            .row(vec!["1", "2"])
+            .row(vec!["3", "4"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert_eq!(lines[0], "╒═══╤═══╕");
+        assert_eq!(lines[2], "╞═══╪═══╡");
+        // Header divider, then both data rows back to back with no divider.
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines.last().unwrap(), "╘═══╧═══╛");
+    }
+
+    #[test]
+    fn test_rounded_style_draws_rounded_corners_with_square_junctions() {
+        let table = TableBuilder::new()
+            .style(TableStyle::Rounded)
+            .header(vec!["A", "B"])
+            .row(vec!["1", "2"])
+            .row(vec!["3", "4"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert_eq!(lines[0], "╭───┬───╮");
+        assert_eq!(lines[2], "├───┼───┤");
+        // Header divider, then both data rows back to back with no divider.
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines.last().unwrap(), "╰───┴───╯");
+    }
+
+    #[test]
+    fn test_fancy_style_falls_back_to_ascii_glyphs_when_ascii_only() {
+        let table = TableBuilder::new()
+            .style(TableStyle::Fancy)
+            .ascii_only(true)
+            .header(vec!["A", "B"])
+            .row(vec!["1", "2"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert_eq!(lines[0], "+---+---+");
+        assert_eq!(lines[2], "+---+---+");
+        // Still no divider between data rows, just ASCII glyphs instead of
+        // Unicode ones — the structural difference from `Grid` survives.
+        assert!(lines.iter().all(|line| !line.contains('═')));
+    }
+
+    #[test]
+    fn test_grid_style_is_unaffected_by_ascii_only() {
+        let unicode = TableBuilder::new()
+            .style(TableStyle::Grid)
+            .header(vec!["A"])
+            .row(vec!["1"])
+            .build()
+            .unwrap();
+        let ascii = TableBuilder::new()
+            .style(TableStyle::Grid)
+            .ascii_only(true)
+            .header(vec!["A"])
+            .row(vec!["1"])
+            .build()
+            .unwrap();
+
+        assert_eq!(unicode.render(), ascii.render());
+    }
+
+    #[test]
+    fn test_plain_style_has_no_borders() {
+        let table = TableBuilder::new()
+            .style(TableStyle::Plain)
+            .header(vec!["Name", "Age"])
+            .row(vec!["Alice", "30"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert_eq!(lines, vec!["Name   Age", "Alice  30 "]);
+    }
+
+    #[test]
+    fn test_render_row_counts_cjk_cells_as_double_width() {
+        let table = TableBuilder::new()
+            .header(vec!["Name"])
+            .row(vec!["日本語"])
+            .row(vec!["Bob"])
+            .build()
+            .unwrap();
+
+        // "日本語" is 6 display columns wide, so the shorter "Bob" row must
+        // be padded out to match rather than the byte/char length of either.
+        let rendered = table.render_row(&["Bob".to_string()]);
+        assert_eq!(rendered, "| Bob    |");
+    }
+
     #[test]
     fn test_table_rendering() {
         let table = TableBuilder::new()
@@ -433,4 +1321,343 @@ mod tests {
         assert_eq!(alignments.len(), 3);
         assert!(alignments.iter().all(|a| *a == Alignment::None));
     }
+
+    #[test]
+    fn test_numeric_column_is_auto_right_aligned() {
+        let table = TableBuilder::new()
+            .header(vec!["Name", "Count"])
+            .row(vec![Cell::Text("a".to_string()), Cell::Int(1)])
+            .row(vec![Cell::Text("bb".to_string()), Cell::Int(22)])
+            .build()
+            .unwrap();
+
+        // Alignments themselves stay None (nothing was explicitly set)...
+        assert!(table.alignments().iter().all(|a| *a == Alignment::None));
+        // ...but the numeric column renders right-aligned regardless.
+        let lines = table.render();
+        assert!(lines[0].contains("Count"));
+        // Right-aligned: several spaces of padding precede "1" rather than
+        // following it.
+        assert!(lines[2].contains("   1 |"));
+        assert!(lines.join("\n").contains("---:"));
+    }
+
+    #[test]
+    fn test_mixed_text_and_numeric_column_is_not_auto_aligned() {
+        let table = TableBuilder::new()
+            .header(vec!["Value"])
+            .row(vec![Cell::Int(1)])
+            .row(vec![Cell::Text("n/a".to_string())])
+            .build()
+            .unwrap();
+
+        // Left-aligned (the default for a non-numeric column): the cell's
+        // own content starts right after the leading "| ", with any padding
+        // trailing it rather than preceding it.
+        let rendered = table.render_row(&["1".to_string()]);
+        assert!(rendered.starts_with("| 1"));
+    }
+
+    #[test]
+    fn test_float_cells_format_to_configured_precision() {
+        let table = TableBuilder::new()
+            .float_precision(2)
+            .header(vec!["Pi"])
+            .row(vec![Cell::Float(std::f64::consts::PI)])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert!(lines[2].contains("3.14"));
+        assert!(!lines[2].contains("3.14159"));
+    }
+
+    #[test]
+    fn test_empty_cell_does_not_defeat_numeric_auto_alignment() {
+        let table = TableBuilder::new()
+            .header(vec!["Value"])
+            .row(vec![Cell::Int(1)])
+            .row(vec![Cell::Empty])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert!(lines.join("\n").contains("---:"));
+    }
+
+    #[test]
+    fn test_max_col_width_wraps_long_cell_into_multiple_physical_lines() {
+        let table = TableBuilder::new()
+            .max_col_width(vec![Some(10), None])
+            .header(vec!["Description", "Count"])
+            .row(vec!["a sentence that is much longer than ten", "1"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        // header + separator + at least 2 wrapped physical lines for the row
+        assert!(lines.len() >= 4);
+        // No physical line exceeds the 10-column cap for the first column.
+        for line in &lines[2..] {
+            let cell = line.split('|').nth(1).unwrap().trim();
+            assert!(super::super::table_layout::display_width(cell) <= 10);
+        }
+    }
+
+    #[test]
+    fn test_header_style_colors_header_in_bordered_styles_but_not_markdown() {
+        let table = TableBuilder::new()
+            .style(TableStyle::Grid)
+            .header_style(CellStyle::new((255, 0, 0)))
+            .header(vec!["Name"])
+            .row(vec!["Alice"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert!(lines[1].contains("\x1b["));
+        // The data row isn't touched by a header-only style.
+        assert!(!lines[3].contains("\x1b["));
+
+        let markdown = TableBuilder::new()
+            .header_style(CellStyle::new((255, 0, 0)))
+            .header(vec!["Name"])
+            .row(vec!["Alice"])
+            .build()
+            .unwrap();
+        // Markdown output never carries ANSI escapes, styled or not.
+        assert!(!markdown.render().join("\n").contains("\x1b["));
+    }
+
+    #[test]
+    fn test_column_style_applies_to_every_row_unless_overridden_per_cell() {
+        let table = TableBuilder::new()
+            .style(TableStyle::Plain)
+            .column_style(0, CellStyle::new((0, 255, 0)))
+            .cell_style(1, 0, CellStyle::new((0, 0, 255)))
+            .row(vec!["a"])
+            .row(vec!["b"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert!(lines[0].contains("\x1b["));
+        assert!(lines[1].contains("\x1b["));
+        // Row 1's cell_style override paints a different color than the
+        // plain column_style, so the two escape sequences differ.
+        let row0_escape = lines[0].split('m').next().unwrap();
+        let row1_escape = lines[1].split('m').next().unwrap();
+        assert_ne!(row0_escape, row1_escape);
+    }
+
+    #[test]
+    fn test_theme_sets_header_style_from_heading_color() {
+        use crate::theme::Monochrome;
+
+        let table = TableBuilder::new()
+            .style(TableStyle::Simple)
+            .theme(&Monochrome)
+            .header(vec!["Name"])
+            .row(vec!["Alice"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert!(lines[1].contains("\x1b["));
+    }
+
+    #[test]
+    fn test_styled_cells_still_pad_to_the_correct_visible_width() {
+        let table = TableBuilder::new()
+            .style(TableStyle::Grid)
+            .column_style(0, CellStyle::new((255, 0, 0)))
+            .header(vec!["Name", "Age"])
+            .row(vec!["Alice", "30"])
+            .row(vec!["Bob", "25"])
+            .build()
+            .unwrap();
+
+        // Column widths (and thus border widths) are unaffected by styling.
+        let lines = table.render();
+        assert_eq!(lines[0], "+-------+-----+");
+    }
+
+    #[test]
+    fn test_max_col_width_blank_pads_shorter_columns_on_continuation_lines() {
+        let table = TableBuilder::new()
+            .max_col_width(vec![Some(5), None])
+            .header(vec!["Text", "Tag"])
+            .row(vec!["one two three", "x"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        // The second column's value appears once, on the row's first
+        // physical line; continuation lines leave it blank.
+        let occurrences = lines.iter().filter(|line| line.contains(" x ")).count();
+        assert_eq!(occurrences, 1);
+    }
+
+    #[test]
+    fn test_wrapped_cell_spans_three_lines_with_alignment_preserved_across_them() {
+        let table = TableBuilder::new()
+            .max_col_width(vec![Some(10), None])
+            .alignments(vec![Alignment::Left, Alignment::Right])
+            .row(vec!["alpha beta gamma delta", "42"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        assert_eq!(lines.len(), 3);
+
+        // "42" appears on the row's first physical line only...
+        let occurrences = lines.iter().filter(|line| line.contains("42")).count();
+        assert_eq!(occurrences, 1);
+        // ...but every physical line, wrapped or not, renders at the same
+        // total width, so the right-aligned numeric column stays aligned
+        // throughout rather than drifting on the continuation lines.
+        let width = lines[0].len();
+        assert!(lines.iter().all(|line| line.len() == width));
+    }
+
+    #[test]
+    fn test_max_width_shrinks_columns_so_every_line_fits_the_budget() {
+        let table = TableBuilder::new()
+            .max_width(30)
+            .header(vec!["Description", "Notes"])
+            .row(vec![
+                "a sentence that is much longer than the budget allows",
+                "also rather long winded indeed",
+            ])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        for line in &lines {
+            assert!(super::super::table_layout::display_width(line) <= 30);
+        }
+        // Truncation keeps one physical line per logical row, unlike
+        // max_col_width's wrapping.
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_max_width_truncation_does_not_split_a_multibyte_char() {
+        let table = TableBuilder::new()
+            .max_width(12)
+            .header(vec!["Emoji"])
+            .row(vec!["a🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉"])
+            .build()
+            .unwrap();
+
+        let lines = table.render();
+        for line in &lines {
+            assert!(super::super::table_layout::display_width(line) <= 12);
+            assert!(line.is_char_boundary(line.len()));
+        }
+        assert!(lines[2].contains('…'));
+    }
+
+    /// Strips ANSI SGR escapes (`\x1b[...m`) from `s`, for asserting that
+    /// [`Table::render_styled`]'s colored output has the same layout as
+    /// [`Table::render`]'s plain output once the escapes are removed.
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(ch) = chars.next() {
+            if ch == '\x1b' {
+                for next in chars.by_ref() {
+                    if next == 'm' {
+                        break;
+                    }
+                }
+            } else {
+                out.push(ch);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_render_styled_colors_header_and_borders_from_the_theme() {
+        use crate::theme::Monochrome;
+
+        let table = TableBuilder::new()
+            .style(TableStyle::Grid)
+            .header(vec!["Name", "Age"])
+            .row(vec!["Alice", "30"])
+            .row(vec!["Bob", "25"])
+            .build()
+            .unwrap();
+
+        let styled = table.render_styled(&Monochrome);
+        // top(0), header(1), header_divider(2), row0(3), row_divider(4), row1(5), bottom(6)
+        assert!(styled[0].contains("\x1b[")); // top border
+        assert!(styled[1].contains("\x1b[")); // header
+        assert!(styled[4].contains("\x1b[")); // row divider (a border line)
+        assert!(styled[5].contains("\x1b[")); // row 1 (odd index, zebra-striped)
+    }
+
+    #[test]
+    fn test_render_styled_stripped_of_ansi_matches_plain_render() {
+        use crate::theme::Monochrome;
+
+        let table = TableBuilder::new()
+            .style(TableStyle::Simple)
+            .header(vec!["Name", "Age"])
+            .row(vec!["Alice", "30"])
+            .row(vec!["Bob", "25"])
+            .build()
+            .unwrap();
+
+        let plain = table.render();
+        let styled: Vec<String> = table
+            .render_styled(&Monochrome)
+            .iter()
+            .map(|line| strip_ansi(line))
+            .collect();
+        assert_eq!(plain, styled);
+    }
+
+    #[test]
+    fn test_render_styled_leaves_markdown_style_uncolored() {
+        use crate::theme::Monochrome;
+
+        let table = TableBuilder::new()
+            .header(vec!["Name"])
+            .row(vec!["Alice"])
+            .build()
+            .unwrap();
+
+        assert_eq!(table.render(), table.render_styled(&Monochrome));
+    }
+
+    #[test]
+    fn test_render_styled_defers_to_an_explicit_header_style() {
+        use crate::theme::Monochrome;
+
+        let with_explicit_style = TableBuilder::new()
+            .style(TableStyle::Grid)
+            .header_style(CellStyle::new((255, 0, 0)))
+            .header(vec!["Name"])
+            .row(vec!["Alice"])
+            .build()
+            .unwrap();
+        let without_explicit_style = TableBuilder::new()
+            .style(TableStyle::Grid)
+            .header(vec!["Name"])
+            .row(vec!["Alice"])
+            .build()
+            .unwrap();
+
+        // The explicit red header_style survives render_styled unchanged,
+        // so it renders a different escape than the theme's own heading
+        // color would have produced.
+        let explicit_header = with_explicit_style.render_styled(&Monochrome)[1].clone();
+        let themed_header = without_explicit_style.render_styled(&Monochrome)[1].clone();
+        assert_ne!(
+            explicit_header.split('m').next(),
+            themed_header.split('m').next()
+        );
+    }
 }
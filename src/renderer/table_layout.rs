@@ -0,0 +1,369 @@
+//! Two-phase table rendering: every cell is buffered (see `handlers.rs`'s
+//! table-tag handling) before anything is printed, so column widths can be
+//! measured from the whole table rather than guessed row by row. Widths are
+//! measured in on-screen display columns, not bytes, so CJK and emoji cells
+//! still line up; when `RenderConfig::max_table_width` is set, cells wider
+//! than their column wrap across multiple lines instead of overflowing.
+
+use anyhow::Result;
+use pulldown_cmark::Alignment;
+
+use super::{config::TableOverflow, state::TableState, styling::TextStyle, MarkdownRenderer};
+
+impl MarkdownRenderer {
+    /// Renders a fully-buffered table: header (if any) followed by its
+    /// rows, box-drawn and padded per column according to `alignments`.
+    pub(super) fn render_table(&self, table: &TableState) -> Result<()> {
+        let column_count = table
+            .header
+            .as_ref()
+            .map(|row| row.len())
+            .or_else(|| table.rows.first().map(|row| row.len()))
+            .unwrap_or(0);
+        if column_count == 0 {
+            return Ok(());
+        }
+
+        let cell_budget = self.config.max_table_width.map(|max| {
+            let borders_and_padding = (column_count + 1) + column_count * 2;
+            max.saturating_sub(borders_and_padding) / column_count.max(1)
+        });
+        let widths = compute_column_widths(table, column_count, cell_budget);
+
+        println!("{}", border_line(&widths, '┌', '┬', '┐'));
+        if let Some(header) = &table.header {
+            self.print_row(header, &widths, &table.alignments, true, cell_budget)?;
+            println!(
+                "{}",
+                alignment_separator_line(&widths, &table.alignments, '├', '┼', '┤')
+            );
+        }
+        for row in &table.rows {
+            self.print_row(row, &widths, &table.alignments, false, cell_budget)?;
+        }
+        println!("{}", border_line(&widths, '└', '┴', '┘'));
+        Ok(())
+    }
+
+    fn print_row(
+        &self,
+        cells: &[String],
+        widths: &[usize],
+        alignments: &[Alignment],
+        is_header: bool,
+        cell_budget: Option<usize>,
+    ) -> Result<()> {
+        let wrapped: Vec<Vec<String>> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, &width)| match cell_budget {
+                Some(_) if display_width(cell) > width => match self.config.table_overflow {
+                    TableOverflow::Wrap => wrap_cell(cell, width),
+                    TableOverflow::Truncate => vec![truncate_cell(cell, width)],
+                },
+                _ => vec![cell.clone()],
+            })
+            .collect();
+        let line_count = wrapped.iter().map(Vec::len).max().unwrap_or(1);
+
+        for line_index in 0..line_count {
+            let mut output = String::from("│");
+            for (column, lines) in wrapped.iter().enumerate() {
+                let width = widths[column];
+                let alignment = alignments.get(column).copied().unwrap_or(Alignment::None);
+                let line = lines.get(line_index).map(String::as_str).unwrap_or("");
+                let padded = pad_cell(line, width, alignment);
+                let styled = self.style_table_cell(&padded, is_header);
+                output.push(' ');
+                output.push_str(&styled);
+                output.push(' ');
+                output.push('│');
+            }
+            println!("{}", output);
+        }
+        Ok(())
+    }
+
+    /// Styles one already-padded cell for printing: header cells use the
+    /// heading color, body cells plain text. Both pass through
+    /// [`Self::apply_text_style`] so `--plain`/`MP_PLAIN` redaction and color
+    /// depth apply to table contents the same way they do everywhere else.
+    fn style_table_cell(&self, padded: &str, is_header: bool) -> String {
+        if is_header {
+            self.apply_text_style(padded, TextStyle::Heading(1))
+        } else {
+            self.apply_text_style(padded, TextStyle::Normal)
+        }
+    }
+}
+
+fn compute_column_widths(
+    table: &TableState,
+    column_count: usize,
+    cell_budget: Option<usize>,
+) -> Vec<usize> {
+    let mut widths = vec![0usize; column_count];
+    let rows = table.header.iter().chain(table.rows.iter());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(display_width(cell));
+        }
+    }
+    if let Some(budget) = cell_budget {
+        for width in &mut widths {
+            *width = (*width).min(budget).max(1);
+        }
+    }
+    widths
+}
+
+/// Pads `text` (already known to fit within `width` display columns) out to
+/// `width`, placing the padding according to `alignment`: `Left`/`None` pad
+/// on the right, `Right` pads on the left, and `Center` splits the padding,
+/// favoring the right side when it can't be split evenly.
+pub(crate) fn pad_cell(text: &str, width: usize, alignment: Alignment) -> String {
+    let deficit = width.saturating_sub(display_width(text));
+    match alignment {
+        Alignment::Right => format!("{}{}", " ".repeat(deficit), text),
+        Alignment::Center => {
+            let left = deficit / 2;
+            let right = deficit - left;
+            format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+        }
+        Alignment::Left | Alignment::None => format!("{}{}", text, " ".repeat(deficit)),
+    }
+}
+
+/// Greedily word-wraps `text` into lines no wider than `width` display
+/// columns. A single word wider than `width` is hard-broken mid-word rather
+/// than left overflowing the column.
+pub(crate) fn wrap_cell(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        for chunk in hard_break(word, width) {
+            let candidate_width =
+                display_width(&current) + usize::from(!current.is_empty()) + display_width(&chunk);
+            if !current.is_empty() && candidate_width > width {
+                lines.push(std::mem::take(&mut current));
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(&chunk);
+        }
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Splits `word` into chunks no wider than `width`, only if it wouldn't
+/// otherwise fit on a line by itself.
+fn hard_break(word: &str, width: usize) -> Vec<String> {
+    if display_width(word) <= width {
+        return vec![word.to_string()];
+    }
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+    for ch in word.chars() {
+        let w = char_width(ch);
+        if current_width + w > width && !current.is_empty() {
+            chunks.push(std::mem::take(&mut current));
+            current_width = 0;
+        }
+        current.push(ch);
+        current_width += w;
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Truncates `cell` to `width` display columns, on a display-width boundary
+/// rather than a byte boundary so a multibyte char is never split, appending
+/// `…` (counted as width 1) when truncation actually happens. Mirrors
+/// `table_builder`'s `truncate_to_width`, kept as a separate copy since the
+/// two table renderers don't otherwise share code.
+pub(crate) fn truncate_cell(cell: &str, width: usize) -> String {
+    if display_width(cell) <= width {
+        return cell.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let budget = width - 1;
+    let mut truncated = String::new();
+    let mut used = 0;
+    for ch in cell.chars() {
+        let w = char_width(ch);
+        if used + w > budget {
+            break;
+        }
+        truncated.push(ch);
+        used += w;
+    }
+    truncated.push('…');
+    truncated
+}
+
+pub(crate) fn border_line(widths: &[usize], left: char, mid: char, right: char) -> String {
+    let mut line = String::new();
+    line.push(left);
+    for (index, width) in widths.iter().enumerate() {
+        if index > 0 {
+            line.push(mid);
+        }
+        line.push_str(&"─".repeat(width + 2));
+    }
+    line.push(right);
+    line
+}
+
+/// Like [`border_line`], but marks each column's alignment the way a
+/// Markdown table separator row does: a `:` at the left end for
+/// [`Alignment::Left`], the right end for [`Alignment::Right`], both ends
+/// for [`Alignment::Center`], and plain dashes for [`Alignment::None`].
+fn alignment_separator_line(
+    widths: &[usize],
+    alignments: &[Alignment],
+    left: char,
+    mid: char,
+    right: char,
+) -> String {
+    let mut line = String::new();
+    line.push(left);
+    for (index, &width) in widths.iter().enumerate() {
+        if index > 0 {
+            line.push(mid);
+        }
+        let span = width + 2;
+        let mut dashes: Vec<char> = vec!['─'; span];
+        match alignments.get(index).copied().unwrap_or(Alignment::None) {
+            Alignment::Left => dashes[0] = ':',
+            Alignment::Right => dashes[span - 1] = ':',
+            Alignment::Center => {
+                dashes[0] = ':';
+                dashes[span - 1] = ':';
+            }
+            Alignment::None => {}
+        }
+        line.extend(dashes);
+    }
+    line.push(right);
+    line
+}
+
+/// Approximates the number of terminal columns `s` occupies: zero-width
+/// marks and most control characters count for nothing, East Asian
+/// Wide/Fullwidth characters (CJK, most emoji) count for two, everything
+/// else counts for one. Backed by the `unicode-width` crate's
+/// `UnicodeWidthStr`/`UnicodeWidthChar` rather than a hand-rolled range
+/// table, so it tracks the Unicode East Asian Width standard exactly.
+pub(crate) fn display_width(s: &str) -> usize {
+    use unicode_width::UnicodeWidthStr;
+    s.width()
+}
+
+pub(crate) fn char_width(ch: char) -> usize {
+    use unicode_width::UnicodeWidthChar;
+    ch.width().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_width_treats_cjk_as_double_width() {
+        assert_eq!(display_width("ab"), 2);
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("café"), 4);
+    }
+
+    #[test]
+    fn test_display_width_treats_emoji_as_double_width() {
+        assert_eq!(display_width("🎉"), 2);
+        assert_eq!(display_width("a🎉b"), 4);
+    }
+
+    #[test]
+    fn test_pad_cell_alignment() {
+        assert_eq!(pad_cell("hi", 5, Alignment::Left), "hi   ");
+        assert_eq!(pad_cell("hi", 5, Alignment::Right), "   hi");
+        assert_eq!(pad_cell("hi", 5, Alignment::Center), " hi  ");
+        assert_eq!(pad_cell("hi", 5, Alignment::None), "hi   ");
+    }
+
+    #[test]
+    fn test_wrap_cell_breaks_on_whitespace() {
+        let lines = wrap_cell("the quick brown fox", 10);
+        assert!(lines.iter().all(|line| display_width(line) <= 10));
+        assert_eq!(lines.join(" "), "the quick brown fox");
+    }
+
+    #[test]
+    fn test_wrap_cell_hard_breaks_long_word() {
+        let lines = wrap_cell("supercalifragilisticexpialidocious", 10);
+        assert!(lines.iter().all(|line| display_width(line) <= 10));
+        assert!(lines.len() > 1);
+    }
+
+    #[test]
+    fn test_alignment_separator_line_marks_colons_per_column() {
+        let alignments = vec![
+            Alignment::Left,
+            Alignment::Center,
+            Alignment::Right,
+            Alignment::None,
+        ];
+        let line = alignment_separator_line(&[2, 2, 2, 2], &alignments, '├', '┼', '┤');
+        assert_eq!(line, "├:───┼:──:┼───:┼────┤");
+    }
+
+    #[test]
+    fn test_truncate_cell_leaves_short_text_unchanged() {
+        assert_eq!(truncate_cell("hi", 5), "hi");
+    }
+
+    #[test]
+    fn test_truncate_cell_appends_ellipsis_without_splitting_a_wide_char() {
+        assert_eq!(truncate_cell("日本語", 5), "日本…");
+        assert!(display_width(&truncate_cell("日本語", 5)) <= 5);
+    }
+
+    #[test]
+    fn test_compute_column_widths_uses_widest_cell() {
+        let table = TableState {
+            alignments: vec![Alignment::None, Alignment::None],
+            current_row: Vec::new(),
+            is_header: false,
+            header: Some(vec!["A".to_string(), "Column".to_string()]),
+            rows: vec![vec!["longer value".to_string(), "x".to_string()]],
+        };
+        let widths = compute_column_widths(&table, 2, None);
+        assert_eq!(widths, vec![12, 6]);
+    }
+
+    #[test]
+    fn test_style_table_cell_redacts_body_cells_under_normalize() {
+        use crate::renderer::MarkdownRenderer;
+
+        let mut renderer = MarkdownRenderer::new();
+        renderer.set_normalize(true);
+        renderer.register_redaction("/secret/path", "[CWD]");
+        assert_eq!(
+            renderer.style_table_cell("/secret/path/file.md", false),
+            "[CWD]/file.md"
+        );
+        assert_eq!(
+            renderer.style_table_cell("/secret/path/file.md", true),
+            "[CWD]/file.md"
+        );
+    }
+}
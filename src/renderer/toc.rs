@@ -0,0 +1,239 @@
+//! Heading outline / table-of-contents collection, modeled on rustdoc's
+//! `html/toc.rs`: headings are pushed in document order and nested by
+//! level, and each gets a unique, stable anchor id via `derive_id`.
+
+use std::collections::{HashMap, HashSet};
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// A single heading in the outline, with any headings nested beneath it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TocEntry {
+    pub level: u8,
+    pub title: String,
+    pub id: String,
+    pub children: Vec<TocEntry>,
+}
+
+/// The full heading outline for a document.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Toc {
+    pub entries: Vec<TocEntry>,
+}
+
+impl Toc {
+    /// Flattens every entry's anchor id, at every depth, into a single set
+    /// — the set of fragments (`#<id>`) an intra-document link can resolve
+    /// against.
+    pub fn anchor_ids(&self) -> HashSet<String> {
+        let mut ids = HashSet::new();
+        let mut stack: Vec<&TocEntry> = self.entries.iter().collect();
+        while let Some(entry) = stack.pop() {
+            ids.insert(entry.id.clone());
+            stack.extend(entry.children.iter());
+        }
+        ids
+    }
+}
+
+/// Incrementally builds a [`Toc`] as headings are encountered in document
+/// order, nesting each new heading under the most recent heading with a
+/// strictly lower level (a jump from H2 to H4 nests the H4 under the H2,
+/// rather than panicking or flattening it to the top level).
+///
+/// `seen_ids` plays the same role as rustdoc's `html::markdown::IdMap`:
+/// it's the thing [`Self::derive_id`] consults to turn a heading's text
+/// into a stable, collision-free anchor slug.
+#[derive(Debug, Default)]
+pub struct TocBuilder {
+    top_level: Vec<TocEntry>,
+    /// The currently open chain of ancestors, shallowest first.
+    chain: Vec<TocEntry>,
+    seen_ids: HashMap<String, usize>,
+}
+
+impl TocBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a heading, returning the anchor id assigned to it.
+    pub fn push(&mut self, level: u8, title: &str) -> String {
+        let id = self.derive_id(title);
+        let entry = TocEntry {
+            level,
+            title: title.to_string(),
+            id: id.clone(),
+            children: Vec::new(),
+        };
+
+        while self.chain.last().is_some_and(|ancestor| ancestor.level >= level) {
+            let done = self.chain.pop().expect("just checked non-empty");
+            self.attach(done);
+        }
+        self.chain.push(entry);
+        id
+    }
+
+    fn attach(&mut self, entry: TocEntry) {
+        match self.chain.last_mut() {
+            Some(parent) => parent.children.push(entry),
+            None => self.top_level.push(entry),
+        }
+    }
+
+    /// Derives a unique anchor id for `title`: lowercased, with runs of
+    /// non-alphanumeric characters collapsed to single hyphens and leading
+    /// or trailing hyphens trimmed, disambiguating collisions by appending
+    /// `-1`, `-2`, ... in order of appearance. Also guards against a
+    /// generated `base-n` id coinciding with some other heading that
+    /// slugifies to that exact string outright (e.g. a heading literally
+    /// titled "Overview-1"), by bumping `n` again until the candidate is
+    /// actually free.
+    pub fn derive_id(&mut self, title: &str) -> String {
+        let base = slugify(title);
+        let count = self.seen_ids.entry(base.clone()).or_insert(0);
+        let mut candidate = if *count == 0 {
+            base.clone()
+        } else {
+            format!("{base}-{count}")
+        };
+        *count += 1;
+
+        while candidate != base && self.seen_ids.contains_key(&candidate) {
+            let count = self.seen_ids.get_mut(&base).expect("just inserted above");
+            candidate = format!("{base}-{count}");
+            *count += 1;
+        }
+
+        self.seen_ids.entry(candidate.clone()).or_insert(0);
+        candidate
+    }
+
+    /// Closes out any still-open headings and returns the finished outline.
+    pub fn finish(mut self) -> Toc {
+        while let Some(done) = self.chain.pop() {
+            self.attach(done);
+        }
+        Toc {
+            entries: self.top_level,
+        }
+    }
+}
+
+fn slugify(title: &str) -> String {
+    let mut slug = String::with_capacity(title.len());
+    let mut last_was_hyphen = true; // suppresses a leading hyphen
+    for ch in title.chars() {
+        if ch.is_alphanumeric() {
+            slug.extend(ch.to_lowercase());
+            last_was_hyphen = false;
+        } else if !last_was_hyphen {
+            slug.push('-');
+            last_was_hyphen = true;
+        }
+    }
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+    if slug.is_empty() {
+        "section".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Scans `content` for headings without rendering it, producing the
+/// document's heading outline. Intended to run as a pre-pass so the
+/// resulting `Toc` can be printed before the body.
+pub fn collect_toc(content: &str, options: Options) -> Toc {
+    let mut builder = TocBuilder::new();
+    let mut current: Option<(u8, String)> = None;
+
+    for event in Parser::new_ext(content, options) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => current = Some((level as u8, String::new())),
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, title)) = current.take() {
+                    builder.push(level, title.trim());
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, title)) = current.as_mut() {
+                    title.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_nests_headings_by_level() {
+        let mut builder = TocBuilder::new();
+        builder.push(1, "Intro");
+        builder.push(2, "Setup");
+        builder.push(2, "Usage");
+        let toc = builder.finish();
+
+        assert_eq!(toc.entries.len(), 1);
+        assert_eq!(toc.entries[0].title, "Intro");
+        assert_eq!(toc.entries[0].children.len(), 2);
+        assert_eq!(toc.entries[0].children[0].title, "Setup");
+        assert_eq!(toc.entries[0].children[1].title, "Usage");
+    }
+
+    #[test]
+    fn test_skipped_level_nests_under_nearest_shallower_heading() {
+        let mut builder = TocBuilder::new();
+        builder.push(2, "Section");
+        builder.push(4, "Deeply nested");
+        let toc = builder.finish();
+
+        assert_eq!(toc.entries.len(), 1);
+        assert_eq!(toc.entries[0].children.len(), 1);
+        assert_eq!(toc.entries[0].children[0].title, "Deeply nested");
+    }
+
+    #[test]
+    fn test_derive_id_collapses_and_trims_non_alphanumeric_runs() {
+        let mut builder = TocBuilder::new();
+        assert_eq!(builder.derive_id("Hello, World!"), "hello-world");
+        assert_eq!(builder.derive_id("  --Leading/Trailing--  "), "leading-trailing");
+    }
+
+    #[test]
+    fn test_derive_id_disambiguates_collisions() {
+        let mut builder = TocBuilder::new();
+        assert_eq!(builder.derive_id("Overview"), "overview");
+        assert_eq!(builder.derive_id("Overview"), "overview-1");
+        assert_eq!(builder.derive_id("Overview"), "overview-2");
+    }
+
+    #[test]
+    fn test_derive_id_skips_a_suffix_claimed_by_an_unrelated_heading() {
+        let mut builder = TocBuilder::new();
+        assert_eq!(builder.derive_id("Overview"), "overview");
+        // A later, differently-worded heading that happens to slugify to
+        // exactly the suffix "Overview" would generate next claims it first.
+        assert_eq!(builder.derive_id("Overview-1"), "overview-1");
+        // So the next "Overview" must skip straight past it to "-2" rather
+        // than returning the already-claimed "overview-1".
+        assert_eq!(builder.derive_id("Overview"), "overview-2");
+    }
+
+    #[test]
+    fn test_collect_toc_scans_headings_without_rendering() {
+        let toc = collect_toc("# Title\n\ntext\n\n## Sub\n", Options::empty());
+        assert_eq!(toc.entries.len(), 1);
+        assert_eq!(toc.entries[0].title, "Title");
+        assert_eq!(toc.entries[0].id, "title");
+        assert_eq!(toc.entries[0].children[0].title, "Sub");
+    }
+}
@@ -0,0 +1,1543 @@
+use std::collections::HashMap;
+use std::env;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+pub trait MarkdownTheme: std::fmt::Debug {
+    fn heading_color(&self, level: u8) -> (u8, u8, u8);
+    fn strong_color(&self) -> (u8, u8, u8);
+    fn emphasis_color(&self) -> (u8, u8, u8);
+    fn link_color(&self) -> (u8, u8, u8);
+    fn code_color(&self) -> (u8, u8, u8);
+    fn code_background(&self) -> (u8, u8, u8);
+    fn list_marker_color(&self) -> (u8, u8, u8);
+    fn delimiter_color(&self) -> (u8, u8, u8);
+    fn text_color(&self) -> (u8, u8, u8);
+    fn cyan(&self) -> (u8, u8, u8);
+    /// Color for text that signals something positive or added, e.g. a
+    /// ` ```diff ` block's `+` lines.
+    fn success_color(&self) -> (u8, u8, u8);
+    /// Color for text that signals something wrong or removed, e.g. a
+    /// ` ```diff ` block's `-` lines.
+    fn error_color(&self) -> (u8, u8, u8);
+    /// Name of the bundled `syntect` theme used to highlight fenced code
+    /// blocks, looked up in `syntect::highlighting::ThemeSet::load_defaults()`'s
+    /// theme map. Lets the fenced-code-block palette follow the rest of the
+    /// theme instead of always using the same `syntect` theme.
+    fn syntax_theme_name(&self) -> &str;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SolarizedOsaka;
+
+impl SolarizedOsaka {
+    const BASE02: (u8, u8, u8) = (7, 54, 66);
+    const BASE01: (u8, u8, u8) = (88, 110, 117);
+    const BASE0: (u8, u8, u8) = (131, 148, 150);
+    const YELLOW: (u8, u8, u8) = (181, 137, 0);
+    const ORANGE: (u8, u8, u8) = (203, 75, 22);
+    const MAGENTA: (u8, u8, u8) = (211, 54, 130);
+    const BLUE: (u8, u8, u8) = (38, 139, 210);
+    const CYAN: (u8, u8, u8) = (42, 161, 152);
+    const GREEN: (u8, u8, u8) = (133, 153, 0);
+    const RED: (u8, u8, u8) = (220, 50, 47);
+}
+
+impl MarkdownTheme for SolarizedOsaka {
+    fn heading_color(&self, level: u8) -> (u8, u8, u8) {
+        match level {
+            1 => Self::BLUE,
+            2 => Self::GREEN,
+            3 => Self::CYAN,
+            4 => Self::YELLOW,
+            5 => Self::ORANGE,
+            _ => Self::MAGENTA,
+        }
+    }
+
+    fn strong_color(&self) -> (u8, u8, u8) {
+        Self::ORANGE
+    }
+
+    fn emphasis_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn link_color(&self) -> (u8, u8, u8) {
+        Self::CYAN
+    }
+
+    fn code_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn code_background(&self) -> (u8, u8, u8) {
+        Self::BASE02
+    }
+
+    fn list_marker_color(&self) -> (u8, u8, u8) {
+        Self::BLUE
+    }
+
+    fn delimiter_color(&self) -> (u8, u8, u8) {
+        Self::BASE01
+    }
+
+    fn text_color(&self) -> (u8, u8, u8) {
+        Self::BASE0
+    }
+
+    fn cyan(&self) -> (u8, u8, u8) {
+        Self::CYAN
+    }
+
+    fn success_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn error_color(&self) -> (u8, u8, u8) {
+        Self::RED
+    }
+
+    fn syntax_theme_name(&self) -> &str {
+        "Solarized (dark)"
+    }
+}
+
+/// The light variant of the Solarized palette: same accent colors as
+/// [`SolarizedOsaka`], but the base tones are inverted for a light
+/// terminal background instead of a dark one.
+#[derive(Debug, Clone, Copy)]
+pub struct SolarizedLight;
+
+impl SolarizedLight {
+    const BASE3: (u8, u8, u8) = (253, 246, 227);
+    const BASE2: (u8, u8, u8) = (238, 232, 213);
+    const BASE01: (u8, u8, u8) = (88, 110, 117);
+    const BASE00: (u8, u8, u8) = (101, 123, 131);
+    const YELLOW: (u8, u8, u8) = (181, 137, 0);
+    const ORANGE: (u8, u8, u8) = (203, 75, 22);
+    const MAGENTA: (u8, u8, u8) = (211, 54, 130);
+    const BLUE: (u8, u8, u8) = (38, 139, 210);
+    const CYAN: (u8, u8, u8) = (42, 161, 152);
+    const GREEN: (u8, u8, u8) = (133, 153, 0);
+    const RED: (u8, u8, u8) = (220, 50, 47);
+}
+
+impl MarkdownTheme for SolarizedLight {
+    fn heading_color(&self, level: u8) -> (u8, u8, u8) {
+        match level {
+            1 => Self::BLUE,
+            2 => Self::GREEN,
+            3 => Self::CYAN,
+            4 => Self::YELLOW,
+            5 => Self::ORANGE,
+            _ => Self::MAGENTA,
+        }
+    }
+
+    fn strong_color(&self) -> (u8, u8, u8) {
+        Self::ORANGE
+    }
+
+    fn emphasis_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn link_color(&self) -> (u8, u8, u8) {
+        Self::CYAN
+    }
+
+    fn code_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn code_background(&self) -> (u8, u8, u8) {
+        Self::BASE2
+    }
+
+    fn list_marker_color(&self) -> (u8, u8, u8) {
+        Self::BLUE
+    }
+
+    fn delimiter_color(&self) -> (u8, u8, u8) {
+        Self::BASE01
+    }
+
+    fn text_color(&self) -> (u8, u8, u8) {
+        Self::BASE00
+    }
+
+    fn cyan(&self) -> (u8, u8, u8) {
+        Self::CYAN
+    }
+
+    fn success_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn error_color(&self) -> (u8, u8, u8) {
+        Self::RED
+    }
+
+    fn syntax_theme_name(&self) -> &str {
+        "Solarized (light)"
+    }
+}
+
+/// A low-capability fallback using only the 16 colors a `TERM=xterm` (or
+/// similar) terminal is guaranteed to render faithfully, for terminals
+/// that don't support 24-bit truecolor escapes.
+#[derive(Debug, Clone, Copy)]
+pub struct Ansi16;
+
+impl Ansi16 {
+    const BLACK: (u8, u8, u8) = (0, 0, 0);
+    const RED: (u8, u8, u8) = (205, 0, 0);
+    const GREEN: (u8, u8, u8) = (0, 205, 0);
+    const YELLOW: (u8, u8, u8) = (205, 205, 0);
+    const BLUE: (u8, u8, u8) = (0, 0, 238);
+    const MAGENTA: (u8, u8, u8) = (205, 0, 205);
+    const CYAN: (u8, u8, u8) = (0, 205, 205);
+    const WHITE: (u8, u8, u8) = (229, 229, 229);
+}
+
+impl MarkdownTheme for Ansi16 {
+    fn heading_color(&self, level: u8) -> (u8, u8, u8) {
+        match level {
+            1 => Self::BLUE,
+            2 => Self::GREEN,
+            3 => Self::CYAN,
+            4 => Self::YELLOW,
+            5 => Self::RED,
+            _ => Self::MAGENTA,
+        }
+    }
+
+    fn strong_color(&self) -> (u8, u8, u8) {
+        Self::RED
+    }
+
+    fn emphasis_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn link_color(&self) -> (u8, u8, u8) {
+        Self::CYAN
+    }
+
+    fn code_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn code_background(&self) -> (u8, u8, u8) {
+        Self::BLACK
+    }
+
+    fn list_marker_color(&self) -> (u8, u8, u8) {
+        Self::BLUE
+    }
+
+    fn delimiter_color(&self) -> (u8, u8, u8) {
+        Self::WHITE
+    }
+
+    fn text_color(&self) -> (u8, u8, u8) {
+        Self::WHITE
+    }
+
+    fn cyan(&self) -> (u8, u8, u8) {
+        Self::CYAN
+    }
+
+    fn success_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn error_color(&self) -> (u8, u8, u8) {
+        Self::RED
+    }
+
+    fn syntax_theme_name(&self) -> &str {
+        "base16-ocean.dark"
+    }
+}
+
+/// No meaningful color at all: every foreground resolves to the same
+/// neutral gray and the code background stays black, so output still has
+/// the contrast of a code block without relying on color to distinguish
+/// elements. Selected automatically for non-TTY output or `NO_COLOR`.
+#[derive(Debug, Clone, Copy)]
+pub struct Monochrome;
+
+impl Monochrome {
+    const GRAY: (u8, u8, u8) = (192, 192, 192);
+    const BLACK: (u8, u8, u8) = (0, 0, 0);
+}
+
+impl MarkdownTheme for Monochrome {
+    fn heading_color(&self, _level: u8) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn strong_color(&self) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn emphasis_color(&self) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn link_color(&self) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn code_color(&self) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn code_background(&self) -> (u8, u8, u8) {
+        Self::BLACK
+    }
+
+    fn list_marker_color(&self) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn delimiter_color(&self) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn text_color(&self) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn cyan(&self) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn success_color(&self) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn error_color(&self) -> (u8, u8, u8) {
+        Self::GRAY
+    }
+
+    fn syntax_theme_name(&self) -> &str {
+        "InspiredGitHub"
+    }
+}
+
+/// An ayu-inspired dark palette, warmer and lower-contrast than
+/// [`SolarizedOsaka`].
+#[derive(Debug, Clone, Copy)]
+pub struct Ayu;
+
+impl Ayu {
+    const BACKGROUND: (u8, u8, u8) = (13, 16, 23);
+    const COMMENT: (u8, u8, u8) = (92, 103, 115);
+    const FOREGROUND: (u8, u8, u8) = (191, 186, 176);
+    const ORANGE: (u8, u8, u8) = (255, 143, 64);
+    const YELLOW: (u8, u8, u8) = (255, 209, 115);
+    const GREEN: (u8, u8, u8) = (170, 217, 76);
+    const BLUE: (u8, u8, u8) = (57, 186, 230);
+    const PURPLE: (u8, u8, u8) = (210, 166, 255);
+    const RED: (u8, u8, u8) = (240, 113, 120);
+    const CYAN: (u8, u8, u8) = (149, 230, 203);
+}
+
+impl MarkdownTheme for Ayu {
+    fn heading_color(&self, level: u8) -> (u8, u8, u8) {
+        match level {
+            1 => Self::ORANGE,
+            2 => Self::BLUE,
+            3 => Self::GREEN,
+            4 => Self::YELLOW,
+            5 => Self::PURPLE,
+            _ => Self::RED,
+        }
+    }
+
+    fn strong_color(&self) -> (u8, u8, u8) {
+        Self::ORANGE
+    }
+
+    fn emphasis_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn link_color(&self) -> (u8, u8, u8) {
+        Self::BLUE
+    }
+
+    fn code_color(&self) -> (u8, u8, u8) {
+        Self::CYAN
+    }
+
+    fn code_background(&self) -> (u8, u8, u8) {
+        Self::BACKGROUND
+    }
+
+    fn list_marker_color(&self) -> (u8, u8, u8) {
+        Self::ORANGE
+    }
+
+    fn delimiter_color(&self) -> (u8, u8, u8) {
+        Self::COMMENT
+    }
+
+    fn text_color(&self) -> (u8, u8, u8) {
+        Self::FOREGROUND
+    }
+
+    fn cyan(&self) -> (u8, u8, u8) {
+        Self::CYAN
+    }
+
+    fn success_color(&self) -> (u8, u8, u8) {
+        Self::GREEN
+    }
+
+    fn error_color(&self) -> (u8, u8, u8) {
+        Self::RED
+    }
+
+    fn syntax_theme_name(&self) -> &str {
+        "base16-eighties.dark"
+    }
+}
+
+/// The built-in themes a caller can select without implementing
+/// [`MarkdownTheme`] themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeKind {
+    SolarizedDark,
+    SolarizedLight,
+    Ayu,
+    Ansi16,
+    Monochrome,
+}
+
+impl ThemeKind {
+    pub fn build(self) -> Box<dyn MarkdownTheme> {
+        match self {
+            ThemeKind::SolarizedDark => Box::new(SolarizedOsaka),
+            ThemeKind::SolarizedLight => Box::new(SolarizedLight),
+            ThemeKind::Ayu => Box::new(Ayu),
+            ThemeKind::Ansi16 => Box::new(Ansi16),
+            ThemeKind::Monochrome => Box::new(Monochrome),
+        }
+    }
+
+    /// The name this theme is selected by in `--theme`/`MP_THEME` (see
+    /// [`by_name`]), lowercase and hyphenated to read naturally on the
+    /// command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemeKind::SolarizedDark => "solarized-dark",
+            ThemeKind::SolarizedLight => "solarized-light",
+            ThemeKind::Ayu => "ayu",
+            ThemeKind::Ansi16 => "ansi16",
+            ThemeKind::Monochrome => "monochrome",
+        }
+    }
+}
+
+/// Every built-in theme, in declaration order — the registry a caller
+/// walks to list `--theme` choices or resolve one by [`ThemeKind::name`].
+pub fn themes() -> impl Iterator<Item = ThemeKind> {
+    [
+        ThemeKind::SolarizedDark,
+        ThemeKind::SolarizedLight,
+        ThemeKind::Ayu,
+        ThemeKind::Ansi16,
+        ThemeKind::Monochrome,
+    ]
+    .into_iter()
+}
+
+/// Looks up a built-in theme by [`ThemeKind::name`], case-insensitively.
+/// Returns `None` for an unrecognized name so the caller (e.g. the
+/// binary's `--theme` handling) can report the valid choices instead of
+/// silently falling back to a default.
+pub fn by_name(name: &str) -> Option<Box<dyn MarkdownTheme>> {
+    themes()
+        .find(|kind| kind.name().eq_ignore_ascii_case(name))
+        .map(ThemeKind::build)
+}
+
+/// Picks a sensible default [`ThemeKind`] for standard output: `MP_THEME`
+/// if it names a known theme, otherwise [`ThemeKind::Monochrome`] when
+/// `NO_COLOR` is set or stdout isn't a TTY (e.g. the output is piped or
+/// redirected to a file), otherwise [`ThemeKind::SolarizedDark`]. This is
+/// what [`super::renderer::config::RenderConfig::default`] starts from.
+pub fn detect_theme_kind() -> ThemeKind {
+    if let Ok(name) = env::var("MP_THEME")
+        && let Some(kind) = themes().find(|kind| kind.name().eq_ignore_ascii_case(&name))
+    {
+        return kind;
+    }
+
+    if env::var_os("NO_COLOR").is_some() || !std::io::stdout().is_terminal() {
+        ThemeKind::Monochrome
+    } else {
+        ThemeKind::SolarizedDark
+    }
+}
+
+/// The [`Box<dyn MarkdownTheme>`] equivalent of [`detect_theme_kind`], for
+/// callers that want a built theme directly rather than a [`ThemeKind`] to
+/// plug into a `--theme`/`MP_THEME` override.
+pub fn detect_theme() -> Box<dyn MarkdownTheme> {
+    detect_theme_kind().build()
+}
+
+/// Resolves a `--theme`/`MP_THEME` argument that may name a built-in (see
+/// [`by_name`]) or point at a theme file (see [`Theme::from_path`]): tries
+/// the built-in lookup first, falling back to loading `name_or_path` as a
+/// file so the same flag works for either.
+pub fn resolve_theme(name_or_path: &str) -> Result<Box<dyn MarkdownTheme>> {
+    if let Some(theme) = by_name(name_or_path) {
+        return Ok(theme);
+    }
+    let theme = Theme::from_path(Path::new(name_or_path)).with_context(|| {
+        format!("'{name_or_path}' is not a known built-in theme or a loadable theme file")
+    })?;
+    Ok(Box::new(theme))
+}
+
+/// The conventional location for a user's default theme file:
+/// `$XDG_CONFIG_HOME/mp/theme.toml`, falling back to `$HOME/.config/mp/theme.toml`.
+/// `None` if neither `$XDG_CONFIG_HOME` nor `$HOME` is set. Consulted by
+/// the binary's `cli::run` when no `--theme`/`MP_THEME` override is given.
+pub fn standard_config_path() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("mp").join("theme.toml"))
+}
+
+/// How many colors the terminal we're writing to can display. Theme colors
+/// are always defined as 24-bit RGB, but weaker terminals and CI logs only
+/// understand a narrower palette; [`styled_text`]/[`styled_text_with_bg`]
+/// downsample to whichever of these is detected so the Solarized palette
+/// stays legible instead of emitting escapes the terminal can't interpret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// 24-bit RGB, emitted as-is (`ESC[38;2;r;g;bm`).
+    TrueColor,
+    /// The xterm 256-color palette: a 6x6x6 color cube plus a 24-step
+    /// grayscale ramp (`ESC[38;5;Nm`).
+    Ansi256,
+    /// The original 16-color ANSI palette (`ESC[30-37m`/`ESC[90-97m`).
+    Ansi16,
+}
+
+/// Detects how many colors the output terminal supports, from
+/// `$COLORTERM`/`$TERM`. Overridable with the `FORCE_COLOR` convention
+/// used by tools like chalk/supports-color (`FORCE_COLOR=3` truecolor, `2`
+/// 256-color, anything else enables the conservative 16-color fallback),
+/// mirroring [`detect_theme`]'s `NO_COLOR` override.
+pub fn detect_color_depth() -> ColorDepth {
+    if let Ok(forced) = env::var("FORCE_COLOR") {
+        return match forced.as_str() {
+            "3" => ColorDepth::TrueColor,
+            "2" => ColorDepth::Ansi256,
+            _ => ColorDepth::Ansi16,
+        };
+    }
+
+    let colorterm = env::var("COLORTERM").unwrap_or_default();
+    if colorterm.contains("truecolor") || colorterm.contains("24bit") {
+        return ColorDepth::TrueColor;
+    }
+
+    let term = env::var("TERM").unwrap_or_default();
+    if term.contains("256color") {
+        ColorDepth::Ansi256
+    } else {
+        ColorDepth::Ansi16
+    }
+}
+
+const ANSI256_CUBE_STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+fn euclidean_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = a.0 as i32 - b.0 as i32;
+    let dg = a.1 as i32 - b.1 as i32;
+    let db = a.2 as i32 - b.2 as i32;
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+fn nearest_cube_step(channel: u8) -> (u8, u8) {
+    ANSI256_CUBE_STEPS
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, &step)| (channel as i16 - step as i16).abs())
+        .map(|(index, &step)| (index as u8, step))
+        .expect("ANSI256_CUBE_STEPS is non-empty")
+}
+
+/// Maps `color` to the nearest xterm 256-color palette index: the 24-step
+/// grayscale ramp (`232..=255`) via `(avg-8)/10` in integer division when that's closer,
+/// otherwise the 6x6x6 color cube (`16 + 36*r + 6*g + b`), picking
+/// whichever candidate is nearer in Euclidean RGB distance.
+fn ansi256_index(color: (u8, u8, u8)) -> u8 {
+    let (r, g, b) = color;
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_level = ((average as i32 - 8) / 10).clamp(0, 23) as u8;
+    let gray_value = 8 + gray_level as u32 * 10;
+    let gray_rgb = (gray_value as u8, gray_value as u8, gray_value as u8);
+
+    let (ri, r_snap) = nearest_cube_step(r);
+    let (gi, g_snap) = nearest_cube_step(g);
+    let (bi, b_snap) = nearest_cube_step(b);
+    let cube_rgb = (r_snap, g_snap, b_snap);
+
+    if euclidean_distance(color, gray_rgb) <= euclidean_distance(color, cube_rgb) {
+        232 + gray_level
+    } else {
+        16 + 36 * ri + 6 * gi + bi
+    }
+}
+
+/// Finds the nearest color in the standard 16-color ANSI palette.
+fn ansi16_index(color: (u8, u8, u8)) -> u8 {
+    ANSI16_PALETTE
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, &candidate)| euclidean_distance(color, candidate))
+        .map(|(index, _)| index as u8)
+        .expect("ANSI16_PALETTE is non-empty")
+}
+
+/// Builds the SGR color parameter(s) for `color` at `depth`, targeting the
+/// foreground (`38;...`) or background (`48;...`) slot.
+fn ansi_color_code(color: (u8, u8, u8), depth: ColorDepth, is_background: bool) -> String {
+    let prefix = if is_background { 48 } else { 38 };
+    match depth {
+        ColorDepth::TrueColor => format!("{prefix};2;{};{};{}", color.0, color.1, color.2),
+        ColorDepth::Ansi256 => format!("{prefix};5;{}", ansi256_index(color)),
+        ColorDepth::Ansi16 => {
+            let index = ansi16_index(color);
+            let base = if is_background { 40 } else { 30 };
+            let bright_base = if is_background { 100 } else { 90 };
+            if index < 8 {
+                format!("{}", base + index)
+            } else {
+                format!("{}", bright_base + (index - 8))
+            }
+        }
+    }
+}
+
+/// Darkens `color` by `steps` applications of a fixed falloff, for content
+/// nested `steps` block quotes deep — each additional level reads as a
+/// step further from the page's normal text rather than identical color
+/// repeated behind more `│ ` markers.
+pub fn dim_color(color: (u8, u8, u8), steps: usize) -> (u8, u8, u8) {
+    const FALLOFF: f32 = 0.85;
+    let factor = FALLOFF.powi(steps as i32);
+    let dim = |channel: u8| (channel as f32 * factor).round() as u8;
+    (dim(color.0), dim(color.1), dim(color.2))
+}
+
+/// Styles `text` with `color` (downsampled to the detected
+/// [`ColorDepth`]) and the given emphasis flags, as a raw ANSI escape
+/// sequence.
+pub fn styled_text<S: AsRef<str>>(
+    text: S,
+    color: (u8, u8, u8),
+    bold: bool,
+    italic: bool,
+    underline: bool,
+) -> String {
+    styled_text_at_depth(text, color, bold, italic, underline, detect_color_depth())
+}
+
+/// Like [`styled_text`], but downsamples to an explicit [`ColorDepth`]
+/// rather than re-detecting it from the environment on every call — used by
+/// [`super::renderer::MarkdownRenderer`] so
+/// [`super::renderer::config::RenderConfig::color_depth`] (which may have
+/// been overridden from the auto-detected default) is actually honored.
+pub fn styled_text_at_depth<S: AsRef<str>>(
+    text: S,
+    color: (u8, u8, u8),
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    depth: ColorDepth,
+) -> String {
+    let mut codes = vec![ansi_color_code(color, depth, false)];
+    if bold {
+        codes.push("1".to_string());
+    }
+    if italic {
+        codes.push("3".to_string());
+    }
+    if underline {
+        codes.push("4".to_string());
+    }
+    format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text.as_ref())
+}
+
+/// Decoration flags beyond bold/italic, modeled on delta's
+/// `DecorationStyle`: a styled span can combine any subset of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Decorations {
+    pub underline: bool,
+    pub overline: bool,
+    pub strikethrough: bool,
+    pub boxed: bool,
+}
+
+/// Like [`styled_text`], but for spans that need decorations beyond
+/// bold/italic/underline — overline, strikethrough, and a "box"/framed
+/// border — combined freely on one span, as used by
+/// [`TextStyle::Custom`](crate::renderer::styling::TextStyle::Custom).
+pub fn styled_text_with_decorations<S: AsRef<str>>(
+    text: S,
+    color: (u8, u8, u8),
+    bold: bool,
+    decorations: Decorations,
+) -> String {
+    styled_text_with_decorations_at_depth(text, color, bold, decorations, detect_color_depth())
+}
+
+/// Like [`styled_text_with_decorations`], but downsamples to an explicit
+/// [`ColorDepth`] instead of re-detecting it; see [`styled_text_at_depth`].
+pub fn styled_text_with_decorations_at_depth<S: AsRef<str>>(
+    text: S,
+    color: (u8, u8, u8),
+    bold: bool,
+    decorations: Decorations,
+    depth: ColorDepth,
+) -> String {
+    let mut codes = vec![ansi_color_code(color, depth, false)];
+    if bold {
+        codes.push("1".to_string());
+    }
+    if decorations.underline {
+        codes.push("4".to_string());
+    }
+    if decorations.strikethrough {
+        codes.push("9".to_string());
+    }
+    if decorations.overline {
+        codes.push("53".to_string());
+    }
+    if decorations.boxed {
+        codes.push("51".to_string());
+    }
+    format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text.as_ref())
+}
+
+/// Like [`styled_text`], but also paints `bg` behind the text, downsampling
+/// both colors together to the detected [`ColorDepth`].
+pub fn styled_text_with_bg<S: AsRef<str>>(text: S, fg: (u8, u8, u8), bg: (u8, u8, u8)) -> String {
+    styled_text_with_bg_at_depth(text, fg, bg, detect_color_depth())
+}
+
+/// Like [`styled_text_with_bg`], but downsamples to an explicit
+/// [`ColorDepth`] instead of re-detecting it; see [`styled_text_at_depth`].
+pub fn styled_text_with_bg_at_depth<S: AsRef<str>>(
+    text: S,
+    fg: (u8, u8, u8),
+    bg: (u8, u8, u8),
+    depth: ColorDepth,
+) -> String {
+    let codes = format!(
+        "{};{}",
+        ansi_color_code(bg, depth, true),
+        ansi_color_code(fg, depth, false)
+    );
+    format!("\x1b[{codes}m{}\x1b[0m", text.as_ref())
+}
+
+/// The color and emphasis flags applied to a single semantic role (a
+/// heading level, code, a link, ...) within a [`Theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemeStyle {
+    pub color: (u8, u8, u8),
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+/// A color in a theme file: either a literal `[r, g, b]` triple, or the
+/// name of an entry in the file's `[colors]` palette table (see
+/// [`ThemeFile::colors`]), resolved against that palette at load time.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ColorValue {
+    Rgb((u8, u8, u8)),
+    Named(String),
+}
+
+impl ColorValue {
+    /// Resolves this value to a concrete RGB triple: `Rgb` is returned as
+    /// given, `Named` is looked up in `palette`. Returns `None` for a
+    /// `Named` value with no matching palette entry, so the caller can fall
+    /// back to the base theme's color rather than silently picking black.
+    fn resolve(&self, palette: &HashMap<String, (u8, u8, u8)>) -> Option<(u8, u8, u8)> {
+        match self {
+            ColorValue::Rgb(rgb) => Some(*rgb),
+            ColorValue::Named(name) => palette.get(name).copied(),
+        }
+    }
+}
+
+/// Parses a `#rrggbb` or `rrggbb` hex string into an RGB triple, as used by
+/// [`ThemeFile::colors`] palette entries.
+fn parse_hex_color(input: &str) -> Option<(u8, u8, u8)> {
+    let hex = input.strip_prefix('#').unwrap_or(input);
+    if hex.len() != 6 {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some((r, g, b))
+}
+
+/// A partial [`ThemeStyle`]: every field is optional, so a theme file only
+/// needs to list the properties it wants to change from the base theme.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ThemeStyleOverride {
+    pub color: Option<ColorValue>,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub underline: Option<bool>,
+}
+
+/// Applies `override_style` onto `base`: `color` is replaced outright when
+/// given (resolved against `palette` if it names a [`ColorValue::Named`]
+/// palette entry), while `bold`/`italic`/`underline` accumulate rather than
+/// overwrite, so a theme file that turns on `bold` for a role can't
+/// accidentally turn off an italic the base already set.
+pub fn merge_styles(
+    base: ThemeStyle,
+    override_style: &ThemeStyleOverride,
+    palette: &HashMap<String, (u8, u8, u8)>,
+) -> ThemeStyle {
+    ThemeStyle {
+        color: override_style
+            .color
+            .as_ref()
+            .and_then(|color| color.resolve(palette))
+            .unwrap_or(base.color),
+        bold: base.bold || override_style.bold.unwrap_or(false),
+        italic: base.italic || override_style.italic.unwrap_or(false),
+        underline: base.underline || override_style.underline.unwrap_or(false),
+    }
+}
+
+fn style(color: (u8, u8, u8), bold: bool, italic: bool) -> ThemeStyle {
+    ThemeStyle {
+        color,
+        bold,
+        italic,
+        underline: false,
+    }
+}
+
+/// A customizable, layered theme: a [`ThemeStyle`] per semantic role,
+/// built from one of the built-in palettes and optionally adjusted with
+/// per-role overrides loaded from a file via [`Theme::from_path`].
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub headings: [ThemeStyle; 6],
+    pub text: ThemeStyle,
+    pub strong: ThemeStyle,
+    pub emphasis: ThemeStyle,
+    pub code: ThemeStyle,
+    pub code_background: (u8, u8, u8),
+    pub link: ThemeStyle,
+    pub list_marker: ThemeStyle,
+    pub delimiter: ThemeStyle,
+    pub table_border: ThemeStyle,
+    pub cyan: (u8, u8, u8),
+    pub success: ThemeStyle,
+    pub error: ThemeStyle,
+    pub syntax_theme: String,
+}
+
+impl Theme {
+    /// The default dark theme, built from [`SolarizedOsaka`].
+    pub fn dark() -> Self {
+        Self::from_base(&SolarizedOsaka)
+    }
+
+    /// A light-background theme, built from [`SolarizedLight`].
+    pub fn light() -> Self {
+        Self::from_base(&SolarizedLight)
+    }
+
+    /// A warm, low-contrast dark theme, built from [`Ayu`].
+    pub fn ayu() -> Self {
+        Self::from_base(&Ayu)
+    }
+
+    fn from_base(base: &dyn MarkdownTheme) -> Self {
+        Theme {
+            headings: std::array::from_fn(|index| {
+                style(base.heading_color(index as u8 + 1), true, false)
+            }),
+            text: style(base.text_color(), false, false),
+            strong: style(base.strong_color(), true, false),
+            emphasis: style(base.emphasis_color(), false, true),
+            code: style(base.code_color(), false, false),
+            code_background: base.code_background(),
+            link: ThemeStyle {
+                color: base.link_color(),
+                bold: false,
+                italic: false,
+                underline: true,
+            },
+            list_marker: style(base.list_marker_color(), false, false),
+            delimiter: style(base.delimiter_color(), false, false),
+            table_border: style(base.delimiter_color(), false, false),
+            cyan: base.cyan(),
+            success: style(base.success_color(), false, false),
+            error: style(base.error_color(), false, false),
+            syntax_theme: base.syntax_theme_name().to_string(),
+        }
+    }
+
+    /// Loads a theme from a TOML or JSON file, chosen by its extension
+    /// (anything other than `.json` is parsed as TOML). The file names a
+    /// `base` built-in (`"dark"` (the default), `"light"`, or `"ayu"`),
+    /// optionally defines a `[colors]` table of named palette entries (see
+    /// [`ThemeFile::colors`]), and layers its per-role overrides on top of
+    /// the base with [`merge_styles`] — each override's color may be a
+    /// literal RGB triple or one of those palette names — so the file only
+    /// needs to list the roles it wants to change.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read theme file '{}'", path.display()))?;
+
+        let overrides: ThemeFile = if path.extension().and_then(|ext| ext.to_str()) == Some("json")
+        {
+            serde_json::from_str(&contents).with_context(|| {
+                format!("Failed to parse theme file '{}' as JSON", path.display())
+            })?
+        } else {
+            toml::from_str(&contents).with_context(|| {
+                format!("Failed to parse theme file '{}' as TOML", path.display())
+            })?
+        };
+
+        let palette: HashMap<String, (u8, u8, u8)> = overrides
+            .colors
+            .iter()
+            .flatten()
+            .filter_map(|(name, hex)| parse_hex_color(hex).map(|rgb| (name.clone(), rgb)))
+            .collect();
+
+        let mut theme = match overrides.base.as_deref() {
+            Some("light") => Theme::light(),
+            Some("ayu") => Theme::ayu(),
+            _ => Theme::dark(),
+        };
+
+        if let Some(headings) = &overrides.headings {
+            for (level, override_style) in headings.iter().enumerate().take(theme.headings.len()) {
+                theme.headings[level] = merge_styles(theme.headings[level], override_style, &palette);
+            }
+        }
+        if let Some(override_style) = &overrides.text {
+            theme.text = merge_styles(theme.text, override_style, &palette);
+        }
+        if let Some(override_style) = &overrides.strong {
+            theme.strong = merge_styles(theme.strong, override_style, &palette);
+        }
+        if let Some(override_style) = &overrides.emphasis {
+            theme.emphasis = merge_styles(theme.emphasis, override_style, &palette);
+        }
+        if let Some(override_style) = &overrides.code {
+            theme.code = merge_styles(theme.code, override_style, &palette);
+        }
+        if let Some(color) = overrides.code_background.as_ref().and_then(|c| c.resolve(&palette)) {
+            theme.code_background = color;
+        }
+        if let Some(override_style) = &overrides.link {
+            theme.link = merge_styles(theme.link, override_style, &palette);
+        }
+        if let Some(override_style) = &overrides.list_marker {
+            theme.list_marker = merge_styles(theme.list_marker, override_style, &palette);
+        }
+        if let Some(override_style) = &overrides.delimiter {
+            theme.delimiter = merge_styles(theme.delimiter, override_style, &palette);
+        }
+        if let Some(override_style) = &overrides.table_border {
+            theme.table_border = merge_styles(theme.table_border, override_style, &palette);
+        }
+        if let Some(override_style) = &overrides.success {
+            theme.success = merge_styles(theme.success, override_style, &palette);
+        }
+        if let Some(override_style) = &overrides.error {
+            theme.error = merge_styles(theme.error, override_style, &palette);
+        }
+        if let Some(syntax_theme) = overrides.syntax_theme {
+            theme.syntax_theme = syntax_theme;
+        }
+
+        Ok(theme)
+    }
+}
+
+impl MarkdownTheme for Theme {
+    fn heading_color(&self, level: u8) -> (u8, u8, u8) {
+        let index = level.saturating_sub(1).min(5) as usize;
+        self.headings[index].color
+    }
+
+    fn strong_color(&self) -> (u8, u8, u8) {
+        self.strong.color
+    }
+
+    fn emphasis_color(&self) -> (u8, u8, u8) {
+        self.emphasis.color
+    }
+
+    fn link_color(&self) -> (u8, u8, u8) {
+        self.link.color
+    }
+
+    fn code_color(&self) -> (u8, u8, u8) {
+        self.code.color
+    }
+
+    fn code_background(&self) -> (u8, u8, u8) {
+        self.code_background
+    }
+
+    fn list_marker_color(&self) -> (u8, u8, u8) {
+        self.list_marker.color
+    }
+
+    fn delimiter_color(&self) -> (u8, u8, u8) {
+        self.delimiter.color
+    }
+
+    fn text_color(&self) -> (u8, u8, u8) {
+        self.text.color
+    }
+
+    fn cyan(&self) -> (u8, u8, u8) {
+        self.cyan
+    }
+
+    fn success_color(&self) -> (u8, u8, u8) {
+        self.success.color
+    }
+
+    fn error_color(&self) -> (u8, u8, u8) {
+        self.error.color
+    }
+
+    fn syntax_theme_name(&self) -> &str {
+        &self.syntax_theme
+    }
+}
+
+/// The on-disk shape of a theme file: a named built-in `base`, an optional
+/// `[colors]` table naming palette entries by hex string (e.g.
+/// `base02 = "#073642"`) that per-role overrides can reference by name
+/// instead of repeating the same triple in several places, plus the
+/// per-role overrides themselves. Deserialized from either TOML or JSON.
+#[derive(Debug, Default, Deserialize)]
+struct ThemeFile {
+    base: Option<String>,
+    colors: Option<HashMap<String, String>>,
+    headings: Option<Vec<ThemeStyleOverride>>,
+    text: Option<ThemeStyleOverride>,
+    strong: Option<ThemeStyleOverride>,
+    emphasis: Option<ThemeStyleOverride>,
+    code: Option<ThemeStyleOverride>,
+    code_background: Option<ColorValue>,
+    link: Option<ThemeStyleOverride>,
+    list_marker: Option<ThemeStyleOverride>,
+    delimiter: Option<ThemeStyleOverride>,
+    table_border: Option<ThemeStyleOverride>,
+    success: Option<ThemeStyleOverride>,
+    error: Option<ThemeStyleOverride>,
+    syntax_theme: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solarized_osaka_theme_colors() {
+        let theme = SolarizedOsaka;
+
+        // Heading colors by level
+        assert_eq!(theme.heading_color(1), SolarizedOsaka::BLUE);
+        assert_eq!(theme.heading_color(2), SolarizedOsaka::GREEN);
+        assert_eq!(theme.heading_color(3), SolarizedOsaka::CYAN);
+        assert_eq!(theme.heading_color(4), SolarizedOsaka::YELLOW);
+        assert_eq!(theme.heading_color(5), SolarizedOsaka::ORANGE);
+        assert_eq!(theme.heading_color(6), SolarizedOsaka::MAGENTA);
+
+        // Text style colors
+        assert_eq!(theme.strong_color(), SolarizedOsaka::ORANGE);
+        assert_eq!(theme.emphasis_color(), SolarizedOsaka::GREEN);
+        assert_eq!(theme.link_color(), SolarizedOsaka::CYAN);
+        assert_eq!(theme.code_color(), SolarizedOsaka::GREEN);
+        assert_eq!(theme.code_background(), SolarizedOsaka::BASE02);
+
+        // UI element colors
+        assert_eq!(theme.list_marker_color(), SolarizedOsaka::BLUE);
+        assert_eq!(theme.delimiter_color(), SolarizedOsaka::BASE01);
+        assert_eq!(theme.text_color(), SolarizedOsaka::BASE0);
+        assert_eq!(theme.cyan(), SolarizedOsaka::CYAN);
+        assert_eq!(theme.success_color(), SolarizedOsaka::GREEN);
+        assert_eq!(theme.error_color(), SolarizedOsaka::RED);
+    }
+
+    #[test]
+    fn test_solarized_light_shares_accents_with_solarized_dark() {
+        let light = SolarizedLight;
+        let dark = SolarizedOsaka;
+        assert_eq!(light.heading_color(1), dark.heading_color(1));
+        assert_eq!(light.cyan(), dark.cyan());
+        assert_ne!(light.text_color(), dark.text_color());
+        assert_ne!(light.code_background(), dark.code_background());
+    }
+
+    #[test]
+    fn test_ansi16_theme_colors() {
+        let theme = Ansi16;
+        assert_eq!(theme.heading_color(1), Ansi16::BLUE);
+        assert_eq!(theme.strong_color(), Ansi16::RED);
+        assert_eq!(theme.code_background(), Ansi16::BLACK);
+    }
+
+    #[test]
+    fn test_monochrome_theme_has_no_palette_variety() {
+        let theme = Monochrome;
+        assert_eq!(theme.heading_color(1), theme.heading_color(6));
+        assert_eq!(theme.strong_color(), theme.text_color());
+        assert_ne!(theme.code_background(), theme.text_color());
+        assert_eq!(theme.success_color(), theme.error_color());
+    }
+
+    #[test]
+    fn test_theme_kind_builds_matching_theme() {
+        assert_eq!(
+            ThemeKind::SolarizedDark.build().heading_color(1),
+            SolarizedOsaka.heading_color(1)
+        );
+        assert_eq!(
+            ThemeKind::Monochrome.build().text_color(),
+            Monochrome.text_color()
+        );
+    }
+
+    #[test]
+    fn test_themes_lists_every_theme_kind_by_name() {
+        let names: Vec<_> = themes().map(ThemeKind::name).collect();
+        assert_eq!(
+            names,
+            vec!["solarized-dark", "solarized-light", "ayu", "ansi16", "monochrome"]
+        );
+    }
+
+    #[test]
+    fn test_by_name_is_case_insensitive_and_rejects_unknown_names() {
+        assert!(by_name("Solarized-Dark").unwrap().heading_color(1) == SolarizedOsaka.heading_color(1));
+        assert!(by_name("nonexistent").is_none());
+    }
+
+    #[test]
+    fn test_detect_theme_kind_honors_mp_theme_override() {
+        // SAFETY: test runs single-threaded within this process' env access.
+        unsafe {
+            std::env::set_var("MP_THEME", "ayu");
+        }
+        assert_eq!(detect_theme_kind(), ThemeKind::Ayu);
+        unsafe {
+            std::env::remove_var("MP_THEME");
+        }
+    }
+
+    #[test]
+    fn test_styled_text_functions() {
+        // Test basic styled_text with &str
+        let text = "test";
+        let result = styled_text(text, (255, 0, 0), true, false, false);
+        assert!(result.to_string().contains("test"));
+
+        // Test styled_text with String
+        let string = String::from("test");
+        let result = styled_text(string, (0, 255, 0), false, true, false);
+        assert!(result.to_string().contains("test"));
+
+        // Test styled_text_with_bg with &str
+        let result = styled_text_with_bg("test", (255, 255, 255), (0, 0, 0));
+        assert!(result.to_string().contains("test"));
+
+        // Test styled_text_with_bg with String
+        let string = String::from("test");
+        let result = styled_text_with_bg(string, (255, 255, 255), (0, 0, 0));
+        assert!(result.to_string().contains("test"));
+    }
+
+    #[test]
+    fn test_styled_text_at_depth_downsamples_to_the_given_depth_not_the_environment() {
+        let truecolor = styled_text_at_depth("x", (1, 2, 3), false, false, false, ColorDepth::TrueColor);
+        assert!(truecolor.contains(";2;1;2;3"));
+
+        let ansi16 = styled_text_at_depth("x", (1, 2, 3), false, false, false, ColorDepth::Ansi16);
+        assert!(!ansi16.contains(";2;1;2;3"));
+    }
+
+    #[test]
+    fn test_styled_text_with_bg_at_depth_downsamples_both_colors() {
+        let truecolor =
+            styled_text_with_bg_at_depth("x", (255, 255, 255), (0, 0, 0), ColorDepth::TrueColor);
+        assert!(truecolor.contains("38;2;255;255;255"));
+        assert!(truecolor.contains("48;2;0;0;0"));
+
+        let ansi16 =
+            styled_text_with_bg_at_depth("x", (255, 255, 255), (0, 0, 0), ColorDepth::Ansi16);
+        assert!(!ansi16.contains("38;2;"));
+    }
+
+    #[test]
+    fn test_styled_text_with_decorations_combines_requested_codes() {
+        let result = styled_text_with_decorations(
+            "test",
+            (255, 0, 0),
+            true,
+            Decorations {
+                underline: true,
+                overline: true,
+                strikethrough: true,
+                boxed: true,
+            },
+        );
+        assert!(result.contains("1"));
+        assert!(result.contains("4"));
+        assert!(result.contains("9"));
+        assert!(result.contains("53"));
+        assert!(result.contains("51"));
+        assert!(result.contains("test"));
+    }
+
+    #[test]
+    fn test_styled_text_with_decorations_omits_unset_codes() {
+        let result =
+            styled_text_with_decorations("test", (255, 0, 0), false, Decorations::default());
+        assert!(!result.contains(";1;"));
+        assert!(!result.contains(";9;"));
+        assert!(result.contains("test"));
+    }
+
+    #[test]
+    fn test_styled_text_with_decorations_at_depth_downsamples_to_the_given_depth() {
+        let truecolor = styled_text_with_decorations_at_depth(
+            "test",
+            (1, 2, 3),
+            false,
+            Decorations::default(),
+            ColorDepth::TrueColor,
+        );
+        assert!(truecolor.contains(";2;1;2;3"));
+
+        let ansi16 = styled_text_with_decorations_at_depth(
+            "test",
+            (1, 2, 3),
+            false,
+            Decorations::default(),
+            ColorDepth::Ansi16,
+        );
+        assert!(!ansi16.contains(";2;1;2;3"));
+    }
+
+    #[test]
+    fn test_dim_color_leaves_zero_depth_unchanged() {
+        assert_eq!(dim_color((200, 100, 50), 0), (200, 100, 50));
+    }
+
+    #[test]
+    fn test_dim_color_darkens_further_at_each_additional_step() {
+        let once = dim_color((200, 100, 50), 1);
+        let twice = dim_color((200, 100, 50), 2);
+        assert!(once.0 < 200 && once.1 < 100 && once.2 < 50);
+        assert!(twice.0 < once.0);
+    }
+
+    #[test]
+    fn test_ansi256_index_quantizes_a_pure_color_to_the_color_cube() {
+        assert_eq!(ansi256_index((255, 0, 0)), 196);
+        assert_eq!(ansi256_index((0, 0, 0)), 16);
+    }
+
+    #[test]
+    fn test_ansi256_index_prefers_the_grayscale_ramp_for_near_equal_channels() {
+        assert_eq!(ansi256_index((128, 128, 128)), 244);
+    }
+
+    #[test]
+    fn test_ansi16_index_finds_the_nearest_basic_palette_entry() {
+        assert_eq!(ansi16_index((0, 0, 0)), 0);
+        assert_eq!(ansi16_index((255, 255, 255)), 15);
+    }
+
+    #[test]
+    fn test_ansi_color_code_emits_truecolor_sgr_by_default() {
+        assert_eq!(
+            ansi_color_code((1, 2, 3), ColorDepth::TrueColor, false),
+            "38;2;1;2;3"
+        );
+        assert_eq!(
+            ansi_color_code((1, 2, 3), ColorDepth::TrueColor, true),
+            "48;2;1;2;3"
+        );
+    }
+
+    #[test]
+    fn test_ansi_color_code_emits_a_256_color_index() {
+        assert_eq!(
+            ansi_color_code((255, 0, 0), ColorDepth::Ansi256, false),
+            "38;5;196"
+        );
+    }
+
+    #[test]
+    fn test_ansi_color_code_emits_a_basic_16_color_code() {
+        assert_eq!(ansi_color_code((0, 0, 0), ColorDepth::Ansi16, false), "30");
+        assert_eq!(
+            ansi_color_code((255, 255, 255), ColorDepth::Ansi16, false),
+            "97"
+        );
+        assert_eq!(
+            ansi_color_code((255, 255, 255), ColorDepth::Ansi16, true),
+            "107"
+        );
+    }
+
+    #[test]
+    fn test_merge_styles_replaces_color_and_accumulates_flags() {
+        let base = ThemeStyle {
+            color: (1, 2, 3),
+            bold: true,
+            italic: false,
+            underline: false,
+        };
+        let override_style = ThemeStyleOverride {
+            color: Some(ColorValue::Rgb((4, 5, 6))),
+            italic: Some(true),
+            ..Default::default()
+        };
+
+        let merged = merge_styles(base, &override_style, &HashMap::new());
+        assert_eq!(merged.color, (4, 5, 6));
+        assert!(merged.bold, "base's bold flag should survive the merge");
+        assert!(merged.italic);
+        assert!(!merged.underline);
+    }
+
+    #[test]
+    fn test_merge_styles_with_no_overrides_keeps_base() {
+        let base = ThemeStyle {
+            color: (1, 2, 3),
+            bold: false,
+            italic: true,
+            underline: false,
+        };
+        let merged = merge_styles(base, &ThemeStyleOverride::default(), &HashMap::new());
+        assert_eq!(merged, base);
+    }
+
+    #[test]
+    fn test_merge_styles_resolves_a_named_palette_color() {
+        let base = ThemeStyle {
+            color: (1, 2, 3),
+            bold: false,
+            italic: false,
+            underline: false,
+        };
+        let override_style = ThemeStyleOverride {
+            color: Some(ColorValue::Named("accent".to_string())),
+            ..Default::default()
+        };
+        let palette = HashMap::from([("accent".to_string(), (7, 8, 9))]);
+
+        let merged = merge_styles(base, &override_style, &palette);
+        assert_eq!(merged.color, (7, 8, 9));
+    }
+
+    #[test]
+    fn test_merge_styles_falls_back_to_base_for_an_unknown_palette_name() {
+        let base = ThemeStyle {
+            color: (1, 2, 3),
+            bold: false,
+            italic: false,
+            underline: false,
+        };
+        let override_style = ThemeStyleOverride {
+            color: Some(ColorValue::Named("missing".to_string())),
+            ..Default::default()
+        };
+
+        let merged = merge_styles(base, &override_style, &HashMap::new());
+        assert_eq!(merged.color, base.color);
+    }
+
+    #[test]
+    fn test_theme_dark_matches_solarized_osaka() {
+        let theme = Theme::dark();
+        assert_eq!(theme.heading_color(1), SolarizedOsaka.heading_color(1));
+        assert_eq!(theme.code_background(), SolarizedOsaka.code_background());
+        assert_eq!(theme.syntax_theme_name(), SolarizedOsaka.syntax_theme_name());
+        assert!(theme.strong.bold);
+    }
+
+    #[test]
+    fn test_theme_ayu_has_its_own_accent_colors() {
+        let theme = Theme::ayu();
+        assert_eq!(theme.heading_color(1), Ayu.heading_color(1));
+        assert_ne!(theme.heading_color(1), SolarizedOsaka.heading_color(1));
+    }
+
+    #[test]
+    fn test_theme_from_path_layers_overrides_onto_named_base() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("markdown_preview_test_theme.toml");
+        std::fs::write(
+            &path,
+            r#"
+            base = "light"
+
+            [strong]
+            color = [9, 9, 9]
+            underline = true
+            "#,
+        )
+        .unwrap();
+
+        let theme = Theme::from_path(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(theme.strong.color, (9, 9, 9));
+        assert!(theme.strong.bold, "light base's bold flag should survive");
+        assert!(theme.strong.underline);
+        assert_eq!(theme.text_color(), SolarizedLight.text_color());
+    }
+
+    #[test]
+    fn test_theme_from_path_resolves_overrides_against_a_colors_table() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("markdown_preview_test_theme_palette.toml");
+        std::fs::write(
+            &path,
+            r##"
+            base = "light"
+            code_background = "accent"
+
+            [colors]
+            accent = "#0a141e"
+
+            [strong]
+            color = "accent"
+            "##,
+        )
+        .unwrap();
+
+        let theme = Theme::from_path(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(theme.strong.color, (10, 20, 30));
+        assert_eq!(theme.code_background(), (10, 20, 30));
+    }
+
+    #[test]
+    fn test_theme_from_path_overrides_syntax_theme() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("markdown_preview_test_theme_syntax.toml");
+        std::fs::write(
+            &path,
+            r#"
+            base = "ayu"
+            syntax_theme = "base16-mocha.dark"
+            "#,
+        )
+        .unwrap();
+
+        let theme = Theme::from_path(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(theme.syntax_theme_name(), "base16-mocha.dark");
+    }
+
+    #[test]
+    fn test_theme_from_path_rejects_unreadable_file() {
+        let path = Path::new("/nonexistent/markdown_preview_test_theme.toml");
+        assert!(Theme::from_path(path).is_err());
+    }
+
+    #[test]
+    fn test_resolve_theme_prefers_a_built_in_name() {
+        let theme = resolve_theme("ayu").unwrap();
+        assert_eq!(theme.heading_color(1), Ayu.heading_color(1));
+    }
+
+    #[test]
+    fn test_resolve_theme_falls_back_to_a_file_path() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("markdown_preview_test_resolve_theme.toml");
+        std::fs::write(&path, "base = \"light\"\n").unwrap();
+
+        let theme = resolve_theme(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(theme.text_color(), SolarizedLight.text_color());
+    }
+
+    #[test]
+    fn test_resolve_theme_rejects_an_unknown_name_and_nonexistent_path() {
+        assert!(resolve_theme("not-a-real-theme").is_err());
+    }
+
+    #[test]
+    fn test_standard_config_path_is_rooted_under_xdg_config_home() {
+        // SAFETY: test runs single-threaded within this process' env access.
+        unsafe {
+            std::env::set_var("XDG_CONFIG_HOME", "/tmp/mp-xdg-test");
+        }
+        assert_eq!(
+            standard_config_path(),
+            Some(PathBuf::from("/tmp/mp-xdg-test/mp/theme.toml"))
+        );
+        unsafe {
+            std::env::remove_var("XDG_CONFIG_HOME");
+        }
+    }
+}